@@ -120,6 +120,19 @@ impl Bitmap {
         self.number_of_bits
     }
 
+    ///
+    /// # Description
+    ///
+    /// Returns the number of bits that are set in the bitmap.
+    ///
+    /// # Returns
+    ///
+    /// The number of bits that are set in the bitmap.
+    ///
+    pub fn usage(&self) -> usize {
+        self.usage
+    }
+
     ///
     /// # Description
     ///
@@ -15,6 +15,10 @@ mod test;
 use ::alloc::alloc;
 use ::core::{
     alloc::Layout,
+    mem::{
+        self,
+        MaybeUninit,
+    },
     ops::{
         Deref,
         DerefMut,
@@ -42,6 +46,12 @@ enum RawArrayStorage<T> {
     Managed { ptr: ptr::NonNull<T>, len: usize },
     /// A storage area that is not managed by [alloc::GlobalAlloc].
     Unmanaged { ptr: ptr::NonNull<T>, len: usize },
+    /// A storage area that is released by a caller-supplied destructor.
+    Custom {
+        ptr: ptr::NonNull<T>,
+        len: usize,
+        dtor: fn(*mut T, usize),
+    },
 }
 
 impl<T> RawArrayStorage<T> {
@@ -60,6 +70,50 @@ impl<T> RawArrayStorage<T> {
     /// On failure, an error is returned instead.
     ///
     fn new_managed(len: usize) -> Result<RawArrayStorage<T>, Error> {
+        let ptr: ptr::NonNull<T> = Self::allocate(len)?;
+
+        // Initialize the backing storage.
+        // Safety: The memory region is valid and the length is valid.
+        unsafe { ptr::write_bytes(ptr.as_ptr(), 0, len) };
+
+        Ok(RawArrayStorage::Managed { ptr, len })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Constructs backing storage for a raw array, without initializing it.
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: Length of the backing storage.
+    ///
+    /// # Returns
+    ///
+    /// On success, the uninitialized backing storage is returned. On failure, an error is returned
+    /// instead.
+    ///
+    fn new_managed_uninit(len: usize) -> Result<RawArrayStorage<T>, Error> {
+        let ptr: ptr::NonNull<T> = Self::allocate(len)?;
+
+        Ok(RawArrayStorage::Managed { ptr, len })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates memory for the backing storage of a raw array.
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: Length of the backing storage.
+    ///
+    /// # Returns
+    ///
+    /// On success, a pointer to the uninitialized memory is returned. On failure, an error is
+    /// returned instead.
+    ///
+    fn allocate(len: usize) -> Result<ptr::NonNull<T>, Error> {
         // Check if the length is invalid.
         if len == 0 || len >= i32::MAX as usize {
             return Err(Error::new(ErrorCode::InvalidArgument, "invalid length"));
@@ -80,11 +134,7 @@ impl<T> RawArrayStorage<T> {
             }
         };
 
-        // Initialize the backing storage.
-        // Safety: The memory region is valid and the length is valid.
-        unsafe { ptr::write_bytes(ptr.as_ptr(), 0, len) };
-
-        Ok(RawArrayStorage::Managed { ptr, len })
+        Ok(ptr)
     }
 
     ///
@@ -133,6 +183,23 @@ impl<T> RawArrayStorage<T> {
         Ok(RawArrayStorage::Unmanaged { ptr, len })
     }
 
+    ///
+    /// # Description
+    ///
+    /// Gets the length of the backing storage.
+    ///
+    /// # Returns
+    ///
+    /// The length of the backing storage.
+    ///
+    fn len(&self) -> usize {
+        match self {
+            RawArrayStorage::Managed { len, .. }
+            | RawArrayStorage::Unmanaged { len, .. }
+            | RawArrayStorage::Custom { len, .. } => *len,
+        }
+    }
+
     ///
     /// # Description
     ///
@@ -150,6 +217,9 @@ impl<T> RawArrayStorage<T> {
             RawArrayStorage::Unmanaged { ptr, len } => unsafe {
                 slice::from_raw_parts_mut(ptr.as_ptr(), *len)
             },
+            RawArrayStorage::Custom { ptr, len, .. } => unsafe {
+                slice::from_raw_parts_mut(ptr.as_ptr(), *len)
+            },
         }
     }
 
@@ -170,10 +240,45 @@ impl<T> RawArrayStorage<T> {
             RawArrayStorage::Unmanaged { ptr, len } => unsafe {
                 slice::from_raw_parts(ptr.as_ptr(), *len)
             },
+            RawArrayStorage::Custom { ptr, len, .. } => unsafe {
+                slice::from_raw_parts(ptr.as_ptr(), *len)
+            },
         }
     }
 }
 
+//==================================================================================================
+// Plain-Old-Data Types
+//==================================================================================================
+
+mod sealed {
+    /// Prevents [`super::Pod`] from being implemented outside of this module.
+    pub trait Sealed {}
+}
+
+///
+/// # Description
+///
+/// A trait for plain-old-data types, which have no padding bytes and for which any bit pattern is
+/// a valid value. This trait is sealed and it is implemented for primitive integer types and
+/// arrays of them only.
+///
+pub trait Pod: Copy + sealed::Sealed {}
+
+macro_rules! impl_pod {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+            impl Pod for $t {}
+        )*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: Pod, const N: usize> sealed::Sealed for [T; N] {}
+impl<T: Pod, const N: usize> Pod for [T; N] {}
+
 //==================================================================================================
 // Raw Array
 //==================================================================================================
@@ -210,6 +315,26 @@ impl<T> RawArray<T> {
         })
     }
 
+    ///
+    /// # Description
+    ///
+    /// Constructs a new managed array, without initializing its elements.
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: Length of the array.
+    ///
+    /// # Returns
+    ///
+    /// On success, the new managed array is returned, with all elements uninitialized.
+    /// On failure, an error is returned instead.
+    ///
+    pub fn new_uninit(len: usize) -> Result<RawArray<MaybeUninit<T>>, Error> {
+        Ok(RawArray {
+            storage: RawArrayStorage::new_managed_uninit(len)?,
+        })
+    }
+
     ///
     /// # Description
     ///
@@ -238,6 +363,616 @@ impl<T> RawArray<T> {
             storage: RawArrayStorage::new_unmanaged(ptr, len)?,
         })
     }
+
+    ///
+    /// # Description
+    ///
+    /// Constructs a new array whose backing storage is released by a caller-supplied destructor
+    /// when the array is dropped.
+    ///
+    /// # Parameters
+    ///
+    /// - `ptr`: Pointer to the backing storage.
+    /// - `len`: Length of the backing storage.
+    /// - `dtor`: Destructor that releases the backing storage. It receives `ptr` and `len`.
+    ///
+    /// # Returns
+    ///
+    /// On success, the new array is returned, with all bits set to zero.
+    /// On failure, an error is returned instead and `dtor` is not called.
+    ///
+    /// # Safety
+    ///
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// - `ptr` must be valid for both reads and writes for `len * mem::size_of::<T>()` many bytes.
+    /// - `ptr` must be properly aligned.
+    /// - `ptr` must point to len consecutive properly initialized values of type `T``.
+    /// - `ptr` must remain valid until `dtor` is called.
+    ///
+    pub unsafe fn from_raw_parts_with_dtor(
+        ptr: *mut T,
+        len: usize,
+        dtor: fn(*mut T, usize),
+    ) -> Result<RawArray<T>, Error> {
+        let storage: RawArrayStorage<T> = match RawArrayStorage::new_unmanaged(ptr, len)? {
+            RawArrayStorage::Unmanaged { ptr, len } => RawArrayStorage::Custom { ptr, len, dtor },
+            storage => storage,
+        };
+
+        Ok(RawArray { storage })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the length of the target array.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the target array.
+    ///
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks whether the target array is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target array has no elements and `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.storage.len() == 0
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Resizes the target array, preserving existing elements. Elements that do not fit in the
+    /// new length are discarded and newly added elements are set to zero.
+    ///
+    /// # Parameters
+    ///
+    /// - `new_len`: New length of the array.
+    ///
+    /// # Returns
+    ///
+    /// On success, empty is returned. On failure, an error is returned instead and the target
+    /// array is left untouched.
+    ///
+    pub fn resize(&mut self, new_len: usize) -> Result<(), Error> {
+        // Check if the backing storage is owned by the array.
+        let (old_ptr, old_len): (ptr::NonNull<T>, usize) = match self.storage {
+            RawArrayStorage::Managed { ptr, len } => (ptr, len),
+            RawArrayStorage::Unmanaged { .. } | RawArrayStorage::Custom { .. } => {
+                return Err(Error::new(
+                    ErrorCode::OperationNotSupported,
+                    "cannot resize unmanaged storage",
+                ));
+            },
+        };
+
+        // Allocate the new backing storage, with all bits set to zero.
+        let mut storage: RawArrayStorage<T> = RawArrayStorage::new_managed(new_len)?;
+
+        // Copy existing elements.
+        // Safety: both storage areas are valid for `min(old_len, new_len)` elements and they do
+        // not overlap.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                old_ptr.as_ptr(),
+                storage.get_mut().as_mut_ptr(),
+                old_len.min(new_len),
+            )
+        };
+
+        // Release the old backing storage.
+        drop(RawArray {
+            storage: mem::replace(&mut self.storage, storage),
+        });
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Constructs a new managed array by applying a fallible function to each element of the
+    /// target array. If the function fails for some element, elements that were already
+    /// constructed are dropped and the error is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: Function to apply to each element.
+    ///
+    /// # Returns
+    ///
+    /// On success, the new managed array is returned. On failure, an error is returned instead.
+    ///
+    pub fn try_map<U, F>(&self, mut f: F) -> Result<RawArray<U>, Error>
+    where
+        F: FnMut(&T) -> Result<U, Error>,
+    {
+        let mut array: RawArray<U> = RawArray::new(self.len())?;
+
+        for (i, elem) in self.iter().enumerate() {
+            match f(elem) {
+                // Safety: the target slot lies within the array and holds no value.
+                Ok(value) => unsafe { ptr::write(array.as_mut_ptr().add(i), value) },
+                Err(e) => {
+                    // Drop elements that were already constructed.
+                    // Safety: the first `i` slots of the array hold initialized values.
+                    unsafe {
+                        ptr::drop_in_place(ptr::slice_from_raw_parts_mut(array.as_mut_ptr(), i))
+                    };
+                    return Err(e);
+                },
+            }
+        }
+
+        Ok(array)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Removes consecutive elements in the in-use prefix of the target array for which `same`
+    /// returns `true`, keeping only the first element of each run. The in-use prefix is compacted,
+    /// removed elements are dropped, and slots past the new in-use length are set to zero.
+    ///
+    /// # Parameters
+    ///
+    /// - `len_in_use`: Number of elements in use, at the beginning of the array. Upon return, it
+    ///   holds the number of elements that remain in use.
+    /// - `same`: Function that asserts whether an element (first argument) is the same as the
+    ///   previous element that was kept (second argument).
+    ///
+    pub fn dedup_by<F>(&mut self, len_in_use: &mut usize, mut same: F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let len: usize = (*len_in_use).min(self.len());
+        if len <= 1 {
+            *len_in_use = len;
+            return;
+        }
+
+        let base: *mut T = self.as_mut_ptr();
+        let mut write: usize = 1;
+        for read in 1..len {
+            // Safety: both indexes lie within the in-use prefix of the array.
+            unsafe {
+                let current: *mut T = base.add(read);
+                if same(&*current, &*base.add(write - 1)) {
+                    ptr::drop_in_place(current);
+                } else {
+                    if read != write {
+                        ptr::copy_nonoverlapping(current, base.add(write), 1);
+                    }
+                    write += 1;
+                }
+            }
+        }
+
+        // Clear slots that are no longer in use.
+        // Safety: the memory region lies within the array.
+        unsafe { ptr::write_bytes(base.add(write), 0, len - write) };
+
+        *len_in_use = write;
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Retains only the elements in the in-use prefix of the target array for which `keep` returns
+    /// `true`. The in-use prefix is compacted preserving the relative order of kept elements,
+    /// removed elements are dropped, and slots past the new in-use length are set to zero.
+    ///
+    /// # Parameters
+    ///
+    /// - `len_in_use`: Number of elements in use, at the beginning of the array. Upon return, it
+    ///   holds the number of elements that remain in use.
+    /// - `keep`: Function that asserts whether an element should be kept.
+    ///
+    pub fn retain<F>(&mut self, len_in_use: &mut usize, mut keep: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len: usize = (*len_in_use).min(self.len());
+
+        let base: *mut T = self.as_mut_ptr();
+        let mut write: usize = 0;
+        for read in 0..len {
+            // Safety: both indexes lie within the in-use prefix of the array.
+            unsafe {
+                let current: *mut T = base.add(read);
+                if keep(&*current) {
+                    if read != write {
+                        ptr::copy_nonoverlapping(current, base.add(write), 1);
+                    }
+                    write += 1;
+                } else {
+                    ptr::drop_in_place(current);
+                }
+            }
+        }
+
+        // Clear slots that are no longer in use.
+        // Safety: the memory region lies within the array.
+        unsafe { ptr::write_bytes(base.add(write), 0, len - write) };
+
+        *len_in_use = write;
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Splits the target array into windows of `chunk` elements and applies a function to each
+    /// window. If the length of the array is not a multiple of `chunk`, the last window is
+    /// shorter.
+    ///
+    /// # Parameters
+    ///
+    /// - `chunk`: Number of elements in each window.
+    /// - `f`: Function to apply to each window. It receives the index of the window and the window
+    ///   itself.
+    ///
+    /// # Returns
+    ///
+    /// On success, empty is returned. On failure, an error is returned instead.
+    ///
+    pub fn chunks_mut_indexed<F>(&mut self, chunk: usize, mut f: F) -> Result<(), Error>
+    where
+        F: FnMut(usize, &mut [T]),
+    {
+        if chunk == 0 {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid chunk size"));
+        }
+
+        for (i, window) in self.chunks_mut(chunk).enumerate() {
+            f(i, window);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Iterates over all elements of the target array exactly once, starting at a given index and
+    /// wrapping around at the end of the array.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Index of the first element to visit. It is taken modulo the length of the array.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over pairs of indices and elements.
+    ///
+    pub fn iter_cycle_from(&self, start: usize) -> impl Iterator<Item = (usize, &T)> {
+        let start: usize = if self.is_empty() {
+            0
+        } else {
+            start % self.len()
+        };
+        self.iter()
+            .enumerate()
+            .skip(start)
+            .chain(self.iter().enumerate().take(start))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Extracts a sub-slice of the target array, without panicking on invalid bounds.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Index of the first element of the sub-slice.
+    /// - `end`: Index past the last element of the sub-slice.
+    ///
+    /// # Returns
+    ///
+    /// On success, the sub-slice is returned. On failure, an error is returned instead.
+    ///
+    pub fn slice(&self, start: usize, end: usize) -> Result<&[T], Error> {
+        self.check_range(start, end)?;
+        Ok(&self[start..end])
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Extracts a mutable sub-slice of the target array, without panicking on invalid bounds.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Index of the first element of the sub-slice.
+    /// - `end`: Index past the last element of the sub-slice.
+    ///
+    /// # Returns
+    ///
+    /// On success, the mutable sub-slice is returned. On failure, an error is returned instead.
+    ///
+    pub fn slice_mut(&mut self, start: usize, end: usize) -> Result<&mut [T], Error> {
+        self.check_range(start, end)?;
+        Ok(&mut self[start..end])
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if a range of indices lies within the target array.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Index of the first element of the range.
+    /// - `end`: Index past the last element of the range.
+    ///
+    /// # Returns
+    ///
+    /// On success, empty is returned. On failure, an error is returned instead.
+    ///
+    fn check_range(&self, start: usize, end: usize) -> Result<(), Error> {
+        if start > end {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid range"));
+        }
+
+        if end > self.len() {
+            return Err(Error::new(ErrorCode::InvalidArgument, "range out of bounds"));
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Copy> RawArray<T> {
+    ///
+    /// # Description
+    ///
+    /// Constructs a new managed array that holds a copy of a slice.
+    ///
+    /// # Parameters
+    ///
+    /// - `src`: Slice to copy.
+    ///
+    /// # Returns
+    ///
+    /// On success, the new managed array is returned. On failure, an error is returned instead.
+    ///
+    pub fn from_slice(src: &[T]) -> Result<RawArray<T>, Error> {
+        let mut array: RawArray<MaybeUninit<T>> = RawArray::new_uninit(src.len())?;
+
+        // Safety: both memory regions are valid for `src.len()` elements and they do not overlap.
+        unsafe { ptr::copy_nonoverlapping(src.as_ptr(), array.as_mut_ptr() as *mut T, src.len()) };
+
+        // Safety: all elements were written.
+        Ok(unsafe { array.assume_init() })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Clones the target array into a new managed array. If the target array is unmanaged, the
+    /// clone is detached from the memory that it refers to.
+    ///
+    /// # Returns
+    ///
+    /// On success, the new managed array is returned. On failure, an error is returned instead.
+    ///
+    pub fn try_clone(&self) -> Result<RawArray<T>, Error> {
+        RawArray::from_slice(self)
+    }
+}
+
+impl<T: Pod> RawArray<T> {
+    ///
+    /// # Description
+    ///
+    /// Iterates over elements of the target array that are not all-bits-zero, which is the state
+    /// that the array was initialized with.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over pairs of indices and elements.
+    ///
+    pub fn iter_nonzero(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.iter().enumerate().filter(|(_, elem)| {
+            // Safety: the element is valid for `size_of::<T>()` bytes, which are all initialized
+            // because `T` has no padding.
+            let bytes: &[u8] = unsafe {
+                slice::from_raw_parts(*elem as *const T as *const u8, mem::size_of::<T>())
+            };
+            bytes.iter().any(|byte| *byte != 0)
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets a view of the target array as a slice of bytes.
+    ///
+    /// # Returns
+    ///
+    /// A slice that spans `len * size_of::<T>()` bytes of the backing storage.
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        let data: &[T] = self.storage.get();
+        // Safety: the backing storage is valid for `len * size_of::<T>()` bytes, which are all
+        // initialized because `T` has no padding.
+        unsafe { slice::from_raw_parts(data.as_ptr() as *const u8, mem::size_of_val(data)) }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets a mutable view of the target array as a slice of bytes.
+    ///
+    /// # Returns
+    ///
+    /// A mutable slice that spans `len * size_of::<T>()` bytes of the backing storage.
+    ///
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        let data: &mut [T] = self.storage.get_mut();
+        let len: usize = mem::size_of_val(data);
+        // Safety: the backing storage is valid for `len * size_of::<T>()` bytes, and any bit
+        // pattern written to them is a valid `T`.
+        unsafe { slice::from_raw_parts_mut(data.as_mut_ptr() as *mut u8, len) }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the number of bits in the target array, when it is treated as a bit array.
+    ///
+    /// # Returns
+    ///
+    /// The number of bits in the target array.
+    ///
+    pub fn number_of_bits(&self) -> usize {
+        self.as_bytes().len() * u8::BITS as usize
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Finds the first bit that is clear in the target array, when it is treated as a bit array.
+    ///
+    /// # Returns
+    ///
+    /// The index of the first clear bit, or `None` if all bits are set.
+    ///
+    pub fn find_first_clear_bit(&self) -> Option<usize> {
+        self.as_bytes()
+            .iter()
+            .position(|byte| *byte != u8::MAX)
+            .map(|i| i * u8::BITS as usize + self.as_bytes()[i].trailing_ones() as usize)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sets a bit in the target array, when it is treated as a bit array.
+    ///
+    /// # Parameters
+    ///
+    /// - `idx`: Index of the bit.
+    ///
+    /// # Returns
+    ///
+    /// On success, empty is returned. On failure, an error is returned instead.
+    ///
+    pub fn set_bit(&mut self, idx: usize) -> Result<(), Error> {
+        let (byte, mask): (usize, u8) = self.bit_position(idx)?;
+        self.as_bytes_mut()[byte] |= mask;
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Clears a bit in the target array, when it is treated as a bit array.
+    ///
+    /// # Parameters
+    ///
+    /// - `idx`: Index of the bit.
+    ///
+    /// # Returns
+    ///
+    /// On success, empty is returned. On failure, an error is returned instead.
+    ///
+    pub fn clear_bit(&mut self, idx: usize) -> Result<(), Error> {
+        let (byte, mask): (usize, u8) = self.bit_position(idx)?;
+        self.as_bytes_mut()[byte] &= !mask;
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Tests a bit in the target array, when it is treated as a bit array.
+    ///
+    /// # Parameters
+    ///
+    /// - `idx`: Index of the bit.
+    ///
+    /// # Returns
+    ///
+    /// On success, `true` is returned if the bit is set and `false` otherwise. On failure, an error
+    /// is returned instead.
+    ///
+    pub fn test_bit(&self, idx: usize) -> Result<bool, Error> {
+        let (byte, mask): (usize, u8) = self.bit_position(idx)?;
+        Ok(self.as_bytes()[byte] & mask != 0)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Locates a bit in the backing storage of the target array.
+    ///
+    /// # Parameters
+    ///
+    /// - `idx`: Index of the bit.
+    ///
+    /// # Returns
+    ///
+    /// On success, the index of the byte that holds the bit and a mask that selects the bit within
+    /// that byte are returned. On failure, an error is returned instead.
+    ///
+    fn bit_position(&self, idx: usize) -> Result<(usize, u8), Error> {
+        if idx >= self.number_of_bits() {
+            return Err(Error::new(ErrorCode::InvalidArgument, "bit index out of bounds"));
+        }
+
+        Ok((idx / u8::BITS as usize, 1 << (idx % u8::BITS as usize)))
+    }
+}
+
+impl<T> RawArray<MaybeUninit<T>> {
+    ///
+    /// # Description
+    ///
+    /// Converts the target array into an array of initialized elements.
+    ///
+    /// # Returns
+    ///
+    /// The array of initialized elements, which takes over the backing storage of the target array.
+    ///
+    /// # Safety
+    ///
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// - All elements of the target array must have been written.
+    ///
+    pub unsafe fn assume_init(self) -> RawArray<T> {
+        // Prevent the backing storage from being released.
+        let this: mem::ManuallyDrop<Self> = mem::ManuallyDrop::new(self);
+
+        // `MaybeUninit<T>` has the same layout as `T`.
+        let storage: RawArrayStorage<T> = match this.storage {
+            RawArrayStorage::Managed { ptr, len } => RawArrayStorage::Managed {
+                ptr: ptr.cast(),
+                len,
+            },
+            RawArrayStorage::Unmanaged { ptr, len } => RawArrayStorage::Unmanaged {
+                ptr: ptr.cast(),
+                len,
+            },
+            RawArrayStorage::Custom { ptr, len, dtor } => RawArrayStorage::Custom {
+                ptr: ptr.cast(),
+                len,
+                dtor: mem::transmute::<fn(*mut MaybeUninit<T>, usize), fn(*mut T, usize)>(dtor),
+            },
+        };
+
+        RawArray { storage }
+    }
 }
 
 impl<T> Deref for RawArray<T> {
@@ -267,6 +1002,7 @@ impl<T> Drop for RawArray<T> {
                 }
             },
             RawArrayStorage::Unmanaged { .. } => (),
+            RawArrayStorage::Custom { ptr, len, dtor } => dtor(ptr.as_ptr(), *len),
         }
     }
 }
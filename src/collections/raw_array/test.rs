@@ -9,7 +9,16 @@ use crate::{
     collections::raw_array::RawArray,
     error::ErrorCode,
 };
-use ::core::ptr;
+use ::alloc::rc::Rc;
+use ::core::{
+    mem::MaybeUninit,
+    ptr,
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+};
+use ::sys::error::Error;
 
 //==================================================================================================
 // Unit Tests
@@ -75,3 +84,652 @@ fn test_from_raw_parts_wrapping_memory_region() {
         Err(e) => panic!("unexpected error code (error={:?})", e),
     }
 }
+
+/// Attempts to map a [`RawArray`] into a new [`RawArray`].
+#[test]
+fn test_try_map() {
+    let mut array: RawArray<u32> = match RawArray::new(4) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+    for (i, elem) in array.iter_mut().enumerate() {
+        *elem = i as u32;
+    }
+
+    let mapped: RawArray<u64> = match array.try_map(|x| Ok(*x as u64 * 2)) {
+        Ok(mapped) => mapped,
+        Err(e) => panic!("failed to map array (error={:?})", e),
+    };
+
+    // Check if the mapped array has the expected length.
+    if mapped.len() != array.len() {
+        panic!(
+            "mapped array has unexpected length (expected={}, got={})",
+            array.len(),
+            mapped.len()
+        );
+    }
+
+    // Check if the mapped array has the expected contents.
+    for (i, elem) in mapped.iter().enumerate() {
+        if *elem != i as u64 * 2 {
+            panic!("mapped array has unexpected element (expected={}, got={})", i * 2, *elem);
+        }
+    }
+}
+
+/// Attempts to map a [`RawArray`] with a function that fails on the third element.
+#[test]
+fn test_try_map_error() {
+    let array: RawArray<u32> = match RawArray::new(4) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+    let counter: Rc<()> = Rc::new(());
+
+    let mut count: usize = 0;
+    match array.try_map(|_| {
+        count += 1;
+        if count == 3 {
+            return Err(Error::new(ErrorCode::InvalidArgument, "third element"));
+        }
+        Ok(counter.clone())
+    }) {
+        Ok(_) => panic!("mapped array with failing function"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Check if elements that were already constructed were dropped.
+    if Rc::strong_count(&counter) != 1 {
+        panic!("leaked elements (count={})", Rc::strong_count(&counter) - 1);
+    }
+}
+
+/// Attempts to remove consecutive duplicates from a [`RawArray`].
+#[test]
+fn test_dedup_by() {
+    let contents: [u32; 10] = [1, 2, 3, 3, 3, 4, 5, 6, 6, 6];
+    let expected: [u32; 6] = [1, 2, 3, 4, 5, 6];
+    let mut array: RawArray<u32> = match RawArray::new(contents.len() + 2) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+    array[..contents.len()].copy_from_slice(&contents);
+
+    let mut len_in_use: usize = contents.len();
+    array.dedup_by(&mut len_in_use, |a, b| a == b);
+
+    // Check if the in-use length was updated.
+    if len_in_use != expected.len() {
+        panic!("unexpected in-use length (expected={}, got={})", expected.len(), len_in_use);
+    }
+
+    // Check if the in-use prefix was compacted.
+    if array[..len_in_use] != expected {
+        panic!("unexpected contents (expected={:?}, got={:?})", expected, &array[..len_in_use]);
+    }
+
+    // Check if slots that are no longer in use were cleared.
+    for i in array[len_in_use..].iter() {
+        if *i != 0 {
+            panic!("slot past in-use length was not cleared");
+        }
+    }
+}
+
+/// Attempts to remove consecutive duplicates from a [`RawArray`] of elements that must be dropped.
+#[test]
+fn test_dedup_by_drop() {
+    let counter: Rc<()> = Rc::new(());
+    let mut array: RawArray<Option<Rc<()>>> = match RawArray::new(4) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+    for elem in array.iter_mut() {
+        unsafe { ptr::write(elem, Some(counter.clone())) };
+    }
+
+    let mut len_in_use: usize = array.len();
+    array.dedup_by(&mut len_in_use, |_, _| true);
+
+    // Check if removed elements were dropped.
+    if len_in_use != 1 || Rc::strong_count(&counter) != 2 {
+        panic!("unexpected state (len={}, count={})", len_in_use, Rc::strong_count(&counter));
+    }
+}
+
+/// Attempts to retain every other element of a [`RawArray`].
+#[test]
+fn test_retain() {
+    let counter: Rc<()> = Rc::new(());
+    let mut array: RawArray<Option<(u32, Rc<()>)>> = match RawArray::new(8) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+    for (i, elem) in array.iter_mut().enumerate() {
+        unsafe { ptr::write(elem, Some((i as u32, counter.clone()))) };
+    }
+
+    let mut len_in_use: usize = 6;
+    let mut index: usize = 0;
+    array.retain(&mut len_in_use, |_| {
+        let keep: bool = index % 2 == 0;
+        index += 1;
+        keep
+    });
+
+    // Check if the in-use length was updated.
+    if len_in_use != 3 {
+        panic!("unexpected in-use length (expected=3, got={})", len_in_use);
+    }
+
+    // Check if the relative order of kept elements was preserved.
+    for (i, elem) in array[..len_in_use].iter().enumerate() {
+        match elem {
+            Some((value, _)) if *value == (2 * i) as u32 => {},
+            _ => panic!("unexpected element at index {}", i),
+        }
+    }
+
+    // Check if slots that are no longer in use were cleared.
+    for elem in array[len_in_use..6].iter() {
+        if elem.is_some() {
+            panic!("slot past in-use length was not cleared");
+        }
+    }
+
+    // Check if removed elements were dropped.
+    if Rc::strong_count(&counter) != 6 {
+        panic!("unexpected reference count (expected=6, got={})", Rc::strong_count(&counter));
+    }
+}
+
+/// Attempts to view a [`RawArray`] as a slice of bytes.
+#[test]
+fn test_as_bytes() {
+    let mut array: RawArray<u32> = match RawArray::new(4) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+
+    // Check if the byte view has the expected length.
+    let expected: usize = array.len() * core::mem::size_of::<u32>();
+    if array.as_bytes().len() != expected {
+        panic!("unexpected length (expected={}, got={})", expected, array.as_bytes().len());
+    }
+
+    // Write bytes and check if they are visible through the typed view.
+    array.as_bytes_mut()[4..8].copy_from_slice(&0x12345678u32.to_ne_bytes());
+    if array[1] != 0x12345678 || array[0] != 0 || array[2] != 0 {
+        panic!("byte-level write not visible (array={:?})", &array[..]);
+    }
+
+    // Write a typed value and check if it is visible through the byte view.
+    array[3] = 0xdeadbeef;
+    if array.as_bytes()[12..16] != 0xdeadbeefu32.to_ne_bytes() {
+        panic!("typed write not visible (bytes={:?})", array.as_bytes());
+    }
+}
+
+/// Attempts to process a [`RawArray`] in indexed blocks.
+#[test]
+fn test_chunks_mut_indexed() {
+    let mut array: RawArray<u8> = match RawArray::new(10) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+
+    // Fill each block with its index.
+    let mut nblocks: usize = 0;
+    if let Err(e) = array.chunks_mut_indexed(4, |i, window| {
+        window.fill(i as u8);
+        nblocks += 1;
+    }) {
+        panic!("failed to process blocks (error={:?})", e);
+    }
+
+    // Check if the partial final block was processed.
+    if nblocks != 3 {
+        panic!("unexpected number of blocks (expected=3, got={})", nblocks);
+    }
+
+    // Check if block boundaries are correct.
+    if array[..] != [0, 0, 0, 0, 1, 1, 1, 1, 2, 2] {
+        panic!("unexpected block boundaries (array={:?})", &array[..]);
+    }
+}
+
+/// Attempts to process a [`RawArray`] in blocks of zero elements.
+#[test]
+fn test_chunks_mut_indexed_zero() {
+    let mut array: RawArray<u8> = match RawArray::new(4) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+
+    match array.chunks_mut_indexed(0, |_, _| {}) {
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        _ => panic!("zero chunk size was not rejected"),
+    }
+}
+
+/// Attempts to iterate over a [`RawArray`] starting at a middle index.
+#[test]
+fn test_iter_cycle_from() {
+    let mut array: RawArray<u8> = match RawArray::new(5) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+    for (i, x) in array.iter_mut().enumerate() {
+        *x = i as u8 * 10;
+    }
+
+    // Check if all indices are visited exactly once, in wrap order.
+    let mut visited: [usize; 5] = [0; 5];
+    let mut n: usize = 0;
+    for (i, x) in array.iter_cycle_from(8) {
+        let expected: usize = (3 + n) % 5;
+        if i != expected || *x != i as u8 * 10 {
+            panic!("unexpected element (expected={}, got=({}, {}))", expected, i, x);
+        }
+        visited[i] += 1;
+        n += 1;
+    }
+
+    if visited != [1; 5] {
+        panic!("elements not visited exactly once (visited={:?})", visited);
+    }
+}
+
+/// Number of times that [`count_dtor`] was called.
+static DTOR_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// A destructor that counts how many times it is called.
+fn count_dtor(_ptr: *mut u8, _len: usize) {
+    DTOR_CALLS.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Attempts to drop a [`RawArray`] that has a custom destructor.
+#[test]
+fn test_from_raw_parts_with_dtor() {
+    let mut data: [u8; 4] = [1; 4];
+    let array: RawArray<u8> = match unsafe {
+        RawArray::from_raw_parts_with_dtor(data.as_mut_ptr(), data.len(), count_dtor)
+    } {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array from raw parts (error={:?})", e),
+    };
+
+    // Check if the destructor does not run before the array is dropped.
+    if DTOR_CALLS.load(Ordering::SeqCst) != 0 {
+        panic!("destructor ran before drop");
+    }
+
+    drop(array);
+
+    // Check if the destructor ran exactly once.
+    let calls: usize = DTOR_CALLS.load(Ordering::SeqCst);
+    if calls != 1 {
+        panic!("unexpected number of destructor calls (expected=1, got={})", calls);
+    }
+}
+
+/// Attempts to set, clear, and test scattered bits of a [`RawArray`].
+#[test]
+fn test_bits() {
+    let mut array: RawArray<u32> = match RawArray::new(2) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+
+    // Check if the number of bits spans the whole backing storage.
+    if array.number_of_bits() != 64 {
+        panic!("unexpected number of bits (expected=64, got={})", array.number_of_bits());
+    }
+
+    // Set scattered bits.
+    for idx in [0, 7, 8, 31, 63] {
+        if let Err(e) = array.set_bit(idx) {
+            panic!("failed to set bit {} (error={:?})", idx, e);
+        }
+    }
+
+    // Check if exactly those bits are set.
+    for idx in 0..64 {
+        let expected: bool = [0, 7, 8, 31, 63].contains(&idx);
+        match array.test_bit(idx) {
+            Ok(set) if set == expected => {},
+            Ok(set) => panic!("unexpected bit {} (expected={}, got={})", idx, expected, set),
+            Err(e) => panic!("failed to test bit {} (error={:?})", idx, e),
+        }
+    }
+
+    // Clear a bit and check if only that bit changed.
+    if let Err(e) = array.clear_bit(31) {
+        panic!("failed to clear bit (error={:?})", e);
+    }
+    if !matches!(array.test_bit(31), Ok(false)) || !matches!(array.test_bit(63), Ok(true)) {
+        panic!("unexpected bits after clear");
+    }
+
+    // Check if out-of-bounds bit indices are rejected.
+    if array.set_bit(64).is_ok() || array.clear_bit(64).is_ok() || array.test_bit(64).is_ok() {
+        panic!("out-of-bounds bit index was not rejected");
+    }
+}
+
+/// Attempts to find the first clear bit of a [`RawArray`] across a word boundary.
+#[test]
+fn test_find_first_clear_bit() {
+    let mut array: RawArray<u32> = match RawArray::new(2) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+
+    // Fill the first word and part of the second one.
+    for idx in 0..35 {
+        if let Err(e) = array.set_bit(idx) {
+            panic!("failed to set bit {} (error={:?})", idx, e);
+        }
+    }
+
+    if array.find_first_clear_bit() != Some(35) {
+        panic!("unexpected first clear bit (expected=35, got={:?})", array.find_first_clear_bit());
+    }
+
+    // Fill the whole array.
+    for idx in 35..64 {
+        if let Err(e) = array.set_bit(idx) {
+            panic!("failed to set bit {} (error={:?})", idx, e);
+        }
+    }
+
+    if let Some(idx) = array.find_first_clear_bit() {
+        panic!("full array has a clear bit (idx={})", idx);
+    }
+}
+
+/// Attempts to extract sub-slices of a [`RawArray`] with valid bounds.
+#[test]
+fn test_slice() {
+    let mut data: [u8; 8] = [0; 8];
+    let unmanaged: RawArray<u8> = match unsafe { RawArray::from_raw_parts(data.as_mut_ptr(), 8) } {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array from raw parts (error={:?})", e),
+    };
+    let managed: RawArray<u8> = match RawArray::new(8) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+
+    for mut array in [managed, unmanaged] {
+        // Fill the array with a recognizable pattern.
+        for (i, x) in array.iter_mut().enumerate() {
+            *x = i as u8;
+        }
+
+        match array.slice(2, 5) {
+            Ok(slice) if slice == [2, 3, 4] => {},
+            Ok(slice) => panic!("unexpected sub-slice (got={:?})", slice),
+            Err(e) => panic!("failed to extract sub-slice (error={:?})", e),
+        }
+
+        // Check if empty and full ranges are accepted.
+        if !matches!(array.slice(8, 8), Ok(slice) if slice.is_empty()) {
+            panic!("failed to extract empty sub-slice");
+        }
+        if !matches!(array.slice(0, 8), Ok(slice) if slice.len() == 8) {
+            panic!("failed to extract full sub-slice");
+        }
+
+        // Check if writes through a mutable sub-slice land in the array.
+        match array.slice_mut(6, 8) {
+            Ok(slice) => slice.fill(0xff),
+            Err(e) => panic!("failed to extract mutable sub-slice (error={:?})", e),
+        }
+        if array[5..] != [5, 0xff, 0xff] {
+            panic!("unexpected contents after write (got={:?})", &array[5..]);
+        }
+    }
+}
+
+/// Attempts to extract a sub-slice of a [`RawArray`] whose start is past its end.
+#[test]
+fn test_slice_start_after_end() {
+    let mut array: RawArray<u8> = match RawArray::new(8) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+
+    match array.slice(5, 2) {
+        Ok(_) => panic!("extracted sub-slice with start after end"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    match array.slice_mut(5, 2) {
+        Ok(_) => panic!("extracted mutable sub-slice with start after end"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to extract a sub-slice of a [`RawArray`] that ends past its length.
+#[test]
+fn test_slice_end_out_of_bounds() {
+    let mut array: RawArray<u8> = match RawArray::new(8) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+
+    match array.slice(4, 9) {
+        Ok(_) => panic!("extracted sub-slice past the end of the array"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    match array.slice_mut(4, 9) {
+        Ok(_) => panic!("extracted mutable sub-slice past the end of the array"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to grow and shrink a managed [`RawArray`].
+#[test]
+fn test_resize() {
+    let mut array: RawArray<u32> = match RawArray::new(4) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+    for (i, x) in array.iter_mut().enumerate() {
+        *x = i as u32 + 1;
+    }
+
+    // Check if growing preserves existing elements and zeroes the new tail.
+    if let Err(e) = array.resize(8) {
+        panic!("failed to grow array (error={:?})", e);
+    }
+    if array[..] != [1, 2, 3, 4, 0, 0, 0, 0] {
+        panic!("unexpected contents after growing (got={:?})", &array[..]);
+    }
+
+    // Check if shrinking preserves the leading elements.
+    if let Err(e) = array.resize(2) {
+        panic!("failed to shrink array (error={:?})", e);
+    }
+    if array[..] != [1, 2] {
+        panic!("unexpected contents after shrinking (got={:?})", &array[..]);
+    }
+}
+
+/// Attempts to resize a [`RawArray`] to an invalid length.
+#[test]
+fn test_resize_invalid_len() {
+    let mut array: RawArray<u32> = match RawArray::new(4) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+    array.fill(7);
+
+    match array.resize(0) {
+        Ok(_) => panic!("resized array to an invalid length"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Check if the array was left untouched.
+    if array[..] != [7, 7, 7, 7] {
+        panic!("array was modified by a failed resize (got={:?})", &array[..]);
+    }
+}
+
+/// Attempts to resize an unmanaged [`RawArray`].
+#[test]
+fn test_resize_unmanaged() {
+    let mut data: [u8; 4] = [0; 4];
+    let mut array: RawArray<u8> = match unsafe { RawArray::from_raw_parts(data.as_mut_ptr(), 4) } {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array from raw parts (error={:?})", e),
+    };
+
+    match array.resize(8) {
+        Ok(_) => panic!("resized unmanaged array"),
+        Err(e) if e.code == ErrorCode::OperationNotSupported => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to create a [`RawArray`] from a slice.
+#[test]
+fn test_from_slice() {
+    let src: [u32; 4] = [1, 2, 3, 4];
+    let array: RawArray<u32> = match RawArray::from_slice(&src) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array from slice (error={:?})", e),
+    };
+
+    // Check if the array holds a copy of the slice.
+    if array[..] != src {
+        panic!("unexpected contents (expected={:?}, got={:?})", src, &array[..]);
+    }
+}
+
+/// Attempts to create a [`RawArray`] from an empty slice.
+#[test]
+fn test_from_slice_empty() {
+    match RawArray::<u32>::from_slice(&[]) {
+        Ok(_) => panic!("created array from empty slice"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to get the length of managed and unmanaged [`RawArray`]s.
+#[test]
+fn test_len() {
+    let mut data: [u8; 4] = [0; 4];
+    let unmanaged: RawArray<u8> = match unsafe { RawArray::from_raw_parts(data.as_mut_ptr(), 4) } {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array from raw parts (error={:?})", e),
+    };
+    let managed: RawArray<u8> = match RawArray::new(8) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+
+    for (array, len) in [(unmanaged, 4), (managed, 8)] {
+        // Check if the length matches the length of the underlying slice.
+        if array.len() != len || array.len() != array[..].len() {
+            panic!("array has unexpected length (expected={}, got={})", len, array.len());
+        }
+        if array.is_empty() {
+            panic!("non-empty array reported as empty");
+        }
+    }
+}
+
+/// Attempts to clone an unmanaged [`RawArray`].
+#[test]
+fn test_try_clone() {
+    let mut data: [u8; 4] = [0; 4];
+    let mut array: RawArray<u8> = match unsafe { RawArray::from_raw_parts(data.as_mut_ptr(), 4) } {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array from raw parts (error={:?})", e),
+    };
+    array.copy_from_slice(&[1, 2, 3, 4]);
+
+    let clone: RawArray<u8> = match array.try_clone() {
+        Ok(clone) => clone,
+        Err(e) => panic!("failed to clone array (error={:?})", e),
+    };
+
+    // Check if the clone holds the same elements.
+    if clone[..] != array[..] {
+        panic!("unexpected contents (expected={:?}, got={:?})", &array[..], &clone[..]);
+    }
+
+    // Check if the clone is detached from the original memory.
+    array.fill(0);
+    if clone[..] != [1, 2, 3, 4] {
+        panic!("clone is not detached from the original array (got={:?})", &clone[..]);
+    }
+}
+
+/// Attempts to iterate over the populated slots of a sparse [`RawArray`].
+#[test]
+fn test_iter_nonzero() {
+    let mut array: RawArray<[u16; 2]> = match RawArray::new(8) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+
+    // Populate a couple of slots, one of them only partially.
+    array[2] = [1, 2];
+    array[5] = [0, 3];
+
+    let expected: [(usize, [u16; 2]); 2] = [(2, [1, 2]), (5, [0, 3])];
+    let mut count: usize = 0;
+    for (i, elem) in array.iter_nonzero() {
+        // Check if the yielded slot is a populated one.
+        if count >= expected.len() || expected[count] != (i, *elem) {
+            panic!("unexpected slot (index={}, elem={:?})", i, elem);
+        }
+        count += 1;
+    }
+
+    // Check if all populated slots were yielded.
+    if count != expected.len() {
+        panic!("unexpected number of slots (expected={}, got={})", expected.len(), count);
+    }
+}
+
+/// Attempts to create a [`RawArray`] without initializing it.
+#[test]
+fn test_new_uninit() {
+    let mut array: RawArray<MaybeUninit<u32>> = match RawArray::new_uninit(4) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create uninitialized array (error={:?})", e),
+    };
+
+    for (i, x) in array.iter_mut().enumerate() {
+        x.write(i as u32 + 1);
+    }
+
+    // Safety: all elements were written.
+    let array: RawArray<u32> = unsafe { array.assume_init() };
+
+    // Check if the array holds the written elements.
+    if array[..] != [1, 2, 3, 4] {
+        panic!("unexpected contents (got={:?})", &array[..]);
+    }
+}
+
+/// Attempts to create an uninitialized [`RawArray`] with an invalid length.
+#[test]
+fn test_new_uninit_invalid_len() {
+    match RawArray::<u32>::new_uninit(0) {
+        Ok(_) => panic!("created uninitialized array with invalid length"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
@@ -8,6 +8,10 @@
 use crate::{
     hal::mem::VirtualAddress,
     kcall::KcallArgs,
+    klog::{
+        Klog,
+        KlogLevel,
+    },
     pm::ProcessManager,
 };
 use ::sys::error::{
@@ -25,6 +29,7 @@ fn do_debug(buf: &[u8]) -> Result<(), Error> {
         Err(e) => {
             let reason: &str = "invalid UTF-8";
             error!("debug(): {} (error={:?})", reason, e);
+            Klog::hexdump(module_path!(), KlogLevel::Debug, buf);
             return Err(Error::new(ErrorCode::InvalidArgument, reason));
         },
     };
@@ -9,6 +9,7 @@ use crate::{
     event::manager::{
         EventManager,
         EventOwnership,
+        OwnershipKind,
     },
     kcall::KcallArgs,
     pm::ProcessManager,
@@ -42,7 +43,7 @@ pub fn evctrl(pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
         Err(e) => return e.code.into_errno(),
     };
 
-    let req: EventCtrlRequest = match EventCtrlRequest::try_from(args.arg1) {
+    let req: EventCtrlRequest = match EventCtrlRequest::from_raw_parts(args.arg1, args.arg2) {
         Ok(req) => req,
         Err(e) => return e.code.into_errno(),
     };
@@ -52,9 +53,18 @@ pub fn evctrl(pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
             Ok(_) => 0,
             Err(e) => e.code.into_errno(),
         },
-        Ok(None) => match pm.remove_event(&ev) {
-            Ok(_) => 0,
-            Err(e) => e.code.into_errno(),
+        Ok(None) => {
+            let result: Result<(), Error> = match req {
+                EventCtrlRequest::UnregisterDefault => pm.remove_default_event(),
+                EventCtrlRequest::Unmonitor => pm.remove_event(&ev, OwnershipKind::Monitor),
+                EventCtrlRequest::Transfer { to } => pm.transfer_event(&ev, to),
+                EventCtrlRequest::Coalesce | EventCtrlRequest::Uncoalesce => Ok(()),
+                _ => pm.remove_event(&ev, OwnershipKind::Exclusive),
+            };
+            match result {
+                Ok(_) => 0,
+                Err(e) => e.code.into_errno(),
+            }
         },
         Err(e) => e.code.into_errno(),
     }
@@ -0,0 +1,96 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    event::manager::EventManager,
+    hal::{
+        arch::InterruptNumber,
+        cpu::InterruptManager,
+        Hal,
+    },
+    kcall::KcallArgs,
+    pm::ProcessManager,
+};
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    event::InterruptEvent,
+    pm::{
+        Capability,
+        ProcessIdentifier,
+    },
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+fn do_intaffinity(
+    hal: &mut Hal,
+    pid: ProcessIdentifier,
+    ev: InterruptEvent,
+    cpu: u8,
+) -> Result<(), Error> {
+    trace!("do_intaffinity(): pid={:?}, ev={:?}, cpu={:?}", pid, ev, cpu);
+
+    // Check if process does not have process management capabilities.
+    if !ProcessManager::has_capability(pid, Capability::ProcessManagement)? {
+        let reason: &str = "process does not have process management capabilities";
+        error!("do_intaffinity(): {}", reason);
+        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+    }
+
+    // Check if process does not own the target interrupt.
+    if !EventManager::owns_interrupt(pid, ev)? {
+        let reason: &str = "process does not own interrupt";
+        error!("do_intaffinity(): {}", reason);
+        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+    }
+
+    // Find the corresponding interrupt number.
+    let intnum: InterruptNumber = match InterruptNumber::VALUES
+        .iter()
+        .find(|intnum| **intnum as usize == usize::from(ev))
+    {
+        Some(intnum) => *intnum,
+        None => {
+            let reason: &str = "interrupt is not supported by the hardware";
+            error!("do_intaffinity(): {}", reason);
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
+        },
+    };
+
+    let intman: &mut InterruptManager = match hal.intman.as_mut() {
+        Some(intman) => intman,
+        None => {
+            let reason: &str = "interrupts are not supported";
+            error!("do_intaffinity(): {}", reason);
+            return Err(Error::new(ErrorCode::OperationNotSupported, reason));
+        },
+    };
+
+    intman.set_affinity(intnum, cpu)
+}
+
+pub fn intaffinity(hal: &mut Hal, args: &KcallArgs) -> i32 {
+    let ev: InterruptEvent = match InterruptEvent::try_from(args.arg0) {
+        Ok(ev) => ev,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    let cpu: u8 = match u8::try_from(args.arg1) {
+        Ok(cpu) => cpu,
+        Err(_) => return ErrorCode::InvalidArgument.into_errno(),
+    };
+
+    match do_intaffinity(hal, args.pid, ev, cpu) {
+        Ok(_) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
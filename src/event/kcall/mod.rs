@@ -6,11 +6,19 @@
 //==================================================================================================
 
 mod evctrl;
+mod intaffinity;
+mod owned;
+mod pending;
 mod resume;
+mod signal;
 
 //==================================================================================================
 // Exports
 //==================================================================================================
 
 pub use evctrl::evctrl;
+pub use intaffinity::intaffinity;
+pub use owned::owned_events;
+pub use pending::pending_counts;
 pub use resume::resume;
+pub use signal::signal;
@@ -0,0 +1,68 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    event::manager::EventManager,
+    kcall::{
+        args::{
+            user_mut,
+            UserMut,
+        },
+        KcallArgs,
+    },
+    pm::ProcessManager,
+};
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    event::OwnedEvents,
+    pm::{
+        Capability,
+        ProcessIdentifier,
+    },
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+fn do_owned_events(
+    pm: &ProcessManager,
+    pid: ProcessIdentifier,
+    target: ProcessIdentifier,
+    owned: &UserMut<OwnedEvents>,
+) -> Result<(), Error> {
+    trace!("do_owned_events(): pid={:?}, target={:?}", pid, target);
+
+    // Check if process may not inspect events of another process.
+    if pid != target && !ProcessManager::has_capability(pid, Capability::ProcessManagement)? {
+        let reason: &str = "process does not have process management capabilities";
+        error!("do_owned_events(): {}", reason);
+        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+    }
+
+    // Check if target process exists.
+    pm.getuid(target)?;
+
+    owned.write(&EventManager::owned_events(target)?)
+}
+
+pub fn owned_events(pm: &ProcessManager, args: &KcallArgs) -> i32 {
+    let target: ProcessIdentifier = ProcessIdentifier::from(args.arg0);
+
+    let owned: UserMut<OwnedEvents> = match user_mut(args.pid, args.arg1 as usize) {
+        Ok(owned) => owned,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match do_owned_events(pm, args.pid, target, &owned) {
+        Ok(()) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
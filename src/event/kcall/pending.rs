@@ -0,0 +1,68 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    event::manager::EventManager,
+    kcall::{
+        args::{
+            user_mut,
+            UserMut,
+        },
+        KcallArgs,
+    },
+    pm::ProcessManager,
+};
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    event::PendingCounts,
+    pm::{
+        Capability,
+        ProcessIdentifier,
+    },
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+fn do_pending_counts(
+    pm: &ProcessManager,
+    pid: ProcessIdentifier,
+    target: ProcessIdentifier,
+    counts: &UserMut<PendingCounts>,
+) -> Result<(), Error> {
+    trace!("do_pending_counts(): pid={:?}, target={:?}", pid, target);
+
+    // Check if process may not inspect events of another process.
+    if pid != target && !ProcessManager::has_capability(pid, Capability::ProcessManagement)? {
+        let reason: &str = "process does not have process management capabilities";
+        error!("do_pending_counts(): {}", reason);
+        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+    }
+
+    // Check if target process exists.
+    pm.getuid(target)?;
+
+    counts.write(&EventManager::pending_counts(target)?)
+}
+
+pub fn pending_counts(pm: &ProcessManager, args: &KcallArgs) -> i32 {
+    let target: ProcessIdentifier = ProcessIdentifier::from(args.arg0);
+
+    let counts: UserMut<PendingCounts> = match user_mut(args.pid, args.arg1 as usize) {
+        Ok(counts) => counts,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match do_pending_counts(pm, args.pid, target, &counts) {
+        Ok(()) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
@@ -5,20 +5,23 @@
 // Imports
 //==================================================================================================
 
-use crate::event::manager::EventManager;
+use crate::{
+    event::manager::EventManager,
+    kcall::KcallArgs,
+};
 use ::sys::event::EventDescriptor;
 
 //==================================================================================================
 // Standalone Functions
 //==================================================================================================
 
-pub fn resume(eventinfo: usize) -> i32 {
-    let eventinfo: EventDescriptor = match EventDescriptor::try_from(eventinfo) {
+pub fn resume(args: &KcallArgs) -> i32 {
+    let eventinfo: EventDescriptor = match EventDescriptor::try_from(args.arg0 as usize) {
         Ok(eventinfo) => eventinfo,
         Err(e) => return e.code.into_errno(),
     };
 
-    match EventManager::resume(eventinfo) {
+    match EventManager::resume(args.pid, eventinfo, args.arg1) {
         Ok(_) => 0,
         Err(e) => e.code.into_errno(),
     }
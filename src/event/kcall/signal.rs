@@ -0,0 +1,64 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    event::manager::EventManager,
+    kcall::KcallArgs,
+    pm::ProcessManager,
+};
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    pm::{
+        Capability,
+        ProcessIdentifier,
+    },
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+fn do_signal(
+    pm: &ProcessManager,
+    pid: ProcessIdentifier,
+    target: ProcessIdentifier,
+    signals: u32,
+) -> Result<(), Error> {
+    trace!("do_signal(): pid={:?}, target={:?}, signals={:#x}", pid, target, signals);
+
+    // Check if no signal was supplied.
+    if signals == 0 {
+        let reason: &str = "no signals to raise";
+        error!("do_signal(): {}", reason);
+        return Err(Error::new(ErrorCode::InvalidArgument, reason));
+    }
+
+    // Check if process may not raise signals on another process.
+    if pid != target && !ProcessManager::has_capability(pid, Capability::ProcessManagement)? {
+        let reason: &str = "process does not have process management capabilities";
+        error!("do_signal(): {}", reason);
+        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+    }
+
+    // Check if target process exists.
+    pm.getuid(target)?;
+
+    EventManager::raise_signals(target, signals)
+}
+
+pub fn signal(pm: &ProcessManager, args: &KcallArgs) -> i32 {
+    let target: ProcessIdentifier = ProcessIdentifier::from(args.arg0);
+    let signals: u32 = args.arg1;
+
+    match do_signal(pm, args.pid, target, signals) {
+        Ok(()) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
@@ -0,0 +1,2258 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    event::{
+        ownership::ExceptionOwnership,
+        signal::PendingSignals,
+    },
+    hal::{
+        arch::{
+            ContextInformation,
+            ExceptionInformation,
+            InterruptNumber,
+        },
+        time,
+        Hal,
+    },
+    pm::{
+        sync::condvar::Condvar,
+        ProcessManager,
+    },
+};
+use ::alloc::{
+    collections::{
+        BTreeSet,
+        LinkedList,
+    },
+    rc::Rc,
+    vec::Vec,
+};
+use ::core::{
+    cell::{
+        RefCell,
+        RefMut,
+    },
+    mem,
+};
+use ::sys::{
+    config,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    event::{
+        Event,
+        EventCtrlRequest,
+        EventDescriptor,
+        EventInformation,
+        ExceptionEvent,
+        InterruptEvent,
+        InterruptMessagePayload,
+        OwnedEvents,
+        PendingCounts,
+        ProcessTerminationInfo,
+        SchedulingEvent,
+    },
+    ipc::{
+        Message,
+        MessageType,
+    },
+    pm::{
+        Capability,
+        ProcessIdentifier,
+    },
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+static mut MANAGER: Option<EventManager> = None;
+
+/// Kernel callbacks of interrupt lines. These are kept apart from the state of the event manager,
+/// so that they may be registered before it is initialized and run while it is borrowed.
+static mut INTERRUPT_CALLBACKS: [Option<InterruptCallback>; usize::BITS as usize] =
+    [None; usize::BITS as usize];
+
+///
+/// # Description
+///
+/// A type that represents a kernel callback for an interrupt line. The callback runs before the
+/// interrupt is delivered to the userland owner of the line, and it returns `true` if the interrupt
+/// should also be delivered to that owner.
+///
+pub type InterruptCallback = fn(InterruptNumber) -> bool;
+
+///
+/// # Description
+///
+/// An enumeration of the classes of events that may wake up a waiting process.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventClass {
+    /// An interrupt was triggered.
+    Interrupt,
+    /// An exception was triggered.
+    Exception,
+    /// A scheduling event was triggered.
+    Scheduling,
+    /// A message was delivered by another process.
+    Ipc,
+}
+
+impl EventClass {
+    /// Number of classes of events.
+    pub const COUNT: usize = 4;
+
+    /// All classes of events.
+    pub const VALUES: [Self; Self::COUNT] = [
+        Self::Interrupt,
+        Self::Exception,
+        Self::Scheduling,
+        Self::Ipc,
+    ];
+}
+
+struct ExceptionEventInformation {
+    pid: ProcessIdentifier,
+    info: ExceptionInformation,
+}
+
+///
+/// # Description
+///
+/// An enumeration of the kinds of ownership that a process may hold over an event.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OwnershipKind {
+    /// The process is the only owner of the event.
+    Exclusive,
+    /// The process owns all exceptions that have no exclusive owner.
+    Default,
+    /// The process observes a copy of the event, without owning it.
+    Monitor,
+}
+
+pub struct EventOwnership {
+    ev: Event,
+    kind: OwnershipKind,
+    pid: ProcessIdentifier,
+    em: &'static mut EventManager,
+}
+
+impl EventOwnership {
+    pub fn event(&self) -> &Event {
+        &self.ev
+    }
+
+    pub fn kind(&self) -> OwnershipKind {
+        self.kind
+    }
+
+    pub fn set_pid(&mut self, pid: ProcessIdentifier) {
+        self.pid = pid;
+    }
+}
+
+impl Drop for EventOwnership {
+    fn drop(&mut self) {
+        match self.em.try_borrow_mut() {
+            Ok(mut em) => match self.ev {
+                Event::Interrupt(ev) => {
+                    let result: Result<(), Error> = match self.kind {
+                        OwnershipKind::Monitor => {
+                            em.do_evctrl_interrupt(Some(self.pid), ev, EventCtrlRequest::Unmonitor)
+                        },
+                        _ => em.do_evctrl_interrupt(None, ev, EventCtrlRequest::Unregister),
+                    };
+                    if let Err(e) = result {
+                        error!("failed to unregister interrupt: {:?}", e);
+                    }
+                },
+                Event::Exception(ev) => {
+                    let req: EventCtrlRequest = match self.kind {
+                        OwnershipKind::Default => EventCtrlRequest::UnregisterDefault,
+                        _ => EventCtrlRequest::Unregister,
+                    };
+                    if let Err(e) = em.do_evctrl_exception(None, ev, req) {
+                        error!("failed to unregister exception: {:?}", e);
+                    }
+                },
+                Event::Scheduling(ev) => {
+                    if let Err(e) =
+                        em.do_evctrl_scheduling(Some(self.pid), ev, EventCtrlRequest::Unregister)
+                    {
+                        error!("failed to unregister scheduling event: {:?}", e);
+                    }
+                },
+            },
+            Err(e) => {
+                error!("failed to borrow event manager: {:?}", e);
+            },
+        }
+    }
+}
+
+///
+/// # Description
+///
+/// A selection of the events that a process is willing to receive in a wait. Events that are not
+/// selected are left pending, so that they are delivered by subsequent waits.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventMask {
+    /// Bitmask of interrupt lines.
+    pub interrupts: usize,
+    /// Bitmask of exceptions.
+    pub exceptions: usize,
+    /// Bitmask of scheduling events.
+    pub scheduling: usize,
+    /// Whether IPC messages are selected.
+    pub ipc: bool,
+}
+
+impl EventMask {
+    /// A mask that selects all events.
+    pub const ALL: Self = Self {
+        interrupts: usize::MAX,
+        exceptions: usize::MAX,
+        scheduling: usize::MAX,
+        ipc: true,
+    };
+
+    ///
+    /// # Description
+    ///
+    /// Computes the events that are selected by both masks.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Other mask.
+    ///
+    /// # Returns
+    ///
+    /// A mask that selects the events that are selected by both masks.
+    ///
+    fn intersection(&self, other: &Self) -> Self {
+        Self {
+            interrupts: self.interrupts & other.interrupts,
+            exceptions: self.exceptions & other.exceptions,
+            scheduling: self.scheduling & other.scheduling,
+            ipc: self.ipc && other.ipc,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Restricts the mask to events that are delivered as replies of a given type.
+    ///
+    /// # Parameters
+    ///
+    /// - `message_type`: Expected type of the reply, if any.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a mask that selects only the events of the expected type is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    fn for_reply(&self, message_type: Option<MessageType>) -> Result<Self, Error> {
+        let typ: MessageType = match message_type {
+            Some(typ) => typ,
+            None => return Ok(*self),
+        };
+
+        Self::check_reply_type(typ)?;
+
+        Ok(Self {
+            interrupts: if typ == MessageType::Interrupt {
+                self.interrupts
+            } else {
+                0
+            },
+            exceptions: if typ == MessageType::Exception {
+                self.exceptions
+            } else {
+                0
+            },
+            scheduling: if typ == MessageType::SchedulingEvent {
+                self.scheduling
+            } else {
+                0
+            },
+            ipc: typ == MessageType::Ipc && self.ipc,
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks whether events of a given type may be delivered as replies.
+    ///
+    /// # Parameters
+    ///
+    /// - `typ`: Type of the reply.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    fn check_reply_type(typ: MessageType) -> Result<(), Error> {
+        match typ {
+            MessageType::Interrupt
+            | MessageType::Exception
+            | MessageType::Ipc
+            | MessageType::SchedulingEvent => Ok(()),
+            _ => {
+                let reason: &str = "invalid reply type";
+                error!("check_reply_type(): {} (message_type={:?})", reason, typ);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+        }
+    }
+}
+
+struct EventManagerInner {
+    interrupt_capable: bool,
+    /// Number of interrupt lines supported by the hardware.
+    ninterrupts: usize,
+    /// Highest interrupt line that is owned by some process.
+    max_registered_interrupt: Option<usize>,
+    /// Identifier of the last event. Identifiers wrap around at [`EventDescriptor::MAX_ID`], but
+    /// an identifier is never handed out while an event with the same identifier is outstanding
+    /// (i.e. pending delivery or resume), so that descriptors of outstanding events are unique.
+    nevents: usize,
+    /// Identifiers of outstanding events.
+    outstanding: BTreeSet<usize>,
+    wait: Option<Rc<Condvar>>,
+    interrupt_ownership: [Option<ProcessIdentifier>; usize::BITS as usize],
+    /// Interrupts that are pending delivery to owners (event and number of occurrences).
+    pending_interrupts: [LinkedList<(EventDescriptor, usize)>; usize::BITS as usize],
+    /// Interrupt lines on which occurrences that fire while another one is pending delivery are
+    /// coalesced into the pending event.
+    coalesced_interrupts: usize,
+    /// Interrupt line that was served last. Lines are scanned for delivery starting at the next
+    /// one, so that lines with a high interrupt rate do not starve others.
+    last_interrupt_idx: usize,
+    /// Processes that observe interrupt lines (process and line).
+    interrupt_monitors: LinkedList<(ProcessIdentifier, usize)>,
+    /// Copies of interrupts that are pending delivery to monitors.
+    monitored_interrupts: LinkedList<(ProcessIdentifier, EventDescriptor)>,
+    exception_ownership: ExceptionOwnership,
+    pending_exceptions: [LinkedList<(EventDescriptor, ExceptionEventInformation, Rc<Condvar>)>;
+        usize::BITS as usize],
+    /// Exception that was served last. Exceptions are scanned for delivery starting at the next
+    /// one, so that frequent exceptions do not starve others.
+    last_exception_idx: usize,
+    /// Processes that subscribed to scheduling events.
+    scheduling_ownership: [LinkedList<ProcessIdentifier>; SchedulingEvent::NUMBER_EVENTS],
+    /// Scheduling events that are pending delivery (recipient, event and information).
+    pending_scheduling: [LinkedList<(ProcessIdentifier, EventDescriptor, ProcessTerminationInfo)>;
+        SchedulingEvent::NUMBER_EVENTS],
+    /// Signals that are pending on processes.
+    signals: PendingSignals,
+}
+
+impl EventManagerInner {
+    ///
+    /// # Description
+    ///
+    /// Instantiates the state of an event manager in which no event is owned or pending.
+    ///
+    /// # Parameters
+    ///
+    /// - `interrupt_capable`: Whether interrupts are supported.
+    /// - `ninterrupts`: Number of interrupt lines supported by the hardware.
+    ///
+    /// # Returns
+    ///
+    /// The state of a new event manager.
+    ///
+    fn new(interrupt_capable: bool, ninterrupts: usize) -> Self {
+        let mut pending_interrupts: [LinkedList<(EventDescriptor, usize)>; usize::BITS as usize] =
+            unsafe { mem::zeroed() };
+        for list in pending_interrupts.iter_mut() {
+            *list = LinkedList::default();
+        }
+
+        let mut interrupt_ownership: [Option<ProcessIdentifier>; usize::BITS as usize] =
+            unsafe { mem::zeroed() };
+        for entry in interrupt_ownership.iter_mut() {
+            *entry = None;
+        }
+
+        let mut pending_exceptions: [LinkedList<(
+            EventDescriptor,
+            ExceptionEventInformation,
+            Rc<Condvar>,
+        )>; usize::BITS as usize] = unsafe { mem::zeroed() };
+        for list in pending_exceptions.iter_mut() {
+            *list = LinkedList::default();
+        }
+
+        let mut pending_scheduling: [LinkedList<(
+            ProcessIdentifier,
+            EventDescriptor,
+            ProcessTerminationInfo,
+        )>; SchedulingEvent::NUMBER_EVENTS] = unsafe { mem::zeroed() };
+        for list in pending_scheduling.iter_mut() {
+            *list = LinkedList::default();
+        }
+
+        let mut scheduling_ownership: [LinkedList<ProcessIdentifier>;
+            SchedulingEvent::NUMBER_EVENTS] = unsafe { mem::zeroed() };
+        for list in scheduling_ownership.iter_mut() {
+            *list = LinkedList::default();
+        }
+
+        Self {
+            interrupt_capable,
+            ninterrupts,
+            max_registered_interrupt: None,
+            nevents: 0,
+            outstanding: BTreeSet::new(),
+            pending_interrupts,
+            coalesced_interrupts: 0,
+            last_interrupt_idx: usize::BITS as usize - 1,
+            interrupt_ownership,
+            interrupt_monitors: LinkedList::new(),
+            monitored_interrupts: LinkedList::new(),
+            pending_exceptions,
+            last_exception_idx: usize::BITS as usize - 1,
+            exception_ownership: ExceptionOwnership::new(),
+            pending_scheduling,
+            scheduling_ownership,
+            signals: PendingSignals::new(),
+            wait: Some(Rc::new(Condvar::new())),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Registers a process as the owner of an interrupt line, and tracks the highest registered
+    /// line.
+    ///
+    /// # Parameters
+    ///
+    /// - `idx`: Interrupt line.
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    fn register_interrupt(&mut self, idx: usize, pid: ProcessIdentifier) -> Result<(), Error> {
+        // Check if target interrupt is already owned by another process.
+        if self.interrupt_ownership[idx].is_some() {
+            let reason: &str = "interrupt is already owned by another process";
+            error!("register_interrupt(): reason={:?}", reason);
+            return Err(Error::new(ErrorCode::ResourceBusy, reason));
+        }
+
+        // Register interrupt.
+        self.interrupt_ownership[idx] = Some(pid);
+        if self.max_registered_interrupt < Some(idx) {
+            self.max_registered_interrupt = Some(idx);
+        }
+
+        Ok(())
+    }
+
+    fn do_evctrl_interrupt(
+        &mut self,
+        pid: Option<ProcessIdentifier>,
+        ev: InterruptEvent,
+        req: EventCtrlRequest,
+    ) -> Result<(), Error> {
+        let idx: usize = usize::from(ev);
+
+        // Check if target interrupt is not supported by the hardware.
+        if idx >= self.ninterrupts {
+            let reason: &str = "interrupt is not supported by the hardware";
+            error!("do_evctrl_interrupt(): reason={:?}, idx={:?}", reason, idx);
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
+        }
+
+        // Handle request.
+        match req {
+            EventCtrlRequest::Register => {
+                // Check if PID is valid.
+                if let Some(pid) = pid {
+                    // Ensure that the process has the required capabilities.
+                    if !ProcessManager::has_capability(pid, Capability::InterruptControl)? {
+                        let reason: &str = "process does not have interrupt control capability";
+                        error!("do_evctrl_interrupt(): reason={:?}", reason);
+                        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                    }
+
+                    return self.register_interrupt(idx, pid);
+                }
+
+                let reason: &str = "invalid process identifier";
+                error!("do_evctrl_interrupt(): reason={:?}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+            EventCtrlRequest::Unregister => {
+                // If PID was supplied, check if it matches the current owner.
+                if let Some(pid) = pid {
+                    if self.interrupt_ownership[idx] != Some(pid) {
+                        let reason: &str = "process does not own interrupt";
+                        error!("do_evctrl_interrupt(): reason={:?}", reason);
+                        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                    }
+                }
+
+                // Unregister interrupt. Coalescing is a setting of the owner, so it is dropped.
+                self.interrupt_ownership[idx] = None;
+                self.coalesced_interrupts &= !(1 << idx);
+                if self.max_registered_interrupt == Some(idx) {
+                    self.max_registered_interrupt = (0..idx)
+                        .rev()
+                        .find(|i| self.interrupt_ownership[*i].is_some());
+                }
+
+                Ok(())
+            },
+            EventCtrlRequest::Monitor => {
+                // Check if PID is valid.
+                if let Some(pid) = pid {
+                    // Ensure that the process has the required capabilities.
+                    if !ProcessManager::has_capability(pid, Capability::InterruptControl)? {
+                        let reason: &str = "process does not have interrupt control capability";
+                        error!("do_evctrl_interrupt(): reason={:?}", reason);
+                        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                    }
+
+                    // Check if process already monitors the target interrupt.
+                    if self.interrupt_monitors.contains(&(pid, idx)) {
+                        let reason: &str = "process already monitors interrupt";
+                        error!("do_evctrl_interrupt(): reason={:?}", reason);
+                        return Err(Error::new(ErrorCode::ResourceBusy, reason));
+                    }
+
+                    // Register monitor.
+                    self.interrupt_monitors.push_back((pid, idx));
+
+                    return Ok(());
+                }
+
+                let reason: &str = "invalid process identifier";
+                error!("do_evctrl_interrupt(): reason={:?}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+            EventCtrlRequest::Unmonitor => {
+                // Check if PID is valid.
+                if let Some(pid) = pid {
+                    // Check if process does not monitor the target interrupt.
+                    if !self.interrupt_monitors.contains(&(pid, idx)) {
+                        let reason: &str = "process does not monitor interrupt";
+                        error!("do_evctrl_interrupt(): reason={:?}", reason);
+                        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                    }
+
+                    // Unregister monitor and discard undelivered copies.
+                    self.interrupt_monitors
+                        .retain(|monitor| *monitor != (pid, idx));
+                    let outstanding: &mut BTreeSet<usize> = &mut self.outstanding;
+                    self.monitored_interrupts.retain(|(p, evdesc)| {
+                        if *p == pid && evdesc.event() == Event::Interrupt(ev) {
+                            outstanding.remove(&evdesc.id());
+                            return false;
+                        }
+                        true
+                    });
+
+                    return Ok(());
+                }
+
+                let reason: &str = "invalid process identifier";
+                error!("do_evctrl_interrupt(): reason={:?}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+            EventCtrlRequest::Transfer { to } => {
+                // Check if the process owns the target interrupt.
+                if pid.is_none() || self.interrupt_ownership[idx] != pid {
+                    let reason: &str = "process does not own interrupt";
+                    error!("do_evctrl_interrupt(): reason={:?}", reason);
+                    return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                }
+
+                // Ensure that the target process has the required capabilities.
+                if !ProcessManager::has_capability(to, Capability::InterruptControl)? {
+                    let reason: &str = "target process does not have interrupt control capability";
+                    error!("do_evctrl_interrupt(): reason={:?}", reason);
+                    return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                }
+
+                // Transfer interrupt. Pending interrupts are left queued for the new owner.
+                self.interrupt_ownership[idx] = Some(to);
+
+                Ok(())
+            },
+            EventCtrlRequest::Coalesce | EventCtrlRequest::Uncoalesce => {
+                // Check if the process owns the target interrupt.
+                if pid.is_some() && self.interrupt_ownership[idx] != pid {
+                    let reason: &str = "process does not own interrupt";
+                    error!("do_evctrl_interrupt(): reason={:?}", reason);
+                    return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                }
+
+                // Occurrences that are already pending are left as they are.
+                match req {
+                    EventCtrlRequest::Coalesce => self.coalesced_interrupts |= 1 << idx,
+                    _ => self.coalesced_interrupts &= !(1 << idx),
+                }
+
+                Ok(())
+            },
+            EventCtrlRequest::RegisterDefault | EventCtrlRequest::UnregisterDefault => {
+                let reason: &str = "default handlers are only supported for exceptions";
+                error!("do_evctrl_interrupt(): reason={:?}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+        }
+    }
+
+    fn do_evctrl_exception(
+        &mut self,
+        pid: Option<ProcessIdentifier>,
+        ev: ExceptionEvent,
+        req: EventCtrlRequest,
+    ) -> Result<(), Error> {
+        let idx: usize = usize::from(ev);
+
+        // Handle request.
+        match req {
+            EventCtrlRequest::Register | EventCtrlRequest::RegisterDefault => {
+                // Check if PID is valid.
+                if let Some(pid) = pid {
+                    // Ensure that the process has the required capabilities.
+                    if !ProcessManager::has_capability(pid, Capability::ExceptionControl)? {
+                        let reason: &str = "process does not have exception control capability";
+                        error!("do_evctrl_exception(): reason={:?}", reason);
+                        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                    }
+
+                    // Register exception.
+                    return match req {
+                        EventCtrlRequest::RegisterDefault => {
+                            self.exception_ownership.register_default(pid)
+                        },
+                        _ => self.exception_ownership.register(idx, pid),
+                    };
+                }
+
+                let reason: &str = "invalid process identifier";
+                error!("do_evctrl_exception(): reason={:?}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+            EventCtrlRequest::Unregister => self.exception_ownership.unregister(idx, pid),
+            EventCtrlRequest::UnregisterDefault => self.exception_ownership.unregister_default(pid),
+            EventCtrlRequest::Transfer { to } => {
+                // Check if PID is valid.
+                if let Some(pid) = pid {
+                    // Check if the process owns the target exception.
+                    if self.exception_ownership.owner(idx) != Some(pid) {
+                        let reason: &str = "process does not own exception";
+                        error!("do_evctrl_exception(): reason={:?}", reason);
+                        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                    }
+
+                    // Ensure that the target process has the required capabilities.
+                    if !ProcessManager::has_capability(to, Capability::ExceptionControl)? {
+                        let reason: &str =
+                            "target process does not have exception control capability";
+                        error!("do_evctrl_exception(): reason={:?}", reason);
+                        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                    }
+
+                    // Transfer exception. Pending exceptions are left queued for the new owner.
+                    return self.exception_ownership.transfer(idx, pid, to);
+                }
+
+                let reason: &str = "invalid process identifier";
+                error!("do_evctrl_exception(): reason={:?}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+            EventCtrlRequest::Monitor | EventCtrlRequest::Unmonitor => {
+                let reason: &str = "exceptions require a resume handshake and cannot be monitored";
+                error!("do_evctrl_exception(): reason={:?}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+            EventCtrlRequest::Coalesce | EventCtrlRequest::Uncoalesce => {
+                let reason: &str = "coalescing is only supported for interrupts";
+                error!("do_evctrl_exception(): reason={:?}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+        }
+    }
+
+    fn do_evctrl_scheduling(
+        &mut self,
+        pid: Option<ProcessIdentifier>,
+        ev: SchedulingEvent,
+        req: EventCtrlRequest,
+    ) -> Result<(), Error> {
+        let idx: usize = usize::from(ev);
+
+        // Check if target scheduling event is always delivered to its recipient.
+        if ev == SchedulingEvent::Signal {
+            let reason: &str = "signals are delivered to their recipient";
+            error!("do_evctrl_scheduling(): reason={:?}", reason);
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
+        }
+
+        // Handle request.
+        match req {
+            EventCtrlRequest::Register => {
+                // Check if PID is valid.
+                if let Some(pid) = pid {
+                    // Ensure that the process has the required capabilities.
+                    if !ProcessManager::has_capability(pid, Capability::ProcessManagement)? {
+                        let reason: &str = "process does not have scheduling control capability";
+                        error!("do_evctrl_scheduling(): reason={:?}", reason);
+                        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                    }
+
+                    // Check if the process already subscribed to the target scheduling event.
+                    if self.scheduling_ownership[idx].contains(&pid) {
+                        let reason: &str = "process already subscribed to scheduling event";
+                        error!("do_evctrl_scheduling(): reason={:?}", reason);
+                        return Err(Error::new(ErrorCode::EntryExists, reason));
+                    }
+
+                    // Check if target scheduling event has too many subscribers.
+                    if self.scheduling_ownership[idx].len()
+                        >= config::kernel::MAX_SCHEDULING_SUBSCRIBERS
+                    {
+                        let reason: &str = "too many subscribers for scheduling event";
+                        error!("do_evctrl_scheduling(): reason={:?}", reason);
+                        return Err(Error::new(ErrorCode::ResourceBusy, reason));
+                    }
+
+                    // Register scheduling event.
+                    self.scheduling_ownership[idx].push_back(pid);
+
+                    return Ok(());
+                }
+
+                let reason: &str = "invalid process identifier";
+                error!("do_evctrl_scheduling(): reason={:?}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+            EventCtrlRequest::Unregister => {
+                match pid {
+                    // Unregister the subscription of the process, if it has one.
+                    Some(pid) => {
+                        if !self.scheduling_ownership[idx].contains(&pid) {
+                            let reason: &str = "process does not own scheduling event";
+                            error!("do_evctrl_scheduling(): reason={:?}", reason);
+                            return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                        }
+
+                        self.unsubscribe_scheduling(idx, pid);
+                    },
+                    // Unregister all subscriptions.
+                    None => {
+                        self.scheduling_ownership[idx].clear();
+                        for (_, evdesc, _) in self.pending_scheduling[idx].iter() {
+                            self.outstanding.remove(&evdesc.id());
+                        }
+                        self.pending_scheduling[idx].clear();
+                    },
+                }
+
+                Ok(())
+            },
+            EventCtrlRequest::Transfer { to } => {
+                // Check if the process owns the target scheduling event.
+                let pid: ProcessIdentifier = match pid {
+                    Some(pid) if self.scheduling_ownership[idx].contains(&pid) => pid,
+                    _ => {
+                        let reason: &str = "process does not own scheduling event";
+                        error!("do_evctrl_scheduling(): reason={:?}", reason);
+                        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                    },
+                };
+
+                // Check if the target process already subscribed to the scheduling event.
+                if self.scheduling_ownership[idx].contains(&to) {
+                    let reason: &str = "target process already subscribed to scheduling event";
+                    error!("do_evctrl_scheduling(): reason={:?}", reason);
+                    return Err(Error::new(ErrorCode::EntryExists, reason));
+                }
+
+                // Ensure that the target process has the required capabilities.
+                if !ProcessManager::has_capability(to, Capability::ProcessManagement)? {
+                    let reason: &str = "target process does not have scheduling control capability";
+                    error!("do_evctrl_scheduling(): reason={:?}", reason);
+                    return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                }
+
+                // Transfer scheduling event. Pending events are left queued for the new owner.
+                for owner in self.scheduling_ownership[idx].iter_mut() {
+                    if *owner == pid {
+                        *owner = to;
+                    }
+                }
+                for (recipient, _, _) in self.pending_scheduling[idx].iter_mut() {
+                    if *recipient == pid {
+                        *recipient = to;
+                    }
+                }
+
+                Ok(())
+            },
+            EventCtrlRequest::RegisterDefault | EventCtrlRequest::UnregisterDefault => {
+                let reason: &str = "default handlers are only supported for exceptions";
+                error!("do_evctrl_scheduling(): reason={:?}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+            EventCtrlRequest::Monitor | EventCtrlRequest::Unmonitor => {
+                let reason: &str = "monitors are only supported for interrupts";
+                error!("do_evctrl_scheduling(): reason={:?}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+            EventCtrlRequest::Coalesce | EventCtrlRequest::Uncoalesce => {
+                let reason: &str = "coalescing is only supported for interrupts";
+                error!("do_evctrl_scheduling(): reason={:?}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Removes the subscription of a process to a scheduling event, discarding the events that are
+    /// pending delivery to it.
+    ///
+    /// # Parameters
+    ///
+    /// - `idx`: Index of the scheduling event.
+    /// - `pid`: Identifier of the subscriber.
+    ///
+    fn unsubscribe_scheduling(&mut self, idx: usize, pid: ProcessIdentifier) {
+        self.scheduling_ownership[idx].retain(|owner| *owner != pid);
+        let outstanding: &mut BTreeSet<usize> = &mut self.outstanding;
+        self.pending_scheduling[idx].retain(|(recipient, evdesc, _)| {
+            if *recipient == pid {
+                outstanding.remove(&evdesc.id());
+                return false;
+            }
+            true
+        });
+    }
+
+    fn interrupt_pending(&self, pid: ProcessIdentifier, idx: usize) -> Result<bool, Error> {
+        // Check if target interrupt line is not owned by the process.
+        if self.interrupt_ownership[idx] != Some(pid) {
+            let reason: &str = "process does not own interrupt";
+            error!("interrupt_pending(): reason={:?}", reason);
+            return Err(Error::new(ErrorCode::PermissionDenied, reason));
+        }
+
+        Ok(!self.pending_interrupts[idx].is_empty())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Counts the interrupts, exceptions and scheduling events that are pending on a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// The number of pending events of each class. IPC messages are not counted.
+    ///
+    fn pending_counts(&self, pid: ProcessIdentifier) -> PendingCounts {
+        let interrupts: usize = self
+            .pending_interrupts
+            .iter()
+            .zip(self.interrupt_ownership.iter())
+            .filter(|(_, owner)| **owner == Some(pid))
+            .map(|(pending, _)| pending.len())
+            .sum::<usize>()
+            + self
+                .monitored_interrupts
+                .iter()
+                .filter(|(p, _)| *p == pid)
+                .count();
+
+        let exceptions: usize = self
+            .pending_exceptions
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| self.exception_ownership.owner(*idx) == Some(pid))
+            .map(|(_, pending)| pending.len())
+            .sum();
+
+        let scheduling: usize = self
+            .pending_scheduling
+            .iter()
+            .flatten()
+            .filter(|(recipient, _, _)| *recipient == pid)
+            .count()
+            + usize::from(self.signals.is_pending(pid));
+
+        PendingCounts {
+            interrupts,
+            exceptions,
+            scheduling,
+            ipc: 0,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Collects the events that are owned by a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// The set of events that are owned by the target process. Monitored interrupts are not
+    /// included.
+    ///
+    fn owned_events(&self, pid: ProcessIdentifier) -> OwnedEvents {
+        let mut owned: OwnedEvents = OwnedEvents {
+            exceptions: self.exception_ownership.owned_by(pid),
+            ..OwnedEvents::default()
+        };
+
+        for (idx, owner) in self.interrupt_ownership.iter().enumerate() {
+            if *owner == Some(pid) {
+                owned.interrupts |= 1 << idx;
+            }
+        }
+
+        for (idx, owners) in self.scheduling_ownership.iter().enumerate() {
+            if owners.contains(&pid) {
+                owned.scheduling |= 1 << idx;
+            }
+        }
+
+        owned
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the classes of events in the order in which they are checked for delivery. The
+    /// order is rotated as events are triggered, so that no class is always checked first.
+    ///
+    /// # Parameters
+    ///
+    /// - `nevents`: Identifier of the last event.
+    ///
+    /// # Returns
+    ///
+    /// An iterator that visits each class of events exactly once.
+    ///
+    fn round_robin(nevents: usize) -> impl Iterator<Item = EventClass> {
+        let first: usize = nevents % EventClass::COUNT;
+        (0..EventClass::COUNT).map(move |i| EventClass::VALUES[(first + i) % EventClass::COUNT])
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the lines of an event category in the order in which they are checked for delivery.
+    /// Lines are visited starting after the one that was served last.
+    ///
+    /// # Parameters
+    ///
+    /// - `last`: Line that was served last.
+    ///
+    /// # Returns
+    ///
+    /// An iterator that visits each line exactly once.
+    ///
+    fn rotate(last: usize) -> impl Iterator<Item = usize> {
+        let nlines: usize = usize::BITS as usize;
+        (1..=nlines).map(move |i| (last + i) % nlines)
+    }
+
+    pub fn try_wait(
+        &mut self,
+        pid: ProcessIdentifier,
+        interrupts: usize,
+        exceptions: usize,
+        scheduling: usize,
+        ipc: bool,
+    ) -> Result<Option<(EventClass, Message)>, Error> {
+        self.try_wait_with(pid, interrupts, exceptions, scheduling, ipc, ProcessManager::try_recv)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Attempts to deliver an event to a process, polling for messages with a given function.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    /// - `interrupts`: Interrupt lines that may be delivered.
+    /// - `exceptions`: Exceptions that may be delivered.
+    /// - `scheduling`: Scheduling events that may be delivered.
+    /// - `ipc`: Whether messages may be delivered.
+    /// - `recv`: Function that polls for a message.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the class of the delivered event and the message that describes it are
+    /// returned, or `None` if no event is pending. Upon failure, an error is returned instead.
+    ///
+    pub(super) fn try_wait_with(
+        &mut self,
+        pid: ProcessIdentifier,
+        interrupts: usize,
+        exceptions: usize,
+        scheduling: usize,
+        ipc: bool,
+        mut recv: impl FnMut() -> Result<Option<Message>, Error>,
+    ) -> Result<Option<(EventClass, Message)>, Error> {
+        for class in Self::round_robin(self.nevents) {
+            let message: Option<Message> = match class {
+                EventClass::Interrupt => self.try_wait_interrupt(pid, interrupts)?,
+                EventClass::Exception => self.try_wait_exception(pid, exceptions),
+                EventClass::Scheduling => self.try_wait_scheduling(pid, scheduling),
+                EventClass::Ipc if ipc => recv()?,
+                EventClass::Ipc => None,
+            };
+
+            if let Some(message) = message {
+                return Ok(Some((class, message)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn try_wait_interrupt(
+        &mut self,
+        pid: ProcessIdentifier,
+        interrupts: usize,
+    ) -> Result<Option<Message>, Error> {
+        for idx in Self::rotate(self.last_interrupt_idx) {
+            if (interrupts & (1 << idx)) != 0 {
+                let ev: Event = Event::from(InterruptEvent::try_from(idx)?);
+
+                // Check if the process owns the line, or only monitors it.
+                let event: Option<(EventDescriptor, usize)> =
+                    if self.interrupt_ownership[idx] == Some(pid) {
+                        self.pending_interrupts[idx].pop_front()
+                    } else {
+                        self.monitored_interrupts
+                            .iter()
+                            .position(|(p, evdesc)| *p == pid && evdesc.event() == ev)
+                            .map(|at| (self.monitored_interrupts.remove(at).1, 1))
+                    };
+
+                if let Some((evdesc, count)) = event {
+                    self.outstanding.remove(&evdesc.id());
+                    self.last_interrupt_idx = idx;
+                    let info: InterruptMessagePayload =
+                        InterruptMessagePayload::new(idx, evdesc.id(), count);
+                    let message: Message = Message {
+                        source: ProcessIdentifier::KERNEL,
+                        destination: pid,
+                        message_type: MessageType::Interrupt,
+                        payload: {
+                            let mut payload: [u8; Message::PAYLOAD_SIZE] =
+                                [0u8; Message::PAYLOAD_SIZE];
+                            payload[0..core::mem::size_of::<InterruptMessagePayload>()]
+                                .copy_from_slice(&info.to_ne_bytes());
+                            payload
+                        },
+                        ..Message::default()
+                    };
+                    return Ok(Some(message));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn try_wait_exception(&mut self, pid: ProcessIdentifier, exceptions: usize) -> Option<Message> {
+        for idx in Self::rotate(self.last_exception_idx) {
+            if (exceptions & (1 << idx)) != 0 {
+                if let Some(entry) = self.pending_exceptions[idx].pop_front() {
+                    self.last_exception_idx = idx;
+                    let mut info: EventInformation = EventInformation::default();
+                    info.id = entry.0.clone();
+                    info.pid = entry.1.pid;
+                    info.number = Some(entry.1.info.num() as usize);
+                    info.code = Some(entry.1.info.code() as usize);
+                    info.address = Some(entry.1.info.addr() as usize);
+                    info.instruction = Some(entry.1.info.instruction() as usize);
+
+                    let mut message: Message = Message::from(info);
+                    message.destination = pid;
+                    message.message_type = MessageType::Exception;
+
+                    self.pending_exceptions[idx].push_back(entry);
+
+                    return Some(message);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn try_wait_scheduling(
+        &mut self,
+        pid: ProcessIdentifier,
+        scheduling: usize,
+    ) -> Option<Message> {
+        for i in 0..SchedulingEvent::NUMBER_EVENTS {
+            if (scheduling & (1 << i)) != 0 {
+                // Signals are coalesced in a bitmask, instead of being queued.
+                if i == SchedulingEvent::Signal as usize {
+                    if let Some(signals) = self.signals.take(pid) {
+                        let message: Message = Message {
+                            source: ProcessIdentifier::KERNEL,
+                            destination: pid,
+                            message_type: MessageType::SchedulingEvent,
+                            status: u32::from(SchedulingEvent::Signal) as i32,
+                            payload: {
+                                let mut payload: [u8; Message::PAYLOAD_SIZE] =
+                                    [0u8; Message::PAYLOAD_SIZE];
+                                payload[0..core::mem::size_of::<u32>()]
+                                    .copy_from_slice(&signals.to_ne_bytes());
+                                payload
+                            },
+                        };
+
+                        return Some(message);
+                    }
+                    continue;
+                }
+
+                let event: Option<usize> = self.pending_scheduling[i]
+                    .iter()
+                    .position(|(recipient, _, _)| *recipient == pid);
+                if let Some(at) = event {
+                    let (_recipient, evdesc, info) = self.pending_scheduling[i].remove(at);
+                    self.outstanding.remove(&evdesc.id());
+                    let message: Message = Message {
+                        source: ProcessIdentifier::KERNEL,
+                        destination: pid,
+                        message_type: MessageType::SchedulingEvent,
+                        status: 0,
+                        payload: {
+                            let mut payload: [u8; Message::PAYLOAD_SIZE] =
+                                [0u8; Message::PAYLOAD_SIZE];
+                            payload[0..core::mem::size_of::<ProcessTerminationInfo>()]
+                                .copy_from_slice(&info.to_ne_bytes());
+                            payload
+                        },
+                    };
+
+                    return Some(message);
+                }
+            }
+        }
+
+        None
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Resumes the process that triggered an exception, optionally raising signals on it. Signals
+    /// are made pending before the process is resumed, so that the process observes them as soon
+    /// as it runs again.
+    ///
+    /// # Parameters
+    ///
+    /// - `ev`: Exception event.
+    /// - `id`: Identifier of the exception event.
+    /// - `signals`: Bitmask of signals to raise on the resumed process, or zero for none.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    fn resume_exception(
+        &mut self,
+        pid: ProcessIdentifier,
+        ev: ExceptionEvent,
+        id: usize,
+        signals: u32,
+    ) -> Result<(), Error> {
+        let idx: usize = usize::from(ev);
+
+        // Check if the exception is owned by another process. Only the owner may resume the
+        // faulting process, as it may also raise signals on it.
+        if let Some(owner) = self.exception_ownership.owner(idx) {
+            if owner != pid {
+                let reason: &str = "process does not own exception";
+                error!("resume_exception(): reason={:?}, pid={:?}", reason, pid);
+                return Err(Error::new(ErrorCode::PermissionDenied, reason));
+            }
+        }
+
+        // Search and remove event from pending exceptions.
+        if let Some(entry) = self.pending_exceptions[idx]
+            .iter()
+            .position(|(evdesc, _info, _resume)| evdesc.id() == id)
+        {
+            let (_enventinfo, excpinfo, resume) = self.pending_exceptions[idx].remove(entry);
+            self.outstanding.remove(&id);
+
+            // Check if the exception is no longer owned. If so, the faulting process cannot be
+            // handled, so its wait is canceled and it terminates itself.
+            if self.exception_ownership.owner(idx).is_none() {
+                let reason: &str = "no owner for exception";
+                error!("resume_exception(): reason={:?}, pid={:?}", reason, excpinfo.pid);
+                resume.cancel(excpinfo.pid)?;
+                return Err(Error::new(ErrorCode::NoSuchEntry, reason));
+            }
+
+            // Raise signals before the faulting process may run again.
+            if signals != 0 {
+                self.raise_signals(excpinfo.pid, signals)?;
+            }
+
+            if let Err(e) = resume.notify_process(excpinfo.pid) {
+                error!(
+                    "resume_exception(): failed to resume (pid={:?}, error={:?})",
+                    excpinfo.pid, e
+                );
+                resume.cancel(excpinfo.pid)?;
+                return Err(e);
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates an identifier for a new event. Identifiers wrap around at
+    /// [`EventDescriptor::MAX_ID`], skipping identifiers of outstanding events. The identifier is
+    /// outstanding until the event is released.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the event identifier is returned. Upon failure, an error is returned instead.
+    ///
+    fn next_event_id(&mut self) -> Result<usize, Error> {
+        // Check if all identifiers are in use.
+        if self.outstanding.len() >= EventDescriptor::MAX_ID {
+            let reason: &str = "too many outstanding events";
+            error!("next_event_id(): reason={:?}", reason);
+            return Err(Error::new(ErrorCode::TryAgain, reason));
+        }
+
+        for _ in 0..EventDescriptor::MAX_ID {
+            self.nevents = if self.nevents >= EventDescriptor::MAX_ID {
+                1
+            } else {
+                self.nevents + 1
+            };
+
+            if self.outstanding.insert(self.nevents) {
+                return Ok(self.nevents);
+            }
+        }
+
+        let reason: &str = "too many outstanding events";
+        error!("next_event_id(): reason={:?}", reason);
+        Err(Error::new(ErrorCode::TryAgain, reason))
+    }
+
+    fn wakeup_interrupt(&mut self, interrupts: usize) -> Result<(), Error> {
+        // Check if an spurious interrupt was received.
+        if !self.interrupt_capable {
+            let reason: &str = "interrupt manager is not capable of handlin ginterrupts";
+            error!("wakeup_interrupt(): reason={:?}", reason);
+            return Err(Error::new(ErrorCode::OperationNotSupported, reason));
+        }
+
+        // Check if the bitmask does not select exactly one interrupt.
+        if interrupts.count_ones() != 1 {
+            let reason: &str = "invalid interrupt bitmask";
+            error!("wakeup_interrupt(): reason={:?}, interrupts={:#x}", reason, interrupts);
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
+        }
+
+        let idx: usize = interrupts.trailing_zeros() as usize;
+        let ev = Event::from(sys::event::InterruptEvent::try_from(idx)?);
+
+        // Get interrupt owner. Interrupts on lines that have no owner are dropped right away, so
+        // that they do not hold on to kernel memory nor event identifiers.
+        let pid: ProcessIdentifier = match self.interrupt_ownership[idx] {
+            Some(owner) => owner,
+            None => {
+                let reason: &str = "no owner for interrupt";
+                error!("wakeup_interrupt(): reason={:?}", reason);
+                return Err(Error::new(ErrorCode::NoSuchProcess, reason));
+            },
+        };
+
+        // Deliver a copy of the interrupt to monitors.
+        let monitors: Vec<ProcessIdentifier> = self
+            .interrupt_monitors
+            .iter()
+            .filter(|(_, i)| *i == idx)
+            .map(|(pid, _)| *pid)
+            .collect();
+        for pid in monitors {
+            // Check if too many copies are pending delivery to the monitor. Monitors only observe
+            // the line, thus a copy is dropped instead of delaying delivery to the owner.
+            let npending: usize = self
+                .monitored_interrupts
+                .iter()
+                .filter(|(p, _)| *p == pid)
+                .count();
+            if npending >= config::kernel::MAX_MONITORED_INTERRUPTS {
+                warn!("wakeup_interrupt(): dropping monitored interrupt (pid={:?})", pid);
+                continue;
+            }
+
+            let id: usize = self.next_event_id()?;
+            self.monitored_interrupts
+                .push_back((pid, EventDescriptor::new(id, ev)));
+            if let Err(e) = self.get_wait().notify_process(pid) {
+                warn!(
+                    "wakeup_interrupt(): failed to wake up monitor (pid={:?}, error={:?})",
+                    pid, e
+                );
+            }
+        }
+
+        // Coalesce the interrupt into the last pending one, if the line is configured to do so.
+        match self.pending_interrupts[idx].back_mut() {
+            Some((_, count)) if (self.coalesced_interrupts & (1 << idx)) != 0 => {
+                *count = count.saturating_add(1);
+            },
+            // Check if too many interrupts are pending delivery on the line. The owner was already
+            // woken up by pending ones, thus the interrupt is dropped.
+            _ if self.pending_interrupts[idx].len() >= config::kernel::MAX_PENDING_INTERRUPTS => {
+                warn!("wakeup_interrupt(): dropping interrupt (idx={:?}, pid={:?})", idx, pid);
+                return Ok(());
+            },
+            _ => {
+                let id: usize = self.next_event_id()?;
+                self.pending_interrupts[idx].push_back((EventDescriptor::new(id, ev), 1));
+            },
+        }
+
+        self.get_wait().notify_process(pid)
+    }
+
+    fn wakeup_exception(
+        &mut self,
+        exceptions: usize,
+        pid: ProcessIdentifier,
+        info: &ExceptionInformation,
+    ) -> Result<Rc<Condvar>, Error> {
+        trace!("wakeup_exception(): exceptions={:#x}, pid={:?}, info={:?}", exceptions, pid, info);
+        let idx: usize = exceptions.trailing_zeros() as usize;
+
+        // Check if too many exceptions of this type are pending.
+        if self.pending_exceptions[idx].len() >= config::kernel::MAX_PENDING_EXCEPTIONS {
+            let reason: &str = "too many pending exceptions";
+            error!("wakeup_exception(): reason={:?}, pid={:?}", reason, pid);
+            return Err(Error::new(ErrorCode::TryAgain, reason));
+        }
+
+        // Get exception owner.
+        let owner: ProcessIdentifier = match self.exception_ownership.owner(idx) {
+            Some(owner) => owner,
+            None => {
+                let reason: &str = "no owner for exception";
+                error!("wakeup_exception(): reason={:?}, pid={:?}", reason, pid);
+                return Err(Error::new(ErrorCode::NoSuchEntry, reason));
+            },
+        };
+
+        let id: usize = self.next_event_id()?;
+        let ev: Event = Event::from(ExceptionEvent::try_from(idx)?);
+        let eventid: EventDescriptor = EventDescriptor::new(id, ev);
+        let resume: Rc<Condvar> = Rc::new(Condvar::new());
+        self.pending_exceptions[idx].push_back((
+            eventid,
+            ExceptionEventInformation {
+                pid,
+                info: info.clone(),
+            },
+            resume.clone(),
+        ));
+
+        // Notify exception owner.
+        if let Err(e) = self.get_wait().notify_process(owner) {
+            error!("wakeup_exception(): failed to notify owner (owner={:?}, error={:?})", owner, e);
+            // NOTE: the exception was pushed last, thus it is safe to pop it.
+            self.pending_exceptions[idx].pop_back();
+            self.outstanding.remove(&id);
+            return Err(e);
+        }
+
+        Ok(resume)
+    }
+
+    fn post_message(
+        &mut self,
+        pm: &mut ProcessManager,
+        pid: ProcessIdentifier,
+        message: Message,
+    ) -> Result<(), Error> {
+        pm.post_message(pid, message)?;
+
+        self.get_wait().notify_process(pid)
+    }
+
+    fn raise_signals(&mut self, pid: ProcessIdentifier, signals: u32) -> Result<(), Error> {
+        let new: u32 = self.signals.raise(pid, signals);
+
+        trace!("raise_signals(): pid={:?}, signals={:#x}, new={:#x}", pid, signals, new);
+        self.get_wait().notify_process(pid)
+    }
+
+    fn notify_process_termination(
+        &mut self,
+        info: ProcessTerminationInfo,
+        watchers: &[ProcessIdentifier],
+    ) -> Result<(), Error> {
+        let idx: usize = SchedulingEvent::ProcessTermination as usize;
+
+        // Discard signals and canceled waits of the terminated process.
+        self.signals.clear(info.pid);
+        self.get_wait().release_process(info.pid);
+        for pending in self.pending_exceptions.iter() {
+            for (_, _, resume) in pending.iter() {
+                resume.release_process(info.pid);
+            }
+        }
+
+        // Discard subscriptions of the terminated process.
+        if self.scheduling_ownership[idx].contains(&info.pid) {
+            self.unsubscribe_scheduling(idx, info.pid);
+        }
+
+        // Deliver the event to every subscriber and to every watcher of the terminated process.
+        let mut recipients: Vec<ProcessIdentifier> =
+            self.scheduling_ownership[idx].iter().copied().collect();
+        for watcher in watchers.iter() {
+            if *watcher != info.pid && !recipients.contains(watcher) {
+                recipients.push(*watcher);
+            }
+        }
+
+        // Check if scheduling event has no recipients.
+        if recipients.is_empty() {
+            let reason: &str = "no owner for scheduling event";
+            error!("notify_process_termination(): reason={:?}", reason);
+            return Err(Error::new(ErrorCode::NoSuchProcess, reason));
+        }
+
+        // Enqueue a copy of the event for every recipient.
+        for pid in recipients.iter() {
+            let id: usize = self.next_event_id()?;
+            let ev: Event = Event::from(SchedulingEvent::ProcessTermination);
+            let eventid: EventDescriptor = EventDescriptor::new(id, ev);
+            self.pending_scheduling[idx].push_back((*pid, eventid, info));
+        }
+
+        // Wake up every recipient. A recipient that fails to be woken up still observes the
+        // event in its next wait, thus failures do not prevent others from being woken up.
+        for pid in recipients {
+            trace!("notify_process_termination(): pid={:?}, info={:?}", pid, info);
+            if let Err(e) = self.get_wait().notify_process(pid) {
+                warn!(
+                    "notify_process_termination(): failed to wake up (pid={:?}, error={:?})",
+                    pid, e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn cancel_wait(&self, pid: ProcessIdentifier) -> Result<(), Error> {
+        self.get_wait().cancel(pid)?;
+
+        // Cancel pending resumes.
+        for pending in self.pending_exceptions.iter() {
+            for (_, _, resume) in pending.iter() {
+                resume.cancel(pid)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_wait(&self) -> &Rc<Condvar> {
+        // NOTE: it is safe to unwrap because the wait field is always Some.
+        self.wait.as_ref().unwrap()
+    }
+}
+
+//==================================================================================================
+// Event Manager
+//==================================================================================================
+
+pub struct EventManager(RefCell<EventManagerInner>);
+
+impl EventManager {
+    ///
+    /// # Description
+    ///
+    /// Resumes an event. When resuming an exception, signals may also be raised on the faulting
+    /// process, atomically with the resume.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Identifier of the calling process.
+    /// - `evdesc`: Descriptor of the event.
+    /// - `signals`: Bitmask of signals to raise on the resumed process, or zero for none.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn resume(
+        pid: ProcessIdentifier,
+        evdesc: EventDescriptor,
+        signals: u32,
+    ) -> Result<(), Error> {
+        trace!("do_resume(): pid={:?}, evdesc={:?}, signals={:#x}", pid, evdesc, signals);
+
+        // Check if signals are supplied for an event that does not resume a process.
+        if signals != 0 && !matches!(evdesc.event(), Event::Exception(_)) {
+            let reason: &str = "signals may only be raised when resuming exceptions";
+            error!("do_resume(): {}", reason);
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
+        }
+
+        match evdesc.event() {
+            Event::Interrupt(_ev) => {
+                // No further action is required for interrupts.
+                Ok(())
+            },
+            Event::Exception(ev) => EventManager::get()?.try_borrow_mut()?.resume_exception(
+                pid,
+                ev,
+                evdesc.id(),
+                signals,
+            ),
+            Event::Scheduling(_ev) => {
+                // No further action is required for scheduling events.
+                Ok(())
+            },
+        }
+    }
+
+    pub fn wait(pid: ProcessIdentifier) -> Result<Message, Error> {
+        let (_class, message): (EventClass, Message) = Self::wait_which(pid)?;
+        Ok(message)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Waits for an event to be delivered to a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the class of the event that was delivered and the message that describes it
+    /// are returned. Upon failure, an error is returned instead.
+    ///
+    pub fn wait_which(pid: ProcessIdentifier) -> Result<(EventClass, Message), Error> {
+        let event: Option<(EventClass, Message)> = Self::do_wait(pid, None, &EventMask::ALL, None)?;
+        // NOTE: it is safe to unwrap because waits without a deadline do not time out.
+        Ok(event.unwrap())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Waits for one of the selected events to be delivered to a process. Events that are not
+    /// selected are left pending, so that they are delivered by subsequent waits.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    /// - `mask`: Events to wait for.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the message that describes the delivered event is returned. Upon failure, an
+    /// error is returned instead.
+    ///
+    #[allow(dead_code)]
+    pub fn wait_for(pid: ProcessIdentifier, mask: EventMask) -> Result<Message, Error> {
+        let event: Option<(EventClass, Message)> = Self::do_wait(pid, None, &mask, None)?;
+        // NOTE: it is safe to unwrap because waits without a deadline do not time out.
+        let (_class, message): (EventClass, Message) = event.unwrap();
+        Ok(message)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Waits for an event to be delivered to a process, for at most a given number of timer ticks.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    /// - `ticks`: Maximum number of timer ticks to wait for.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the message that describes the delivered event is returned, or `None` if no
+    /// event was delivered within `ticks` timer ticks. Upon failure, an error is returned instead.
+    ///
+    pub fn wait_timeout(pid: ProcessIdentifier, ticks: u64) -> Result<Option<Message>, Error> {
+        let deadline: u64 = time::ticks().saturating_add(ticks);
+        let event: Option<(EventClass, Message)> =
+            Self::do_wait(pid, None, &EventMask::ALL, Some(deadline))?;
+        Ok(event.map(|(_class, message)| message))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks whether events of a given type may be delivered as replies, so that callers may
+    /// reject a wait for a reply before taking any action that the reply answers.
+    ///
+    /// # Parameters
+    ///
+    /// - `message_type`: Expected type of the reply.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn check_reply_type(message_type: MessageType) -> Result<(), Error> {
+        EventMask::check_reply_type(message_type)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Waits for a reply of a given type to be delivered to a process. Events and messages of other
+    /// types are left pending, so that they are delivered by subsequent waits.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    /// - `message_type`: Expected type of the reply.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the message that describes the reply is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    pub fn wait_reply(pid: ProcessIdentifier, message_type: MessageType) -> Result<Message, Error> {
+        let event: Option<(EventClass, Message)> =
+            Self::do_wait(pid, Some(message_type), &EventMask::ALL, None)?;
+        // NOTE: it is safe to unwrap because waits without a deadline do not time out.
+        let (_class, message): (EventClass, Message) = event.unwrap();
+        Ok(message)
+    }
+
+    fn do_wait(
+        pid: ProcessIdentifier,
+        message_type: Option<MessageType>,
+        mask: &EventMask,
+        deadline: Option<u64>,
+    ) -> Result<Option<(EventClass, Message)>, Error> {
+        trace!(
+            "do_wait(): pid={:?}, message_type={:?}, mask={:?}, deadline={:?}",
+            pid,
+            message_type,
+            mask,
+            deadline
+        );
+
+        // Get the interrupts that the process owns. Lines above the highest registered one are
+        // not owned by any process, thus they are not scanned.
+        let mut interrupts: usize = 0;
+        let nlines: usize = Self::max_registered_interrupt()?.map_or(0, |max| max + 1);
+        for idx in 0..nlines {
+            if let Some(p) = EventManager::get()?.try_borrow_mut()?.interrupt_ownership[idx] {
+                if p == pid {
+                    interrupts |= 1 << idx;
+                }
+            }
+        }
+
+        // Get the interrupts that the process monitors.
+        for (p, idx) in EventManager::get()?
+            .try_borrow_mut()?
+            .interrupt_monitors
+            .iter()
+        {
+            if *p == pid {
+                interrupts |= 1 << *idx;
+            }
+        }
+
+        // Get the exceptions that are delivered to the process.
+        let exceptions: usize = EventManager::get()?
+            .try_borrow_mut()?
+            .exception_ownership
+            .owned_by(pid);
+
+        // Get the scheduling events that the process owns. Signals are always delivered, and so
+        // are process terminations, because these are queued only for processes that subscribed
+        // to them or that watch the terminated process.
+        let mut scheduling: usize = (1 << SchedulingEvent::Signal as usize)
+            | (1 << SchedulingEvent::ProcessTermination as usize);
+        for i in 0..SchedulingEvent::NUMBER_EVENTS {
+            if EventManager::get()?.try_borrow_mut()?.scheduling_ownership[i].contains(&pid) {
+                scheduling |= 1 << i;
+            }
+        }
+
+        // Filter out events that are not selected or that do not match the expected reply type.
+        let owned: EventMask = EventMask {
+            interrupts,
+            exceptions,
+            scheduling,
+            ipc: true,
+        };
+        let selected: EventMask = owned.intersection(mask).for_reply(message_type)?;
+
+        let wait: Rc<Condvar> = EventManager::get()?.try_borrow_mut()?.get_wait().clone();
+
+        loop {
+            let event: Option<(EventClass, Message)> =
+                EventManager::get()?.try_borrow_mut()?.try_wait(
+                    pid,
+                    selected.interrupts,
+                    selected.exceptions,
+                    selected.scheduling,
+                    selected.ipc,
+                )?;
+
+            if let Some(event) = event {
+                break Ok(Some(event));
+            }
+
+            match deadline {
+                Some(deadline) => {
+                    // Check if the deadline has expired.
+                    let now: u64 = time::ticks();
+                    if now >= deadline {
+                        break Ok(None);
+                    }
+
+                    wait.wait_timeout(deadline - now)?;
+                },
+                None => wait.wait()?,
+            }
+        }
+    }
+
+    pub fn evctrl(
+        pid: ProcessIdentifier,
+        ev: Event,
+        req: EventCtrlRequest,
+    ) -> Result<Option<EventOwnership>, Error> {
+        trace!("do_evctrl(): ev={:?}, req={:?}", ev, req);
+
+        let em: &'static mut EventManager = EventManager::get_mut()?;
+
+        match ev {
+            Event::Interrupt(interrupt_event) => {
+                // Check if the interrupt manager is capable of handling interrupts.
+                if !em.try_borrow_mut()?.interrupt_capable {
+                    let reason: &str = "interrupt manager is not capable of handlin ginterrupts";
+                    error!("do_evctrl(): {:?} (reason={:?})", reason, req);
+                    return Err(Error::new(ErrorCode::OperationNotSupported, reason));
+                }
+                em.try_borrow_mut()?
+                    .do_evctrl_interrupt(Some(pid), interrupt_event, req)?;
+            },
+            Event::Exception(exception_event) => {
+                em.try_borrow_mut()?
+                    .do_evctrl_exception(Some(pid), exception_event, req)?;
+            },
+            Event::Scheduling(scheduling_event) => {
+                em.try_borrow_mut()?
+                    .do_evctrl_scheduling(Some(pid), scheduling_event, req)?;
+            },
+        }
+
+        let kind: OwnershipKind = match req {
+            EventCtrlRequest::Register => OwnershipKind::Exclusive,
+            EventCtrlRequest::RegisterDefault => OwnershipKind::Default,
+            EventCtrlRequest::Monitor => OwnershipKind::Monitor,
+            EventCtrlRequest::Unregister
+            | EventCtrlRequest::UnregisterDefault
+            | EventCtrlRequest::Unmonitor
+            | EventCtrlRequest::Transfer { .. }
+            | EventCtrlRequest::Coalesce
+            | EventCtrlRequest::Uncoalesce => return Ok(None),
+        };
+
+        Ok(Some(EventOwnership { ev, kind, pid, em }))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks whether a process owns an interrupt line.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    /// - `ev`: Interrupt event.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `true` is returned if the process owns the interrupt line and `false`
+    /// otherwise. Upon failure, an error is returned instead.
+    ///
+    pub fn owns_interrupt(pid: ProcessIdentifier, ev: InterruptEvent) -> Result<bool, Error> {
+        let idx: usize = usize::from(ev);
+        Ok(Self::get()?.try_borrow_mut()?.interrupt_ownership[idx] == Some(pid))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks whether an interrupt is pending on a line that is owned by a process, without
+    /// consuming it. This enables drivers to poll for work before deciding to wait.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    /// - `ev`: Interrupt event.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `true` is returned if an interrupt is pending on the line and `false`
+    /// otherwise. Upon failure, an error is returned instead.
+    ///
+    #[allow(dead_code)]
+    pub fn interrupt_pending(pid: ProcessIdentifier, ev: InterruptEvent) -> Result<bool, Error> {
+        Self::get()?
+            .try_borrow_mut()?
+            .interrupt_pending(pid, usize::from(ev))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Counts the events of each class that are pending on a process, so that it may apply
+    /// backpressure when its queues are deep. All counts are taken under a single borrow of the
+    /// event manager, so that they form a consistent snapshot.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the number of pending events of each class is returned. Upon failure, an
+    /// error is returned instead.
+    ///
+    pub fn pending_counts(pid: ProcessIdentifier) -> Result<PendingCounts, Error> {
+        let em: RefMut<EventManagerInner> = Self::get()?.try_borrow_mut()?;
+        let mut counts: PendingCounts = em.pending_counts(pid);
+        counts.ipc = ProcessManager::number_pending_messages(pid)?;
+        Ok(counts)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Lists the events that are owned by a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the set of events that are owned by the target process is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn owned_events(pid: ProcessIdentifier) -> Result<OwnedEvents, Error> {
+        Ok(Self::get()?.try_borrow_mut()?.owned_events(pid))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the highest interrupt line that is owned by some process.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the highest interrupt line that is owned by some process is returned, or
+    /// `None` if no interrupt line is owned. Upon failure, an error is returned instead.
+    ///
+    pub fn max_registered_interrupt() -> Result<Option<usize>, Error> {
+        Ok(Self::get()?.try_borrow_mut()?.max_registered_interrupt)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Registers a kernel callback for an interrupt line. The callback coexists with the userland
+    /// owner of the line, if any, and runs first whenever the interrupt is triggered.
+    ///
+    /// # Parameters
+    ///
+    /// - `intnum`: Interrupt number.
+    /// - `callback`: Kernel callback.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    /// # Notes
+    ///
+    /// - Callbacks may be registered before the event manager is initialized.
+    ///
+    pub fn register_interrupt_callback(
+        intnum: InterruptNumber,
+        callback: InterruptCallback,
+    ) -> Result<(), Error> {
+        let idx: usize = intnum as usize;
+
+        // Safety: callbacks are only registered by the kernel thread.
+        unsafe {
+            // Check if another callback is already registered for the target interrupt.
+            let registered: Option<InterruptCallback> = INTERRUPT_CALLBACKS[idx];
+            if registered.is_some() {
+                let reason: &str = "interrupt callback already registered";
+                error!("register_interrupt_callback(): reason={:?}", reason);
+                return Err(Error::new(ErrorCode::ResourceBusy, reason));
+            }
+
+            INTERRUPT_CALLBACKS[idx] = Some(callback);
+        }
+
+        Ok(())
+    }
+
+    pub fn post_message(
+        pm: &mut ProcessManager,
+        pid: ProcessIdentifier,
+        message: Message,
+    ) -> Result<(), Error> {
+        Self::get_mut()?
+            .try_borrow_mut()?
+            .post_message(pm, pid, message)
+    }
+
+    pub fn notify_process_termination(
+        info: ProcessTerminationInfo,
+        watchers: &[ProcessIdentifier],
+    ) -> Result<(), Error> {
+        Self::get_mut()?
+            .try_borrow_mut()?
+            .notify_process_termination(info, watchers)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Raises signals on a process. Signals that are already pending are coalesced, and the
+    /// process observes all pending signals at once in its next wait.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Target process.
+    /// - `signals`: Bitmask of signals to raise.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. Otherwise, an error is returned instead.
+    ///
+    pub fn raise_signals(pid: ProcessIdentifier, signals: u32) -> Result<(), Error> {
+        Self::get_mut()?
+            .try_borrow_mut()?
+            .raise_signals(pid, signals)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Cancels any wait of a process on the event manager. This should be called when the process
+    /// is terminated, so that its waiting threads unwind instead of staying blocked forever.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. Otherwise, an error is returned instead.
+    ///
+    pub fn cancel_wait(pid: ProcessIdentifier) -> Result<(), Error> {
+        Self::get_mut()?.try_borrow_mut()?.cancel_wait(pid)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Wakes up processes whose timed wait on the event manager has expired.
+    ///
+    /// # Parameters
+    ///
+    /// - `now`: Current time, in timer ticks.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    /// # Notes
+    ///
+    /// - This function is called from the timer interrupt handler, which may fire before the
+    ///   event manager is initialized or while it is borrowed. In both cases, expired waits are
+    ///   handled on a later tick.
+    ///
+    pub fn expire_waits(now: u64) -> Result<(), Error> {
+        let wait: Rc<Condvar> = unsafe {
+            match MANAGER {
+                Some(ref em) => match em.0.try_borrow() {
+                    Ok(em) => em.get_wait().clone(),
+                    Err(_) => return Ok(()),
+                },
+                None => return Ok(()),
+            }
+        };
+
+        wait.expire(now)
+    }
+
+    fn try_borrow_mut(&self) -> Result<RefMut<EventManagerInner>, Error> {
+        match self.0.try_borrow_mut() {
+            Ok(em) => Ok(em),
+            Err(e) => {
+                let reason: &str = "failed to borrow event manager";
+                error!("try_borrow_mut(): {:?} (error={:?})", reason, e);
+                Err(Error::new(ErrorCode::PermissionDenied, reason))
+            },
+        }
+    }
+
+    fn get() -> Result<&'static EventManager, Error> {
+        unsafe {
+            match MANAGER {
+                Some(ref em) => Ok(em),
+                None => {
+                    let reason: &str = "event manager is not initialized";
+                    error!("get(): reason={:?}", reason);
+                    Err(Error::new(ErrorCode::TryAgain, reason))
+                },
+            }
+        }
+    }
+
+    fn get_mut() -> Result<&'static mut EventManager, Error> {
+        unsafe {
+            match MANAGER {
+                Some(ref mut em) => Ok(em),
+                None => {
+                    let reason: &str = "event manager is not initialized";
+                    error!("get_mut(): reason={:?}", reason);
+                    Err(Error::new(ErrorCode::TryAgain, reason))
+                },
+            }
+        }
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Runs the kernel callback of an interrupt line, if any. Note that the event manager should not be
+/// borrowed while the callback runs, because the callback may itself call into the event manager.
+///
+/// # Parameters
+///
+/// - `intnum`: Interrupt number.
+///
+/// # Returns
+///
+/// `true` if the interrupt should also be delivered to the userland owner of the line and `false`
+/// otherwise.
+///
+fn run_interrupt_callback(intnum: InterruptNumber) -> bool {
+    // Safety: callbacks are only registered by the kernel thread.
+    let callback: Option<InterruptCallback> = unsafe { INTERRUPT_CALLBACKS[intnum as usize] };
+    match callback {
+        Some(callback) => callback(intnum),
+        None => true,
+    }
+}
+
+fn interrupt_handler(intnum: InterruptNumber) {
+    trace!("interrupt_handler(): intnum={:?}", intnum);
+
+    // Run kernel callback first, and check if the interrupt should not be delivered to userland.
+    if !run_interrupt_callback(intnum) {
+        return;
+    }
+
+    match EventManager::get_mut() {
+        Ok(em) => match em.try_borrow_mut() {
+            Ok(mut em) => match em.wakeup_interrupt(1 << intnum as usize) {
+                Ok(()) => {},
+                Err(e) => {
+                    error!("failed to wake up event manager: {:?}", e);
+                },
+            },
+            Err(e) => {
+                error!("failed to borrow event manager: {:?}", e);
+            },
+        },
+        Err(e) => {
+            error!("failed to get event manager: {:?}", e);
+        },
+    }
+}
+
+fn exception_handler(info: &ExceptionInformation, _ctx: &ContextInformation) {
+    trace!("exception_handler(): info={:?}", info);
+    let pid: ProcessIdentifier = match ProcessManager::get_pid() {
+        Ok(pid) => pid,
+        Err(e) => {
+            error!("failed to get process identifier: {:?}", e);
+            return;
+        },
+    };
+
+    let resume: Result<Rc<Condvar>, Error> = match EventManager::get() {
+        Ok(em) => match em.try_borrow_mut() {
+            Ok(mut em) => em.wakeup_exception(1 << info.num() as usize, pid, info),
+            Err(e) => {
+                error!("failed to borrow event manager: {:?}", e);
+                return;
+            },
+        },
+        Err(e) => {
+            error!("failed to get event manager: {:?}", e);
+            return;
+        },
+    };
+
+    // Terminate the faulting process if the exception cannot be delivered.
+    let resume: Rc<Condvar> = match resume {
+        Ok(resume) => resume,
+        Err(e) => {
+            error!("failed to wake up event manager: {:?}", e);
+            terminate_faulting(pid, info.num() as u32, ProcessManager::fault);
+            return;
+        },
+    };
+
+    if resume.wait().is_err() {
+        terminate_faulting(pid, info.num() as u32, ProcessManager::fault);
+    }
+}
+
+///
+/// # Description
+///
+/// Terminates a process that raised an exception that could not be handled. If the process cannot
+/// be terminated right away, its termination is deferred until the next harvest of zombie
+/// processes, and the processor is yielded so that it does not run again meanwhile.
+///
+/// # Parameters
+///
+/// - `pid`: ID of the faulting process.
+/// - `exception`: Number of the exception.
+/// - `fault`: Function that terminates the calling process, which only returns on failure.
+///
+fn terminate_faulting(
+    pid: ProcessIdentifier,
+    exception: u32,
+    fault: impl FnOnce(u32) -> Result<!, Error>,
+) {
+    let result: Result<!, Error> = fault(exception);
+    error!("failed to terminate process, deferring (pid={:?}, result={:?})", pid, result);
+    ProcessManager::defer_fault(pid, exception);
+
+    // NOTE: if the processor cannot be yielded, the process faults again once it resumes.
+    if let Err(e) = ProcessManager::switch() {
+        error!("failed to yield processor: {:?}", e);
+    }
+}
+
+pub fn init(hal: &mut Hal) -> Result<(), Error> {
+    let mut interrupt_capable: bool = true;
+    let mut ninterrupts: usize = 0;
+
+    // TODO: add comments about safety.
+    unsafe {
+        hal.excpman.register_handler(exception_handler)?;
+    }
+
+    if let Some(intman) = &mut hal.intman {
+        ninterrupts = intman.number_of_lines();
+        // NOTE: the timer line is also handled here, and the scheduler tick runs as its callback.
+        for intnum in InterruptNumber::VALUES {
+            match intman.register_handler(intnum, interrupt_handler) {
+                Ok(()) => {
+                    if let Err(e) = intman.unmask(intnum) {
+                        warn!("failed to mask interrupt: {:?}", e);
+                    }
+                },
+                Err(e) => warn!("failed to register interrupt handler: {:?}", e),
+            }
+        }
+    } else {
+        warn!("no interrupt manager found, disabling interrupt support");
+        interrupt_capable = false;
+    }
+
+    let em: RefCell<EventManagerInner> =
+        RefCell::new(EventManagerInner::new(interrupt_capable, ninterrupts));
+
+    let manager: EventManager = EventManager(em);
+
+    unsafe {
+        MANAGER = Some(manager);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
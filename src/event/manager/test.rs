@@ -0,0 +1,1547 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    event::manager::{
+        self,
+        EventClass,
+        EventManager,
+        EventManagerInner,
+        EventMask,
+    },
+    hal::arch::{
+        ExceptionInformation,
+        InterruptNumber,
+    },
+    pm::ProcessManager,
+};
+use ::alloc::{
+    collections::VecDeque,
+    vec::Vec,
+};
+use ::core::{
+    mem,
+    sync::atomic::{
+        AtomicBool,
+        AtomicUsize,
+        Ordering,
+    },
+};
+use ::sys::{
+    config,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    event::{
+        Event,
+        EventCtrlRequest,
+        EventDescriptor,
+        ExceptionEvent,
+        InterruptEvent,
+        InterruptMessagePayload,
+        OwnedEvents,
+        PendingCounts,
+        ProcessTerminationInfo,
+        SchedulingEvent,
+        TerminationCause,
+    },
+    ipc::{
+        Message,
+        MessageType,
+    },
+    pm::{
+        ProcessIdentifier,
+        ThreadIdentifier,
+    },
+};
+
+//==================================================================================================
+// Global Variables
+//==================================================================================================
+
+/// Number of times that the test interrupt callback was invoked.
+static NCALLBACKS: AtomicUsize = AtomicUsize::new(0);
+
+/// Whether the test interrupt callback delivers interrupts to userland.
+static DELIVER: AtomicBool = AtomicBool::new(false);
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Kernel callback that counts the interrupts that it observes.
+fn count_interrupt(_intnum: InterruptNumber) -> bool {
+    NCALLBACKS.fetch_add(1, Ordering::Relaxed);
+    DELIVER.load(Ordering::Relaxed)
+}
+
+/// Tests if a kernel callback and a userland owner both observe an interrupt on a shared line.
+fn test_interrupt_callback() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let intnum: InterruptNumber = InterruptNumber::Free3;
+    let idx: usize = intnum as usize;
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[idx] = Some(pid);
+
+    // Register a kernel callback on the line, which is owned by a userland process.
+    if let Err(e) = EventManager::register_interrupt_callback(intnum, count_interrupt) {
+        error!("failed to register interrupt callback (error={:?})", e);
+        return false;
+    }
+
+    let passed: bool = check_interrupt_callback(&mut em, pid, intnum);
+
+    // Unregister the callback, so that it does not outlive the test.
+    // Safety: callbacks are only registered by the kernel thread.
+    unsafe { manager::INTERRUPT_CALLBACKS[idx] = None };
+
+    passed
+}
+
+/// Checks if an interrupt is observed by the kernel callback of its line and by its owner.
+fn check_interrupt_callback(
+    em: &mut EventManagerInner,
+    pid: ProcessIdentifier,
+    intnum: InterruptNumber,
+) -> bool {
+    let idx: usize = intnum as usize;
+
+    // Check if a second callback cannot be registered on the same line.
+    match EventManager::register_interrupt_callback(intnum, count_interrupt) {
+        Err(e) if e.code == ErrorCode::ResourceBusy => {},
+        result => {
+            error!("second interrupt callback was registered (result={:?})", result);
+            return false;
+        },
+    }
+
+    // Check if the interrupt handler runs the callback, which keeps the interrupt to the kernel.
+    DELIVER.store(false, Ordering::Relaxed);
+    NCALLBACKS.store(0, Ordering::Relaxed);
+    manager::interrupt_handler(intnum);
+    if NCALLBACKS.load(Ordering::Relaxed) != 1 {
+        error!("interrupt callback was not invoked by the interrupt handler");
+        return false;
+    }
+
+    // Check if both the callback and the owner observe an interrupt that is also delivered.
+    DELIVER.store(true, Ordering::Relaxed);
+    if !manager::run_interrupt_callback(intnum) {
+        error!("interrupt callback did not deliver interrupt to userland");
+        return false;
+    }
+    if let Err(e) = em.wakeup_interrupt(1 << idx) {
+        error!("failed to wake up interrupt (error={:?})", e);
+        return false;
+    }
+    if NCALLBACKS.load(Ordering::Relaxed) != 2 {
+        error!("interrupt callback was not invoked");
+        return false;
+    }
+    match em.try_wait(pid, 1 << idx, 0, 0, false) {
+        Ok(Some((EventClass::Interrupt, _))) => true,
+        result => {
+            error!("owner did not observe interrupt (result={:?})", result);
+            false
+        },
+    }
+}
+
+/// Tests if a pending interrupt can be observed without consuming it.
+fn test_interrupt_pending() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let ev: InterruptEvent = InterruptEvent::Interrupt1;
+    let idx: usize = usize::from(ev);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[idx] = Some(pid);
+
+    // Enqueue an interrupt.
+    let id: usize = match em.next_event_id() {
+        Ok(id) => id,
+        Err(_) => return false,
+    };
+    em.pending_interrupts[idx].push_back((EventDescriptor::new(id, Event::from(ev)), 1));
+
+    // Check if the interrupt is reported as pending, more than once.
+    for _ in 0..2 {
+        match em.interrupt_pending(pid, idx) {
+            Ok(true) => {},
+            Ok(false) => {
+                error!("pending interrupt was not reported");
+                return false;
+            },
+            Err(e) => {
+                error!("failed to check pending interrupt (error={:?})", e);
+                return false;
+            },
+        }
+    }
+
+    // Consume the interrupt.
+    match em.try_wait(pid, 1 << idx, 0, 0, false) {
+        Ok(Some(_)) => {},
+        _ => {
+            error!("failed to consume pending interrupt");
+            return false;
+        },
+    }
+
+    // Check if the interrupt is no longer reported as pending.
+    match em.interrupt_pending(pid, idx) {
+        Ok(false) => true,
+        Ok(true) => {
+            error!("consumed interrupt is still reported as pending");
+            false
+        },
+        Err(e) => {
+            error!("failed to check pending interrupt (error={:?})", e);
+            false
+        },
+    }
+}
+
+/// Tests if pending interrupts of a line that is not owned by the caller are not disclosed.
+fn test_interrupt_pending_not_owner() -> bool {
+    let ev: InterruptEvent = InterruptEvent::Interrupt1;
+    let idx: usize = usize::from(ev);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[idx] = Some(ProcessIdentifier::from(2));
+
+    match em.interrupt_pending(ProcessIdentifier::from(3), idx) {
+        Err(e) if e.code == ErrorCode::PermissionDenied => true,
+        _ => {
+            error!("pending interrupt was disclosed to a process that does not own the line");
+            false
+        },
+    }
+}
+
+/// Tests if interrupts that are not delivered through a valid vector are ignored.
+fn test_interrupt_invalid_vector() -> bool {
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    let invalid: [usize; 3] = [0, 0b11, 1 << (usize::BITS - 1)];
+
+    // Check if interrupts that do not map to exactly one line are rejected.
+    for interrupts in invalid.iter() {
+        if em.wakeup_interrupt(*interrupts).is_ok() {
+            error!("invalid interrupt was delivered (interrupts={:#x})", interrupts);
+            return false;
+        }
+    }
+
+    // Check if no interrupt was queued.
+    if em
+        .pending_interrupts
+        .iter()
+        .any(|pending| !pending.is_empty())
+    {
+        error!("invalid interrupt was queued");
+        return false;
+    }
+
+    true
+}
+
+/// Tests if descriptors of outstanding events remain distinguishable when identifiers wrap around.
+fn test_event_id_wraparound() -> bool {
+    let ev: InterruptEvent = InterruptEvent::Interrupt1;
+    let idx: usize = usize::from(ev);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+
+    // Enqueue an event that outlives the wraparound.
+    let first: usize = match em.next_event_id() {
+        Ok(id) => id,
+        Err(_) => return false,
+    };
+    em.pending_interrupts[idx].push_back((EventDescriptor::new(first, Event::from(ev)), 1));
+
+    // Force identifiers near the wrap boundary and enqueue more events.
+    em.nevents = EventDescriptor::MAX_ID - 1;
+    let mut outstanding: [usize; 3] = [first, 0, 0];
+    for id in outstanding.iter_mut().skip(1) {
+        *id = match em.next_event_id() {
+            Ok(id) => id,
+            Err(_) => return false,
+        };
+        em.pending_interrupts[idx].push_back((EventDescriptor::new(*id, Event::from(ev)), 1));
+    }
+
+    // Check if identifiers wrapped around.
+    if outstanding[1] != EventDescriptor::MAX_ID {
+        error!("unexpected identifier before wraparound (id={})", outstanding[1]);
+        return false;
+    }
+
+    // Check if descriptors of outstanding events are distinct.
+    if outstanding[2] == first || outstanding[2] == outstanding[1] {
+        error!("identifier of outstanding event was reused (id={})", outstanding[2]);
+        return false;
+    }
+
+    // Check if descriptors carry the identifiers they were created with.
+    em.pending_interrupts[idx]
+        .iter()
+        .zip(outstanding.iter())
+        .all(|((evdesc, _), id)| evdesc.id() == *id)
+}
+
+/// Tests if interrupts on lines that have no owner are neither queued nor hold identifiers.
+fn test_unowned_interrupt() -> bool {
+    let idx: usize = usize::from(InterruptEvent::Interrupt1);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+
+    // Check if an interrupt on a line that has no owner is rejected.
+    match em.wakeup_interrupt(1 << idx) {
+        Err(e) if e.code == ErrorCode::NoSuchProcess => {},
+        result => {
+            error!("unowned interrupt was not rejected (result={:?})", result);
+            return false;
+        },
+    }
+
+    // Check if the interrupt was not queued and no identifier was allocated.
+    if !em.pending_interrupts[idx].is_empty() || !em.outstanding.is_empty() {
+        error!("unowned interrupt was queued");
+        return false;
+    }
+
+    true
+}
+
+/// Tests if interrupts that are pending delivery on a line are bounded.
+fn test_pending_interrupts_bounded() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let idx: usize = usize::from(InterruptEvent::Interrupt1);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[idx] = Some(pid);
+
+    // Trigger more interrupts than a line may have pending.
+    for _ in 0..=config::kernel::MAX_PENDING_INTERRUPTS {
+        if let Err(e) = em.wakeup_interrupt(1 << idx) {
+            error!("failed to wake up interrupt (error={:?})", e);
+            return false;
+        }
+    }
+
+    // Check if pending interrupts are bounded, and so are identifiers.
+    if em.pending_interrupts[idx].len() != config::kernel::MAX_PENDING_INTERRUPTS
+        || em.outstanding.len() != config::kernel::MAX_PENDING_INTERRUPTS
+    {
+        error!(
+            "unexpected number of pending interrupts (expected={}, got={})",
+            config::kernel::MAX_PENDING_INTERRUPTS,
+            em.pending_interrupts[idx].len()
+        );
+        return false;
+    }
+
+    true
+}
+
+/// Tests if raising signals does not hold on to event identifiers.
+fn test_signals_do_not_hold_ids() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let idx: usize = usize::from(InterruptEvent::Interrupt1);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[idx] = Some(pid);
+
+    // Raise more signals than there are event identifiers.
+    for _ in 0..=EventDescriptor::MAX_ID {
+        if let Err(e) = em.raise_signals(pid, 0b1) {
+            error!("failed to raise signals (error={:?})", e);
+            return false;
+        }
+    }
+
+    // Check if no identifier is outstanding.
+    if !em.outstanding.is_empty() {
+        error!("signals hold event identifiers (outstanding={})", em.outstanding.len());
+        return false;
+    }
+
+    // Check if an interrupt can still be delivered.
+    if let Err(e) = em.wakeup_interrupt(1 << idx) {
+        error!("failed to wake up interrupt (error={:?})", e);
+        return false;
+    }
+    match em.try_wait(pid, 1 << idx, 0, 0, false) {
+        Ok(Some((EventClass::Interrupt, _))) => true,
+        result => {
+            error!("interrupt was not delivered (result={:?})", result);
+            false
+        },
+    }
+}
+
+/// Tests if both the owner and a monitor receive an interrupt, but only the owner consumes it.
+fn test_interrupt_monitor() -> bool {
+    let owner: ProcessIdentifier = ProcessIdentifier::from(2);
+    let monitor: ProcessIdentifier = ProcessIdentifier::from(3);
+    let ev: InterruptEvent = InterruptEvent::Interrupt1;
+    let idx: usize = usize::from(ev);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[idx] = Some(owner);
+    em.interrupt_monitors.push_back((monitor, idx));
+
+    // Trigger an interrupt.
+    if let Err(e) = em.wakeup_interrupt(1 << idx) {
+        error!("failed to wake up interrupt (error={:?})", e);
+        return false;
+    }
+
+    // Check if the monitor receives a copy of the interrupt, only once.
+    if !matches!(em.try_wait(monitor, 1 << idx, 0, 0, false), Ok(Some(_))) {
+        error!("monitor did not receive interrupt");
+        return false;
+    }
+    if !matches!(em.try_wait(monitor, 1 << idx, 0, 0, false), Ok(None)) {
+        error!("monitor received interrupt twice");
+        return false;
+    }
+
+    // Check if the interrupt is still pending for the owner.
+    if !matches!(em.interrupt_pending(owner, idx), Ok(true)) {
+        error!("monitor consumed the interrupt of the owner");
+        return false;
+    }
+
+    // Check if the owner receives the interrupt and consumes it.
+    if !matches!(em.try_wait(owner, 1 << idx, 0, 0, false), Ok(Some(_))) {
+        error!("owner did not receive interrupt");
+        return false;
+    }
+    if !matches!(em.interrupt_pending(owner, idx), Ok(false)) {
+        error!("interrupt is still pending after the owner consumed it");
+        return false;
+    }
+
+    true
+}
+
+/// Tests if copies of interrupts that are pending delivery to a monitor are bounded.
+fn test_interrupt_monitor_bounded() -> bool {
+    let owner: ProcessIdentifier = ProcessIdentifier::from(2);
+    let monitor: ProcessIdentifier = ProcessIdentifier::from(3);
+    let ev: InterruptEvent = InterruptEvent::Interrupt1;
+    let idx: usize = usize::from(ev);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[idx] = Some(owner);
+    em.interrupt_monitors.push_back((monitor, idx));
+
+    // Trigger more interrupts than a monitor may have pending.
+    for _ in 0..=config::kernel::MAX_MONITORED_INTERRUPTS {
+        if let Err(e) = em.wakeup_interrupt(1 << idx) {
+            error!("failed to wake up interrupt (error={:?})", e);
+            return false;
+        }
+    }
+
+    // Check if copies for the monitor are bounded.
+    if em.monitored_interrupts.len() != config::kernel::MAX_MONITORED_INTERRUPTS {
+        error!(
+            "unexpected number of monitored interrupts (expected={}, got={})",
+            config::kernel::MAX_MONITORED_INTERRUPTS,
+            em.monitored_interrupts.len()
+        );
+        return false;
+    }
+
+    // Check if the owner still receives every interrupt.
+    if em.pending_interrupts[idx].len() != config::kernel::MAX_MONITORED_INTERRUPTS + 1 {
+        error!("owner did not receive every interrupt");
+        return false;
+    }
+
+    true
+}
+
+/// Tests if pending counts match the number of events that were enqueued for a process.
+fn test_pending_counts() -> bool {
+    const NINTERRUPTS: usize = 3;
+    const NEXCEPTIONS: usize = 2;
+    const NTERMINATIONS: usize = 4;
+
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let other: ProcessIdentifier = ProcessIdentifier::from(3);
+    let interrupt: usize = usize::from(InterruptEvent::Interrupt1);
+    let exception: usize = usize::from(ExceptionEvent::Exception0);
+    let termination: usize = SchedulingEvent::ProcessTermination as usize;
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[interrupt] = Some(pid);
+    em.scheduling_ownership[termination].push_back(pid);
+    if let Err(e) = em.exception_ownership.register(exception, pid) {
+        error!("failed to register exception (error={:?})", e);
+        return false;
+    }
+
+    // Enqueue events for the process.
+    for _ in 0..NINTERRUPTS {
+        if let Err(e) = em.wakeup_interrupt(1 << interrupt) {
+            error!("failed to wake up interrupt (error={:?})", e);
+            return false;
+        }
+    }
+    // Safety: exception information is plain data, for which all-zeroes is a valid value.
+    let info: ExceptionInformation = unsafe { mem::zeroed() };
+    for _ in 0..NEXCEPTIONS {
+        if let Err(e) = em.wakeup_exception(1 << exception, other, &info) {
+            error!("failed to wake up exception (error={:?})", e);
+            return false;
+        }
+    }
+    for _ in 0..NTERMINATIONS {
+        if let Err(e) = em.notify_process_termination(
+            ProcessTerminationInfo::new(other, 0, TerminationCause::Exited),
+            &[],
+        ) {
+            error!("failed to notify process termination (error={:?})", e);
+            return false;
+        }
+    }
+    if let Err(e) = em.raise_signals(pid, 0b101) {
+        error!("failed to raise signals (error={:?})", e);
+        return false;
+    }
+
+    // Check if counts match, with coalesced signals counting as a single event.
+    let expected: PendingCounts = PendingCounts {
+        interrupts: NINTERRUPTS,
+        exceptions: NEXCEPTIONS,
+        scheduling: NTERMINATIONS + 1,
+        ipc: 0,
+    };
+    let counts: PendingCounts = em.pending_counts(pid);
+    if counts != expected {
+        error!("unexpected pending counts (expected={:?}, got={:?})", expected, counts);
+        return false;
+    }
+
+    // Check if nothing is reported for another process.
+    let counts: PendingCounts = em.pending_counts(other);
+    if counts != PendingCounts::default() {
+        error!("unexpected pending counts for another process (got={:?})", counts);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if a process observes signals raised along with the resume of its exception.
+fn test_resume_with_signals() -> bool {
+    const SIGNALS: u32 = 0b10;
+
+    let debugger: ProcessIdentifier = ProcessIdentifier::from(2);
+    let debuggee: ProcessIdentifier = ProcessIdentifier::from(3);
+    let ev: ExceptionEvent = ExceptionEvent::Exception3;
+    let idx: usize = usize::from(ev);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    if let Err(e) = em.exception_ownership.register(idx, debugger) {
+        error!("failed to register exception (error={:?})", e);
+        return false;
+    }
+
+    // Trigger an exception on the debuggee.
+    // Safety: exception information is plain data, for which all-zeroes is a valid value.
+    let info: ExceptionInformation = unsafe { mem::zeroed() };
+    if let Err(e) = em.wakeup_exception(1 << idx, debuggee, &info) {
+        error!("failed to wake up exception (error={:?})", e);
+        return false;
+    }
+    let id: usize = match em.pending_exceptions[idx].front() {
+        Some((evdesc, _, _)) => evdesc.id(),
+        None => {
+            error!("exception is not pending");
+            return false;
+        },
+    };
+
+    // Check if no signal is pending before the resume.
+    if em.signals.is_pending(debuggee) {
+        error!("signal is pending before the resume");
+        return false;
+    }
+
+    // Resume the debuggee with a signal.
+    // Check if a process other than the owner cannot resume the debuggee.
+    match em.resume_exception(debuggee, ev, id, SIGNALS) {
+        Err(e) if e.code == ErrorCode::PermissionDenied => {},
+        result => {
+            error!("exception was resumed by a non-owner (result={:?})", result);
+            return false;
+        },
+    }
+    if em.pending_exceptions[idx].is_empty() || em.signals.is_pending(debuggee) {
+        error!("resume by a non-owner had side effects");
+        return false;
+    }
+
+    if let Err(e) = em.resume_exception(debugger, ev, id, SIGNALS) {
+        error!("failed to resume exception (error={:?})", e);
+        return false;
+    }
+
+    // Check if the exception is no longer pending.
+    if !em.pending_exceptions[idx].is_empty() {
+        error!("exception is still pending after the resume");
+        return false;
+    }
+
+    // Check if the debuggee observes the signal right away.
+    let signal: usize = 1 << SchedulingEvent::Signal as usize;
+    match em.try_wait(debuggee, 0, 0, signal, false) {
+        Ok(Some((_, message)))
+            if message.payload[0..mem::size_of::<u32>()] == SIGNALS.to_ne_bytes() =>
+        {
+            true
+        },
+        Ok(Some((_, message))) => {
+            error!("unexpected signal message (status={:?})", message.status);
+            false
+        },
+        Ok(None) => {
+            error!("debuggee did not observe the signal");
+            false
+        },
+        Err(e) => {
+            error!("failed to wait for signal (error={:?})", e);
+            false
+        },
+    }
+}
+
+/// Tests if resuming an exception resumes the one with the supplied identifier.
+fn test_resume_by_id() -> bool {
+    let debugger: ProcessIdentifier = ProcessIdentifier::from(2);
+    let debuggees: [ProcessIdentifier; 2] =
+        [ProcessIdentifier::from(3), ProcessIdentifier::from(4)];
+    let ev: ExceptionEvent = ExceptionEvent::Exception3;
+    let idx: usize = usize::from(ev);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    if let Err(e) = em.exception_ownership.register(idx, debugger) {
+        error!("failed to register exception (error={:?})", e);
+        return false;
+    }
+
+    // Trigger an exception of the same type on every debuggee.
+    // Safety: exception information is plain data, for which all-zeroes is a valid value.
+    let info: ExceptionInformation = unsafe { mem::zeroed() };
+    for debuggee in debuggees.iter() {
+        if let Err(e) = em.wakeup_exception(1 << idx, *debuggee, &info) {
+            error!("failed to wake up exception (error={:?})", e);
+            return false;
+        }
+    }
+
+    // Resume the exception of the last debuggee.
+    let id: usize = match em.pending_exceptions[idx].back() {
+        Some((evdesc, _, _)) => evdesc.id(),
+        None => {
+            error!("exception is not pending");
+            return false;
+        },
+    };
+    if let Err(e) = em.resume_exception(debugger, ev, id, 0) {
+        error!("failed to resume exception (error={:?})", e);
+        return false;
+    }
+
+    // Check if only the exception of the first debuggee is still pending.
+    match em.pending_exceptions[idx].front() {
+        Some((evdesc, excpinfo, _))
+            if em.pending_exceptions[idx].len() == 1
+                && evdesc.id() != id
+                && excpinfo.pid == debuggees[0] => {},
+        _ => {
+            error!("wrong exception was resumed");
+            return false;
+        },
+    }
+
+    // Check if the identifier of the resumed exception was released.
+    if em.outstanding.contains(&id) {
+        error!("identifier of resumed exception is still outstanding (id={})", id);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if identifiers of events are released once events are delivered.
+fn test_event_id_release() -> bool {
+    let owner: ProcessIdentifier = ProcessIdentifier::from(2);
+    let monitor: ProcessIdentifier = ProcessIdentifier::from(3);
+    let ev: InterruptEvent = InterruptEvent::Interrupt1;
+    let idx: usize = usize::from(ev);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[idx] = Some(owner);
+    em.interrupt_monitors.push_back((monitor, idx));
+
+    // Trigger an interrupt.
+    if let Err(e) = em.wakeup_interrupt(1 << idx) {
+        error!("failed to wake up interrupt (error={:?})", e);
+        return false;
+    }
+
+    // Check if the owner and the monitor got distinct identifiers.
+    if em.outstanding.len() != 2 {
+        error!("unexpected number of outstanding events (got={})", em.outstanding.len());
+        return false;
+    }
+
+    // Deliver the interrupt to the owner and to the monitor.
+    for pid in [owner, monitor] {
+        match em.try_wait(pid, 1 << idx, 0, 0, false) {
+            Ok(Some(_)) => {},
+            result => {
+                error!("interrupt was not delivered (pid={:?}, result={:?})", pid, result);
+                return false;
+            },
+        }
+    }
+
+    // Check if identifiers were released.
+    if !em.outstanding.is_empty() {
+        error!("identifiers of delivered events are still outstanding");
+        return false;
+    }
+
+    true
+}
+
+/// Tests if the round-robin visits each class of events exactly once, wherever it starts.
+fn test_round_robin() -> bool {
+    for nevents in 0..2 * EventClass::COUNT {
+        let classes: Vec<EventClass> = EventManagerInner::round_robin(nevents).collect();
+
+        // Check if the round-robin visits exactly as many classes as there are.
+        if classes.len() != EventClass::COUNT {
+            error!(
+                "unexpected number of classes visited (expected={}, got={})",
+                EventClass::COUNT,
+                classes.len()
+            );
+            return false;
+        }
+
+        // Check if every class is visited.
+        if let Some(class) = EventClass::VALUES
+            .iter()
+            .find(|class| !classes.contains(class))
+        {
+            error!("class was not visited (nevents={}, class={:?})", nevents, class);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests if interrupts of different lines are delivered in alternation.
+fn test_interrupt_fairness() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let lines: [usize; 2] = [
+        usize::from(InterruptEvent::Interrupt1),
+        usize::from(InterruptEvent::Interrupt3),
+    ];
+    let nrounds: usize = 4;
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+
+    // Enqueue interrupts on both lines.
+    let mut interrupts: usize = 0;
+    for idx in lines {
+        em.interrupt_ownership[idx] = Some(pid);
+        interrupts |= 1 << idx;
+        for _ in 0..nrounds {
+            let id: usize = match em.next_event_id() {
+                Ok(id) => id,
+                Err(_) => return false,
+            };
+            let ev: Event = match InterruptEvent::try_from(idx) {
+                Ok(ev) => Event::from(ev),
+                Err(_) => return false,
+            };
+            em.pending_interrupts[idx].push_back((EventDescriptor::new(id, ev), 1));
+        }
+    }
+
+    // Check if lines are served in alternation.
+    for i in 0..(lines.len() * nrounds) {
+        let before: Vec<usize> = lines
+            .iter()
+            .map(|&idx| em.pending_interrupts[idx].len())
+            .collect();
+
+        match em.try_wait(pid, interrupts, 0, 0, false) {
+            Ok(Some((EventClass::Interrupt, _))) => {},
+            _ => {
+                error!("failed to consume pending interrupt");
+                return false;
+            },
+        }
+
+        let expected: usize = lines[i % lines.len()];
+        if em.pending_interrupts[expected].len() + 1 != before[i % lines.len()] {
+            error!("interrupt was not delivered in alternation (round={}, line={})", i, expected);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests if each delivered event is reported with the class that matches its kind.
+fn test_wait_class() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let interrupt: usize = usize::from(InterruptEvent::Interrupt3);
+    let exception: usize = usize::from(ExceptionEvent::Exception3);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[interrupt] = Some(pid);
+    if let Err(e) = em.exception_ownership.register(exception, pid) {
+        error!("failed to register exception (error={:?})", e);
+        return false;
+    }
+
+    // Raise one event of each kind.
+    if let Err(e) = em.wakeup_interrupt(1 << interrupt) {
+        error!("failed to wake up interrupt (error={:?})", e);
+        return false;
+    }
+    // Safety: exception information is plain data, for which all-zeroes is a valid value.
+    let info: ExceptionInformation = unsafe { mem::zeroed() };
+    if let Err(e) = em.wakeup_exception(1 << exception, pid, &info) {
+        error!("failed to wake up exception (error={:?})", e);
+        return false;
+    }
+    let mut mailbox: VecDeque<Message> = VecDeque::new();
+    mailbox.push_back(Message::new(pid, pid, MessageType::Ipc, None, [0; Message::PAYLOAD_SIZE]));
+
+    // Check if each class is reported once, along with a message of the matching type.
+    let mut classes: Vec<EventClass> = Vec::new();
+    for _ in 0..3 {
+        // NOTE: exceptions stay pending until resumed, thus they are not selected again.
+        let exceptions: usize = if classes.contains(&EventClass::Exception) {
+            0
+        } else {
+            1 << exception
+        };
+        let (class, message): (EventClass, Message) = match em.try_wait_with(
+            pid,
+            1 << interrupt,
+            exceptions,
+            0,
+            true,
+            || Ok(mailbox.pop_front()),
+        ) {
+            Ok(Some(event)) => event,
+            result => {
+                error!("failed to deliver event (result={:?})", result);
+                return false;
+            },
+        };
+
+        let expected: MessageType = match class {
+            EventClass::Interrupt => MessageType::Interrupt,
+            EventClass::Exception => MessageType::Exception,
+            EventClass::Ipc => MessageType::Ipc,
+            EventClass::Scheduling => {
+                error!("unexpected scheduling event");
+                return false;
+            },
+        };
+        if { message.message_type } != expected || classes.contains(&class) {
+            error!("unexpected event (class={:?}, message={:?})", class, message);
+            return false;
+        }
+        classes.push(class);
+    }
+
+    true
+}
+
+/// Tests if delivered interrupts carry the line that fired and the identifier of the event.
+fn test_interrupt_payload() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let ev: InterruptEvent = InterruptEvent::Interrupt3;
+    let idx: usize = usize::from(ev);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[idx] = Some(pid);
+
+    // Enqueue an interrupt.
+    let id: usize = match em.next_event_id() {
+        Ok(id) => id,
+        Err(_) => return false,
+    };
+    em.pending_interrupts[idx].push_back((EventDescriptor::new(id, Event::from(ev)), 1));
+
+    // Consume the interrupt.
+    let message: Message = match em.try_wait(pid, 1 << idx, 0, 0, false) {
+        Ok(Some((EventClass::Interrupt, message))) => message,
+        _ => {
+            error!("failed to consume pending interrupt");
+            return false;
+        },
+    };
+
+    // Check if the payload describes the interrupt.
+    let mut bytes: [u8; mem::size_of::<InterruptMessagePayload>()] =
+        [0; mem::size_of::<InterruptMessagePayload>()];
+    bytes.copy_from_slice(&message.payload[0..mem::size_of::<InterruptMessagePayload>()]);
+    let payload: InterruptMessagePayload = InterruptMessagePayload::from_ne_bytes(bytes);
+    if payload != InterruptMessagePayload::new(idx, id, 1) {
+        error!("unexpected interrupt payload (expected={}/{}, got={:?})", idx, id, payload);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if interrupts that fire while another one is pending are coalesced on lines that opt in.
+fn test_interrupt_coalescing() -> bool {
+    const NOCCURRENCES: usize = 3;
+
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let ev: InterruptEvent = InterruptEvent::Interrupt4;
+    let idx: usize = usize::from(ev);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[idx] = Some(pid);
+
+    // Enable coalescing on the line.
+    if let Err(e) = em.do_evctrl_interrupt(Some(pid), ev, EventCtrlRequest::Coalesce) {
+        error!("failed to enable coalescing (error={:?})", e);
+        return false;
+    }
+
+    // Trigger several interrupts.
+    for _ in 0..NOCCURRENCES {
+        if let Err(e) = em.wakeup_interrupt(1 << idx) {
+            error!("failed to wake up interrupt (error={:?})", e);
+            return false;
+        }
+    }
+
+    // Check if a single event is pending.
+    if em.pending_interrupts[idx].len() != 1 {
+        error!("interrupts were not coalesced (pending={})", em.pending_interrupts[idx].len());
+        return false;
+    }
+
+    // Check if the payload carries the number of occurrences.
+    let message: Message = match em.try_wait(pid, 1 << idx, 0, 0, false) {
+        Ok(Some((EventClass::Interrupt, message))) => message,
+        _ => {
+            error!("failed to consume pending interrupt");
+            return false;
+        },
+    };
+    let mut bytes: [u8; mem::size_of::<InterruptMessagePayload>()] =
+        [0; mem::size_of::<InterruptMessagePayload>()];
+    bytes.copy_from_slice(&message.payload[0..mem::size_of::<InterruptMessagePayload>()]);
+    let payload: InterruptMessagePayload = InterruptMessagePayload::from_ne_bytes(bytes);
+    if payload.number != idx || payload.count != NOCCURRENCES {
+        error!(
+            "unexpected interrupt payload (expected={}/{}, got={:?})",
+            idx, NOCCURRENCES, payload
+        );
+        return false;
+    }
+
+    // Disable coalescing and check if every occurrence is queued again.
+    if let Err(e) = em.do_evctrl_interrupt(Some(pid), ev, EventCtrlRequest::Uncoalesce) {
+        error!("failed to disable coalescing (error={:?})", e);
+        return false;
+    }
+    for _ in 0..NOCCURRENCES {
+        if let Err(e) = em.wakeup_interrupt(1 << idx) {
+            error!("failed to wake up interrupt (error={:?})", e);
+            return false;
+        }
+    }
+    if em.pending_interrupts[idx].len() != NOCCURRENCES {
+        error!("interrupts were coalesced (pending={})", em.pending_interrupts[idx].len());
+        return false;
+    }
+
+    true
+}
+
+/// Tests if the termination cause of a process round-trips through a scheduling event payload.
+fn test_termination_payload() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let other: ProcessIdentifier = ProcessIdentifier::from(3);
+    let termination: usize = SchedulingEvent::ProcessTermination as usize;
+    let causes: [TerminationCause; 3] = [
+        TerminationCause::Exited,
+        TerminationCause::Faulted { exception: 14 },
+        TerminationCause::Killed,
+    ];
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.scheduling_ownership[termination].push_back(pid);
+
+    for cause in causes.iter() {
+        // Notify the termination of a process.
+        let info: ProcessTerminationInfo = ProcessTerminationInfo::new(other, -1, *cause);
+        if let Err(e) = em.notify_process_termination(info, &[]) {
+            error!("failed to notify process termination (error={:?})", e);
+            return false;
+        }
+
+        // Consume the scheduling event.
+        let message: Message = match em.try_wait(pid, 0, 0, 1 << termination, false) {
+            Ok(Some((EventClass::Scheduling, message))) => message,
+            _ => {
+                error!("failed to consume pending scheduling event");
+                return false;
+            },
+        };
+
+        // Check if the payload describes the termination.
+        let mut bytes: [u8; mem::size_of::<ProcessTerminationInfo>()] =
+            [0; mem::size_of::<ProcessTerminationInfo>()];
+        bytes.copy_from_slice(&message.payload[0..mem::size_of::<ProcessTerminationInfo>()]);
+        let payload: ProcessTerminationInfo = ProcessTerminationInfo::from_ne_bytes(bytes);
+        if payload != info {
+            error!("unexpected termination payload (expected={:?}, got={:?})", info, payload);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Checks if every subscriber receives a pending process termination exactly once.
+fn check_termination_received(
+    em: &mut EventManagerInner,
+    subscribers: &[ProcessIdentifier],
+) -> bool {
+    let termination: usize = SchedulingEvent::ProcessTermination as usize;
+    for pid in subscribers.iter() {
+        if !matches!(em.try_wait(*pid, 0, 0, 1 << termination, false), Ok(Some(_))) {
+            error!("subscriber did not receive termination (pid={:?})", pid);
+            return false;
+        }
+        if !matches!(em.try_wait(*pid, 0, 0, 1 << termination, false), Ok(None)) {
+            error!("subscriber received termination twice (pid={:?})", pid);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests if the termination of a process is broadcast to every subscriber.
+fn test_termination_broadcast() -> bool {
+    let subscribers: [ProcessIdentifier; 2] =
+        [ProcessIdentifier::from(2), ProcessIdentifier::from(4)];
+    let other: ProcessIdentifier = ProcessIdentifier::from(3);
+    let ev: SchedulingEvent = SchedulingEvent::ProcessTermination;
+    let termination: usize = ev as usize;
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    for pid in subscribers.iter() {
+        em.scheduling_ownership[termination].push_back(*pid);
+    }
+
+    // Notify the termination of a process.
+    let info: ProcessTerminationInfo =
+        ProcessTerminationInfo::new(other, 0, TerminationCause::Exited);
+    if let Err(e) = em.notify_process_termination(info, &[]) {
+        error!("failed to notify process termination (error={:?})", e);
+        return false;
+    }
+
+    // Check if every subscriber receives the event exactly once.
+    if !check_termination_received(&mut em, &subscribers) {
+        return false;
+    }
+
+    // Notify a termination while the first subscriber cannot be woken up.
+    em.get_wait()
+        .enqueue(subscribers[0], ThreadIdentifier::from(4), None);
+    if let Err(e) = em.notify_process_termination(info, &[]) {
+        error!("failed to notify process termination (error={:?})", e);
+        return false;
+    }
+
+    // Check if every subscriber still receives the event.
+    if !check_termination_received(&mut em, &subscribers) {
+        return false;
+    }
+
+    // Unregister the first subscriber and notify another termination.
+    if let Err(e) = em.do_evctrl_scheduling(Some(subscribers[0]), ev, EventCtrlRequest::Unregister)
+    {
+        error!("failed to unregister subscriber (error={:?})", e);
+        return false;
+    }
+    if let Err(e) = em.notify_process_termination(info, &[]) {
+        error!("failed to notify process termination (error={:?})", e);
+        return false;
+    }
+
+    // Check if only the remaining subscriber receives the event.
+    if !matches!(em.try_wait(subscribers[0], 0, 0, 1 << termination, false), Ok(None)) {
+        error!("unregistered subscriber received termination");
+        return false;
+    }
+    if !matches!(em.try_wait(subscribers[1], 0, 0, 1 << termination, false), Ok(Some(_))) {
+        error!("remaining subscriber did not receive termination");
+        return false;
+    }
+
+    // Check if unregistering a process that did not subscribe fails.
+    match em.do_evctrl_scheduling(Some(other), ev, EventCtrlRequest::Unregister) {
+        Err(e) if e.code == ErrorCode::PermissionDenied => true,
+        _ => {
+            error!("unregistered a process that did not subscribe");
+            false
+        },
+    }
+}
+
+/// Tests if the termination of a process is delivered to processes that watch it.
+fn test_termination_watchers() -> bool {
+    let subscriber: ProcessIdentifier = ProcessIdentifier::from(2);
+    let watcher: ProcessIdentifier = ProcessIdentifier::from(4);
+    let other: ProcessIdentifier = ProcessIdentifier::from(3);
+    let termination: usize = SchedulingEvent::ProcessTermination as usize;
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    let info: ProcessTerminationInfo =
+        ProcessTerminationInfo::new(other, 0, TerminationCause::Exited);
+
+    // Check if a watcher receives the event even when there are no subscribers.
+    if let Err(e) = em.notify_process_termination(info, &[watcher]) {
+        error!("failed to notify process termination (error={:?})", e);
+        return false;
+    }
+    if !check_termination_received(&mut em, &[watcher]) {
+        return false;
+    }
+
+    // Check if a watcher that also subscribed receives the event only once.
+    em.scheduling_ownership[termination].push_back(subscriber);
+    if let Err(e) = em.notify_process_termination(info, &[watcher, subscriber]) {
+        error!("failed to notify process termination (error={:?})", e);
+        return false;
+    }
+    if !check_termination_received(&mut em, &[subscriber, watcher]) {
+        return false;
+    }
+
+    // Check if the notification fails when there are no recipients.
+    em.scheduling_ownership[termination].clear();
+    match em.notify_process_termination(info, &[]) {
+        Err(e) if e.code == ErrorCode::NoSuchProcess => true,
+        _ => {
+            error!("notified process termination without recipients");
+            false
+        },
+    }
+}
+
+/// Tests if interrupts of lines that are not selected by a wait are left pending.
+fn test_wait_for_mask() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let lines: [usize; 2] = [
+        usize::from(InterruptEvent::Interrupt1),
+        usize::from(InterruptEvent::Interrupt3),
+    ];
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+
+    // Enqueue an interrupt on each line.
+    let mut owned: EventMask = EventMask {
+        interrupts: 0,
+        exceptions: 0,
+        scheduling: 0,
+        ipc: false,
+    };
+    for idx in lines {
+        em.interrupt_ownership[idx] = Some(pid);
+        owned.interrupts |= 1 << idx;
+        let id: usize = match em.next_event_id() {
+            Ok(id) => id,
+            Err(_) => return false,
+        };
+        let ev: Event = match InterruptEvent::try_from(idx) {
+            Ok(ev) => Event::from(ev),
+            Err(_) => return false,
+        };
+        em.pending_interrupts[idx].push_back((EventDescriptor::new(id, ev), 1));
+    }
+
+    // Wait only for the second line.
+    let mask: EventMask = EventMask {
+        interrupts: 1 << lines[1],
+        ..EventMask::ALL
+    };
+    let selected: EventMask = owned.intersection(&mask);
+    for expected in [true, false] {
+        match em.try_wait(
+            pid,
+            selected.interrupts,
+            selected.exceptions,
+            selected.scheduling,
+            selected.ipc,
+        ) {
+            Ok(delivered) if delivered.is_some() == expected => {},
+            _ => {
+                error!("unexpected delivery (expected={})", expected);
+                return false;
+            },
+        }
+    }
+
+    // Check if only the selected line was consumed.
+    if !em.pending_interrupts[lines[1]].is_empty() {
+        error!("interrupt of selected line was not delivered");
+        return false;
+    }
+    if em.pending_interrupts[lines[0]].len() != 1 {
+        error!("interrupt of unselected line was not left pending");
+        return false;
+    }
+
+    // Check if the unselected line is delivered by an unrestricted wait.
+    let selected: EventMask = owned.intersection(&EventMask::ALL);
+    match em.try_wait(
+        pid,
+        selected.interrupts,
+        selected.exceptions,
+        selected.scheduling,
+        selected.ipc,
+    ) {
+        Ok(Some((EventClass::Interrupt, _))) if em.pending_interrupts[lines[0]].is_empty() => true,
+        _ => {
+            error!("interrupt of unselected line was not delivered later");
+            false
+        },
+    }
+}
+
+/// Tests if waits for a reply only deliver events of the expected type.
+fn test_wait_for_reply() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let idx: usize = usize::from(InterruptEvent::Interrupt1);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+
+    // Enqueue an interrupt.
+    em.interrupt_ownership[idx] = Some(pid);
+    let id: usize = match em.next_event_id() {
+        Ok(id) => id,
+        Err(_) => return false,
+    };
+    let ev: Event = match InterruptEvent::try_from(idx) {
+        Ok(ev) => Event::from(ev),
+        Err(_) => return false,
+    };
+    em.pending_interrupts[idx].push_back((EventDescriptor::new(id, ev), 1));
+    let owned: EventMask = EventMask {
+        interrupts: 1 << idx,
+        exceptions: usize::MAX,
+        scheduling: usize::MAX,
+        ipc: true,
+    };
+
+    // Check if replies of other kinds are rejected.
+    if owned.for_reply(Some(MessageType::Ikc)).is_ok() {
+        error!("reply of invalid type was accepted");
+        return false;
+    }
+
+    // Check if a wait for an exception reply does not consume the interrupt.
+    let selected: EventMask = match owned.for_reply(Some(MessageType::Exception)) {
+        Ok(selected) if selected.interrupts == 0 && selected.scheduling == 0 && !selected.ipc => {
+            selected
+        },
+        _ => {
+            error!("events of other types were selected");
+            return false;
+        },
+    };
+    match em.try_wait(
+        pid,
+        selected.interrupts,
+        selected.exceptions,
+        selected.scheduling,
+        selected.ipc,
+    ) {
+        Ok(None) if em.pending_interrupts[idx].len() == 1 => {},
+        _ => {
+            error!("interrupt was delivered to a wait for an exception reply");
+            return false;
+        },
+    }
+
+    // Check if a wait for an interrupt reply delivers the interrupt.
+    let selected: EventMask = match owned.for_reply(Some(MessageType::Interrupt)) {
+        Ok(selected) => selected,
+        Err(_) => return false,
+    };
+    match em.try_wait(
+        pid,
+        selected.interrupts,
+        selected.exceptions,
+        selected.scheduling,
+        selected.ipc,
+    ) {
+        Ok(Some((EventClass::Interrupt, _))) if em.pending_interrupts[idx].is_empty() => true,
+        _ => {
+            error!("interrupt was not delivered to a wait for an interrupt reply");
+            false
+        },
+    }
+}
+
+/// Tests if exceptions beyond the pending limit are rejected instead of queued.
+fn test_pending_exceptions_bounded() -> bool {
+    let owner: ProcessIdentifier = ProcessIdentifier::from(2);
+    let faulting: ProcessIdentifier = ProcessIdentifier::from(3);
+    let idx: usize = usize::from(ExceptionEvent::Exception3);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    if let Err(e) = em.exception_ownership.register(idx, owner) {
+        error!("failed to register exception (error={:?})", e);
+        return false;
+    }
+
+    // Fill the pending list up to its limit.
+    // Safety: exception information is plain data, for which all-zeroes is a valid value.
+    let info: ExceptionInformation = unsafe { mem::zeroed() };
+    for _ in 0..config::kernel::MAX_PENDING_EXCEPTIONS {
+        if let Err(e) = em.wakeup_exception(1 << idx, faulting, &info) {
+            error!("failed to wake up exception (error={:?})", e);
+            return false;
+        }
+    }
+
+    // Check if a further exception is rejected.
+    match em.wakeup_exception(1 << idx, faulting, &info) {
+        Err(e) if e.code == ErrorCode::TryAgain => {},
+        _ => {
+            error!("exception beyond the pending limit was not rejected");
+            return false;
+        },
+    }
+
+    // Check if the rejected exception was not queued.
+    if em.pending_exceptions[idx].len() != config::kernel::MAX_PENDING_EXCEPTIONS {
+        error!(
+            "unexpected number of pending exceptions (expected={}, got={})",
+            config::kernel::MAX_PENDING_EXCEPTIONS,
+            em.pending_exceptions[idx].len()
+        );
+        return false;
+    }
+
+    true
+}
+
+/// Tests if an unowned exception is rejected without disrupting delivery of other exceptions.
+fn test_unowned_exception() -> bool {
+    let owner: ProcessIdentifier = ProcessIdentifier::from(2);
+    let faulting: ProcessIdentifier = ProcessIdentifier::from(3);
+    let unowned: usize = usize::from(ExceptionEvent::Exception1);
+    let owned: usize = usize::from(ExceptionEvent::Exception3);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    if let Err(e) = em.exception_ownership.register(owned, owner) {
+        error!("failed to register exception (error={:?})", e);
+        return false;
+    }
+
+    // Check if an unowned exception is rejected, so that the faulting process is terminated.
+    // Safety: exception information is plain data, for which all-zeroes is a valid value.
+    let info: ExceptionInformation = unsafe { mem::zeroed() };
+    match em.wakeup_exception(1 << unowned, faulting, &info) {
+        Err(e) if e.code == ErrorCode::NoSuchEntry => {},
+        _ => {
+            error!("unowned exception was not rejected");
+            return false;
+        },
+    }
+
+    // Check if the unowned exception was not queued.
+    if !em.pending_exceptions[unowned].is_empty() {
+        error!("unowned exception was queued");
+        return false;
+    }
+
+    // Check if the faulting process is marked for termination when it cannot be terminated right
+    // away, so that it is reaped on the next harvest of zombie processes.
+    manager::terminate_faulting(faulting, unowned as u32, |_| {
+        Err(Error::new(ErrorCode::ResourceBusy, "process manager is busy"))
+    });
+    if !ProcessManager::is_fault_deferred(faulting) {
+        error!("termination of faulting process was not deferred");
+        return false;
+    }
+
+    // Check if owned exceptions are still delivered.
+    if let Err(e) = em.wakeup_exception(1 << owned, faulting, &info) {
+        error!("failed to wake up exception (error={:?})", e);
+        return false;
+    }
+    match em.try_wait(owner, 0, 1 << owned, 0, false) {
+        Ok(Some((EventClass::Exception, _))) => true,
+        _ => {
+            error!("owned exception was not delivered");
+            false
+        },
+    }
+}
+
+/// Tests if ownership of an interrupt may not be transferred by a process that does not own it.
+fn test_transfer_not_owner() -> bool {
+    let owner: ProcessIdentifier = ProcessIdentifier::from(2);
+    let other: ProcessIdentifier = ProcessIdentifier::from(3);
+    let ev: InterruptEvent = InterruptEvent::Interrupt1;
+    let idx: usize = usize::from(ev);
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[idx] = Some(owner);
+
+    match em.do_evctrl_interrupt(Some(other), ev, EventCtrlRequest::Transfer { to: other }) {
+        Err(e) if e.code == ErrorCode::PermissionDenied => {},
+        _ => {
+            error!("interrupt was transferred by a process that does not own it");
+            return false;
+        },
+    }
+
+    // Check if ownership is unchanged.
+    if em.interrupt_ownership[idx] != Some(owner) {
+        error!("ownership of interrupt changed (owner={:?})", em.interrupt_ownership[idx]);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if the highest registered interrupt line is tracked, and if lines that are not supported
+/// by the hardware are rejected.
+fn test_max_registered_interrupt() -> bool {
+    const NINTERRUPTS: usize = 16;
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let mut em: EventManagerInner = EventManagerInner::new(true, NINTERRUPTS);
+
+    for idx in [3, 10] {
+        if let Err(e) = em.register_interrupt(idx, pid) {
+            error!("failed to register interrupt (idx={}, error={:?})", idx, e);
+            return false;
+        }
+    }
+
+    // Check if the highest registered line is reported.
+    if em.max_registered_interrupt != Some(10) {
+        error!("unexpected highest line (max={:?})", em.max_registered_interrupt);
+        return false;
+    }
+
+    // Check if a line that is not supported by the hardware is rejected.
+    let ev: InterruptEvent = match InterruptEvent::try_from(40) {
+        Ok(ev) => ev,
+        Err(e) => {
+            error!("failed to get interrupt event (error={:?})", e);
+            return false;
+        },
+    };
+    match em.do_evctrl_interrupt(Some(pid), ev, EventCtrlRequest::Register) {
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        result => {
+            error!("unsupported interrupt was registered (result={:?})", result);
+            return false;
+        },
+    }
+    if em.max_registered_interrupt != Some(10) {
+        error!("highest line changed (max={:?})", em.max_registered_interrupt);
+        return false;
+    }
+
+    // Check if the highest line falls back to the next registered one once it is unregistered.
+    let ev: InterruptEvent = InterruptEvent::Interrupt10;
+    if let Err(e) = em.do_evctrl_interrupt(None, ev, EventCtrlRequest::Unregister) {
+        error!("failed to unregister interrupt (error={:?})", e);
+        return false;
+    }
+    if em.max_registered_interrupt != Some(3) {
+        error!("unexpected highest line after unregister (max={:?})", em.max_registered_interrupt);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if the events owned by a process are listed, and only those.
+fn test_owned_events() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let other: ProcessIdentifier = ProcessIdentifier::from(3);
+    let interrupt: InterruptEvent = InterruptEvent::Interrupt3;
+    let exception: ExceptionEvent = ExceptionEvent::Exception1;
+    let scheduling: SchedulingEvent = SchedulingEvent::ProcessTermination;
+    let mut em: EventManagerInner = EventManagerInner::new(true, usize::BITS as usize);
+    em.interrupt_ownership[usize::from(interrupt)] = Some(pid);
+    em.interrupt_ownership[usize::from(InterruptEvent::Interrupt1)] = Some(other);
+    em.scheduling_ownership[usize::from(scheduling)].push_back(pid);
+    if let Err(e) = em.exception_ownership.register(usize::from(exception), pid) {
+        error!("failed to register exception (error={:?})", e);
+        return false;
+    }
+
+    let owned: OwnedEvents = em.owned_events(pid);
+    let expected: OwnedEvents = OwnedEvents {
+        interrupts: 1 << usize::from(interrupt),
+        exceptions: 1 << usize::from(exception),
+        scheduling: 1 << usize::from(scheduling),
+    };
+    if owned != expected {
+        error!("unexpected owned events (expected={:?}, got={:?})", expected, owned);
+        return false;
+    }
+
+    // Check if events are looked up in the set.
+    if !owned.contains(Event::from(interrupt))
+        || owned.contains(Event::from(InterruptEvent::Interrupt1))
+    {
+        error!("unexpected membership of interrupts in owned events");
+        return false;
+    }
+
+    true
+}
+
+/// Runs all unit tests for the event manager.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_interrupt_pending);
+    passed &= run_test!(test_interrupt_callback);
+    passed &= run_test!(test_interrupt_pending_not_owner);
+    passed &= run_test!(test_interrupt_invalid_vector);
+    passed &= run_test!(test_event_id_wraparound);
+    passed &= run_test!(test_unowned_interrupt);
+    passed &= run_test!(test_pending_interrupts_bounded);
+    passed &= run_test!(test_signals_do_not_hold_ids);
+    passed &= run_test!(test_interrupt_monitor);
+    passed &= run_test!(test_interrupt_monitor_bounded);
+    passed &= run_test!(test_pending_counts);
+    passed &= run_test!(test_resume_with_signals);
+    passed &= run_test!(test_resume_by_id);
+    passed &= run_test!(test_event_id_release);
+    passed &= run_test!(test_round_robin);
+    passed &= run_test!(test_interrupt_fairness);
+    passed &= run_test!(test_wait_for_mask);
+    passed &= run_test!(test_wait_for_reply);
+    passed &= run_test!(test_wait_class);
+    passed &= run_test!(test_interrupt_payload);
+    passed &= run_test!(test_interrupt_coalescing);
+    passed &= run_test!(test_termination_payload);
+    passed &= run_test!(test_termination_broadcast);
+    passed &= run_test!(test_termination_watchers);
+    passed &= run_test!(test_pending_exceptions_bounded);
+    passed &= run_test!(test_unowned_exception);
+    passed &= run_test!(test_transfer_not_owner);
+    passed &= run_test!(test_max_registered_interrupt);
+    passed &= run_test!(test_owned_events);
+
+    passed
+}
@@ -7,6 +7,8 @@
 
 mod kcall;
 mod manager;
+mod ownership;
+mod signal;
 
 //==================================================================================================
 // Imports
@@ -23,6 +25,8 @@ pub use kcall::*;
 pub use manager::{
     EventManager,
     EventOwnership,
+    InterruptCallback,
+    OwnershipKind,
 };
 
 //==================================================================================================
@@ -32,3 +36,14 @@ pub use manager::{
 pub fn init(hal: &mut Hal) -> Result<(), Error> {
     manager::init(hal)
 }
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= manager::test();
+    passed &= ownership::test();
+    passed &= signal::test();
+
+    passed
+}
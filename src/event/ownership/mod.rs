@@ -0,0 +1,264 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    pm::ProcessIdentifier,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A type that keeps track of which processes own exception vectors.
+///
+/// # Notes
+///
+/// - A vector that is owned by some process is always delivered to that process.
+/// - A vector that is not owned by any process is delivered to the default owner, if any.
+///
+pub struct ExceptionOwnership {
+    /// Owners of individual exception vectors.
+    owners: [Option<ProcessIdentifier>; usize::BITS as usize],
+    /// Owner of all exception vectors that are not individually owned.
+    default: Option<ProcessIdentifier>,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl ExceptionOwnership {
+    ///
+    /// # Description
+    ///
+    /// Instantiates an exception ownership table in which no vector is owned.
+    ///
+    /// # Returns
+    ///
+    /// A new exception ownership table.
+    ///
+    pub fn new() -> Self {
+        Self {
+            owners: [None; usize::BITS as usize],
+            default: None,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Registers a process as the owner of an exception vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `idx`: Exception vector.
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn register(&mut self, idx: usize, pid: ProcessIdentifier) -> Result<(), Error> {
+        // Check if target exception is already owned by another process.
+        if self.owners[idx].is_some() {
+            let reason: &str = "exception is already owned by another process";
+            error!("register(): reason={:?}", reason);
+            return Err(Error::new(ErrorCode::ResourceBusy, reason));
+        }
+
+        self.owners[idx] = Some(pid);
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Unregisters the owner of an exception vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `idx`: Exception vector.
+    /// - `pid`: Process identifier that is expected to own the vector, if any.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn unregister(&mut self, idx: usize, pid: Option<ProcessIdentifier>) -> Result<(), Error> {
+        // If PID was supplied, check if it matches the current owner.
+        if let Some(pid) = pid {
+            if self.owners[idx] != Some(pid) {
+                let reason: &str = "process does not own exception";
+                error!("unregister(): reason={:?}", reason);
+                return Err(Error::new(ErrorCode::PermissionDenied, reason));
+            }
+        }
+
+        self.owners[idx] = None;
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Transfers ownership of an exception vector from one process to another.
+    ///
+    /// # Parameters
+    ///
+    /// - `idx`: Exception vector.
+    /// - `from`: Process identifier of the current owner.
+    /// - `to`: Process identifier of the new owner.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn transfer(
+        &mut self,
+        idx: usize,
+        from: ProcessIdentifier,
+        to: ProcessIdentifier,
+    ) -> Result<(), Error> {
+        // Check if the vector is not individually owned by the source process.
+        if self.owners[idx] != Some(from) {
+            let reason: &str = "process does not own exception";
+            error!("transfer(): reason={:?}", reason);
+            return Err(Error::new(ErrorCode::PermissionDenied, reason));
+        }
+
+        self.owners[idx] = Some(to);
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Registers a process as the default owner of exception vectors.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn register_default(&mut self, pid: ProcessIdentifier) -> Result<(), Error> {
+        // Check if there is a default owner already.
+        if self.default.is_some() {
+            let reason: &str = "default exception handler is already registered";
+            error!("register_default(): reason={:?}", reason);
+            return Err(Error::new(ErrorCode::ResourceBusy, reason));
+        }
+
+        self.default = Some(pid);
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Unregisters the default owner of exception vectors.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier that is expected to be the default owner, if any.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn unregister_default(&mut self, pid: Option<ProcessIdentifier>) -> Result<(), Error> {
+        // If PID was supplied, check if it matches the current default owner.
+        if let Some(pid) = pid {
+            if self.default != Some(pid) {
+                let reason: &str = "process is not the default exception handler";
+                error!("unregister_default(): reason={:?}", reason);
+                return Err(Error::new(ErrorCode::PermissionDenied, reason));
+            }
+        }
+
+        self.default = None;
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the process to which an exception vector is delivered.
+    ///
+    /// # Parameters
+    ///
+    /// - `idx`: Exception vector.
+    ///
+    /// # Returns
+    ///
+    /// The owner of the vector, the default owner if the vector is not individually owned, or
+    /// `None` if there is no owner at all.
+    ///
+    pub fn owner(&self, idx: usize) -> Option<ProcessIdentifier> {
+        self.owners[idx].or(self.default)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the exception vectors that are delivered to a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// A bitmask of the exception vectors that are delivered to the target process.
+    ///
+    pub fn owned_by(&self, pid: ProcessIdentifier) -> usize {
+        let mut exceptions: usize = 0;
+        for idx in 0..self.owners.len() {
+            if self.owner(idx) == Some(pid) {
+                exceptions |= 1 << idx;
+            }
+        }
+
+        exceptions
+    }
+}
+
+impl Default for ExceptionOwnership {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
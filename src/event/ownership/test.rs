@@ -0,0 +1,134 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::event::ownership::ExceptionOwnership;
+use ::sys::pm::ProcessIdentifier;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if the default owner only receives exceptions that are not individually owned.
+fn test_default_owner() -> bool {
+    let owner: ProcessIdentifier = ProcessIdentifier::from(2);
+    let runtime: ProcessIdentifier = ProcessIdentifier::from(3);
+    let (owned, unowned): (usize, usize) = (14, 0);
+    let mut ownership: ExceptionOwnership = ExceptionOwnership::new();
+
+    if let Err(e) = ownership.register(owned, owner) {
+        error!("failed to register exception (error={:?})", e);
+        return false;
+    }
+    if let Err(e) = ownership.register_default(runtime) {
+        error!("failed to register default exception handler (error={:?})", e);
+        return false;
+    }
+
+    // Check if the unowned vector is delivered to the default owner.
+    if ownership.owner(unowned) != Some(runtime) {
+        error!("unowned exception was not delivered to the default handler");
+        return false;
+    }
+
+    // Check if the owned vector is still delivered to its owner.
+    if ownership.owner(owned) != Some(owner) {
+        error!("owned exception was not delivered to its owner");
+        return false;
+    }
+
+    // Check if wait masks are consistent with delivery.
+    if ownership.owned_by(owner) != 1 << owned {
+        error!("unexpected exceptions for owner");
+        return false;
+    }
+    if ownership.owned_by(runtime) != !(1 << owned) {
+        error!("unexpected exceptions for default handler");
+        return false;
+    }
+
+    // Check if the owned vector falls back to the default owner once released.
+    if let Err(e) = ownership.unregister(owned, Some(owner)) {
+        error!("failed to unregister exception (error={:?})", e);
+        return false;
+    }
+    if ownership.owner(owned) != Some(runtime) {
+        error!("released exception was not delivered to the default handler");
+        return false;
+    }
+
+    true
+}
+
+/// Tests if only one default owner may be registered at a time.
+fn test_default_owner_busy() -> bool {
+    let mut ownership: ExceptionOwnership = ExceptionOwnership::new();
+
+    if ownership
+        .register_default(ProcessIdentifier::from(2))
+        .is_err()
+    {
+        return false;
+    }
+    if ownership
+        .register_default(ProcessIdentifier::from(3))
+        .is_ok()
+    {
+        error!("registered a second default exception handler");
+        return false;
+    }
+    if ownership
+        .unregister_default(Some(ProcessIdentifier::from(3)))
+        .is_ok()
+    {
+        error!("unregistered a default exception handler that is not the caller");
+        return false;
+    }
+    if ownership
+        .unregister_default(Some(ProcessIdentifier::from(2)))
+        .is_err()
+    {
+        return false;
+    }
+
+    ownership.owner(0).is_none()
+}
+
+/// Tests if ownership of an exception vector is transferred only by its owner.
+fn test_transfer() -> bool {
+    let mut ownership: ExceptionOwnership = ExceptionOwnership::new();
+    let from: ProcessIdentifier = ProcessIdentifier::from(2);
+    let to: ProcessIdentifier = ProcessIdentifier::from(3);
+
+    if ownership.register(1, from).is_err() {
+        return false;
+    }
+    if ownership.transfer(1, to, to).is_ok() {
+        error!("transferred an exception vector that is not owned by the caller");
+        return false;
+    }
+    if ownership.transfer(1, from, to).is_err() {
+        return false;
+    }
+    if ownership.owner(1) != Some(to) {
+        error!("exception vector was not transferred");
+        return false;
+    }
+
+    // Check if the previous owner may no longer unregister the vector.
+    ownership.unregister(1, Some(from)).is_err()
+}
+
+/// Runs all unit tests for exception ownership.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_default_owner);
+    passed &= run_test!(test_default_owner_busy);
+    passed &= run_test!(test_transfer);
+
+    passed
+}
@@ -0,0 +1,149 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::alloc::collections::LinkedList;
+use ::sys::pm::ProcessIdentifier;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A type that keeps track of signals that are pending on processes.
+///
+/// # Notes
+///
+/// - Signals are coalesced: raising a signal that is already pending has no effect.
+///
+pub struct PendingSignals {
+    /// Bitmasks of pending signals (process and signals).
+    pending: LinkedList<(ProcessIdentifier, u32)>,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl PendingSignals {
+    ///
+    /// # Description
+    ///
+    /// Instantiates an empty table of pending signals.
+    ///
+    /// # Returns
+    ///
+    /// A new table of pending signals.
+    ///
+    pub fn new() -> Self {
+        Self {
+            pending: LinkedList::new(),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Raises signals on a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Target process.
+    /// - `signals`: Bitmask of signals to raise.
+    ///
+    /// # Returns
+    ///
+    /// The bitmask of signals that were not pending before.
+    ///
+    pub fn raise(&mut self, pid: ProcessIdentifier, signals: u32) -> u32 {
+        match self.pending.iter_mut().find(|(p, _)| *p == pid) {
+            Some((_, pending)) => {
+                let new: u32 = signals & !*pending;
+                *pending |= signals;
+                new
+            },
+            None => {
+                if signals != 0 {
+                    self.pending.push_back((pid, signals));
+                }
+                signals
+            },
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks whether any signal is pending on a process, without taking it.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Target process.
+    ///
+    /// # Returns
+    ///
+    /// `true` if some signal is pending on the process and `false` otherwise.
+    ///
+    pub fn is_pending(&self, pid: ProcessIdentifier) -> bool {
+        self.pending.iter().any(|(p, _)| *p == pid)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Takes all signals that are pending on a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Target process.
+    ///
+    /// # Returns
+    ///
+    /// The bitmask of pending signals, or `None` if no signal is pending.
+    ///
+    pub fn take(&mut self, pid: ProcessIdentifier) -> Option<u32> {
+        let idx: usize = self.pending.iter().position(|(p, _)| *p == pid)?;
+        let (_, signals): (ProcessIdentifier, u32) = self.pending.remove(idx);
+        Some(signals)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Discards all signals that are pending on a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Target process.
+    ///
+    pub fn clear(&mut self, pid: ProcessIdentifier) {
+        self.pending.retain(|(p, _)| *p != pid)
+    }
+}
+
+impl Default for PendingSignals {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
@@ -0,0 +1,76 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::event::signal::PendingSignals;
+use ::sys::pm::ProcessIdentifier;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if signals that are raised several times are observed once as a coalesced bitmask.
+fn test_coalesced_signals() -> bool {
+    let recipient: ProcessIdentifier = ProcessIdentifier::from(2);
+    let other: ProcessIdentifier = ProcessIdentifier::from(3);
+    let mut signals: PendingSignals = PendingSignals::new();
+
+    // Raise three signals, two of them in the same call, and one of them twice.
+    if signals.raise(recipient, 0b0011) != 0b0011 {
+        error!("unexpected newly raised signals");
+        return false;
+    }
+    if signals.raise(recipient, 0b0110) != 0b0100 {
+        error!("pending signal was reported as newly raised");
+        return false;
+    }
+
+    // Check if signals of other processes are kept apart.
+    if signals.take(other).is_some() {
+        error!("observed signals of another process");
+        return false;
+    }
+
+    // Check if the recipient observes the coalesced bitmask once.
+    match signals.take(recipient) {
+        Some(0b0111) => {},
+        Some(pending) => {
+            error!("unexpected pending signals (pending={:#b})", pending);
+            return false;
+        },
+        None => {
+            error!("pending signals were not observed");
+            return false;
+        },
+    }
+    if signals.take(recipient).is_some() {
+        error!("drained signals were observed twice");
+        return false;
+    }
+
+    true
+}
+
+/// Tests if the signals of a process are discarded when cleared.
+fn test_clear_signals() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let mut signals: PendingSignals = PendingSignals::new();
+
+    signals.raise(pid, 0b1000);
+    signals.clear(pid);
+
+    signals.take(pid).is_none()
+}
+
+/// Runs all unit tests for pending signals.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_coalesced_signals);
+    passed &= run_test!(test_clear_signals);
+
+    passed
+}
@@ -5,7 +5,13 @@
 // Imports
 //==================================================================================================
 
-use crate::hal::arch::x86::cpu::tss;
+use crate::hal::arch::x86::cpu::{
+    fpu::{
+        self,
+        FpuState,
+    },
+    tss,
+};
 use ::arch::cpu::tss::Tss;
 
 //==================================================================================================
@@ -39,10 +45,13 @@ pub struct ContextInformation {
     eflags: u32,
     esp: u32,
     ss: u32,
+    /// Floating-point state. This is not touched by low-level assembly dispatcher code.
+    fpu: FpuState,
 }
 
-// `Context` must be 72 bytes long. This must match low-level assembly dispatcher code.
-sys::static_assert_size!(ContextInformation, 76);
+// `Context` must be 76 bytes long, plus the floating-point state. This must match low-level
+// assembly dispatcher code.
+sys::static_assert_size!(ContextInformation, 76 + core::mem::size_of::<FpuState>());
 
 //==================================================================================================
 // Implementations
@@ -88,7 +97,18 @@ impl ContextInformation {
         }
 
         let tss: *const Tss = tss::get_curr();
+
+        // Save floating-point state of the current context. Contexts that have never run start
+        // with a clean floating-point state, because they do not return from this function.
+        (*from).fpu.save();
+        if !(*to).fpu.is_initialized() {
+            fpu::reset();
+        }
+
         __context_switch(from, to, tss);
+
+        // Restore floating-point state of the current context, once it is switched back in.
+        (*from).fpu.restore();
     }
 }
 
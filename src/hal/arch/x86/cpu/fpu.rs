@@ -0,0 +1,199 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::core::arch;
+
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Size of the area that is used by the `fxsave` and `fxrstor` instructions (in bytes).
+const FXSAVE_AREA_SIZE: usize = 512;
+
+/// Required alignment of the area that is used by the `fxsave` and `fxrstor` instructions.
+const FXSAVE_AREA_ALIGN: usize = 16;
+
+/// Default value of the MXCSR register (all SIMD floating-point exceptions masked).
+const MXCSR_DEFAULT: u32 = 0x1f80;
+
+//==================================================================================================
+// Global Variables
+//==================================================================================================
+
+/// Asserts whether the `fxsave` and `fxrstor` instructions are enabled.
+static mut FXSR_ENABLED: bool = false;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Stores the floating-point (x87, MMX and SSE) state of a thread.
+///
+/// # Notes
+///
+/// The `fxsave` and `fxrstor` instructions require a 16-byte aligned area, but this structure is
+/// embedded in a packed structure. Therefore, the storage is over-allocated and the aligned area is
+/// computed at runtime. This requires the structure not to be moved while it holds a saved state.
+///
+#[repr(C)]
+pub struct FpuState {
+    /// Storage for the aligned save area.
+    storage: [u8; FXSAVE_AREA_SIZE + FXSAVE_AREA_ALIGN],
+    /// Asserts whether a state was ever saved in the storage.
+    initialized: bool,
+}
+
+// `FpuState` must be 529 bytes long.
+sys::static_assert_size!(FpuState, FXSAVE_AREA_SIZE + FXSAVE_AREA_ALIGN + 1);
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl FpuState {
+    ///
+    /// # Description
+    ///
+    /// Gets a pointer to the aligned save area of the target floating-point state.
+    ///
+    /// # Returns
+    ///
+    /// A pointer to the aligned save area of the target floating-point state.
+    ///
+    fn area(&mut self) -> *mut u8 {
+        let base: *mut u8 = self.storage.as_mut_ptr();
+        let offset: usize = base.align_offset(FXSAVE_AREA_ALIGN);
+        // Safety: the offset lies within the storage, because it was over-allocated.
+        unsafe { base.add(offset) }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Saves the floating-point state of the processor in the target floating-point state.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe because it reads the floating-point state of the processor.
+    ///
+    pub unsafe fn save(&mut self) {
+        if !FXSR_ENABLED {
+            return;
+        }
+
+        let area: *mut u8 = self.area();
+        arch::asm!("fxsave ({0})", in(reg) area, options(nostack, att_syntax));
+        self.initialized = true;
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Restores the floating-point state of the processor from the target floating-point state.
+    /// If no state was ever saved, the floating-point state of the processor is reset instead.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe because it overwrites the floating-point state of the processor.
+    ///
+    pub unsafe fn restore(&mut self) {
+        if !FXSR_ENABLED {
+            return;
+        }
+
+        if !self.initialized {
+            reset();
+            return;
+        }
+
+        let area: *mut u8 = self.area();
+        arch::asm!("fxrstor ({0})", in(reg) area, options(nostack, att_syntax));
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Asserts whether a state was ever saved in the target floating-point state.
+    ///
+    /// # Returns
+    ///
+    /// If a state was ever saved, `true` is returned. Otherwise, `false` is returned instead.
+    ///
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+}
+
+impl Default for FpuState {
+    fn default() -> Self {
+        Self {
+            storage: [0; FXSAVE_AREA_SIZE + FXSAVE_AREA_ALIGN],
+            initialized: false,
+        }
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Resets the floating-point state of the processor.
+///
+/// # Safety
+///
+/// This function is unsafe because it overwrites the floating-point state of the processor.
+///
+pub unsafe fn reset() {
+    if !FXSR_ENABLED {
+        return;
+    }
+
+    let mxcsr: u32 = MXCSR_DEFAULT;
+    arch::asm!(
+        "fninit",
+        "ldmxcsr ({0})",
+        in(reg) &mxcsr as *const u32,
+        options(nostack, att_syntax)
+    );
+}
+
+///
+/// # Description
+///
+/// Enables the floating-point unit and SSE extensions in the underlying core.
+///
+/// # Safety
+///
+/// This function is unsafe because it changes control registers of the processor.
+///
+pub unsafe fn init() {
+    // Enable floating-point unit: clear emulation (EM) and task switched (TS) flags, and set
+    // monitor coprocessor (MP) flag.
+    // Enable SSE extensions: set OSFXSR and OSXMMEXCPT flags.
+    arch::asm!(
+        "mov %cr0, %eax",
+        "and $0xfffffff3, %eax",
+        "or $0x2, %eax",
+        "mov %eax, %cr0",
+        "mov %cr4, %eax",
+        "or $0x600, %eax",
+        "mov %eax, %cr4",
+        "fninit",
+        out("eax") _,
+        options(nostack, att_syntax)
+    );
+
+    FXSR_ENABLED = true;
+
+    reset();
+}
@@ -158,16 +158,70 @@ impl InterruptController {
         }
     }
 
+    ///
+    /// # Description
+    ///
+    /// Gets the number of interrupt lines that are supported by the interrupt controller.
+    ///
+    /// # Returns
+    ///
+    /// The number of interrupt lines that are supported by the interrupt controller.
+    ///
+    pub fn number_of_lines(&self) -> usize {
+        match self.intctrl {
+            InterruptControllerType::Legacy(_) => Pic::NUMBER_OF_LINES,
+            InterruptControllerType::Xapic(_, ref ioapic) => ioapic.number_of_lines(),
+        }
+    }
+
     pub fn unmask(&mut self, intnum: InterruptNumber) -> Result<(), Error> {
         match self.intctrl {
             InterruptControllerType::Legacy(ref mut pic) => {
                 pic.unmask(intnum as u16);
                 Ok(())
             },
-            // FIXME: enable interrupt on right CPU.
+            // NOTE: the destination set by `set_affinity()` is preserved.
             InterruptControllerType::Xapic(_, ref mut ioapic) => {
                 let intnum: u8 = self.intmap[intnum];
-                ioapic.enable(intnum, 0)
+                ioapic.unmask(intnum)
+            },
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Routes an interrupt to a given CPU.
+    ///
+    /// # Parameters
+    ///
+    /// - `intnum`: Interrupt number.
+    /// - `cpu_apic_id`: Local APIC ID of the target CPU.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty result is returned. Otherwise, an error is returned.
+    ///
+    pub fn set_affinity(&mut self, intnum: InterruptNumber, cpu_apic_id: u8) -> Result<(), Error> {
+        // On a uniprocessor boot, all interrupts are routed to the bootstrap processor.
+        if !cfg!(feature = "smp") {
+            if cpu_apic_id != 0 {
+                let reason: &str = "invalid cpu number";
+                error!("set_affinity(): {}", reason);
+                return Err(Error::new(ErrorCode::InvalidArgument, reason));
+            }
+            return Ok(());
+        }
+
+        match self.intctrl {
+            InterruptControllerType::Legacy(_) => {
+                let reason: &str = "legacy pic does not support interrupt affinity";
+                error!("set_affinity(): {}", reason);
+                Err(Error::new(ErrorCode::OperationNotSupported, reason))
+            },
+            InterruptControllerType::Xapic(_, ref mut ioapic) => {
+                let gsi: u32 = ioapic.gsi() + self.intmap[intnum] as u32;
+                ioapic.set_affinity(gsi, cpu_apic_id)
             },
         }
     }
@@ -9,12 +9,180 @@ use crate::hal::{
     io::IoMemoryRegion,
     mem::Address,
 };
+use ::alloc::vec::Vec;
 use ::arch::cpu::ioapic;
 use ::sys::error::{
     Error,
     ErrorCode,
 };
 
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Maximum number of CPUs that can be targeted in physical destination mode.
+///
+/// When using physical destination mode, only the lower 4 bits of the destination field are used.
+/// The specification is unclear about the behavior of the upper bits. See 82093AA I/O ADVANCED
+/// PROGRAMMABLE INTERRUPT CONTROLLER (IOAPIC) for details.
+const MAXIMUM_NUMBER_CPUS: u8 = 16;
+
+//==================================================================================================
+// Redirection Table
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A shadow copy of the redirection table of an I/O APIC. Redirection entries are recorded here
+/// whenever they are written to the hardware, so that individual fields can be updated later.
+///
+pub(super) struct RedirectionTable {
+    /// Redirection entries (high and low words).
+    entries: Vec<(u32, u32)>,
+}
+
+impl RedirectionTable {
+    ///
+    /// # Description
+    ///
+    /// Instantiates a redirection table.
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: Number of redirection entries.
+    ///
+    /// # Return Values
+    ///
+    /// A new redirection table with all entries set to zero.
+    ///
+    pub(super) fn new(len: usize) -> Self {
+        let mut entries: Vec<(u32, u32)> = Vec::with_capacity(len);
+        entries.resize(len, (0, 0));
+        Self { entries }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the number of redirection entries.
+    ///
+    /// # Return Values
+    ///
+    /// The number of redirection entries in the table.
+    ///
+    pub(super) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Records a redirection entry.
+    ///
+    /// # Parameters
+    ///
+    /// - `irq`: Interrupt line.
+    /// - `high`: High word of the redirection entry.
+    /// - `low`: Low word of the redirection entry.
+    ///
+    pub(super) fn write(&mut self, irq: u32, high: u32, low: u32) {
+        if let Some(entry) = self.entries.get_mut(irq as usize) {
+            *entry = (high, low);
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets a redirection entry.
+    ///
+    /// # Parameters
+    ///
+    /// - `irq`: Interrupt line.
+    ///
+    /// # Return Values
+    ///
+    /// The high and low words of the redirection entry are returned, or `None` if `irq` does not
+    /// lie in the table.
+    ///
+    pub(super) fn get(&self, irq: u32) -> Option<(u32, u32)> {
+        self.entries.get(irq as usize).copied()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Rewrites the destination field of a redirection entry.
+    ///
+    /// # Parameters
+    ///
+    /// - `irq`: Interrupt line.
+    /// - `cpu_apic_id`: Local APIC ID of the target CPU.
+    ///
+    /// # Return Values
+    ///
+    /// Upon success, the updated high and low words of the redirection entry are returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub(super) fn set_destination(
+        &mut self,
+        irq: u32,
+        cpu_apic_id: u8,
+    ) -> Result<(u32, u32), Error> {
+        // Check CPU number lies in a valid range.
+        if cpu_apic_id >= MAXIMUM_NUMBER_CPUS {
+            let reason: &str = "invalid cpu number";
+            error!("set_destination(): {}", reason);
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
+        }
+
+        let entry: &mut (u32, u32) = match self.entries.get_mut(irq as usize) {
+            Some(entry) => entry,
+            None => {
+                let reason: &str = "invalid irq number";
+                error!("set_destination(): {}", reason);
+                return Err(Error::new(ErrorCode::InvalidArgument, reason));
+            },
+        };
+
+        // NOTE: the destination field lies in the upper byte of the high word.
+        entry.0 = (entry.0 & !(0xffu32 << ioapic::IoapicRedirectionTableHigh::IOREDTBL_DEST_SHIFT))
+            | ((cpu_apic_id as u32) << ioapic::IoapicRedirectionTableHigh::IOREDTBL_DEST_SHIFT);
+
+        Ok(*entry)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Clears the mask bit of a redirection entry.
+    ///
+    /// # Parameters
+    ///
+    /// - `irq`: Interrupt line.
+    ///
+    /// # Return Values
+    ///
+    /// Upon success, the updated high and low words of the redirection entry are returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub(super) fn unmask(&mut self, irq: u32) -> Result<(u32, u32), Error> {
+        let entry: &mut (u32, u32) = match self.entries.get_mut(irq as usize) {
+            Some(entry) => entry,
+            None => {
+                let reason: &str = "invalid irq number";
+                error!("unmask(): {}", reason);
+                return Err(Error::new(ErrorCode::InvalidArgument, reason));
+            },
+        };
+
+        entry.1 &= !ioapic::IoapicRedirectionTableLow::IOREDTBL_INTMASK_MASK;
+
+        Ok(*entry)
+    }
+}
+
 //==================================================================================================
 // Uninitialized I/O APIC
 //==================================================================================================
@@ -77,6 +245,8 @@ impl UninitIoapic {
         let mut ioapic: Ioapic = Ioapic {
             _base: self.base.clone(),
             intvec_base: self.intvec_base,
+            gsi: self.gsi,
+            redirection: RedirectionTable::new(0),
             ptr: ioapic::Ioapic::new(self.base.base().into_raw_value()),
         };
 
@@ -90,13 +260,13 @@ impl UninitIoapic {
         ioapic.print_info();
 
         let maxintr: u8 = ioapic::IoapicVersion::maxredirect(ioapic.deref_mut());
+        ioapic.redirection = RedirectionTable::new(maxintr as usize + 1);
 
         // For all interrupts: set physical destination mode to APIC ID 0; set high
         // activate; set edge-triggered; set disabled; set fixed delivery mode;
         // identity map interrupts.
         for irq in 0..=maxintr {
-            ioapic::IoapicRedirectionTable::write(
-                ioapic.deref_mut(),
+            ioapic.write_redirection(
                 irq as u32,
                 0,
                 ioapic::IoapicRedirectionTableLow::IOREDTBL_INTMASK_MASK
@@ -122,50 +292,105 @@ pub struct Ioapic {
     _base: IoMemoryRegion, // NOTE: we must keep this here to avoid deallocation.
     /// Interrupt vector base.
     intvec_base: u8,
+    /// Global System Interrupt (GSI).
+    gsi: u32,
+    /// Shadow copy of the redirection table.
+    redirection: RedirectionTable,
     /// Underlying I/O APIC.
     ptr: ioapic::Ioapic,
 }
 
 impl Ioapic {
-    /// Enables an interrupt line.
-    pub fn enable(&mut self, irq: u8, cpunum: u8) -> Result<(), Error> {
-        info!("trace(): irq={}, cpunum={}", irq, cpunum);
-        // When using physical destination mode, only the lower 4 bits of the
-        // destination field are used. The specification is unclear about the
-        // behavior of the upper bits. See 82093AA I/O ADVANCED PROGRAMMABLE
-        // INTERRUPT CONTROLLER (IOAPIC) for details.
-        const MAXIMUM_NUMBER_CPUS: u8 = 16;
-
-        // Check IRQ lies in a valid range.
-        if irq >= ioapic::IoapicVersion::maxredirect(self.deref_mut()) {
-            let reason: &str = "invalid irq number";
-            error!("enable(): {}", reason);
-            return Err(Error::new(ErrorCode::InvalidArgument, reason));
-        }
+    ///
+    /// # Description
+    ///
+    /// Unmasks an interrupt line, preserving its destination and remaining fields.
+    ///
+    /// # Parameters
+    ///
+    /// - `irq`: Interrupt line.
+    ///
+    /// # Return Values
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn unmask(&mut self, irq: u8) -> Result<(), Error> {
+        trace!("unmask(): irq={}", irq);
 
-        // Check CPU number lies in a valid range.
-        if cpunum > MAXIMUM_NUMBER_CPUS {
-            let reason: &str = "invalid cpu number";
-            error!("enable(): {}", reason);
-            return Err(Error::new(ErrorCode::InvalidArgument, reason));
-        }
+        let (high, low): (u32, u32) = self.redirection.unmask(irq as u32)?;
+        ioapic::IoapicRedirectionTable::write(self.deref_mut(), irq as u32, high, low);
 
-        // Set physical destination mode to cpunum; set high activate; set
-        // edge-triggered; set enabled; set fixed delivery mode; identity map
-        // interrupt.
-        // NOTE: The following cast is safe because "cpunum" is a 4-bit value.
-        // NOTE: The following cast is safe because "irq" is an 8-bit value.
-        let intvec_base: u8 = self.intvec_base;
-        ioapic::IoapicRedirectionTable::write(
-            self.deref_mut(),
-            irq as u32,
-            (cpunum as u32) << ioapic::IoapicRedirectionTableHigh::IOREDTBL_DEST_SHIFT,
-            (intvec_base + irq) as u32,
-        );
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Routes an interrupt line to a given CPU.
+    ///
+    /// # Parameters
+    ///
+    /// - `gsi`: Global System Interrupt (GSI) of the target interrupt line.
+    /// - `cpu_apic_id`: Local APIC ID of the target CPU.
+    ///
+    /// # Return Values
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn set_affinity(&mut self, gsi: u32, cpu_apic_id: u8) -> Result<(), Error> {
+        trace!("set_affinity(): gsi={}, cpu_apic_id={}", gsi, cpu_apic_id);
+
+        // Check if GSI is not handled by this I/O APIC.
+        let irq: u32 = match gsi.checked_sub(self.gsi) {
+            Some(irq) if irq <= ioapic::IoapicVersion::maxredirect(self.deref_mut()) as u32 => irq,
+            _ => {
+                let reason: &str = "invalid gsi";
+                error!("set_affinity(): {}", reason);
+                return Err(Error::new(ErrorCode::InvalidArgument, reason));
+            },
+        };
+
+        // Rewrite destination field, preserving remaining fields of the redirection entry.
+        let (high, low): (u32, u32) = self.redirection.set_destination(irq, cpu_apic_id)?;
+        ioapic::IoapicRedirectionTable::write(self.deref_mut(), irq, high, low);
 
         Ok(())
     }
 
+    ///
+    /// # Description
+    ///
+    /// Gets the number of interrupt lines of the target I/O APIC.
+    ///
+    pub fn number_of_lines(&self) -> usize {
+        self.redirection.len()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the Global System Interrupt (GSI) base of the target I/O APIC.
+    ///
+    pub fn gsi(&self) -> u32 {
+        self.gsi
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Writes a redirection entry to the hardware and records it in the shadow table.
+    ///
+    /// # Parameters
+    ///
+    /// - `irq`: Interrupt line.
+    /// - `high`: High word of the redirection entry.
+    /// - `low`: Low word of the redirection entry.
+    ///
+    fn write_redirection(&mut self, irq: u32, high: u32, low: u32) {
+        ioapic::IoapicRedirectionTable::write(self.deref_mut(), irq, high, low);
+        self.redirection.write(irq, high, low);
+    }
+
     ///
     /// # Description
     ///
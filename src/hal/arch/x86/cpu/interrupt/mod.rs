@@ -12,6 +12,9 @@ mod number;
 mod pic;
 mod xapic;
 
+#[cfg(test)]
+mod test;
+
 //==================================================================================================
 // Imports
 //==================================================================================================
@@ -239,3 +242,12 @@ pub fn init(
         },
     }
 }
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
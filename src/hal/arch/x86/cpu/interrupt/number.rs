@@ -1,6 +1,15 @@
 // Copyright(c) The Maintainers of Nanvix.
 // Licensed under the MIT License.
 
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::sys::error::{
+    Error,
+    ErrorCode,
+};
+
 //==================================================================================================
 // Structures
 //==================================================================================================
@@ -25,6 +34,10 @@ pub enum InterruptNumber {
     SecondaryAta = 15,
 }
 
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
 impl InterruptNumber {
     pub const VALUES: [InterruptNumber; 15] = [
         InterruptNumber::Timer,
@@ -44,3 +57,31 @@ impl InterruptNumber {
         InterruptNumber::SecondaryAta,
     ];
 }
+
+impl TryFrom<u32> for InterruptNumber {
+    type Error = Error;
+
+    ///
+    /// # Description
+    ///
+    /// Attempts to convert a [`u32`] to an interrupt number.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: [`u32`] value to convert.
+    ///
+    /// # Returns
+    ///
+    /// On success, the interrupt number is returned. On failure, an error is returned instead.
+    ///
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match Self::VALUES.iter().find(|intnum| **intnum as u32 == value) {
+            Some(intnum) => Ok(*intnum),
+            None => {
+                let reason: &str = "invalid interrupt number";
+                error!("try_from(): {} (value={})", reason, value);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+        }
+    }
+}
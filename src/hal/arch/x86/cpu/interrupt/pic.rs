@@ -196,6 +196,9 @@ pub struct Pic {
 }
 
 impl Pic {
+    /// Number of interrupt lines of the master and slave PICs.
+    pub const NUMBER_OF_LINES: usize = 16;
+
     ///
     /// # Description
     ///
@@ -0,0 +1,137 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::hal::arch::x86::cpu::interrupt::{
+    ioapic::RedirectionTable,
+    InterruptNumber,
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if [`RedirectionTable::set_destination()`] rewrites only the destination field.
+fn test_set_destination() -> bool {
+    const IRQ: u32 = 3;
+    const HIGH: u32 = 0x00ab_cdef;
+    const LOW: u32 = 0x0001_0023;
+    const CPU: u8 = 5;
+
+    let mut table: RedirectionTable = RedirectionTable::new(8);
+    table.write(IRQ, HIGH, LOW);
+
+    if let Err(e) = table.set_destination(IRQ, CPU) {
+        error!("failed to set destination (error={:?})", e);
+        return false;
+    }
+
+    // Check if the destination field was updated and remaining bits were preserved.
+    match table.get(IRQ) {
+        Some((high, low)) if high == ((CPU as u32) << 24) | HIGH && low == LOW => {},
+        Some((high, low)) => {
+            error!("unexpected redirection entry (high={:#010x}, low={:#010x})", high, low);
+            return false;
+        },
+        None => {
+            error!("missing redirection entry");
+            return false;
+        },
+    }
+
+    true
+}
+
+/// Tests if [`RedirectionTable::set_destination()`] rejects invalid arguments.
+fn test_set_destination_invalid() -> bool {
+    let mut table: RedirectionTable = RedirectionTable::new(8);
+
+    // Check if an invalid CPU is rejected.
+    if table.set_destination(0, 16).is_ok() {
+        error!("invalid cpu was accepted");
+        return false;
+    }
+
+    // Check if an invalid interrupt line is rejected.
+    if table.set_destination(8, 0).is_ok() {
+        error!("invalid irq was accepted");
+        return false;
+    }
+
+    true
+}
+
+/// Tests if [`RedirectionTable::unmask()`] clears only the mask bit.
+fn test_unmask() -> bool {
+    const IRQ: u32 = 3;
+    const HIGH: u32 = 0x0500_0000;
+    const LOW: u32 = 0x0001_0023;
+
+    let mut table: RedirectionTable = RedirectionTable::new(8);
+    table.write(IRQ, HIGH, LOW);
+
+    if let Err(e) = table.unmask(IRQ) {
+        error!("failed to unmask (error={:?})", e);
+        return false;
+    }
+
+    // Check if the mask bit was cleared and the destination was preserved.
+    match table.get(IRQ) {
+        Some((high, low)) if high == HIGH && low == LOW & !(1 << 16) => {},
+        Some((high, low)) => {
+            error!("unexpected redirection entry (high={:#010x}, low={:#010x})", high, low);
+            return false;
+        },
+        None => {
+            error!("missing redirection entry");
+            return false;
+        },
+    }
+
+    // Check if an invalid interrupt line is rejected.
+    if table.unmask(8).is_ok() {
+        error!("invalid irq was accepted");
+        return false;
+    }
+
+    true
+}
+
+/// Tests if [`InterruptNumber::try_from()`] accepts only numbers of remapped interrupt lines.
+fn test_interrupt_number() -> bool {
+    // Check if every interrupt number round-trips.
+    for intnum in InterruptNumber::VALUES.iter() {
+        match InterruptNumber::try_from(*intnum as u32) {
+            Ok(n) if n == *intnum => {},
+            _ => {
+                error!("valid interrupt number was rejected (intnum={:?})", intnum);
+                return false;
+            },
+        }
+    }
+
+    // Check if the cascade line and out-of-range numbers are rejected.
+    for value in [2, 16, u32::MAX].iter() {
+        if InterruptNumber::try_from(*value).is_ok() {
+            error!("invalid interrupt number was accepted (value={})", value);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Runs all unit tests for interrupts.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_set_destination);
+    passed &= run_test!(test_set_destination_invalid);
+    passed &= run_test!(test_unmask);
+    passed &= run_test!(test_interrupt_number);
+
+    passed
+}
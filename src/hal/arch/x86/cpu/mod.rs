@@ -7,12 +7,16 @@
 
 mod context;
 mod exception;
+mod fpu;
 mod idt;
 mod interrupt;
 
 #[cfg(feature = "smp")]
 mod clock;
 
+#[cfg(test)]
+mod test;
+
 //==================================================================================================
 // Imports
 //==================================================================================================
@@ -49,6 +53,7 @@ pub use interrupt::{
     InterruptHandler,
     InterruptNumber,
 };
+pub mod pat;
 pub mod tss;
 
 //==================================================================================================
@@ -102,6 +107,20 @@ pub fn init(
     let (gdt, gdtr, tss): (Gdt, GdtPtr, TssRef) = unsafe { Gdt::init(&kstack)? };
     unsafe { idt::init() };
 
+    // Enable floating-point unit and SSE extensions, if they are supported.
+    if arch::cpu::cpuid::has_cpuid() && cpuid::has_fpu() && cpuid::has_fxsr() {
+        unsafe { fpu::init() };
+    } else {
+        warn!("fxsr not supported, floating-point state will not be preserved");
+    }
+
+    // Program page attribute table, if it is supported.
+    if arch::cpu::cpuid::has_cpuid() && cpuid::has_pat() {
+        unsafe { pat::init() };
+    } else {
+        warn!("pat not supported, write-combining mappings will not be available");
+    }
+
     let controller: Option<InterruptController> = match interrupt::init(ioports, ioaddresses, madt)
     {
         Ok(controller) => Some(controller),
@@ -118,6 +137,22 @@ pub fn init(
 pub fn initialize_application_core(kstack: *const u8) -> Result<(Gdt, GdtPtr, TssRef), Error> {
     let (gdt, gdtr, tss): (Gdt, GdtPtr, TssRef) = unsafe { Gdt::init(kstack)? };
     unsafe { idt::load() };
+    if cpuid::has_fpu() && cpuid::has_fxsr() {
+        unsafe { fpu::init() };
+    }
+    if cpuid::has_pat() {
+        unsafe { pat::init() };
+    }
 
     Ok((gdt, gdtr, tss))
 }
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= interrupt::test();
+    passed &= test::test();
+
+    passed
+}
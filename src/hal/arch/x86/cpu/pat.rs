@@ -0,0 +1,71 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::core::arch;
+
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Model-specific register that holds the page attribute table.
+const IA32_PAT: u32 = 0x277;
+
+///
+/// Value of the page attribute table. It matches the power-up default, except for entry 4, which is
+/// set to write-combining:
+///
+/// - Entry 0 (PAT=0, PCD=0, PWT=0): write-back.
+/// - Entry 1 (PAT=0, PCD=0, PWT=1): write-through.
+/// - Entry 2 (PAT=0, PCD=1, PWT=0): uncached minus.
+/// - Entry 3 (PAT=0, PCD=1, PWT=1): uncacheable.
+/// - Entry 4 (PAT=1, PCD=0, PWT=0): write-combining.
+/// - Entry 5 (PAT=1, PCD=0, PWT=1): write-through.
+/// - Entry 6 (PAT=1, PCD=1, PWT=0): uncached minus.
+/// - Entry 7 (PAT=1, PCD=1, PWT=1): uncacheable.
+///
+const PAT_VALUE: u64 = 0x0007_0401_0007_0406;
+
+//==================================================================================================
+// Global Variables
+//==================================================================================================
+
+/// Asserts whether the page attribute table is enabled.
+static mut PAT_ENABLED: bool = false;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Asserts whether the page attribute table is enabled in the underlying core.
+///
+pub fn is_enabled() -> bool {
+    unsafe { PAT_ENABLED }
+}
+
+///
+/// # Description
+///
+/// Programs the page attribute table of the underlying core.
+///
+/// # Safety
+///
+/// This function is unsafe because it changes model-specific registers of the processor.
+///
+pub unsafe fn init() {
+    arch::asm!(
+        "wrmsr",
+        in("ecx") IA32_PAT,
+        in("eax") PAT_VALUE as u32,
+        in("edx") (PAT_VALUE >> 32) as u32,
+        options(nostack)
+    );
+
+    PAT_ENABLED = true;
+}
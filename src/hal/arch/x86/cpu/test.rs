@@ -0,0 +1,126 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::hal::arch::x86::cpu::fpu::{
+    self,
+    FpuState,
+};
+use ::arch::cpu::cpuid;
+use ::core::arch;
+
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Value of the x87 control word after the floating-point unit is reset.
+const FCW_DEFAULT: u16 = 0x037f;
+
+/// Value of the MXCSR register after the floating-point unit is reset.
+const MXCSR_DEFAULT: u32 = 0x1f80;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Reads the x87 control word and the MXCSR register of the processor.
+fn read_control() -> (u16, u32) {
+    let mut fcw: u16 = 0;
+    let mut mxcsr: u32 = 0;
+    unsafe {
+        arch::asm!(
+            "fnstcw ({0})",
+            "stmxcsr ({1})",
+            in(reg) &mut fcw as *mut u16,
+            in(reg) &mut mxcsr as *mut u32,
+            options(nostack, att_syntax)
+        );
+    }
+    (fcw, mxcsr)
+}
+
+/// Writes the x87 control word and the MXCSR register of the processor.
+fn write_control(fcw: u16, mxcsr: u32) {
+    unsafe {
+        arch::asm!(
+            "fldcw ({0})",
+            "ldmxcsr ({1})",
+            in(reg) &fcw as *const u16,
+            in(reg) &mxcsr as *const u32,
+            options(nostack, att_syntax)
+        );
+    }
+}
+
+/// Tests if the floating-point state of a context is saved and restored across a context switch,
+/// following the same sequence as [`super::ContextInformation::switch()`].
+fn test_fpu_save_restore() -> bool {
+    // Floating-point state of the context that is switched out (rounding towards zero).
+    const FROM: (u16, u32) = (0x0f7f, 0x7f80);
+    // Floating-point state of the context that is switched in (rounding upwards).
+    const TO: (u16, u32) = (0x0b7f, 0x5f80);
+
+    if !(cpuid::has_cpuid() && cpuid::has_fpu() && cpuid::has_fxsr()) {
+        warn!("fxsr not supported, skipping floating-point state test");
+        return true;
+    }
+
+    // Enable the floating-point unit, so that its state is actually saved and restored.
+    unsafe { fpu::init() };
+
+    let mut from: FpuState = FpuState::default();
+    let mut to: FpuState = FpuState::default();
+
+    // Switch out the first context, and switch in a context that has never run.
+    write_control(FROM.0, FROM.1);
+    unsafe {
+        from.save();
+        to.restore();
+    }
+
+    // Check if the context that has never run starts with a clean floating-point state.
+    if read_control() != (FCW_DEFAULT, MXCSR_DEFAULT) {
+        error!("unexpected initial floating-point state (state={:x?})", read_control());
+        return false;
+    }
+
+    // Switch the first context back in.
+    write_control(TO.0, TO.1);
+    unsafe {
+        to.save();
+        from.restore();
+    }
+
+    // Check if the floating-point state of the first context was restored.
+    if read_control() != FROM {
+        error!("unexpected restored floating-point state (state={:x?})", read_control());
+        return false;
+    }
+
+    // Switch the second context back in and check if its floating-point state was restored.
+    unsafe {
+        from.save();
+        to.restore();
+    }
+    let passed: bool = read_control() == TO;
+    if !passed {
+        error!("unexpected restored floating-point state (state={:x?})", read_control());
+    }
+
+    // Leave the processor with a clean floating-point state.
+    unsafe { fpu::reset() };
+
+    passed
+}
+
+/// Runs all unit tests for the processor.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_fpu_save_restore);
+
+    passed
+}
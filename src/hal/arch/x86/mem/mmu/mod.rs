@@ -15,6 +15,9 @@ use ::sys::mm::Alignment;
 pub mod page_directory;
 pub mod page_table;
 
+#[cfg(test)]
+mod test;
+
 //==================================================================================================
 // Constants
 //==================================================================================================
@@ -39,3 +42,17 @@ pub unsafe fn load_page_directory(cr3: usize) {
         options(nostack, att_syntax)
     );
 }
+
+#[inline(never)]
+pub unsafe fn invalidate_page(vaddr: usize) {
+    arch::asm!("invlpg ({0})", in(reg) vaddr, options(nostack, att_syntax));
+}
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
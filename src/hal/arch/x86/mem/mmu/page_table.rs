@@ -5,13 +5,16 @@
 // Imports
 //==================================================================================================
 
-use crate::hal::mem::{
-    AccessPermission,
-    Address,
-    FrameAddress,
-    PageAddress,
-    PageAligned,
-    PhysicalAddress,
+use crate::hal::{
+    arch::x86::cpu::pat,
+    mem::{
+        AccessPermission,
+        Address,
+        FrameAddress,
+        PageAddress,
+        PageAligned,
+        PhysicalAddress,
+    },
 };
 use ::alloc::boxed::Box;
 use ::arch::mem::{
@@ -33,11 +36,27 @@ use ::core::ops::{
     Deref,
     DerefMut,
 };
-use ::sys::error::{
-    Error,
-    ErrorCode,
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::CachingMode,
 };
 
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Page write-through (PWT) bit of a page table entry.
+pub(super) const PTE_PWT: u32 = 1 << 3;
+
+/// Page cache disable (PCD) bit of a page table entry.
+pub(super) const PTE_PCD: u32 = 1 << 4;
+
+/// Page attribute table (PAT) bit of a page table entry.
+pub(super) const PTE_PAT: u32 = 1 << 7;
+
 //==================================================================================================
 // Structures
 //==================================================================================================
@@ -248,6 +267,146 @@ impl PageTable {
         Ok(paddr)
     }
 
+    ///
+    /// # Description
+    ///
+    /// Checks whether a page in the target page table is writable.
+    ///
+    /// # Parameters
+    ///
+    /// - `page_address`: Page address to check.
+    ///
+    /// # Return Values
+    ///
+    /// Upon success, `true` is returned if the target page is writable and `false` otherwise.
+    /// Upon failure, an error is returned instead.
+    ///
+    pub fn is_writable(&self, page_address: PageAddress) -> Result<bool, Error> {
+        // Obtain a cached copy of the page table entry.
+        let pte: PageTableEntry = match self.read_pte(page_address) {
+            Some(pte) => pte,
+            None => {
+                let reason: &str = "failed to read page table entry";
+                error!("is_writable(): {}", reason);
+                return Err(Error::new(ErrorCode::TryAgain, reason));
+            },
+        };
+
+        // Check if page is not present.
+        if !pte.is_present() {
+            let reason: &str = "page is not present";
+            error!("is_writable(): {}", reason);
+            return Err(Error::new(ErrorCode::NoSuchEntry, reason));
+        }
+
+        // NOTE: the read/write flag lies in bit 1 of a page table entry.
+        Ok((pte.into_raw_value() & (1 << 1)) != 0)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Changes the caching mode of a page in the target page table.
+    ///
+    /// # Parameters
+    ///
+    /// - `page_address`: Page address to change.
+    /// - `mode`: Caching mode.
+    ///
+    /// # Return Values
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn set_caching(
+        &mut self,
+        page_address: PageAddress,
+        mode: CachingMode,
+    ) -> Result<(), Error> {
+        // Obtain a cached copy of the page table entry.
+        let pte: PageTableEntry = match self.read_pte(page_address) {
+            Some(pte) => pte,
+            None => {
+                let reason: &str = "failed to read page table entry";
+                error!("set_caching(): {}", reason);
+                return Err(Error::new(ErrorCode::TryAgain, reason));
+            },
+        };
+
+        // Check if page is not present.
+        if !pte.is_present() {
+            let reason: &str = "page is not present";
+            error!("set_caching(): {}", reason);
+            return Err(Error::new(ErrorCode::NoSuchEntry, reason));
+        }
+
+        // Compute attribute bits. See `pat::PAT_VALUE` for the encoding of each mode.
+        let bits: u32 = match mode {
+            CachingMode::Uncacheable => PTE_PCD | PTE_PWT,
+            CachingMode::WriteCombining => {
+                // Check if page attribute table is not enabled.
+                if !pat::is_enabled() {
+                    let reason: &str = "write-combining is not supported";
+                    error!("set_caching(): {}", reason);
+                    return Err(Error::new(ErrorCode::OperationNotSupported, reason));
+                }
+                PTE_PAT
+            },
+            CachingMode::WriteBack => 0,
+        };
+
+        // Write page table entry.
+        // NOTE: the raw entry is written, because the PAT bit is not exposed by `PageTableEntry`.
+        let pte_idx: usize = page_address.get_pte_index();
+        self.entries[pte_idx] = (pte.into_raw_value() & !(PTE_PAT | PTE_PCD | PTE_PWT)) | bits;
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the caching mode of a page in the target page table.
+    ///
+    /// # Parameters
+    ///
+    /// - `page_address`: Page address to check.
+    ///
+    /// # Return Values
+    ///
+    /// Upon success, the caching mode of the target page is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    pub fn caching(&self, page_address: PageAddress) -> Result<CachingMode, Error> {
+        // Obtain a cached copy of the page table entry.
+        let pte: PageTableEntry = match self.read_pte(page_address) {
+            Some(pte) => pte,
+            None => {
+                let reason: &str = "failed to read page table entry";
+                error!("caching(): {}", reason);
+                return Err(Error::new(ErrorCode::TryAgain, reason));
+            },
+        };
+
+        // Check if page is not present.
+        if !pte.is_present() {
+            let reason: &str = "page is not present";
+            error!("caching(): {}", reason);
+            return Err(Error::new(ErrorCode::NoSuchEntry, reason));
+        }
+
+        let pte_idx: usize = page_address.get_pte_index();
+        match self.entries[pte_idx] & (PTE_PAT | PTE_PCD | PTE_PWT) {
+            0 => Ok(CachingMode::WriteBack),
+            PTE_PAT => Ok(CachingMode::WriteCombining),
+            bits if bits & PTE_PCD != 0 => Ok(CachingMode::Uncacheable),
+            _ => {
+                let reason: &str = "unknown caching mode";
+                error!("caching(): {}", reason);
+                Err(Error::new(ErrorCode::InvalidArgument, reason))
+            },
+        }
+    }
+
     /// Changes access permissions on a page.
     pub fn ctrl(
         &mut self,
@@ -302,6 +461,11 @@ impl PageTable {
         pte
     }
 
+    #[cfg(test)]
+    pub(super) fn read_raw_pte(&self, vaddr: PageAddress) -> u32 {
+        self.entries[vaddr.get_pte_index()]
+    }
+
     fn write_pte(&mut self, vaddr: PageAddress, pte: PageTableEntry) {
         let pte_idx: usize = vaddr.get_pte_index();
         self.entries[pte_idx] = pte.into_raw_value();
@@ -0,0 +1,115 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::hal::{
+    arch::x86::{
+        cpu::pat,
+        mem::mmu::page_table::{
+            PageTable,
+            PageTableStorage,
+            PTE_PAT,
+            PTE_PCD,
+            PTE_PWT,
+        },
+    },
+    mem::{
+        AccessPermission,
+        Address,
+        FrameAddress,
+        PageAddress,
+        PageAligned,
+        PhysicalAddress,
+        VirtualAddress,
+    },
+};
+use ::sys::mm::CachingMode;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if [`PageTable::set_caching()`] sets attribute bits that match the requested mode.
+fn test_set_caching() -> bool {
+    let mut page_table: PageTable = PageTable::new(PageTableStorage::new());
+
+    let vaddr: PageAddress = match PageAligned::from_address(VirtualAddress::new(0x00400000)) {
+        Ok(vaddr) => PageAddress::new(vaddr),
+        Err(e) => {
+            error!("failed to create page address (error={:?})", e);
+            return false;
+        },
+    };
+    let paddr: FrameAddress = match PhysicalAddress::from_raw_value(0x00400000) {
+        Ok(paddr) => match PageAligned::from_address(paddr) {
+            Ok(paddr) => FrameAddress::new(paddr),
+            Err(e) => {
+                error!("failed to create frame address (error={:?})", e);
+                return false;
+            },
+        },
+        Err(e) => {
+            error!("failed to create physical address (error={:?})", e);
+            return false;
+        },
+    };
+
+    if let Err(e) = page_table.map(vaddr, paddr, false, false, true, AccessPermission::RDWR) {
+        error!("failed to map page (error={:?})", e);
+        return false;
+    }
+
+    let modes: [(CachingMode, u32); 3] = [
+        (CachingMode::Uncacheable, PTE_PCD | PTE_PWT),
+        (CachingMode::WriteBack, 0),
+        (CachingMode::WriteCombining, PTE_PAT),
+    ];
+
+    // Skip write-combining if the page attribute table is not enabled.
+    let nmodes: usize = if pat::is_enabled() {
+        modes.len()
+    } else {
+        modes.len() - 1
+    };
+
+    for (mode, bits) in modes[..nmodes].iter() {
+        if let Err(e) = page_table.set_caching(vaddr, *mode) {
+            error!("failed to set caching mode (mode={:?}, error={:?})", mode, e);
+            return false;
+        }
+
+        // Check if attribute bits match the requested mode.
+        let raw: u32 = page_table.read_raw_pte(vaddr);
+        if raw & (PTE_PAT | PTE_PCD | PTE_PWT) != *bits {
+            error!("unexpected attribute bits (mode={:?}, pte={:#010x})", mode, raw);
+            return false;
+        }
+
+        // Check if the caching mode is reported back.
+        match page_table.caching(vaddr) {
+            Ok(m) if m == *mode => {},
+            Ok(m) => {
+                error!("unexpected caching mode (expected={:?}, got={:?})", mode, m);
+                return false;
+            },
+            Err(e) => {
+                error!("failed to get caching mode (error={:?})", e);
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Runs all unit tests for page tables.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_set_caching);
+
+    passed
+}
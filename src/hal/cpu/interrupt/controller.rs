@@ -84,10 +84,22 @@ impl InterruptController {
         self.0.borrow_mut().start_core(coreid, entry, kstack)
     }
 
+    pub fn number_of_lines(&self) -> usize {
+        self.0.borrow().number_of_lines()
+    }
+
     pub fn unmask(&self, intnum: arch::InterruptNumber) -> Result<(), Error> {
         self.0.borrow_mut().unmask(intnum)
     }
 
+    pub fn set_affinity(
+        &self,
+        intnum: arch::InterruptNumber,
+        cpu_apic_id: u8,
+    ) -> Result<(), Error> {
+        self.0.borrow_mut().set_affinity(intnum, cpu_apic_id)
+    }
+
     pub fn get_handler(
         &self,
         intnum: arch::InterruptNumber,
@@ -71,10 +71,45 @@ impl InterruptManager {
         self.controller.set_handler(intnum, Some(handler))
     }
 
+    ///
+    /// # Description
+    ///
+    /// Gets the number of interrupt lines that are supported by the hardware.
+    ///
+    /// # Returns
+    ///
+    /// The number of interrupt lines that are supported by the hardware.
+    ///
+    pub fn number_of_lines(&self) -> usize {
+        self.controller.number_of_lines()
+    }
+
     pub fn unmask(&mut self, intnum: arch::InterruptNumber) -> Result<(), Error> {
         self.controller.unmask(intnum)
     }
 
+    ///
+    /// # Description
+    ///
+    /// Routes an interrupt to a given CPU.
+    ///
+    /// # Parameters
+    ///
+    /// - `intnum`: Interrupt number.
+    /// - `cpu_apic_id`: Local APIC ID of the target CPU.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error code is returned.
+    ///
+    pub fn set_affinity(
+        &mut self,
+        intnum: arch::InterruptNumber,
+        cpu_apic_id: u8,
+    ) -> Result<(), Error> {
+        self.controller.set_affinity(intnum, cpu_apic_id)
+    }
+
     fn init(&mut self) -> Result<(), Error> {
         trace!("initializing interrupt manager");
         for intnum in arch::InterruptNumber::VALUES {
@@ -103,8 +138,24 @@ impl InterruptManager {
     ///
     /// - `intnum`: Number of the interrupt.
     ///
+    /// # Notes
+    ///
+    /// - The interrupt number is received as a raw value from the low-level dispatcher, and it is
+    ///   validated before the interrupt is acknowledged. An invalid number, such as the one of the
+    ///   cascade line, is not delivered through a remapped interrupt vector, thus it is ignored
+    ///   without issuing an end-of-interrupt.
+    ///
     #[no_mangle]
-    extern "C" fn do_interrupt(intnum: arch::InterruptNumber) {
+    extern "C" fn do_interrupt(intnum: u32) {
+        // Check if the interrupt number is invalid.
+        let intnum: arch::InterruptNumber = match arch::InterruptNumber::try_from(intnum) {
+            Ok(intnum) => intnum,
+            Err(e) => {
+                error!("ignoring interrupt (intnum={}, error={:?})", intnum, e);
+                return;
+            },
+        };
+
         match InterruptController::try_get() {
             Ok(controller) => {
                 if let Err(e) = controller.ack(intnum) {
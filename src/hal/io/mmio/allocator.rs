@@ -8,6 +8,7 @@
 use crate::hal::{
     io::IoMemoryRegion,
     mem::{
+        Address,
         TruncatedMemoryRegion,
         VirtualAddress,
     },
@@ -41,17 +42,27 @@ impl IoMemoryAllocator {
     pub fn register(&mut self, region: TruncatedMemoryRegion<VirtualAddress>) -> Result<(), Error> {
         trace!("register(): region={:?}", region);
 
-        // TODO: Check regions overlap.
-
         // TODO: Keep the list sorted.
 
-        // Check if address is already registered.
+        let start: usize = region.start().into_raw_value();
+        let end: usize = start + region.size();
+
         for reg in self.regions.iter() {
+            // Check if address is already registered.
             if reg.base() == region.start() {
                 let reason: &str = "address already registered";
                 error!("register(): {}", reason);
                 return Err(Error::new(ErrorCode::EntryExists, reason));
             }
+
+            // Check if regions overlap.
+            let reg_start: usize = reg.base().into_raw_value();
+            let reg_end: usize = reg_start + reg.size();
+            if start < reg_end && reg_start < end {
+                let reason: &str = "region overlaps a registered region";
+                error!("register(): {} (region={:?}, registered={:?})", reason, region, reg);
+                return Err(Error::new(ErrorCode::ResourceBusy, reason));
+            }
         }
 
         self.regions.push_back(IoMemoryRegion::new(region));
@@ -59,6 +70,54 @@ impl IoMemoryAllocator {
         Ok(())
     }
 
+    ///
+    /// # Description
+    ///
+    /// Removes a memory region that was previously registered with the allocator. The region can
+    /// no longer be allocated afterwards. A region that is still attached to some process cannot be
+    /// removed, thus it should be released with the `FreeMmio` kernel call first.
+    ///
+    /// # Parameters
+    ///
+    /// - `region`: Memory region to remove.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    #[allow(dead_code)]
+    pub fn deregister(&mut self, region: &IoMemoryRegion) -> Result<(), Error> {
+        trace!("deregister(): region={:?}", region);
+
+        // Check if region is not registered.
+        let index: usize = match self.regions.iter().position(|reg| reg == region) {
+            Some(index) => index,
+            None => {
+                let reason: &str = "region not registered";
+                error!("deregister(): {}", reason);
+                return Err(Error::new(ErrorCode::NoSuchEntry, reason));
+            },
+        };
+
+        // Check if region is in use. The allocator and the caller each hold a handle to it.
+        if region.ref_count() > 2 {
+            let reason: &str = "region in use";
+            error!("deregister(): {} (region={:?})", reason, region);
+            return Err(Error::new(ErrorCode::ResourceBusy, reason));
+        }
+
+        let mut tail: LinkedList<IoMemoryRegion> = self.regions.split_off(index);
+        tail.pop_front();
+        self.regions.append(&mut tail);
+
+        Ok(())
+    }
+
+    /// Iterates over all memory regions that are registered with the allocator.
+    pub fn iter(&self) -> impl Iterator<Item = &IoMemoryRegion> {
+        self.regions.iter()
+    }
+
     /// Allocates an I/O address from the memory allocator.
     pub fn allocate(&mut self, addr: VirtualAddress) -> Result<IoMemoryRegion, Error> {
         for region in self.regions.iter() {
@@ -30,6 +30,10 @@ impl IoMemoryRegion {
         self.0.borrow().start()
     }
 
+    pub fn size(&self) -> usize {
+        self.0.borrow().size()
+    }
+
     pub fn perm(&self) -> AccessPermission {
         self.0.borrow().perm()
     }
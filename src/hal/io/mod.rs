@@ -8,6 +8,9 @@
 mod mmio;
 mod pmio;
 
+#[cfg(test)]
+mod test;
+
 //==================================================================================================
 // Exports
 //==================================================================================================
@@ -24,3 +27,16 @@ pub use pmio::{
     ReadOnlyIoPort,
     ReadWriteIoPort,
 };
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
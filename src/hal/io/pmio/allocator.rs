@@ -144,6 +144,60 @@ impl IoPortAllocator {
         self.register(entry)
     }
 
+    ///
+    /// **Description**
+    /// Registers a contiguous block of I/O ports with the allocator. Either all ports in the block
+    /// are registered or none is.
+    ///
+    /// **Parameters**
+    /// - `base`: Number of the first I/O port in the block.
+    /// - `count`: Number of I/O ports in the block.
+    /// - `access`: Access type of the I/O ports.
+    ///
+    /// **Returns**
+    /// - `Ok(())` on success.
+    /// - `Err(Error::EINVAL)` if the block exceeds the I/O port address space.
+    /// - `Err(Error::EADDRINUSE)` if any I/O port in the block is already registered.
+    ///
+    pub fn register_range(
+        &mut self,
+        base: u16,
+        count: u16,
+        access: IoPortType,
+    ) -> Result<(), Error> {
+        // Check if the block exceeds the I/O port address space.
+        if base.checked_add(count.saturating_sub(1)).is_none() {
+            let reason: &str = "io port range out of bounds";
+            error!("register_range(): {} (base={:#06x}, count={})", reason, base, count);
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
+        }
+
+        for offset in 0..count {
+            let info: IoPortInfo = IoPortInfo::new(base + offset);
+            let port: IoPort = match access {
+                IoPortType::ReadOnly => IoPort::new_read_only(info),
+                IoPortType::WriteOnly => IoPort::new_write_only(info),
+                IoPortType::ReadWrite => IoPort::new_read_write(info),
+            };
+
+            if let Err(e) = self.register(port) {
+                // Roll back ports that were registered so far.
+                let ports: LinkedList<Rc<RefCell<IoPortAllocatorEntry>>> =
+                    ::core::mem::take(&mut self.ports);
+                self.ports = ports
+                    .into_iter()
+                    .filter(|entry| {
+                        let number: u16 = entry.borrow().port.number();
+                        number < base || number >= base + offset
+                    })
+                    .collect();
+                return Err(e);
+            }
+        }
+
+        Ok(())
+    }
+
     ///
     /// **Description**
     /// Registers an I/O port with the allocator.
@@ -178,6 +232,20 @@ impl IoPortAllocator {
         Ok(())
     }
 
+    ///
+    /// **Description**
+    /// Iterates over all I/O ports that are registered with the allocator.
+    ///
+    /// **Returns**
+    /// An iterator that yields the number and the type of each registered I/O port.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (u16, IoPortType)> + '_ {
+        self.ports.iter().map(|entry| {
+            let e = entry.borrow();
+            (e.port.number(), e.port.typ)
+        })
+    }
+
     ///
     /// **Description**
     /// Allocates a read-only I/O port.
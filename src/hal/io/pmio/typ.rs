@@ -19,7 +19,7 @@ use ::sys::error::{
 ///
 /// A type that encodes the type of an I/O port.
 ///
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IoPortType {
     ReadOnly,
     WriteOnly,
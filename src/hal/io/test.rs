@@ -0,0 +1,340 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::hal::{
+    io::{
+        IoMemoryAllocator,
+        IoMemoryRegion,
+        IoPortAllocator,
+        IoPortType,
+    },
+    mem::{
+        AccessPermission,
+        Address,
+        MemoryRegionType,
+        PageAligned,
+        TruncatedMemoryRegion,
+        VirtualAddress,
+    },
+};
+use ::alloc::vec::Vec;
+use ::arch::mem;
+use ::sys::error::ErrorCode;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if [`IoMemoryAllocator::iter()`] yields exactly the registered regions.
+fn test_mmio_iter() -> bool {
+    let mut ioaddresses: IoMemoryAllocator = IoMemoryAllocator::new();
+    let bases: [usize; 3] = [0xfec00000, 0xfee00000, 0xfed00000];
+
+    // Register memory regions.
+    for base in bases.iter() {
+        let start: PageAligned<VirtualAddress> = match PageAligned::from_raw_value(*base) {
+            Ok(start) => start,
+            Err(err) => {
+                error!("failed to create address (err={:?})", err);
+                return false;
+            },
+        };
+        let region: TruncatedMemoryRegion<VirtualAddress> = match TruncatedMemoryRegion::new(
+            "test",
+            start,
+            mem::PAGE_SIZE,
+            MemoryRegionType::Mmio,
+            AccessPermission::RDWR,
+        ) {
+            Ok(region) => region,
+            Err(err) => {
+                error!("failed to create memory region (err={:?})", err);
+                return false;
+            },
+        };
+        if let Err(err) = ioaddresses.register(region) {
+            error!("failed to register memory region (err={:?})", err);
+            return false;
+        }
+    }
+
+    // Check if iterator yields exactly the registered regions.
+    let regions: Vec<usize> = ioaddresses
+        .iter()
+        .map(|region| region.base().into_raw_value())
+        .collect();
+    if regions.len() != bases.len() {
+        error!("unexpected number of regions (expected={}, got={})", bases.len(), regions.len());
+        return false;
+    }
+    for base in bases.iter() {
+        if !regions.contains(base) {
+            error!("missing region (base={:#010x})", base);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Builds a memory-mapped I/O region.
+fn mmio_region(base: usize, size: usize) -> Option<TruncatedMemoryRegion<VirtualAddress>> {
+    let start: PageAligned<VirtualAddress> = match PageAligned::from_raw_value(base) {
+        Ok(start) => start,
+        Err(err) => {
+            error!("failed to create address (err={:?})", err);
+            return None;
+        },
+    };
+    match TruncatedMemoryRegion::new(
+        "test",
+        start,
+        size,
+        MemoryRegionType::Mmio,
+        AccessPermission::RDWR,
+    ) {
+        Ok(region) => Some(region),
+        Err(err) => {
+            error!("failed to create memory region (err={:?})", err);
+            None
+        },
+    }
+}
+
+/// Tests if [`IoMemoryAllocator::deregister()`] removes a region that is not in use, which may be
+/// registered again.
+fn test_mmio_deregister() -> bool {
+    const BASE: usize = 0xfed00000;
+
+    let mut ioaddresses: IoMemoryAllocator = IoMemoryAllocator::new();
+
+    // Register memory region.
+    let region: TruncatedMemoryRegion<VirtualAddress> = match mmio_region(BASE, mem::PAGE_SIZE) {
+        Some(region) => region,
+        None => return false,
+    };
+    if let Err(err) = ioaddresses.register(region) {
+        error!("failed to register memory region (err={:?})", err);
+        return false;
+    }
+    let addr: VirtualAddress = match VirtualAddress::from_raw_value(BASE) {
+        Ok(addr) => addr,
+        Err(err) => {
+            error!("failed to create address (err={:?})", err);
+            return false;
+        },
+    };
+    let region: IoMemoryRegion = match ioaddresses.allocate(addr) {
+        Ok(region) => region,
+        Err(err) => {
+            error!("failed to allocate memory region (err={:?})", err);
+            return false;
+        },
+    };
+
+    // Check if region cannot be deregistered while it is in use.
+    let attached: IoMemoryRegion = region.clone();
+    match ioaddresses.deregister(&region) {
+        Err(err) if err.code == ErrorCode::ResourceBusy => {},
+        Err(err) => {
+            error!("unexpected error (err={:?})", err);
+            return false;
+        },
+        Ok(()) => {
+            error!("deregistered memory region that is in use");
+            return false;
+        },
+    }
+    drop(attached);
+
+    // Deregister memory region.
+    if let Err(err) = ioaddresses.deregister(&region) {
+        error!("failed to deregister memory region (err={:?})", err);
+        return false;
+    }
+
+    // Check if region is no longer registered.
+    match ioaddresses.deregister(&region) {
+        Err(err) if err.code == ErrorCode::NoSuchEntry => {},
+        Err(err) => {
+            error!("unexpected error (err={:?})", err);
+            return false;
+        },
+        Ok(()) => {
+            error!("deregistered memory region twice");
+            return false;
+        },
+    }
+    if ioaddresses.iter().count() != 0 {
+        error!("memory region is still registered");
+        return false;
+    }
+
+    // Check if region may be registered again.
+    let region: TruncatedMemoryRegion<VirtualAddress> = match mmio_region(BASE, mem::PAGE_SIZE) {
+        Some(region) => region,
+        None => return false,
+    };
+    if let Err(err) = ioaddresses.register(region) {
+        error!("failed to register memory region again (err={:?})", err);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if [`IoMemoryAllocator::register()`] rejects regions that overlap a registered region.
+fn test_mmio_overlap() -> bool {
+    const BASE: usize = 0xfed00000;
+
+    let mut ioaddresses: IoMemoryAllocator = IoMemoryAllocator::new();
+
+    // Register memory region that spans two pages.
+    let region: TruncatedMemoryRegion<VirtualAddress> = match mmio_region(BASE, 2 * mem::PAGE_SIZE)
+    {
+        Some(region) => region,
+        None => return false,
+    };
+    if let Err(err) = ioaddresses.register(region) {
+        error!("failed to register memory region (err={:?})", err);
+        return false;
+    }
+
+    // Attempt to register a region that starts in the middle of the first one.
+    let region: TruncatedMemoryRegion<VirtualAddress> =
+        match mmio_region(BASE + mem::PAGE_SIZE, 2 * mem::PAGE_SIZE) {
+            Some(region) => region,
+            None => return false,
+        };
+    match ioaddresses.register(region) {
+        Err(err) if err.code == ErrorCode::ResourceBusy => {},
+        Err(err) => {
+            error!("unexpected error (err={:?})", err);
+            return false;
+        },
+        Ok(()) => {
+            error!("registered overlapping memory region");
+            return false;
+        },
+    }
+
+    // Check if an adjacent region is accepted.
+    let region: TruncatedMemoryRegion<VirtualAddress> =
+        match mmio_region(BASE + 2 * mem::PAGE_SIZE, mem::PAGE_SIZE) {
+            Some(region) => region,
+            None => return false,
+        };
+    if let Err(err) = ioaddresses.register(region) {
+        error!("failed to register adjacent memory region (err={:?})", err);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if [`IoPortAllocator::iter()`] yields exactly the registered ports.
+fn test_pmio_iter() -> bool {
+    let mut ioports: IoPortAllocator = IoPortAllocator::new();
+    let ports: [(u16, IoPortType); 3] = [
+        (0x3f8, IoPortType::ReadWrite),
+        (0x3f9, IoPortType::ReadOnly),
+        (0x3fa, IoPortType::WriteOnly),
+    ];
+
+    // Register I/O ports.
+    for (number, typ) in ports.iter() {
+        let result = match typ {
+            IoPortType::ReadOnly => ioports.register_read_only(*number),
+            IoPortType::WriteOnly => ioports.register_write_only(*number),
+            IoPortType::ReadWrite => ioports.register_read_write(*number),
+        };
+        if let Err(err) = result {
+            error!("failed to register io port (err={:?})", err);
+            return false;
+        }
+    }
+
+    // Check if iterator yields exactly the registered ports.
+    let registered: Vec<(u16, IoPortType)> = ioports.iter().collect();
+    if registered.len() != ports.len() {
+        error!("unexpected number of ports (expected={}, got={})", ports.len(), registered.len());
+        return false;
+    }
+    for port in ports.iter() {
+        if !registered.contains(port) {
+            error!("missing io port (number={:#06x}, typ={:?})", port.0, port.1);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests if [`IoPortAllocator::register_range()`] registers all ports in a block or none of them.
+fn test_pmio_register_range() -> bool {
+    const BASE: u16 = 0x3f8;
+    const COUNT: u16 = 4;
+
+    let mut ioports: IoPortAllocator = IoPortAllocator::new();
+
+    // Register a block of I/O ports.
+    if let Err(err) = ioports.register_range(BASE, COUNT, IoPortType::ReadWrite) {
+        error!("failed to register io port range (err={:?})", err);
+        return false;
+    }
+    let registered: Vec<(u16, IoPortType)> = ioports.iter().collect();
+    for number in BASE..BASE + COUNT {
+        if !registered.contains(&(number, IoPortType::ReadWrite)) {
+            error!("missing io port (number={:#06x})", number);
+            return false;
+        }
+    }
+
+    // Attempt to register a block that starts right before the previous one and overlaps it.
+    match ioports.register_range(BASE - 1, COUNT + 2, IoPortType::ReadOnly) {
+        Err(err) if err.code == ErrorCode::AddressInUse => {},
+        Err(err) => {
+            error!("unexpected error (err={:?})", err);
+            return false;
+        },
+        Ok(()) => {
+            error!("registered overlapping io port range");
+            return false;
+        },
+    }
+
+    // Check if the failed registration was rolled back.
+    let registered: Vec<(u16, IoPortType)> = ioports.iter().collect();
+    if registered.len() != COUNT as usize {
+        error!("unexpected number of ports (expected={}, got={})", COUNT, registered.len());
+        return false;
+    }
+
+    // Check if a block that exceeds the I/O port address space is rejected.
+    if ioports
+        .register_range(u16::MAX, 2, IoPortType::ReadWrite)
+        .is_ok()
+    {
+        error!("registered io port range out of bounds");
+        return false;
+    }
+
+    true
+}
+
+/// Runs all unit tests for I/O resource allocators.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_mmio_iter);
+    passed &= run_test!(test_mmio_deregister);
+    passed &= run_test!(test_mmio_overlap);
+    passed &= run_test!(test_pmio_iter);
+    passed &= run_test!(test_pmio_register_range);
+
+    passed
+}
@@ -10,6 +10,7 @@ pub mod cpu;
 pub mod io;
 pub mod mem;
 pub mod platform;
+pub mod time;
 
 //==================================================================================================
 // Imports
@@ -83,6 +84,14 @@ pub fn init(
         mem_lower,
     )?;
 
+    // Print I/O resource map.
+    for region in ioaddresses.iter() {
+        info!("mmio region: base={:?}, perm={:?}", region.base(), region.perm());
+    }
+    for (number, typ) in ioports.iter() {
+        info!("io port: number={:#06x}, typ={:?}", number, typ);
+    }
+
     // Initialize the interrupt manager.
     let intman: Option<InterruptManager> = match platform.arch.controller.take() {
         Some(controller) => Some(InterruptManager::new(controller)?),
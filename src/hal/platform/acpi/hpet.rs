@@ -0,0 +1,148 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::arch::cpu::acpi::AcpiSdtHeader;
+use ::core::ops::Range;
+use ::sys::error::{
+    Error,
+    ErrorCode,
+};
+
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Length of the HPET description table.
+const HPET_LENGTH: usize = 56;
+
+/// Offset of the hardware revision field in the HPET description table.
+const HARDWARE_REV_ID_OFFSET: usize = 36;
+
+/// Offset of the capabilities field in the HPET description table.
+const CAPABILITIES_OFFSET: usize = 37;
+
+/// Offset of the PCI vendor field in the HPET description table.
+const PCI_VENDOR_ID_OFFSET: usize = 38;
+
+/// Offset of the address structure in the HPET description table.
+const ADDRESS_OFFSET: usize = 40;
+
+/// Offset of the HPET number field in the HPET description table.
+const HPET_NUMBER_OFFSET: usize = 52;
+
+/// Offset of the minimum tick field in the HPET description table.
+const MINIMUM_TICK_OFFSET: usize = 53;
+
+/// Offset of the page protection field in the HPET description table.
+const PAGE_PROTECTION_OFFSET: usize = 55;
+
+/// Address space identifier of system memory.
+pub const ADDRESS_SPACE_SYSTEM_MEMORY: u8 = 0;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Information in the High Precision Event Timer (HPET) description table.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HpetInfo {
+    /// Hardware revision.
+    pub hardware_rev_id: u8,
+    /// Number of comparators in the first timer block.
+    pub comparator_count: u8,
+    /// Whether the main counter is 64 bits wide.
+    pub counter_size_64: bool,
+    /// Whether legacy replacement interrupt routing is supported.
+    pub legacy_replacement: bool,
+    /// PCI vendor of the first timer block.
+    pub pci_vendor_id: u16,
+    /// Address space of the timer block registers.
+    pub address_space_id: u8,
+    /// Width of the timer block registers, in bits.
+    pub register_bit_width: u8,
+    /// Offset of the timer block registers, in bits.
+    pub register_bit_offset: u8,
+    /// Base address of the timer block registers.
+    pub base_address: u64,
+    /// Sequence number of the timer block.
+    pub hpet_number: u8,
+    /// Minimum clock tick in periodic mode, without losing interrupts.
+    pub minimum_tick: u16,
+    /// Page protection and OEM attributes.
+    pub page_protection: u8,
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// Parses an HPET description table.
+///
+/// # Arguments
+///
+/// * `table` - HPET description table. The whole table must lie in mapped memory.
+///
+/// # Returns
+///
+/// Upon successful completion, information about the HPET is returned. Upon failure, an error is
+/// returned instead.
+///
+pub unsafe fn parse_hpet(table: *const AcpiSdtHeader) -> Result<HpetInfo, Error> {
+    // Check if the table is too small to hold the HPET description.
+    let length: usize = (*table).length as usize;
+    if length < HPET_LENGTH {
+        let reason: &str = "invalid hpet table length";
+        error!("parse_hpet(): {} (length={})", reason, length);
+        return Err(Error::new(ErrorCode::BadFile, reason));
+    }
+
+    let base: *const u8 = table as *const u8;
+    let capabilities: u8 = base.add(CAPABILITIES_OFFSET).read();
+
+    Ok(HpetInfo {
+        hardware_rev_id: base.add(HARDWARE_REV_ID_OFFSET).read(),
+        comparator_count: (capabilities & 0x1f) + 1,
+        counter_size_64: (capabilities & (1 << 5)) != 0,
+        legacy_replacement: (capabilities & (1 << 7)) != 0,
+        pci_vendor_id: (base.add(PCI_VENDOR_ID_OFFSET) as *const u16).read_unaligned(),
+        address_space_id: base.add(ADDRESS_OFFSET).read(),
+        register_bit_width: base.add(ADDRESS_OFFSET + 1).read(),
+        register_bit_offset: base.add(ADDRESS_OFFSET + 2).read(),
+        base_address: (base.add(ADDRESS_OFFSET + 4) as *const u64).read_unaligned(),
+        hpet_number: base.add(HPET_NUMBER_OFFSET).read(),
+        minimum_tick: (base.add(MINIMUM_TICK_OFFSET) as *const u16).read_unaligned(),
+        page_protection: base.add(PAGE_PROTECTION_OFFSET).read(),
+    })
+}
+
+///
+/// Finds and parses the HPET description table.
+///
+/// # Arguments
+///
+/// * `rsdt` - Root System Description Table.
+/// * `mapped` - Memory range that is mapped and may be safely accessed. Tables lying outside of
+///   this range are not dereferenced.
+///
+/// # Returns
+///
+/// Upon successful completion, information about the HPET is returned. Upon failure, an error is
+/// returned instead.
+///
+#[allow(dead_code)]
+pub unsafe fn find_hpet(
+    rsdt: *const AcpiSdtHeader,
+    mapped: Range<usize>,
+) -> Result<HpetInfo, Error> {
+    let table: *const AcpiSdtHeader = super::find_table_by_sig(rsdt, "HPET", mapped)?;
+    parse_hpet(table)
+}
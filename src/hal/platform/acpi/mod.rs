@@ -0,0 +1,482 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Lint Configuration
+//==================================================================================================
+
+#![cfg_attr(feature = "microvm", allow(dead_code))]
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+pub mod hpet;
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::arch::cpu::acpi::AcpiSdtHeader;
+use ::core::ops::Range;
+use ::sys::error::{
+    Error,
+    ErrorCode,
+};
+
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Length of the Root System Description Pointer (RSDP), as of ACPI 1.0.
+const RSDP_LENGTH: usize = 20;
+
+/// Length of the extended Root System Description Pointer (RSDP), as of ACPI 2.0.
+const RSDP_EXTENDED_LENGTH: usize = 36;
+
+/// Offset of the revision field in the RSDP.
+const RSDP_REVISION_OFFSET: usize = 15;
+
+/// Offset of the RSDT address field in the RSDP.
+const RSDP_RSDT_OFFSET: usize = 16;
+
+/// Offset of the XSDT address field in the RSDP.
+const RSDP_XSDT_OFFSET: usize = 24;
+
+/// First RSDP revision that provides an XSDT.
+const RSDP_XSDT_REVISION: u8 = 2;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// Type of a root system description table.
+///
+#[derive(Clone, Copy)]
+enum RootTable {
+    /// Root System Description Table (RSDT), whose entries are 32-bit physical addresses.
+    Rsdt,
+    /// Extended System Description Table (XSDT), whose entries are 64-bit physical addresses.
+    Xsdt,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl RootTable {
+    /// Returns the size of an entry in the root table.
+    fn entry_size(self) -> usize {
+        match self {
+            RootTable::Rsdt => core::mem::size_of::<u32>(),
+            RootTable::Xsdt => core::mem::size_of::<u64>(),
+        }
+    }
+
+    /// Reads the physical address in the `i`-th entry of the root table, whose entries start at
+    /// `entries`.
+    unsafe fn entry(self, entries: *const u8, i: usize) -> u64 {
+        match self {
+            RootTable::Rsdt => (entries as *const u32).add(i).read_unaligned() as u64,
+            RootTable::Xsdt => (entries as *const u64).add(i).read_unaligned(),
+        }
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// Computes the checksum of an ACPI table.
+///
+/// # Arguments
+///
+/// * `ptr` - Pointer to the table.
+/// * `len` - Length of the table in bytes.
+///
+/// # Returns
+///
+/// The wrapping sum of all bytes in the table is returned. A valid table sums to zero.
+///
+unsafe fn checksum(ptr: *const u8, len: usize) -> u8 {
+    let mut sum: u8 = 0;
+    for i in 0..len {
+        sum = sum.wrapping_add(ptr.add(i).read());
+    }
+    sum
+}
+
+///
+/// Validates the checksum of a system description table.
+///
+/// # Arguments
+///
+/// * `header` - Header of the table. The whole table must lie in mapped memory.
+///
+/// # Returns
+///
+/// `true` if all `length` bytes of the table sum to zero modulo 256 and `false` otherwise.
+///
+unsafe fn validate_checksum(header: *const AcpiSdtHeader) -> bool {
+    let length: usize = (*header).length as usize;
+
+    // Check if the table is too small to hold its own header.
+    if length < core::mem::size_of::<AcpiSdtHeader>() {
+        return false;
+    }
+
+    checksum(header as *const u8, length) == 0
+}
+
+///
+/// Checks whether a memory area lies within a mapped memory range.
+///
+/// # Arguments
+///
+/// * `base` - Base address of the area.
+/// * `len` - Length of the area in bytes.
+/// * `mapped` - Mapped memory range.
+///
+/// # Returns
+///
+/// `true` if the area lies entirely within the mapped range and `false` otherwise.
+///
+fn is_mapped(base: usize, len: usize, mapped: &Range<usize>) -> bool {
+    match base.checked_add(len) {
+        Some(end) => base >= mapped.start && end <= mapped.end,
+        None => false,
+    }
+}
+
+///
+/// Enumerates the entries of a root system description table.
+///
+/// # Arguments
+///
+/// * `sdt` - Root system description table, which is either an RSDT or an XSDT.
+/// * `kind` - Type of the root table.
+/// * `mapped` - Memory range that is mapped and may be safely accessed. Tables lying outside of
+///   this range are not dereferenced.
+///
+/// # Returns
+///
+/// Upon successful completion, an iterator over the entries is returned. The iterator yields the
+/// header of each table that is listed in the root table, or an error if the header cannot be
+/// safely accessed. The rest of the table is not checked. Upon failure, an error is returned
+/// instead.
+///
+unsafe fn entries_in(
+    sdt: *const AcpiSdtHeader,
+    kind: RootTable,
+    mapped: Range<usize>,
+) -> Result<impl Iterator<Item = Result<*const AcpiSdtHeader, Error>>, Error> {
+    // Check if the root table header lies outside mapped memory.
+    if !is_mapped(sdt as usize, core::mem::size_of::<AcpiSdtHeader>(), &mapped) {
+        let reason: &str = "root table lies outside mapped memory";
+        error!("entries_in(): {} (sdt={:?})", reason, sdt);
+        return Err(Error::new(ErrorCode::BadAddress, reason));
+    }
+
+    let length: usize = (*sdt).length as usize;
+
+    // Check if the root table is too small to hold its own header.
+    if length < core::mem::size_of::<AcpiSdtHeader>() {
+        let reason: &str = "invalid root table length";
+        error!("entries_in(): {} (length={})", reason, length);
+        return Err(Error::new(ErrorCode::BadFile, reason));
+    }
+
+    // Check if the root table entries lie outside mapped memory.
+    if !is_mapped(sdt as usize, length, &mapped) {
+        let reason: &str = "root table lies outside mapped memory";
+        error!("entries_in(): {} (sdt={:?}, length={})", reason, sdt, length);
+        return Err(Error::new(ErrorCode::BadAddress, reason));
+    }
+
+    // Check if the root table is corrupted.
+    if !validate_checksum(sdt) {
+        let reason: &str = "invalid root table checksum";
+        error!("entries_in(): {}", reason);
+        return Err(Error::new(ErrorCode::BadFile, reason));
+    }
+
+    let entries: usize = (length - core::mem::size_of::<AcpiSdtHeader>()) / kind.entry_size();
+    let ptr: *const u8 = sdt.offset(1) as *const u8;
+
+    Ok((0..entries).map(move |i| {
+        // Safety: entries lie within the root table, which is mapped.
+        let table: *const AcpiSdtHeader = to_table_ptr(unsafe { kind.entry(ptr, i) })?;
+
+        // Check if the table header lies outside mapped memory.
+        if !is_mapped(table as usize, core::mem::size_of::<AcpiSdtHeader>(), &mapped) {
+            let reason: &str = "table lies outside mapped memory";
+            error!("entries_in(): {} (table={:?})", reason, table);
+            return Err(Error::new(ErrorCode::BadAddress, reason));
+        }
+
+        Ok(table)
+    }))
+}
+
+///
+/// Checks whether a table that is listed in a root system description table may be used.
+///
+/// # Arguments
+///
+/// * `table` - Table, whose header must lie in mapped memory.
+/// * `mapped` - Memory range that is mapped and may be safely accessed.
+///
+/// # Returns
+///
+/// If the table is valid, `true` is returned. If the table is corrupted, `false` is returned. If
+/// the table cannot be safely accessed, an error is returned instead.
+///
+unsafe fn check_table(table: *const AcpiSdtHeader, mapped: &Range<usize>) -> Result<bool, Error> {
+    // Check if the table lies outside mapped memory.
+    if !is_mapped(table as usize, (*table).length as usize, mapped) {
+        let reason: &str = "table lies outside mapped memory";
+        error!("check_table(): {} (table={:?})", reason, table);
+        return Err(Error::new(ErrorCode::BadAddress, reason));
+    }
+
+    // Check if the table is corrupted.
+    if !validate_checksum(table) {
+        let reason: &str = "invalid table checksum";
+        warn!("check_table(): {} (table={:?})", reason, table);
+        return Ok(false);
+    }
+
+    Ok(AcpiSdtHeader::from_ptr(table).is_some())
+}
+
+///
+/// Checks whether a table has a given signature.
+///
+/// # Arguments
+///
+/// * `table` - Table, whose header must lie in mapped memory.
+/// * `sig` - Signature of the table.
+///
+/// # Returns
+///
+/// `true` if the table has the given signature and `false` otherwise.
+///
+pub unsafe fn has_signature(table: *const AcpiSdtHeader, sig: &str) -> bool {
+    let signature: [u8; 4] = (*table).signature.map(|c| c as u8);
+    trace!("has_signature(): signature={:?}", core::str::from_utf8(&signature));
+    sig.as_bytes() == signature
+}
+
+///
+/// Enumerates the tables that are listed in a root system description table.
+///
+/// # Arguments
+///
+/// * `sdt` - Root system description table, which is either an RSDT or an XSDT. The type of the
+///   root table is told apart by its signature.
+/// * `mapped` - Memory range that is mapped and may be safely accessed. Tables lying outside of
+///   this range are not dereferenced.
+///
+/// # Returns
+///
+/// Upon successful completion, an iterator over the tables is returned. The iterator yields each
+/// valid table once, and skips tables that are corrupted or cannot be safely accessed. Upon
+/// failure, an error is returned instead.
+///
+pub unsafe fn tables(
+    sdt: *const AcpiSdtHeader,
+    mapped: Range<usize>,
+) -> Result<impl Iterator<Item = *const AcpiSdtHeader>, Error> {
+    // Check if the root table header lies outside mapped memory.
+    if !is_mapped(sdt as usize, core::mem::size_of::<AcpiSdtHeader>(), &mapped) {
+        let reason: &str = "root table lies outside mapped memory";
+        error!("tables(): {} (sdt={:?})", reason, sdt);
+        return Err(Error::new(ErrorCode::BadAddress, reason));
+    }
+
+    let kind: RootTable = if has_signature(sdt, "XSDT") {
+        RootTable::Xsdt
+    } else {
+        RootTable::Rsdt
+    };
+
+    Ok(entries_in(sdt, kind, mapped.clone())?.filter_map(move |table| {
+        let table: *const AcpiSdtHeader = table.ok()?;
+        // Safety: the table is only dereferenced if it lies in mapped memory.
+        match unsafe { check_table(table, &mapped) } {
+            Ok(true) => Some(table),
+            _ => None,
+        }
+    }))
+}
+
+///
+/// Finds an APIC table by its signature in a root system description table.
+///
+/// # Arguments
+///
+/// * `sdt` - Root system description table, which is either an RSDT or an XSDT.
+/// * `kind` - Type of the root table.
+/// * `sig` - Signature of the table.
+/// * `mapped` - Memory range that is mapped and may be safely accessed. Tables lying outside of
+///   this range are not dereferenced.
+///
+/// # Returns
+///
+/// Upon successful completion, a pointer to the table is returned. Upon failure, an error is
+/// returned instead.
+///
+unsafe fn find_table_in(
+    sdt: *const AcpiSdtHeader,
+    kind: RootTable,
+    sig: &str,
+    mapped: Range<usize>,
+) -> Result<*const AcpiSdtHeader, Error> {
+    info!("looking for table: {:?}", sig);
+
+    for table in entries_in(sdt, kind, mapped.clone())? {
+        let table: *const AcpiSdtHeader = table?;
+
+        // Check signature. Only the table that matches is checked as a whole.
+        if has_signature(table, sig) && check_table(table, &mapped)? {
+            return Ok(table);
+        }
+    }
+
+    // Table not found.
+    let reason: &str = "table not found";
+    error!("find_table_by_sig(): {}", reason);
+    Err(Error::new(ErrorCode::NoSuchEntry, reason))
+}
+
+///
+/// Converts the physical address of a system description table into a pointer.
+///
+/// # Arguments
+///
+/// * `addr` - Physical address of the table.
+///
+/// # Returns
+///
+/// Upon successful completion, a pointer to the table is returned. If the table does not lie
+/// within the address space of the kernel, an error is returned instead.
+///
+fn to_table_ptr(addr: u64) -> Result<*const AcpiSdtHeader, Error> {
+    match usize::try_from(addr) {
+        Ok(addr) => Ok(addr as *const AcpiSdtHeader),
+        Err(_) => {
+            let reason: &str = "table lies outside of the address space";
+            error!("to_table_ptr(): {} (addr={:#x})", reason, addr);
+            Err(Error::new(ErrorCode::OperationNotSupported, reason))
+        },
+    }
+}
+
+///
+/// Finds an APIC table by its signature in a Root System Description Table (RSDT), whose entries
+/// are 32-bit physical addresses.
+///
+/// # Arguments
+///
+/// * `rsdt` - Root System Description Table.
+/// * `sig` - Signature of the table.
+/// * `mapped` - Memory range that is mapped and may be safely accessed. Tables lying outside of
+///   this range are not dereferenced.
+///
+/// # Returns
+///
+/// Upon successful completion, a pointer to the table is returned. Upon failure, an error is
+/// returned instead.
+///
+pub unsafe fn find_table_by_sig(
+    rsdt: *const AcpiSdtHeader,
+    sig: &str,
+    mapped: Range<usize>,
+) -> Result<*const AcpiSdtHeader, Error> {
+    find_table_in(rsdt, RootTable::Rsdt, sig, mapped)
+}
+
+///
+/// Finds an APIC table by its signature in an Extended System Description Table (XSDT), whose
+/// entries are 64-bit physical addresses.
+///
+/// # Arguments
+///
+/// * `xsdt` - Extended System Description Table.
+/// * `sig` - Signature of the table.
+/// * `mapped` - Memory range that is mapped and may be safely accessed. Tables lying outside of
+///   this range are not dereferenced.
+///
+/// # Returns
+///
+/// Upon successful completion, a pointer to the table is returned. If the table lies above the
+/// address space of the kernel, an error with [`ErrorCode::OperationNotSupported`] is returned.
+/// Upon other failures, an error is returned instead.
+///
+#[allow(dead_code)]
+pub unsafe fn find_table_by_sig_xsdt(
+    xsdt: *const AcpiSdtHeader,
+    sig: &str,
+    mapped: Range<usize>,
+) -> Result<*const AcpiSdtHeader, Error> {
+    find_table_in(xsdt, RootTable::Xsdt, sig, mapped)
+}
+
+///
+/// Locates the root system description table, starting from the Root System Description Pointer
+/// (RSDP). The XSDT is used if the RSDP revision provides one, and the RSDT otherwise.
+///
+/// # Arguments
+///
+/// * `rsdp` - Root System Description Pointer.
+/// * `mapped` - Memory range that is mapped and may be safely accessed.
+///
+/// # Returns
+///
+/// Upon successful completion, a pointer to the root table is returned. Upon failure, an error is
+/// returned instead.
+///
+pub unsafe fn root_table(
+    rsdp: *const u8,
+    mapped: &Range<usize>,
+) -> Result<*const AcpiSdtHeader, Error> {
+    // Check if the RSDP lies outside mapped memory or is corrupted.
+    if !is_mapped(rsdp as usize, RSDP_LENGTH, mapped) || checksum(rsdp, RSDP_LENGTH) != 0 {
+        let reason: &str = "invalid rsdp";
+        error!("root_table(): {} (rsdp={:?})", reason, rsdp);
+        return Err(Error::new(ErrorCode::BadFile, reason));
+    }
+
+    // Check if the RSDP revision does not provide an XSDT.
+    let revision: u8 = rsdp.add(RSDP_REVISION_OFFSET).read();
+    if revision < RSDP_XSDT_REVISION {
+        let rsdt: u32 = (rsdp.add(RSDP_RSDT_OFFSET) as *const u32).read_unaligned();
+        return Ok(rsdt as usize as *const AcpiSdtHeader);
+    }
+
+    // Check if the extended RSDP lies outside mapped memory or is corrupted.
+    if !is_mapped(rsdp as usize, RSDP_EXTENDED_LENGTH, mapped)
+        || checksum(rsdp, RSDP_EXTENDED_LENGTH) != 0
+    {
+        let reason: &str = "invalid extended rsdp";
+        error!("root_table(): {} (rsdp={:?})", reason, rsdp);
+        return Err(Error::new(ErrorCode::BadFile, reason));
+    }
+
+    let xsdt: u64 = (rsdp.add(RSDP_XSDT_OFFSET) as *const u64).read_unaligned();
+    to_table_ptr(xsdt)
+}
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
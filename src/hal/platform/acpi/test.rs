@@ -0,0 +1,432 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::hal::platform::acpi::{
+    self,
+    hpet::HpetInfo,
+};
+use ::alloc::vec::Vec;
+use ::arch::cpu::acpi::AcpiSdtHeader;
+use ::core::{
+    mem::{
+        size_of,
+        size_of_val,
+    },
+    ops::Range,
+};
+use ::sys::error::ErrorCode;
+
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Offset of the length field in a system description table header.
+const LENGTH_OFFSET: usize = 4;
+
+/// Offset of the checksum field in a system description table header.
+const CHECKSUM_OFFSET: usize = 9;
+
+/// Index of the word where a synthetic table is placed, after its RSDT.
+const TABLE_INDEX: usize = 16;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Returns the memory range spanned by a synthetic system description table.
+fn range_of(table: &[u32]) -> Range<usize> {
+    let start: usize = table.as_ptr() as usize;
+    start..(start + size_of_val(table))
+}
+
+/// Writes the length field of a synthetic system description table.
+fn set_length(table: &mut [u32], length: u32) {
+    table[LENGTH_OFFSET / size_of::<u32>()] = length;
+}
+
+/// Fixes up the checksum field of a synthetic system description table.
+fn set_checksum(table: &mut [u32], length: usize) {
+    let bytes: *mut u8 = table.as_mut_ptr() as *mut u8;
+    // Safety: the checksum field lies within the table.
+    unsafe {
+        bytes.add(CHECKSUM_OFFSET).write(0);
+        let sum: u8 = acpi::checksum(bytes, length);
+        bytes.add(CHECKSUM_OFFSET).write(0u8.wrapping_sub(sum));
+    }
+}
+
+/// Tests if [`acpi::find_table_by_sig()`] rejects an RSDT whose length is too small.
+fn test_find_table_rsdt_too_small() -> bool {
+    let mut rsdt: [u32; 16] = [0; 16];
+    set_length(&mut rsdt, (size_of::<AcpiSdtHeader>() - 1) as u32);
+    set_checksum(&mut rsdt, size_of::<AcpiSdtHeader>() - 1);
+
+    match unsafe {
+        acpi::find_table_by_sig(rsdt.as_ptr() as *const AcpiSdtHeader, "APIC", range_of(&rsdt))
+    } {
+        Err(e) if e.code == ErrorCode::BadFile => true,
+        Err(e) => {
+            error!("unexpected error code (error={:?})", e);
+            false
+        },
+        Ok(_) => {
+            error!("found table in rsdt with invalid length");
+            false
+        },
+    }
+}
+
+/// Tests if [`acpi::find_table_by_sig()`] rejects an RSDT with an invalid checksum.
+fn test_find_table_rsdt_bad_checksum() -> bool {
+    let mut rsdt: [u32; 16] = [0; 16];
+    let length: usize = size_of::<AcpiSdtHeader>();
+    set_length(&mut rsdt, length as u32);
+    set_checksum(&mut rsdt, length);
+
+    // Corrupt the table.
+    rsdt[LENGTH_OFFSET / size_of::<u32>() + 1] ^= 1;
+
+    match unsafe {
+        acpi::find_table_by_sig(rsdt.as_ptr() as *const AcpiSdtHeader, "APIC", range_of(&rsdt))
+    } {
+        Err(e) if e.code == ErrorCode::BadFile => true,
+        Err(e) => {
+            error!("unexpected error code (error={:?})", e);
+            false
+        },
+        Ok(_) => {
+            error!("found table in corrupted rsdt");
+            false
+        },
+    }
+}
+
+/// Tests if [`acpi::find_table_by_sig()`] does not find tables in an empty RSDT.
+fn test_find_table_rsdt_empty() -> bool {
+    let mut rsdt: [u32; 16] = [0; 16];
+    let length: usize = size_of::<AcpiSdtHeader>();
+    set_length(&mut rsdt, length as u32);
+    set_checksum(&mut rsdt, length);
+
+    match unsafe {
+        acpi::find_table_by_sig(rsdt.as_ptr() as *const AcpiSdtHeader, "APIC", range_of(&rsdt))
+    } {
+        Err(e) if e.code == ErrorCode::NoSuchEntry => true,
+        Err(e) => {
+            error!("unexpected error code (error={:?})", e);
+            false
+        },
+        Ok(_) => {
+            error!("found table in empty rsdt");
+            false
+        },
+    }
+}
+
+/// Tests if [`acpi::find_table_by_sig()`] rejects an RSDT entry that points to unmapped memory.
+fn test_find_table_rsdt_unmapped_entry() -> bool {
+    let mut rsdt: [u32; 16] = [0; 16];
+    let length: usize = size_of::<AcpiSdtHeader>() + size_of::<u32>();
+    set_length(&mut rsdt, length as u32);
+
+    // Point the entry past the end of mapped memory.
+    let mapped: Range<usize> = range_of(&rsdt);
+    rsdt[size_of::<AcpiSdtHeader>() / size_of::<u32>()] = mapped.end as u32;
+    set_checksum(&mut rsdt, length);
+
+    match unsafe { acpi::find_table_by_sig(rsdt.as_ptr() as *const AcpiSdtHeader, "APIC", mapped) }
+    {
+        Err(e) if e.code == ErrorCode::BadAddress => true,
+        Err(e) => {
+            error!("unexpected error code (error={:?})", e);
+            false
+        },
+        Ok(_) => {
+            error!("found table in unmapped memory");
+            false
+        },
+    }
+}
+
+/// Builds a synthetic RSDT with a single entry that points to an APIC table in the same buffer.
+fn build_rsdt_with_table(buf: &mut [u32]) -> *const AcpiSdtHeader {
+    // Build table.
+    let table_length: usize = size_of::<AcpiSdtHeader>();
+    buf[TABLE_INDEX] = u32::from_le_bytes(*b"APIC");
+    set_length(&mut buf[TABLE_INDEX..], table_length as u32);
+    set_checksum(&mut buf[TABLE_INDEX..], table_length);
+
+    // Build RSDT.
+    let table: *const AcpiSdtHeader = buf[TABLE_INDEX..].as_ptr() as *const AcpiSdtHeader;
+    let length: usize = size_of::<AcpiSdtHeader>() + size_of::<u32>();
+    set_length(buf, length as u32);
+    buf[size_of::<AcpiSdtHeader>() / size_of::<u32>()] = table as usize as u32;
+    set_checksum(buf, length);
+
+    table
+}
+
+/// Tests if [`acpi::find_table_by_sig()`] skips a table with an invalid checksum.
+fn test_find_table_bad_checksum() -> bool {
+    let mut buf: [u32; 32] = [0; 32];
+    let table: *const AcpiSdtHeader = build_rsdt_with_table(&mut buf);
+
+    // Check if a valid table is found.
+    match unsafe {
+        acpi::find_table_by_sig(buf.as_ptr() as *const AcpiSdtHeader, "APIC", range_of(&buf))
+    } {
+        Ok(found) if found == table => {},
+        Ok(found) => {
+            error!("unexpected table (expected={:?}, got={:?})", table, found);
+            return false;
+        },
+        Err(e) => {
+            error!("failed to find valid table (error={:?})", e);
+            return false;
+        },
+    }
+
+    // Corrupt the table.
+    buf[TABLE_INDEX + CHECKSUM_OFFSET / size_of::<u32>()] ^= 1 << 24;
+
+    match unsafe {
+        acpi::find_table_by_sig(buf.as_ptr() as *const AcpiSdtHeader, "APIC", range_of(&buf))
+    } {
+        Err(e) if e.code == ErrorCode::NoSuchEntry => true,
+        Err(e) => {
+            error!("unexpected error code (error={:?})", e);
+            false
+        },
+        Ok(_) => {
+            error!("found corrupted table");
+            false
+        },
+    }
+}
+
+/// Builds a synthetic XSDT with a single entry, followed by an APIC table in the same buffer.
+fn build_xsdt(buf: &mut [u32; 32], entry: Option<u64>) -> *const AcpiSdtHeader {
+    // Build table.
+    let table_length: usize = size_of::<AcpiSdtHeader>();
+    buf[TABLE_INDEX] = u32::from_le_bytes(*b"APIC");
+    set_length(&mut buf[TABLE_INDEX..], table_length as u32);
+    set_checksum(&mut buf[TABLE_INDEX..], table_length);
+
+    // Build XSDT.
+    let table: *const AcpiSdtHeader = buf[TABLE_INDEX..].as_ptr() as *const AcpiSdtHeader;
+    let entry: u64 = entry.unwrap_or(table as usize as u64);
+    let length: usize = size_of::<AcpiSdtHeader>() + size_of::<u64>();
+    let index: usize = size_of::<AcpiSdtHeader>() / size_of::<u32>();
+    set_length(buf, length as u32);
+    buf[index] = entry as u32;
+    buf[index + 1] = (entry >> 32) as u32;
+    set_checksum(buf, length);
+
+    table
+}
+
+/// Tests if [`acpi::find_table_by_sig_xsdt()`] finds a table through a 64-bit entry.
+fn test_find_table_xsdt() -> bool {
+    let mut buf: [u32; 32] = [0; 32];
+    let table: *const AcpiSdtHeader = build_xsdt(&mut buf, None);
+
+    match unsafe {
+        acpi::find_table_by_sig_xsdt(buf.as_ptr() as *const AcpiSdtHeader, "APIC", range_of(&buf))
+    } {
+        Ok(found) if found == table => true,
+        Ok(found) => {
+            error!("unexpected table (expected={:?}, got={:?})", table, found);
+            false
+        },
+        Err(e) => {
+            error!("failed to find table (error={:?})", e);
+            false
+        },
+    }
+}
+
+/// Tests if [`acpi::find_table_by_sig_xsdt()`] rejects a table above 4 GiB.
+fn test_find_table_xsdt_above_4gib() -> bool {
+    let mut buf: [u32; 32] = [0; 32];
+    build_xsdt(&mut buf, Some(1 << 32));
+
+    match unsafe {
+        acpi::find_table_by_sig_xsdt(buf.as_ptr() as *const AcpiSdtHeader, "APIC", range_of(&buf))
+    } {
+        Err(e) if e.code == ErrorCode::OperationNotSupported => true,
+        Err(e) => {
+            error!("unexpected error code (error={:?})", e);
+            false
+        },
+        Ok(_) => {
+            error!("found table above 4 gib");
+            false
+        },
+    }
+}
+
+/// Tests if [`acpi::hpet::find_hpet()`] parses the fields of an HPET description table.
+fn test_find_hpet() -> bool {
+    const HPET_LENGTH: usize = 56;
+
+    let mut buf: [u32; 32] = [0; 32];
+
+    // Build HPET description table.
+    buf[TABLE_INDEX] = u32::from_le_bytes(*b"HPET");
+    set_length(&mut buf[TABLE_INDEX..], HPET_LENGTH as u32);
+    let base: usize = TABLE_INDEX + size_of::<AcpiSdtHeader>() / size_of::<u32>();
+    // Revision 1, 3 comparators, 64-bit counter, legacy replacement, PCI vendor 0x8086.
+    buf[base] = 0x8086_a201;
+    // System memory with 64-bit registers at 0xfed00000.
+    buf[base + 1] = 0x0000_4000;
+    buf[base + 2] = 0xfed0_0000;
+    buf[base + 3] = 0x0000_0000;
+    // HPET number 0, minimum tick 0x80 and page protection 0.
+    buf[base + 4] = 0x0000_8000;
+    set_checksum(&mut buf[TABLE_INDEX..], HPET_LENGTH);
+
+    // Build RSDT.
+    let length: usize = size_of::<AcpiSdtHeader>() + size_of::<u32>();
+    set_length(&mut buf, length as u32);
+    buf[size_of::<AcpiSdtHeader>() / size_of::<u32>()] =
+        buf[TABLE_INDEX..].as_ptr() as usize as u32;
+    set_checksum(&mut buf, length);
+
+    let expected: HpetInfo = HpetInfo {
+        hardware_rev_id: 1,
+        comparator_count: 3,
+        counter_size_64: true,
+        legacy_replacement: true,
+        pci_vendor_id: 0x8086,
+        address_space_id: acpi::hpet::ADDRESS_SPACE_SYSTEM_MEMORY,
+        register_bit_width: 64,
+        register_bit_offset: 0,
+        base_address: 0xfed0_0000,
+        hpet_number: 0,
+        minimum_tick: 0x80,
+        page_protection: 0,
+    };
+
+    match unsafe { acpi::hpet::find_hpet(buf.as_ptr() as *const AcpiSdtHeader, range_of(&buf)) } {
+        Ok(hpet) if hpet == expected => true,
+        Ok(hpet) => {
+            error!("unexpected hpet (expected={:?}, got={:?})", expected, hpet);
+            false
+        },
+        Err(e) => {
+            error!("failed to find hpet (error={:?})", e);
+            false
+        },
+    }
+}
+
+/// Tests if [`acpi::tables()`] yields each valid table once and skips corrupted ones.
+fn test_tables() -> bool {
+    let mut buf: [u32; 40] = [0; 40];
+    let table: *const AcpiSdtHeader = build_rsdt_with_table(&mut buf);
+
+    // Append an entry that points to a corrupted table.
+    let corrupted: usize = TABLE_INDEX + size_of::<AcpiSdtHeader>() / size_of::<u32>();
+    buf[corrupted] = u32::from_le_bytes(*b"FACP");
+    set_length(&mut buf[corrupted..], size_of::<AcpiSdtHeader>() as u32);
+    let length: usize = size_of::<AcpiSdtHeader>() + 2 * size_of::<u32>();
+    set_length(&mut buf, length as u32);
+    buf[size_of::<AcpiSdtHeader>() / size_of::<u32>() + 1] =
+        buf[corrupted..].as_ptr() as usize as u32;
+    set_checksum(&mut buf, length);
+
+    let tables: Vec<*const AcpiSdtHeader> =
+        match unsafe { acpi::tables(buf.as_ptr() as *const AcpiSdtHeader, range_of(&buf)) } {
+            Ok(tables) => tables.collect(),
+            Err(e) => {
+                error!("failed to enumerate tables (error={:?})", e);
+                return false;
+            },
+        };
+
+    // Check if only the valid table is yielded.
+    if tables != [table] {
+        error!("unexpected tables (expected={:?}, got={:?})", [table], tables);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if [`acpi::tables()`] enumerates the 64-bit entries of an XSDT.
+fn test_tables_xsdt() -> bool {
+    let mut buf: [u32; 32] = [0; 32];
+    let table: *const AcpiSdtHeader = build_xsdt(&mut buf, None);
+    buf[0] = u32::from_le_bytes(*b"XSDT");
+    set_checksum(&mut buf, size_of::<AcpiSdtHeader>() + size_of::<u64>());
+
+    let tables: Vec<*const AcpiSdtHeader> =
+        match unsafe { acpi::tables(buf.as_ptr() as *const AcpiSdtHeader, range_of(&buf)) } {
+            Ok(tables) => tables.collect(),
+            Err(e) => {
+                error!("failed to enumerate tables (error={:?})", e);
+                return false;
+            },
+        };
+
+    if tables != [table] {
+        error!("unexpected tables (expected={:?}, got={:?})", [table], tables);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if [`acpi::find_table_by_sig()`] finds a table that is listed after a table that does not
+/// entirely lie in mapped memory.
+fn test_find_table_after_unmapped() -> bool {
+    let mut buf: [u32; 40] = [0; 40];
+    let table: *const AcpiSdtHeader = build_rsdt_with_table(&mut buf);
+    let mapped: Range<usize> = range_of(&buf);
+
+    // Prepend an entry that points to a table whose length runs past mapped memory.
+    let unmapped: usize = TABLE_INDEX + size_of::<AcpiSdtHeader>() / size_of::<u32>();
+    buf[unmapped] = u32::from_le_bytes(*b"FACP");
+    set_length(&mut buf[unmapped..], mapped.len() as u32);
+    let entries: usize = size_of::<AcpiSdtHeader>() / size_of::<u32>();
+    buf[entries + 1] = buf[entries];
+    buf[entries] = buf[unmapped..].as_ptr() as usize as u32;
+    let length: usize = size_of::<AcpiSdtHeader>() + 2 * size_of::<u32>();
+    set_length(&mut buf, length as u32);
+    set_checksum(&mut buf, length);
+
+    match unsafe { acpi::find_table_by_sig(buf.as_ptr() as *const AcpiSdtHeader, "APIC", mapped) } {
+        Ok(found) if found == table => true,
+        Ok(found) => {
+            error!("unexpected table (expected={:?}, got={:?})", table, found);
+            false
+        },
+        Err(e) => {
+            error!("failed to find table (error={:?})", e);
+            false
+        },
+    }
+}
+
+/// Runs all unit tests for ACPI table lookup.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_find_table_rsdt_too_small);
+    passed &= run_test!(test_find_table_rsdt_bad_checksum);
+    passed &= run_test!(test_find_table_rsdt_empty);
+    passed &= run_test!(test_find_table_rsdt_unmapped_entry);
+    passed &= run_test!(test_find_table_bad_checksum);
+    passed &= run_test!(test_find_table_xsdt);
+    passed &= run_test!(test_find_table_xsdt_above_4gib);
+    passed &= run_test!(test_find_hpet);
+    passed &= run_test!(test_tables);
+    passed &= run_test!(test_tables_xsdt);
+    passed &= run_test!(test_find_table_after_unmapped);
+
+    passed
+}
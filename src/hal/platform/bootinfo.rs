@@ -14,6 +14,7 @@ use crate::{
         },
         platform::madt::MadtInfo,
     },
+    kconfig::KernelConfig,
     kmod::KernelModule,
 };
 use ::alloc::collections::LinkedList;
@@ -39,6 +40,8 @@ pub struct BootInfo {
     pub mmio_regions: LinkedList<TruncatedMemoryRegion<VirtualAddress>>,
     /// Kernel modules.
     pub kernel_modules: LinkedList<KernelModule>,
+    /// Runtime kernel configuration.
+    pub kconfig: KernelConfig,
 }
 
 //==================================================================================================
@@ -58,6 +61,7 @@ impl BootInfo {
     /// - `memory_regions`: General-purpose memory regions.
     /// - `mmio_regions`: Memory-mapped I/O regions.
     /// - `kernel_modules`: Kernel modules.
+    /// - `kconfig`: Runtime kernel configuration.
     ///
     /// # Returns
     ///
@@ -69,6 +73,7 @@ impl BootInfo {
         memory_regions: LinkedList<MemoryRegion<VirtualAddress>>,
         mmio_regions: LinkedList<TruncatedMemoryRegion<VirtualAddress>>,
         kernel_modules: LinkedList<KernelModule>,
+        kconfig: KernelConfig,
     ) -> Self {
         Self {
             madt,
@@ -76,6 +81,7 @@ impl BootInfo {
             memory_regions,
             mmio_regions,
             kernel_modules,
+            kconfig,
         }
     }
 }
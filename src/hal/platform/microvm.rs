@@ -25,6 +25,7 @@ use crate::{
             madt::MadtInfo,
         },
     },
+    kconfig::KernelConfig,
     kmod::KernelModule,
 };
 use ::alloc::{
@@ -168,6 +169,11 @@ pub fn shutdown() -> ! {
 ///
 /// A new boot information structure.
 ///
+/// # Notes
+///
+/// - The kernel command line is not supported on this platform, thus the runtime kernel
+///   configuration always matches the compile-time defaults.
+///
 pub fn parse_bootinfo(magic: u32, info: usize) -> Result<BootInfo, Error> {
     // Check if magic number matches what we expect.
     if magic != MICROVM_BOOT_MAGIC {
@@ -202,7 +208,14 @@ pub fn parse_bootinfo(magic: u32, info: usize) -> Result<BootInfo, Error> {
         kernel_modules.push_back(module);
     }
 
-    Ok(BootInfo::new(None, None, LinkedList::new(), LinkedList::new(), kernel_modules))
+    Ok(BootInfo::new(
+        None,
+        None,
+        LinkedList::new(),
+        LinkedList::new(),
+        kernel_modules,
+        KernelConfig::new(),
+    ))
 }
 
 pub fn init(
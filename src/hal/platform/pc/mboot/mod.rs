@@ -19,7 +19,10 @@ use self::{
     acpi::MbootAcpi,
     basic_mem_info::MbootBasicMeminfo,
     mboot_tag::MbootTagType,
-    memory_map::MbootMemoryMap,
+    memory_map::{
+        MbootMemoryMap,
+        MbootMemoryType,
+    },
     module::MbootModule,
 };
 use crate::{
@@ -36,10 +39,19 @@ use crate::{
             VirtualAddress,
         },
         platform::{
+            self,
+            acpi::hpet::{
+                HpetInfo,
+                ADDRESS_SPACE_SYSTEM_MEMORY,
+            },
             madt,
             madt::MadtInfo,
         },
     },
+    kconfig::{
+        self,
+        KernelConfig,
+    },
     kmod::KernelModule,
 };
 use ::alloc::{
@@ -52,17 +64,21 @@ use ::alloc::{
 use ::arch::{
     self,
     cpu::{
-        acpi::{
-            AcpiSdtHeader,
-            Rsdp,
-        },
+        acpi::AcpiSdtHeader,
         madt::Madt,
     },
 };
-use ::core::mem;
-use ::sys::error::{
-    Error,
-    ErrorCode,
+use ::core::{
+    ffi::CStr,
+    mem,
+    ops::Range,
+};
+use ::sys::{
+    config,
+    error::{
+        Error,
+        ErrorCode,
+    },
 };
 
 use crate::hal::platform::bootinfo::BootInfo;
@@ -314,11 +330,12 @@ fn parse_mmap(
         } else {
             AccessPermission::default()
         };
-        let name: &str = match typ {
-            MemoryRegionType::Usable => "usable",
-            MemoryRegionType::Reserved => "reserved",
-            MemoryRegionType::Mmio => "mmio",
-            MemoryRegionType::Bad => "bad",
+        let name: &str = match entry.typ() {
+            MbootMemoryType::Available => "usable",
+            MbootMemoryType::Reserved => "reserved",
+            MbootMemoryType::AcpiReclaimable => "acpi reclaimable",
+            MbootMemoryType::Nvs => "acpi nvs",
+            MbootMemoryType::BadRam => "bad",
         };
         let region: MemoryRegion<VirtualAddress> = MemoryRegion::new(name, start, size, typ, perm)?;
         memory_regions.push_back(region);
@@ -335,29 +352,40 @@ fn parse_mmap(
 /// # Parameters
 ///
 /// - `tag`: Mboot tag for parse.
-/// - `mmio_regions`: List of mapped I/O memory regions.
 ///
 /// # Returns
 ///
-/// Upon success, returns information about the machine. Otherwise, it returns an error.
+/// Upon success, returns RSDP structure. Otherwise, it returns an error.
 ///
-fn parse_acpiold(
-    tag: &MbootTag,
-    mmio_regions: &mut LinkedList<TruncatedMemoryRegion<VirtualAddress>>,
-) -> Result<Option<MadtInfo>, Error> {
+fn parse_acpiold(tag: &MbootTag) -> Result<MbootAcpi, Error> {
     let acpi: MbootAcpi = unsafe {
         // Safety: `MbootAcpi` is a prefix of `MbootTag`.
         let ptr: *const MbootTag = tag as *const MbootTag;
         // Safety: `ptr` points to a valid `MbootAcpi`.
         MbootAcpi::from_raw(ptr as *const u8)?
     };
-    let rsdp: Rsdp = unsafe { Rsdp::from_ptr(acpi.rsdp()).unwrap() };
-    let ptr: *const AcpiSdtHeader = unsafe {
-        crate::hal::platform::acpi::find_table_by_sig(
-            rsdp.rsdt_addr as *const AcpiSdtHeader,
-            "APIC",
-        )?
-    };
+
+    Ok(acpi)
+}
+
+///
+/// # Description
+///
+/// Parse Multiple APIC Description Table (MADT).
+///
+/// # Parameters
+///
+/// - `ptr`: MADT, which must lie in mapped memory.
+/// - `mmio_regions`: List of mapped I/O memory regions.
+///
+/// # Returns
+///
+/// Upon success, returns information about the machine. Otherwise, it returns an error.
+///
+fn parse_madt(
+    ptr: *const AcpiSdtHeader,
+    mmio_regions: &mut LinkedList<TruncatedMemoryRegion<VirtualAddress>>,
+) -> Result<Option<MadtInfo>, Error> {
     let madt: Option<MadtInfo> = match unsafe { madt::parse(ptr as *const Madt) } {
         Ok(madt) => {
             madt.display();
@@ -401,6 +429,126 @@ fn parse_acpiold(
     Ok(madt)
 }
 
+///
+/// # Description
+///
+/// Parse High Precision Event Timer (HPET) description table, and book the memory of its timer
+/// block.
+///
+/// # Parameters
+///
+/// - `ptr`: HPET description table, which must lie in mapped memory.
+/// - `mmio_regions`: List of mapped I/O memory regions.
+///
+/// # Returns
+///
+/// Upon success, returns information about the HPET. Otherwise, it returns an error.
+///
+fn parse_hpet(
+    ptr: *const AcpiSdtHeader,
+    mmio_regions: &mut LinkedList<TruncatedMemoryRegion<VirtualAddress>>,
+) -> Result<HpetInfo, Error> {
+    let hpet: HpetInfo = unsafe { platform::acpi::hpet::parse_hpet(ptr)? };
+    info!("hpet: {:?}", hpet);
+
+    // Check if timer block registers are not memory-mapped.
+    if hpet.address_space_id != ADDRESS_SPACE_SYSTEM_MEMORY {
+        let reason: &str = "hpet registers are not memory-mapped";
+        error!("parse_hpet(): {} (address_space_id={})", reason, hpet.address_space_id);
+        return Err(Error::new(ErrorCode::OperationNotSupported, reason));
+    }
+
+    // Book memory of timer block.
+    let base: usize = match usize::try_from(hpet.base_address) {
+        Ok(base) => base,
+        Err(_) => {
+            let reason: &str = "hpet lies outside of the address space";
+            error!("parse_hpet(): {} (base={:#x})", reason, hpet.base_address);
+            return Err(Error::new(ErrorCode::OperationNotSupported, reason));
+        },
+    };
+    let addr: PageAligned<VirtualAddress> = PageAligned::from_raw_value(base)?;
+    let region: TruncatedMemoryRegion<VirtualAddress> = TruncatedMemoryRegion::new(
+        "hpet",
+        addr,
+        arch::mem::PAGE_SIZE,
+        MemoryRegionType::Mmio,
+        AccessPermission::RDWR,
+    )?;
+    mmio_regions.push_back(region);
+
+    Ok(hpet)
+}
+
+///
+/// # Description
+///
+/// Parse the ACPI tables that describe the machine, starting from the RSDP of a Multiboot ACPI
+/// tag. Tables are enumerated once, and only those that were not found yet are parsed.
+///
+/// # Parameters
+///
+/// - `acpi`: Multiboot ACPI tag.
+/// - `madt`: Information about the machine, if already found.
+/// - `hpet`: Information about the HPET, if already found.
+/// - `mmio_regions`: List of mapped I/O memory regions.
+///
+/// # Returns
+///
+/// Upon success, returns empty. Otherwise, it returns an error.
+///
+fn parse_acpi(
+    acpi: &MbootAcpi,
+    madt: &mut Option<MadtInfo>,
+    hpet: &mut Option<HpetInfo>,
+    mmio_regions: &mut LinkedList<TruncatedMemoryRegion<VirtualAddress>>,
+) -> Result<(), Error> {
+    // Check if all tables were already found.
+    if madt.is_some() && hpet.is_some() {
+        return Ok(());
+    }
+
+    // Look for all tables in a single walk.
+    let mapped: Range<usize> = 0..config::kernel::MEMORY_SIZE;
+    let mut madt_table: Option<*const AcpiSdtHeader> = None;
+    let mut hpet_table: Option<*const AcpiSdtHeader> = None;
+    unsafe {
+        let sdt: *const AcpiSdtHeader = platform::acpi::root_table(acpi.rsdp(), &mapped)?;
+        for table in platform::acpi::tables(sdt, mapped)? {
+            if madt_table.is_none() && platform::acpi::has_signature(table, "APIC") {
+                madt_table = Some(table);
+            } else if hpet_table.is_none() && platform::acpi::has_signature(table, "HPET") {
+                hpet_table = Some(table);
+            }
+        }
+    }
+
+    // Check if HPET was not found yet.
+    if hpet.is_none() {
+        match hpet_table {
+            Some(table) => match parse_hpet(table, mmio_regions) {
+                Ok(info) => *hpet = Some(info),
+                Err(e) => warn!("failed to parse hpet: {:?}", e),
+            },
+            None => warn!("parse_acpi(): hpet table not found"),
+        }
+    }
+
+    // Check if MADT was not found yet.
+    if madt.is_none() {
+        match madt_table {
+            Some(table) => *madt = parse_madt(table, mmio_regions)?,
+            None => {
+                let reason: &str = "madt not found";
+                error!("parse_acpi(): {}", reason);
+                return Err(Error::new(ErrorCode::NoSuchEntry, reason));
+            },
+        }
+    }
+
+    Ok(())
+}
+
 ///
 /// # Description
 ///
@@ -425,6 +573,39 @@ fn parse_acpinew(tag: &MbootTag) -> Result<MbootAcpi, Error> {
     Ok(acpi)
 }
 
+///
+/// # Description
+///
+/// Parse the kernel command line from Multiboot tag.
+///
+/// # Parameters
+///
+/// - `tag`: Mboot tag for parse.
+///
+/// # Returns
+///
+/// Upon success, returns the runtime kernel configuration. Otherwise, it returns an error.
+///
+fn parse_cmdline(tag: &MbootTag) -> Result<KernelConfig, Error> {
+    let cmdline: &str = unsafe {
+        // Safety: the command line is a null-terminated string that follows the tag header.
+        let ptr: *const u8 = (tag as *const MbootTag as *const u8).add(mem::size_of::<MbootTag>());
+        match CStr::from_ptr(ptr as *const i8).to_str() {
+            Ok(cmdline) => cmdline,
+            Err(_) => {
+                let reason: &str = "invalid command line";
+                error!("parse_cmdline(): {}", reason);
+                return Err(Error::new(ErrorCode::BadFile, reason));
+            },
+        }
+    };
+
+    // Apply log levels first, so that they take effect while the rest of the kernel is booting.
+    kconfig::set_log_levels(cmdline)?;
+
+    KernelConfig::parse(cmdline)
+}
+
 ///
 /// # Description
 ///
@@ -480,13 +661,23 @@ pub fn parse(bootloader_magic: u32, addr: usize) -> Result<BootInfo, Error> {
     let mut mmio_regions: LinkedList<TruncatedMemoryRegion<VirtualAddress>> = LinkedList::new();
     // Machine information.
     let mut madt: Option<MadtInfo> = None;
+    let mut hpet: Option<HpetInfo> = None;
     // Lower memory size.
     let mut mem_lower: Option<usize> = None;
+    // Runtime kernel configuration.
+    let mut kconfig: KernelConfig = KernelConfig::new();
 
     while tag.typ != MbootTagType::End {
         match tag.typ {
             MbootTagType::Cmdline => {
                 info!("command_line: {:?}", tag);
+                kconfig = match parse_cmdline(tag) {
+                    Ok(kconfig) => kconfig,
+                    Err(e) => {
+                        warn!("failed to parse command line, using defaults: {:?}", e);
+                        KernelConfig::new()
+                    },
+                };
             },
             MbootTagType::BootLoaderName => {
                 info!("bootloader_name: {:?}", tag);
@@ -527,11 +718,19 @@ pub fn parse(bootloader_magic: u32, addr: usize) -> Result<BootInfo, Error> {
                 info!("smbios: {:?}", tag);
             },
             MbootTagType::AcpiOld => {
-                madt = parse_acpiold(tag, &mut mmio_regions)?;
+                let acpiold: MbootAcpi = parse_acpiold(tag)?;
+
+                // Parse tables that were not found through the new RSDP.
+                parse_acpi(&acpiold, &mut madt, &mut hpet, &mut mmio_regions)?;
             },
             MbootTagType::AcpiNew => {
                 let acpinew = parse_acpinew(tag)?;
                 acpinew.display();
+
+                // Parse tables that were not found through the old RSDP.
+                if let Err(e) = parse_acpi(&acpinew, &mut madt, &mut hpet, &mut mmio_regions) {
+                    warn!("failed to parse acpi tables through new rsdp: {:?}", e);
+                }
             },
             MbootTagType::Network => {
                 info!("network: {:?}", tag);
@@ -579,5 +778,5 @@ pub fn parse(bootloader_magic: u32, addr: usize) -> Result<BootInfo, Error> {
         return Err(Error::new(ErrorCode::BadAddress, "invalid multiboot size"));
     }
 
-    Ok(BootInfo::new(madt, mem_lower, memory_regions, mmio_regions, kernel_modules))
+    Ok(BootInfo::new(madt, mem_lower, memory_regions, mmio_regions, kernel_modules, kconfig))
 }
@@ -13,6 +13,7 @@ use crate::hal::{
     io::{
         IoMemoryAllocator,
         IoPortAllocator,
+        IoPortType,
     },
     mem::{
         AccessPermission,
@@ -35,7 +36,6 @@ use ::arch::{
 };
 
 use ::sys::{
-    config,
     error::{
         Error,
         ErrorCode,
@@ -160,8 +160,10 @@ fn register_pit(ioports: &mut IoPortAllocator) -> Result<Pit, Error> {
     // Register ports for the PIT.
     ioports.register_read_write(::arch::cpu::pit::PIT_CTRL)?;
     ioports.register_read_write(::arch::cpu::pit::PIT_DATA)?;
+    ioports.register_read_write(pit::PIT_DATA2)?;
+    ioports.register_read_write(pit::PIT_PORTB)?;
 
-    Pit::new(ioports, config::kernel::TIMER_FREQ)
+    Pit::new(ioports, crate::kconfig::get().timer_freq)
 }
 
 pub fn init(
@@ -178,16 +180,11 @@ pub fn init(
     ioports.register_read_write(pic::PIC_CTRL_SLAVE as u16)?;
     ioports.register_read_write(pic::PIC_DATA_SLAVE as u16)?;
 
-    // Register I/O ports from 0x3f8 to 0x3fc as read/write.
+    // Register I/O ports for serial devices.
     for base in [0x3F8, 0x2F8, 0x3E8, 0x2E8, 0x3E0, 0x2E0, 0x3F0, 0x2F0].iter() {
-        for p in [0, 1, 2, 3, 4, 7].iter() {
-            ioports.register_read_write(base + p)?;
-        }
-
-        // Register read-only ports.
-        for p in [5, 6].iter() {
-            ioports.register_read_only(base + p)?;
-        }
+        ioports.register_range(*base, 5, IoPortType::ReadWrite)?;
+        ioports.register_range(base + 5, 2, IoPortType::ReadOnly)?;
+        ioports.register_read_write(base + 7)?;
     }
 
     // Register memory mapped I/O regions.
@@ -12,13 +12,50 @@
 // Imports
 //==================================================================================================
 
-use crate::hal::io::{
-    IoPortAllocator,
-    ReadWriteIoPort,
+use crate::hal::{
+    io::{
+        IoPortAllocator,
+        ReadWriteIoPort,
+    },
+    time,
+};
+use ::arch::{
+    cpu::{
+        self,
+        pit,
+    },
+    io,
 };
-use ::arch::cpu::pit;
 use ::sys::error::Error;
 
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Data port of PIT channel 2.
+pub const PIT_DATA2: u16 = 0x42;
+
+/// Port B of the keyboard controller, which controls the gate of PIT channel 2.
+pub const PIT_PORTB: u16 = 0x61;
+
+/// Selects PIT channel 2, low/high byte access, interrupt on terminal count mode, binary counting.
+const PIT_SEL2_ONESHOT: u8 = 0xb0;
+
+/// Gate of PIT channel 2.
+const PORTB_GATE2: u8 = 1 << 0;
+
+/// Speaker enable.
+const PORTB_SPEAKER: u8 = 1 << 1;
+
+/// Output of PIT channel 2.
+const PORTB_OUT2: u8 = 1 << 5;
+
+/// Duration of the calibration of the time-stamp counter (in milliseconds).
+const CALIBRATION_MS: u32 = 10;
+
+/// Maximum number of times that port B is polled while waiting for channel 2 to expire.
+const MAX_POLLS: u32 = 10_000_000;
+
 //==================================================================================================
 // Structures
 //==================================================================================================
@@ -26,15 +63,25 @@ use ::sys::error::Error;
 pub struct Pit {
     ctrl: ReadWriteIoPort,
     data: ReadWriteIoPort,
+    _data2: ReadWriteIoPort,
+    _portb: ReadWriteIoPort,
 }
 
 impl Pit {
     pub fn new(ioports: &mut IoPortAllocator, freq: u32) -> Result<Self, Error> {
         let ctrl = ioports.allocate_read_write(pit::PIT_CTRL)?;
         let data = ioports.allocate_read_write(pit::PIT_DATA)?;
+        let _data2 = ioports.allocate_read_write(PIT_DATA2)?;
+        let _portb = ioports.allocate_read_write(PIT_PORTB)?;
 
-        let mut pit = Self { ctrl, data };
+        let mut pit = Self {
+            ctrl,
+            data,
+            _data2,
+            _portb,
+        };
 
+        pit.calibrate_tsc();
         pit.init(freq);
 
         Ok(pit)
@@ -51,4 +98,111 @@ impl Pit {
         self.data.write8((freq_divisor & 0xff) as u8);
         self.data.write8(((freq_divisor >> 8) & 0xff) as u8);
     }
+
+    ///
+    /// # Description
+    ///
+    /// Measures the frequency of the time-stamp counter against PIT channel 2, by polling it in
+    /// one-shot mode. Interrupts are not required.
+    ///
+    fn calibrate_tsc(&mut self) {
+        // Check if the time-stamp counter is not available.
+        if !cpu::cpuid::has_cpuid() || !cpu::cpuid::has_tsc() {
+            warn!("calibrate_tsc(): tsc not supported, delays are measured against the pit");
+            return;
+        }
+
+        info!("calibrating tsc...");
+        let count: u16 = (pit::PIT_FREQUENCY / (1000 / CALIBRATION_MS)) as u16;
+
+        let start: u64 = cpu::rdtsc();
+        // Safety: ports of channel 2 are owned by the target PIT.
+        let expired: bool = unsafe { wait_channel2(count) };
+        let end: u64 = cpu::rdtsc();
+
+        // Check if channel 2 did not reach its terminal count.
+        if !expired {
+            warn!("calibrate_tsc(): pit channel 2 did not expire");
+            return;
+        }
+
+        let freq: u64 = end.wrapping_sub(start) / u64::from(CALIBRATION_MS);
+        match u32::try_from(freq) {
+            Ok(freq) if freq > 0 => time::set_tsc_frequency(freq),
+            _ => warn!("calibrate_tsc(): bogus tsc frequency (freq={} kHz)", freq),
+        }
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Waits for a given number of microseconds by polling PIT channel 2. Interrupts are not required.
+///
+/// # Parameters
+///
+/// - `micros`: Number of microseconds to wait.
+///
+pub fn delay(micros: u32) {
+    let mut ticks: u64 = u64::from(micros) * u64::from(pit::PIT_FREQUENCY) / 1_000_000;
+    while ticks > 0 {
+        let count: u16 = ticks.min(u64::from(u16::MAX)) as u16;
+
+        // Safety: ports of channel 2 are owned by the PIT, which does not use them after boot.
+        if !unsafe { wait_channel2(count) } {
+            warn!("delay(): pit channel 2 did not expire (micros={})", micros);
+            return;
+        }
+
+        ticks -= u64::from(count);
+    }
+}
+
+///
+/// # Description
+///
+/// Programs PIT channel 2 in one-shot mode and polls it until it reaches its terminal count.
+///
+/// # Parameters
+///
+/// - `count`: Number of PIT ticks to wait.
+///
+/// # Returns
+///
+/// If the terminal count was reached, `true` is returned. If polling gave up after
+/// [`MAX_POLLS`] attempts, `false` is returned instead.
+///
+/// # Safety
+///
+/// This function is unsafe because it accesses ports of channel 2 directly. The caller must
+/// ensure that no one else uses them concurrently.
+///
+unsafe fn wait_channel2(count: u16) -> bool {
+    // Enable the gate of channel 2, with the speaker disabled.
+    let portb: u8 = io::in8(PIT_PORTB);
+    io::out8(PIT_PORTB, (portb & !PORTB_SPEAKER) | PORTB_GATE2);
+
+    // Program channel 2 in one-shot mode. Counting starts once the high byte is written.
+    io::out8(pit::PIT_CTRL, PIT_SEL2_ONESHOT);
+    io::out8(PIT_DATA2, (count & 0xff) as u8);
+    io::out8(PIT_DATA2, (count >> 8) as u8);
+
+    // Wait for the terminal count, giving up if the channel does not seem to be counting.
+    let mut expired: bool = false;
+    for _ in 0..MAX_POLLS {
+        if io::in8(PIT_PORTB) & PORTB_OUT2 != 0 {
+            expired = true;
+            break;
+        }
+        cpu::pause();
+    }
+
+    // Restore port B.
+    io::out8(PIT_PORTB, portb);
+
+    expired
 }
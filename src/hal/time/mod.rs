@@ -0,0 +1,369 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+#[cfg(feature = "pit")]
+use crate::hal::platform;
+use crate::kconfig;
+use ::core::{
+    hint,
+    sync::atomic::{
+        self,
+        AtomicU32,
+        Ordering,
+    },
+};
+
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Maximum number of microseconds that a busy-wait delay may last.
+const MAX_UDELAY: u32 = 1_000_000;
+
+//==================================================================================================
+// Traits
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A free-running counter that can be used for busy-waiting.
+///
+trait Counter {
+    /// Reads the current value of the counter.
+    fn read(&mut self) -> u64;
+    /// Gets the frequency of the counter (in kHz).
+    fn frequency_khz(&self) -> u32;
+}
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A monotonic 64-bit tick counter.
+///
+/// # Notes
+///
+/// - The counter is split in two 32-bit words, because 64-bit loads and stores are not atomic on
+///   32-bit machines. A sequence number protects readers against torn reads (seqlock).
+/// - There must be a single writer at a time.
+///
+struct TickCounter {
+    /// Sequence number. It is odd while an update is in progress.
+    seq: AtomicU32,
+    /// Lower 32 bits of the counter.
+    low: AtomicU32,
+    /// Upper 32 bits of the counter.
+    high: AtomicU32,
+}
+
+///
+/// # Description
+///
+/// Time-stamp counter of the processor.
+///
+struct Tsc;
+
+//==================================================================================================
+// Global Variables
+//==================================================================================================
+
+/// System tick counter.
+static TICKS: TickCounter = TickCounter::new(0);
+
+/// Calibrated frequency of the time-stamp counter (in kHz). It is zero while not calibrated.
+static TSC_FREQUENCY_KHZ: AtomicU32 = AtomicU32::new(0);
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl TickCounter {
+    ///
+    /// # Description
+    ///
+    /// Instantiates a tick counter.
+    ///
+    /// # Parameters
+    ///
+    /// - `ticks`: Initial value of the counter.
+    ///
+    /// # Returns
+    ///
+    /// A new tick counter.
+    ///
+    const fn new(ticks: u64) -> Self {
+        Self {
+            seq: AtomicU32::new(0),
+            low: AtomicU32::new(ticks as u32),
+            high: AtomicU32::new((ticks >> 32) as u32),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Increments the tick counter.
+    ///
+    fn increment(&self) {
+        let seq: u32 = self.seq.load(Ordering::Relaxed);
+
+        // Signal that an update is in progress.
+        self.seq.store(seq.wrapping_add(1), Ordering::Relaxed);
+        atomic::fence(Ordering::Release);
+
+        let ticks: u64 = self.load().wrapping_add(1);
+        self.low.store(ticks as u32, Ordering::Relaxed);
+        self.high.store((ticks >> 32) as u32, Ordering::Relaxed);
+
+        // Signal that the update is complete.
+        self.seq.store(seq.wrapping_add(2), Ordering::Release);
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Reads the tick counter.
+    ///
+    /// # Returns
+    ///
+    /// The current value of the tick counter.
+    ///
+    fn read(&self) -> u64 {
+        loop {
+            let seq: u32 = self.seq.load(Ordering::Acquire);
+
+            // Check if an update is in progress.
+            if seq & 1 != 0 {
+                hint::spin_loop();
+                continue;
+            }
+
+            let ticks: u64 = self.load();
+            atomic::fence(Ordering::Acquire);
+
+            // Check if the counter was not updated while it was read.
+            if self.seq.load(Ordering::Relaxed) == seq {
+                break ticks;
+            }
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Loads the two words of the tick counter, without any consistency check.
+    ///
+    /// # Returns
+    ///
+    /// The value of the tick counter.
+    ///
+    fn load(&self) -> u64 {
+        (u64::from(self.high.load(Ordering::Relaxed)) << 32)
+            | u64::from(self.low.load(Ordering::Relaxed))
+    }
+}
+
+impl Counter for Tsc {
+    fn read(&mut self) -> u64 {
+        ::arch::cpu::rdtsc()
+    }
+
+    fn frequency_khz(&self) -> u32 {
+        TSC_FREQUENCY_KHZ.load(Ordering::Relaxed)
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Advances the system tick counter by one tick.
+///
+/// # Notes
+///
+/// - This function should only be called by the timer interrupt handler.
+///
+pub fn tick() {
+    TICKS.increment();
+}
+
+///
+/// # Description
+///
+/// Gets the number of timer ticks since the system was started.
+///
+/// # Returns
+///
+/// The number of timer ticks since the system was started.
+///
+pub fn ticks() -> u64 {
+    TICKS.read()
+}
+
+///
+/// # Description
+///
+/// Gets the time elapsed since the system was started.
+///
+/// # Returns
+///
+/// The time elapsed since the system was started, in milliseconds.
+///
+pub fn uptime_ms() -> u64 {
+    ticks_to_ms(ticks())
+}
+
+///
+/// # Description
+///
+/// Converts a number of timer ticks to milliseconds.
+///
+/// # Parameters
+///
+/// - `ticks`: Number of timer ticks.
+///
+/// # Returns
+///
+/// The number of milliseconds that corresponds to `ticks`.
+///
+pub fn ticks_to_ms(ticks: u64) -> u64 {
+    ticks.saturating_mul(1000) / u64::from(kconfig::get().timer_freq)
+}
+
+///
+/// # Description
+///
+/// Sets the frequency of the time-stamp counter, as measured against a reference timer.
+///
+/// # Parameters
+///
+/// - `freq`: Frequency of the time-stamp counter (in kHz).
+///
+pub fn set_tsc_frequency(freq: u32) {
+    info!("tsc frequency: {} kHz", freq);
+    TSC_FREQUENCY_KHZ.store(freq, Ordering::Relaxed);
+}
+
+///
+/// # Description
+///
+/// Waits for a given number of microseconds in a spin loop.
+///
+/// # Parameters
+///
+/// - `micros`: Number of microseconds to wait.
+///
+/// # Notes
+///
+/// - This function does not rely on interrupts, so it may be used early in driver initialization.
+/// - Delays are capped at [`MAX_UDELAY`] microseconds.
+/// - Delays are measured against the time-stamp counter, or against a reference timer if the
+///   time-stamp counter is not calibrated.
+///
+#[allow(dead_code)]
+pub fn udelay(micros: u32) {
+    // Check if the time-stamp counter is not calibrated.
+    if TSC_FREQUENCY_KHZ.load(Ordering::Relaxed) == 0 {
+        reference_delay(cap_delay(micros));
+        return;
+    }
+
+    busy_wait(&mut Tsc, micros);
+}
+
+///
+/// # Description
+///
+/// Waits for a given number of microseconds by polling a counter.
+///
+/// # Parameters
+///
+/// - `counter`: Counter to poll.
+/// - `micros`: Number of microseconds to wait.
+///
+fn busy_wait<T: Counter>(counter: &mut T, micros: u32) {
+    let micros: u32 = cap_delay(micros);
+    let cycles: u64 = u64::from(micros) * u64::from(counter.frequency_khz()) / 1000;
+    let start: u64 = counter.read();
+    while counter.read().wrapping_sub(start) < cycles {
+        hint::spin_loop();
+    }
+}
+
+///
+/// # Description
+///
+/// Caps a busy-wait delay.
+///
+/// # Parameters
+///
+/// - `micros`: Requested number of microseconds.
+///
+/// # Returns
+///
+/// The requested number of microseconds, capped at [`MAX_UDELAY`].
+///
+fn cap_delay(micros: u32) -> u32 {
+    // Check if the requested delay is too long.
+    if micros > MAX_UDELAY {
+        warn!("cap_delay(): delay too long, capping (micros={}, max={})", micros, MAX_UDELAY);
+        MAX_UDELAY
+    } else {
+        micros
+    }
+}
+
+///
+/// # Description
+///
+/// Waits for a given number of microseconds by polling the programmable interval timer.
+///
+/// # Parameters
+///
+/// - `micros`: Number of microseconds to wait.
+///
+#[cfg(feature = "pit")]
+fn reference_delay(micros: u32) {
+    platform::pit::delay(micros);
+}
+
+///
+/// # Description
+///
+/// Skips a delay, because there is no reference timer to measure it against.
+///
+/// # Parameters
+///
+/// - `micros`: Number of microseconds to wait.
+///
+#[cfg(not(feature = "pit"))]
+fn reference_delay(micros: u32) {
+    warn!("reference_delay(): no reference timer, skipping delay (micros={})", micros);
+}
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
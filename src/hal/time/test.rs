@@ -0,0 +1,176 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+#[cfg(feature = "pit")]
+use crate::hal::{
+    platform::pit,
+    time::TSC_FREQUENCY_KHZ,
+};
+use crate::{
+    hal::time::{
+        self,
+        Counter,
+        TickCounter,
+        MAX_UDELAY,
+    },
+    kconfig,
+};
+#[cfg(feature = "pit")]
+use ::arch::cpu::{
+    self,
+    cpuid,
+};
+#[cfg(feature = "pit")]
+use ::core::sync::atomic::Ordering;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+/// A counter that advances by a fixed step on every read.
+struct StubCounter {
+    /// Number of cycles elapsed.
+    cycles: u64,
+    /// Number of cycles elapsed between two reads.
+    step: u64,
+}
+
+impl Counter for StubCounter {
+    fn read(&mut self) -> u64 {
+        self.cycles += self.step;
+        self.cycles
+    }
+
+    fn frequency_khz(&self) -> u32 {
+        1_000
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if the tick counter is monotonic across a simulated tick.
+fn test_ticks_monotonic() -> bool {
+    // Start close to a word boundary, so that the upper word is also updated.
+    let initial: u64 = u64::from(u32::MAX);
+    let counter: TickCounter = TickCounter::new(initial);
+
+    let before: u64 = counter.read();
+    counter.increment();
+    let after: u64 = counter.read();
+
+    // Check if the counter advanced by exactly one tick.
+    if before != initial || after != initial + 1 {
+        error!("unexpected tick count (before={}, after={})", before, after);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if timer ticks are correctly converted to milliseconds.
+fn test_ticks_to_ms() -> bool {
+    let freq: u64 = u64::from(kconfig::get().timer_freq);
+
+    // Check if one second worth of ticks is converted to 1000 milliseconds.
+    if time::ticks_to_ms(freq) != 1000 {
+        error!("unexpected conversion (ticks={}, ms={})", freq, time::ticks_to_ms(freq));
+        return false;
+    }
+
+    // Check if a single tick is converted to the timer period.
+    if time::ticks_to_ms(1) != 1000 / freq {
+        error!("unexpected conversion (ticks=1, ms={})", time::ticks_to_ms(1));
+        return false;
+    }
+
+    true
+}
+
+/// Tests if a delay lasts about the requested time, both when it is measured against PIT channel
+/// 2 and when it is measured against the time-stamp counter.
+#[cfg(feature = "pit")]
+fn test_udelay() -> bool {
+    // Duration of the calibration of the time-stamp counter (in microseconds).
+    const CALIBRATION_MICROS: u32 = 10_000;
+    // Longer than a single countdown of PIT channel 2, so that the fallback waits several times.
+    const MICROS: u32 = 100_000;
+
+    if !(cpuid::has_cpuid() && cpuid::has_tsc()) {
+        warn!("tsc not supported, skipping delay test");
+        return true;
+    }
+
+    // Measure the frequency of the time-stamp counter against PIT channel 2.
+    let start: u64 = cpu::rdtsc();
+    pit::delay(CALIBRATION_MICROS);
+    let cycles: u64 = cpu::rdtsc().wrapping_sub(start);
+    let freq_khz: u32 = match u32::try_from(cycles * 1000 / u64::from(CALIBRATION_MICROS)) {
+        Ok(freq_khz) if freq_khz > 0 => freq_khz,
+        _ => {
+            error!("bogus tsc frequency (cycles={})", cycles);
+            return false;
+        },
+    };
+
+    let mut passed: bool = true;
+    let saved: u32 = TSC_FREQUENCY_KHZ.load(Ordering::Relaxed);
+    for calibrated in [false, true] {
+        TSC_FREQUENCY_KHZ.store(if calibrated { freq_khz } else { 0 }, Ordering::Relaxed);
+
+        let start: u64 = cpu::rdtsc();
+        time::udelay(MICROS);
+        let elapsed: u64 = cpu::rdtsc().wrapping_sub(start) * 1000 / u64::from(freq_khz);
+
+        // Check if the delay lasted about the requested time, allowing for calibration error.
+        if elapsed < u64::from(MICROS - MICROS / 10) || elapsed > 2 * u64::from(MICROS) {
+            error!(
+                "unexpected delay (calibrated={}, requested={}, elapsed={})",
+                calibrated, MICROS, elapsed
+            );
+            passed = false;
+        }
+    }
+    TSC_FREQUENCY_KHZ.store(saved, Ordering::Relaxed);
+
+    passed
+}
+
+/// Tests if a bogus long busy-wait delay is capped.
+fn test_udelay_capped() -> bool {
+    const STEP: u64 = 1_000;
+
+    let mut counter: StubCounter = StubCounter {
+        cycles: 0,
+        step: STEP,
+    };
+    time::busy_wait(&mut counter, u32::MAX);
+
+    let elapsed: u64 = counter.cycles - STEP;
+    if elapsed < u64::from(MAX_UDELAY) || elapsed >= u64::from(MAX_UDELAY) + STEP {
+        error!("unexpected delay (max={}, elapsed={})", MAX_UDELAY, elapsed);
+        return false;
+    }
+
+    true
+}
+
+/// Runs all unit tests for time keeping.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_ticks_monotonic);
+    passed &= run_test!(test_ticks_to_ms);
+    #[cfg(feature = "pit")]
+    {
+        passed &= run_test!(test_udelay);
+    }
+    passed &= run_test!(test_udelay_capped);
+
+    passed
+}
@@ -23,6 +23,7 @@ use ::sys::{
         Error,
         ErrorCode,
     },
+    mm::CachingMode,
     pm::{
         Capability,
         ProcessIdentifier,
@@ -38,8 +39,9 @@ fn do_mmio_alloc(
     pm: &mut ProcessManager,
     pid: ProcessIdentifier,
     addr: PageAligned<VirtualAddress>,
+    mode: CachingMode,
 ) -> Result<(), Error> {
-    trace!("do_mmio_alloc(): pid={:?}, addr={:?}", pid, addr.into_inner());
+    trace!("do_mmio_alloc(): pid={:?}, addr={:?}, mode={:?}", pid, addr.into_inner(), mode);
 
     // Check if process does not have I/O management capabilities.
     if !ProcessManager::has_capability(pid, Capability::IoManagement)? {
@@ -48,11 +50,18 @@ fn do_mmio_alloc(
         return Err(Error::new(ErrorCode::PermissionDenied, reason));
     }
 
+    // Check if write-back caching was requested, which is not suitable for device registers.
+    if mode == CachingMode::WriteBack {
+        let reason: &'static str = "write-back caching is not allowed for mmio regions";
+        error!("do_mmio_alloc(): {}", reason);
+        return Err(Error::new(ErrorCode::InvalidArgument, reason));
+    }
+
     // Attempt to allocate I/O memory region.
     let region: IoMemoryRegion = hal.ioaddresses.allocate(addr.into_inner())?;
 
     // Attached I/O memory region to the process.
-    pm.mmio_alloc(pid, region)?;
+    pm.mmio_alloc(pid, region, mode)?;
 
     Ok(())
 }
@@ -64,7 +73,24 @@ pub fn mmio_alloc(hal: &mut Hal, pm: &mut ProcessManager, args: &KcallArgs) -> i
         Err(e) => return e.code.into_errno(),
     };
 
-    match do_mmio_alloc(hal, pm, args.pid, addr) {
+    match do_mmio_alloc(hal, pm, args.pid, addr, CachingMode::default()) {
+        Ok(_) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
+
+pub fn mmio_alloc_caching(hal: &mut Hal, pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
+    // Parse arguments.
+    let addr: PageAligned<VirtualAddress> = match PageAligned::from_raw_value(args.arg0 as usize) {
+        Ok(base) => base,
+        Err(e) => return e.code.into_errno(),
+    };
+    let mode: CachingMode = match CachingMode::try_from(args.arg1) {
+        Ok(mode) => mode,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match do_mmio_alloc(hal, pm, args.pid, addr, mode) {
         Ok(_) => 0,
         Err(e) => e.code.into_errno(),
     }
@@ -16,7 +16,10 @@ mod pmio_write;
 // Exports
 //==================================================================================================
 
-pub use mmio_alloc::mmio_alloc;
+pub use mmio_alloc::{
+    mmio_alloc,
+    mmio_alloc_caching,
+};
 pub use mmio_free::mmio_free;
 pub use pmio_alloc::pmio_alloc;
 pub use pmio_free::pmio_free;
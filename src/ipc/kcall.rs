@@ -7,22 +7,39 @@
 
 use crate::{
     event::EventManager,
-    kcall::KcallArgs,
+    hal::time,
+    kcall::{
+        args::{
+            user_mut,
+            user_ref,
+            UserMut,
+        },
+        KcallArgs,
+        ScoreBoard,
+    },
+    kconfig,
     pm::{
         self,
         ProcessManager,
     },
 };
+use ::alloc::vec::Vec;
 use ::sys::{
+    config,
     error::{
         Error,
         ErrorCode,
     },
     ipc::{
+        MailboxStats,
         Message,
         MessageType,
     },
-    pm::ProcessIdentifier,
+    number::KcallNumber,
+    pm::{
+        Capability,
+        ProcessIdentifier,
+    },
 };
 
 //==================================================================================================
@@ -45,15 +62,11 @@ fn do_send(pm: &mut ProcessManager, src: ProcessIdentifier, message: Message) ->
     EventManager::post_message(pm, message.destination, message)
 }
 
-pub fn send(pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
-    let src: ProcessIdentifier = args.pid;
-
-    // Copy message to kernel space.
-    let mut message: Message = Message::default();
-    if let Err(e) = pm::copy_from_user(src, &mut message, args.arg0 as *const Message) {
-        return e.code.into_errno();
-    }
-
+fn do_route(
+    pm: &mut ProcessManager,
+    src: ProcessIdentifier,
+    message: Message,
+) -> Result<(), Error> {
     // TODO: Check if source process has permission to send message to destination process.
 
     // Route message based on its type.
@@ -64,25 +77,216 @@ pub fn send(pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
                 // Check if standard input/output is available.
                 if #[cfg(feature = "stdio")] {
                     // It is, so write message to standard output.
-                    match crate::stdio::write(message) {
-                        Ok(_) => 0,
-                        Err(e) => e.code.into_errno(),
-                    }
+                    crate::stdio::write(message)
                 } else {
                     // Standard input/output is not available.
-                    error!("send(): stdio is not available");
-                    ErrorCode::ProtocolNotSupported.into_errno()
+                    let reason: &str = "stdio is not available";
+                    error!("send(): {}", reason);
+                    Err(Error::new(ErrorCode::ProtocolNotSupported, reason))
                 }
             }
         },
         // Local-host communication.
-        _ => {
-            // Post message.
-            match do_send(pm, src, message) {
-                Ok(_) => 0,
-                Err(e) => e.code.into_errno(),
+        _ => do_send(pm, src, message),
+    }
+}
+
+pub fn send(pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
+    let src: ProcessIdentifier = args.pid;
+
+    // Copy message to kernel space.
+    let message: Message = match user_ref::<Message>(src, args.arg0 as usize) {
+        Ok(message) => match message.read() {
+            Ok(message) => message,
+            Err(e) => return e.code.into_errno(),
+        },
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match do_route(pm, src, message) {
+        Ok(()) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
+
+fn do_send_bounded(pm: &mut ProcessManager) -> Result<(), Error> {
+    // Check if the kernel may buffer one more message.
+    if pm.number_buffered_messages()? >= config::kernel::MAX_IKC_MESSAGES {
+        let reason: &str = "message buffer is full";
+        trace!("do_send_bounded(): {}", reason);
+        return Err(Error::new(ErrorCode::TryAgain, reason));
+    }
+
+    Ok(())
+}
+
+pub fn send_bounded(pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
+    match do_send_bounded(pm) {
+        Ok(()) => send(pm, args),
+        Err(e) => e.code.into_errno(),
+    }
+}
+
+fn do_send_timeout(message: u32, ticks: u32) -> Result<(), Error> {
+    trace!("do_send_timeout(): ticks={:?}", ticks);
+
+    let deadline: u64 = time::ticks().saturating_add(ticks as u64);
+
+    loop {
+        let result: i32 =
+            ScoreBoard::get_mut()?.dispatch(KcallNumber::SendTimeout.into(), message, 0, 0, 0)?;
+
+        // Check if message was enqueued, or if sending failed for another reason.
+        if result != ErrorCode::TryAgain.into_errno() {
+            if result != 0 {
+                let reason: &str = "failed to send message";
+                error!("do_send_timeout(): {} (result={:?})", reason, result);
+                return Err(Error::new(ErrorCode::try_from(result)?, reason));
             }
+            return Ok(());
+        }
+
+        // Wait until a receiver drains a message, or the tick budget is exhausted.
+        pm::wait_buffer_space(send_timeout_slice(time::ticks(), deadline)?)?;
+    }
+}
+
+///
+/// # Description
+///
+/// Computes how long a sender may wait for room in the message buffer before retrying.
+///
+/// # Parameters
+///
+/// - `now`: Current time, in timer ticks.
+/// - `deadline`: Time at which the tick budget of the sender is exhausted.
+///
+/// # Returns
+///
+/// Upon successful completion, the number of ticks to wait for is returned. If the tick budget is
+/// exhausted, an error with [`ErrorCode::TimedOut`] is returned instead.
+///
+/// # Notes
+///
+/// - Waits are capped to a scheduling quantum, so that a receiver that drains a message before the
+///   sender starts waiting delays the retry by at most one quantum.
+///
+pub(super) fn send_timeout_slice(now: u64, deadline: u64) -> Result<u64, Error> {
+    // Check if tick budget is exhausted.
+    if now >= deadline {
+        let reason: &str = "message could not be enqueued in time";
+        trace!("send_timeout_slice(): {}", reason);
+        return Err(Error::new(ErrorCode::TimedOut, reason));
+    }
+
+    Ok((deadline - now).min(kconfig::get().scheduler_freq as u64))
+}
+
+pub fn send_timeout(msg: u32, ticks: u32) -> i32 {
+    match do_send_timeout(msg, ticks) {
+        Ok(()) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
+
+fn do_read_nth(src: ProcessIdentifier, messages: usize, index: usize) -> Result<Message, Error> {
+    // Compute address of target message.
+    let addr: usize = match core::mem::size_of::<Message>()
+        .checked_mul(index)
+        .and_then(|offset| messages.checked_add(offset))
+    {
+        Some(addr) => addr,
+        None => {
+            let reason: &str = "message array overflows address space";
+            error!("do_read_nth(): {}", reason);
+            return Err(Error::new(ErrorCode::BadAddress, reason));
         },
+    };
+
+    // Copy message to kernel space.
+    let message: Message = user_ref::<Message>(src, addr)?.read()?;
+
+    // Sanity check message source.
+    if { message.source } != src {
+        let reason: &str = "invalid message source";
+        error!("do_read_nth(): {}", reason);
+        return Err(Error::new(ErrorCode::InvalidArgument, reason));
+    }
+
+    Ok(message)
+}
+
+///
+/// # Description
+///
+/// Sends a batch of messages, one at a time, until one of them is not accepted.
+///
+/// # Parameters
+///
+/// - `batch`: Messages to send.
+/// - `send`: Function that sends a single message.
+///
+/// # Returns
+///
+/// Upon successful completion, the number of messages that were accepted is returned. If the first
+/// message is not accepted for a reason other than the message buffer being full, an error is
+/// returned instead.
+///
+/// # Notes
+///
+/// - Once some messages were accepted, the count is reported even if the next message failed for
+///   another reason. The caller resumes at the first message that was not accepted, and sending it
+///   again reports the error.
+///
+pub(super) fn send_batch(
+    batch: Vec<Message>,
+    mut send: impl FnMut(Message) -> Result<(), Error>,
+) -> Result<usize, Error> {
+    let mut sent: usize = 0;
+    for message in batch {
+        match send(message) {
+            Ok(()) => sent += 1,
+            // Stop at the first message that would block.
+            Err(e) if e.code == ErrorCode::TryAgain => break,
+            Err(e) if sent > 0 => {
+                trace!("send_batch(): stopping at message {} (error={:?})", sent, e);
+                break;
+            },
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(sent)
+}
+
+fn do_send_many(
+    pm: &mut ProcessManager,
+    src: ProcessIdentifier,
+    messages: usize,
+    count: usize,
+) -> Result<usize, Error> {
+    trace!("do_send_many(): src={:?}, messages={:#x}, count={:?}", src, messages, count);
+
+    // Consider at most as many messages as the kernel may buffer.
+    let count: usize = count.min(config::kernel::MAX_IKC_MESSAGES);
+
+    // Copy all messages to kernel space before sending any, so that a bad message fails the whole
+    // batch instead of a suffix of it.
+    let mut batch: Vec<Message> = Vec::with_capacity(count);
+    for index in 0..count {
+        batch.push(do_read_nth(src, messages, index)?);
+    }
+
+    send_batch(batch, |message| {
+        do_send_bounded(pm)?;
+        do_route(pm, src, message)
+    })
+}
+
+pub fn send_many(pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
+    match do_send_many(pm, args.pid, args.arg0 as usize, args.arg1 as usize) {
+        Ok(sent) => sent as i32,
+        Err(e) => e.code.into_errno(),
     }
 }
 
@@ -99,9 +303,286 @@ pub fn recv(msg: usize) -> i32 {
         Err(e) => return e.code.into_errno(),
     };
 
+    // Check message buffer before waiting, so that no message is lost.
+    let msg: UserMut<Message> = match user_mut(pid, msg) {
+        Ok(msg) => msg,
+        Err(e) => return e.code.into_errno(),
+    };
+
     match do_recv(pid) {
         Ok(message) => {
-            if let Err(e) = pm::copy_to_user(pid, msg as *mut Message, &message) {
+            if let Err(e) = msg.write(&message) {
+                return e.code.into_errno();
+            }
+            0
+        },
+        Err(e) => e.code.into_errno(),
+    }
+}
+
+fn do_try_recv(pid: ProcessIdentifier) -> Result<Message, Error> {
+    trace!("do_try_recv(): pid={:?}", pid);
+
+    // Poll for a message, without waiting.
+    match EventManager::wait_timeout(pid, 0)? {
+        Some(message) => Ok(message),
+        None => {
+            let reason: &str = "no message is pending";
+            trace!("do_try_recv(): {}", reason);
+            Err(Error::new(ErrorCode::TryAgain, reason))
+        },
+    }
+}
+
+pub fn try_recv(msg: usize) -> i32 {
+    let pid: ProcessIdentifier = match ProcessManager::get_pid() {
+        Ok(pid) => pid,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    // Check message buffer before polling, so that no message is lost.
+    let msg: UserMut<Message> = match user_mut(pid, msg) {
+        Ok(msg) => msg,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match do_try_recv(pid) {
+        Ok(message) => {
+            if let Err(e) = msg.write(&message) {
+                return e.code.into_errno();
+            }
+            0
+        },
+        Err(e) => e.code.into_errno(),
+    }
+}
+
+fn do_peek_message() -> Result<Message, Error> {
+    trace!("do_peek_message()");
+
+    match ProcessManager::peek_message()? {
+        Some(message) => Ok(message),
+        None => {
+            let reason: &str = "no message is pending";
+            trace!("do_peek_message(): {}", reason);
+            Err(Error::new(ErrorCode::TryAgain, reason))
+        },
+    }
+}
+
+pub fn peek_message(msg: usize) -> i32 {
+    let pid: ProcessIdentifier = match ProcessManager::get_pid() {
+        Ok(pid) => pid,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    let msg: UserMut<Message> = match user_mut(pid, msg) {
+        Ok(msg) => msg,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match do_peek_message() {
+        Ok(message) => {
+            if let Err(e) = msg.write(&message) {
+                return e.code.into_errno();
+            }
+            0
+        },
+        Err(e) => e.code.into_errno(),
+    }
+}
+
+fn do_flush_mailbox() -> Result<usize, Error> {
+    trace!("do_flush_mailbox()");
+
+    ProcessManager::flush_mailbox()
+}
+
+pub fn flush_mailbox() -> i32 {
+    match do_flush_mailbox() {
+        Ok(count) => match i32::try_from(count) {
+            Ok(count) => count,
+            Err(_) => ErrorCode::ValueOutOfRange.into_errno(),
+        },
+        Err(e) => e.code.into_errno(),
+    }
+}
+
+fn do_hold_message(message: Message) -> Result<(), Error> {
+    trace!("do_hold_message(): src={:?}", { message.source });
+
+    ProcessManager::hold_message(message)
+}
+
+pub fn hold_message(msg: usize) -> i32 {
+    let pid: ProcessIdentifier = match ProcessManager::get_pid() {
+        Ok(pid) => pid,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    // Copy message to kernel space.
+    let message: Message = match user_ref::<Message>(pid, msg) {
+        Ok(message) => match message.read() {
+            Ok(message) => message,
+            Err(e) => return e.code.into_errno(),
+        },
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match do_hold_message(message) {
+        Ok(()) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
+
+fn do_release_messages() -> Result<usize, Error> {
+    trace!("do_release_messages()");
+
+    ProcessManager::release_messages()
+}
+
+pub fn release_messages() -> i32 {
+    match do_release_messages() {
+        Ok(count) => match i32::try_from(count) {
+            Ok(count) => count,
+            Err(_) => ErrorCode::ValueOutOfRange.into_errno(),
+        },
+        Err(e) => e.code.into_errno(),
+    }
+}
+
+fn do_set_mailbox_fair(fair: bool) -> Result<(), Error> {
+    trace!("do_set_mailbox_fair(): fair={:?}", fair);
+
+    ProcessManager::set_mailbox_fair(fair)
+}
+
+pub fn set_mailbox_fair(fair: u32) -> i32 {
+    // Check if flag is invalid.
+    let fair: bool = match fair {
+        0 => false,
+        1 => true,
+        _ => return ErrorCode::InvalidArgument.into_errno(),
+    };
+
+    match do_set_mailbox_fair(fair) {
+        Ok(()) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
+
+fn do_mailbox_stats(
+    pid: ProcessIdentifier,
+    target: ProcessIdentifier,
+    stats: &UserMut<MailboxStats>,
+) -> Result<(), Error> {
+    trace!("do_mailbox_stats(): pid={:?}, target={:?}", pid, target);
+
+    // Check if process may not inspect the mailbox of another process.
+    if pid != target && !ProcessManager::has_capability(pid, Capability::ProcessManagement)? {
+        let reason: &str = "process does not have process management capabilities";
+        error!("do_mailbox_stats(): {}", reason);
+        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+    }
+
+    stats.write(&ProcessManager::mailbox_stats(target)?)
+}
+
+pub fn mailbox_stats(args: &KcallArgs) -> i32 {
+    let target: ProcessIdentifier = ProcessIdentifier::from(args.arg0);
+
+    let stats: UserMut<MailboxStats> = match user_mut(args.pid, args.arg1 as usize) {
+        Ok(stats) => stats,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match do_mailbox_stats(args.pid, target, &stats) {
+        Ok(()) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
+
+fn do_sendwait(
+    pid: ProcessIdentifier,
+    message: u32,
+    reply_type: MessageType,
+) -> Result<Message, Error> {
+    trace!("do_sendwait(): pid={:?}, reply_type={:?}", pid, reply_type);
+
+    send_and_wait(
+        reply_type,
+        || {
+            let result: i32 =
+                ScoreBoard::get_mut()?.dispatch(KcallNumber::Send.into(), message, 0, 0, 0)?;
+            if result != 0 {
+                let reason: &str = "failed to send message";
+                error!("do_sendwait(): {} (result={:?})", reason, result);
+                return Err(Error::new(ErrorCode::try_from(result)?, reason));
+            }
+            Ok(())
+        },
+        |reply_type| EventManager::wait_reply(pid, reply_type),
+    )
+}
+
+///
+/// # Description
+///
+/// Sends a message and waits for a reply of a given type.
+///
+/// # Parameters
+///
+/// - `reply_type`: Expected type of the reply.
+/// - `send`: Function that sends the message.
+/// - `wait`: Function that waits for a reply of the expected type.
+///
+/// # Returns
+///
+/// Upon successful completion, the reply is returned. Upon failure, an error is returned instead.
+///
+/// # Notes
+///
+/// - The reply type is checked before the message is sent, so that a message is never sent if its
+///   reply cannot be waited for.
+/// - Replies are buffered until they are consumed, thus a reply that arrives before the caller
+///   starts waiting is still delivered.
+///
+pub(super) fn send_and_wait(
+    reply_type: MessageType,
+    send: impl FnOnce() -> Result<(), Error>,
+    wait: impl FnOnce(MessageType) -> Result<Message, Error>,
+) -> Result<Message, Error> {
+    EventManager::check_reply_type(reply_type)?;
+
+    send()?;
+
+    wait(reply_type)
+}
+
+pub fn sendwait(msg: usize, reply_type: usize, reply: usize) -> i32 {
+    let pid: ProcessIdentifier = match ProcessManager::get_pid() {
+        Ok(pid) => pid,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    // Parse reply type.
+    let reply_type: MessageType = match u8::try_from(reply_type) {
+        Ok(reply_type) => match MessageType::try_from_bytes([reply_type]) {
+            Ok(reply_type) => reply_type,
+            Err(e) => return e.code.into_errno(),
+        },
+        Err(_) => return ErrorCode::InvalidArgument.into_errno(),
+    };
+
+    // Check reply buffer before sending, so that no reply is lost.
+    let reply: UserMut<Message> = match user_mut(pid, reply) {
+        Ok(reply) => reply,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match do_sendwait(pid, msg as u32, reply_type) {
+        Ok(message) => {
+            if let Err(e) = reply.write(&message) {
                 return e.code.into_errno();
             }
             0
@@ -5,8 +5,23 @@
 // Imports
 //==================================================================================================
 
-use ::alloc::collections::LinkedList;
-use ::sys::ipc::Message;
+use ::alloc::{
+    collections::LinkedList,
+    vec::Vec,
+};
+use ::core::mem;
+use ::sys::{
+    config,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    ipc::{
+        Message,
+        MessageType,
+    },
+    pm::ProcessIdentifier,
+};
 
 //==================================================================================================
 //  Structures
@@ -14,7 +29,23 @@ use ::sys::ipc::Message;
 
 #[derive(Default)]
 pub struct Mailbox {
+    /// Pending messages, in arrival order (FIFO mode).
     buffer: LinkedList<Message>,
+    /// Pending messages grouped by source, in round-robin order, along with their sequence
+    /// numbers (fair mode).
+    queues: LinkedList<(ProcessIdentifier, LinkedList<(i64, Message)>)>,
+    /// Sequence number of the message that was last placed ahead of all others (fair mode).
+    head_sequence: i64,
+    /// Sequence number of the next message that arrives (fair mode).
+    tail_sequence: i64,
+    /// Whether messages are delivered round-robin across sources.
+    fair: bool,
+    /// Number of pending messages.
+    count: usize,
+    /// Highest number of pending messages ever observed.
+    high_water: usize,
+    /// Messages that were set aside, in arrival order.
+    held: LinkedList<Message>,
 }
 
 //==================================================================================================
@@ -22,11 +53,313 @@ pub struct Mailbox {
 //==================================================================================================
 
 impl Mailbox {
+    ///
+    /// # Description
+    ///
+    /// Enables or disables fair queuing. In fair mode, messages are delivered round-robin across
+    /// distinct sources, so that a flooding sender does not starve others. Otherwise, messages are
+    /// delivered in arrival order. Pending messages are kept across mode changes, and they are
+    /// delivered in arrival order once fair queuing is disabled.
+    ///
+    /// # Parameters
+    ///
+    /// - `fair`: Whether fair queuing should be enabled.
+    ///
+    pub fn set_fair(&mut self, fair: bool) {
+        if self.fair == fair {
+            return;
+        }
+
+        self.fair = fair;
+        if fair {
+            // Regroup pending messages by source, keeping their arrival order.
+            let mut pending: LinkedList<Message> = mem::take(&mut self.buffer);
+            while let Some(message) = pending.pop_front() {
+                self.enqueue(message);
+            }
+        } else {
+            // Merge pending messages back in arrival order.
+            let mut pending: Vec<(i64, Message)> = Vec::with_capacity(self.count);
+            while let Some((_, queue)) = self.queues.pop_front() {
+                pending.extend(queue);
+            }
+            pending.sort_by_key(|(sequence, _)| *sequence);
+            self.buffer = pending.into_iter().map(|(_, message)| message).collect();
+        }
+    }
+
     pub fn send(&mut self, message: Message) {
-        self.buffer.push_back(message);
+        self.account_send();
+
+        if !self.fair {
+            self.buffer.push_back(message);
+            return;
+        }
+
+        self.enqueue(message);
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sends a message ahead of pending messages of lower priority. Exceptions have the highest
+    /// priority, followed by interrupts, and then by all other messages. Messages of equal priority
+    /// are delivered in arrival order. In fair mode, the message only jumps ahead of messages from
+    /// the same source.
+    ///
+    /// # Parameters
+    ///
+    /// - `message`: Message to send.
+    ///
+    pub fn send_prioritized(&mut self, message: Message) {
+        if !self.fair {
+            self.account_send();
+            Self::insert_prioritized(&mut self.buffer, message, |message| message);
+            return;
+        }
+
+        // Enqueue the message along with others from the same source.
+        let sequence: i64 = self.tail_sequence;
+        match self
+            .queues
+            .iter_mut()
+            .find(|(source, _)| *source == { message.source })
+        {
+            Some((_, queue)) => {
+                Self::insert_prioritized(queue, (sequence, message), |(_, message)| message);
+                self.tail_sequence += 1;
+                self.account_send();
+            },
+            None => self.send(message),
+        }
     }
 
     pub fn receive(&mut self) -> Option<Message> {
-        self.buffer.pop_front()
+        let message: Option<Message> = if !self.fair {
+            self.buffer.pop_front()
+        } else {
+            // Serve the source at the head, then move it to the back if it has more messages.
+            let (source, mut queue): (ProcessIdentifier, LinkedList<(i64, Message)>) =
+                self.queues.pop_front()?;
+            let message: Option<Message> = queue.pop_front().map(|(_, message)| message);
+            if !queue.is_empty() {
+                self.queues.push_back((source, queue));
+            }
+            message
+        };
+
+        if message.is_some() {
+            self.count -= 1;
+        }
+
+        message
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the message that would be delivered next, without removing it from the mailbox.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the next message, or `None` if the mailbox is empty.
+    ///
+    pub fn peek(&self) -> Option<&Message> {
+        if !self.fair {
+            return self.buffer.front();
+        }
+
+        self.queues
+            .front()
+            .and_then(|(_, queue)| queue.front())
+            .map(|(_, message)| message)
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the highest number of messages that were ever pending in the mailbox at once.
+    ///
+    /// # Returns
+    ///
+    /// The high-water mark of the mailbox.
+    ///
+    pub fn high_water(&self) -> usize {
+        self.high_water
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn drain(&mut self) -> usize {
+        let count: usize = self.len() + self.held.len();
+        self.buffer.clear();
+        self.queues.clear();
+        self.held.clear();
+        self.count = 0;
+        count
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sets aside a received message, so that it is not delivered again until it is released.
+    ///
+    /// # Parameters
+    ///
+    /// - `message`: Message to hold.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. If too many messages are held, an error with
+    /// [`ErrorCode::TryAgain`] is returned instead.
+    ///
+    pub fn hold(&mut self, message: Message) -> Result<(), Error> {
+        // Check if too many messages are held. Held messages are supplied by the process itself,
+        // thus they are bounded so that it cannot exhaust kernel memory.
+        if self.held.len() >= config::kernel::MAX_IKC_MESSAGES {
+            let reason: &str = "too many held messages";
+            error!("hold(): {}", reason);
+            return Err(Error::new(ErrorCode::TryAgain, reason));
+        }
+
+        self.held.push_back(message);
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Releases all held messages, so that they are delivered ahead of pending messages and in
+    /// the order they were held. In fair mode, held messages are delivered ahead of pending
+    /// messages from the same source.
+    ///
+    /// # Returns
+    ///
+    /// The number of released messages.
+    ///
+    pub fn release(&mut self) -> usize {
+        let count: usize = self.held.len();
+
+        while let Some(message) = self.held.pop_back() {
+            self.account_send();
+
+            if !self.fair {
+                self.buffer.push_front(message);
+                continue;
+            }
+
+            // Requeue the message ahead of others from the same source.
+            self.head_sequence -= 1;
+            let sequence: i64 = self.head_sequence;
+            match self
+                .queues
+                .iter_mut()
+                .find(|(source, _)| *source == { message.source })
+            {
+                Some((_, queue)) => queue.push_front((sequence, message)),
+                None => {
+                    let mut queue: LinkedList<(i64, Message)> = LinkedList::new();
+                    let source: ProcessIdentifier = message.source;
+                    queue.push_back((sequence, message));
+                    self.queues.push_front((source, queue));
+                },
+            }
+        }
+
+        count
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Enqueues a message in fair mode, behind others from the same source.
+    ///
+    /// # Parameters
+    ///
+    /// - `message`: Message to enqueue.
+    ///
+    fn enqueue(&mut self, message: Message) {
+        let sequence: i64 = self.tail_sequence;
+        self.tail_sequence += 1;
+
+        match self
+            .queues
+            .iter_mut()
+            .find(|(source, _)| *source == { message.source })
+        {
+            Some((_, queue)) => queue.push_back((sequence, message)),
+            None => {
+                let mut queue: LinkedList<(i64, Message)> = LinkedList::new();
+                let source: ProcessIdentifier = message.source;
+                queue.push_back((sequence, message));
+                self.queues.push_back((source, queue));
+            },
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Accounts for a message that is sent to the mailbox.
+    ///
+    fn account_send(&mut self) {
+        self.count += 1;
+        self.high_water = self.high_water.max(self.count);
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the delivery priority of a message. Higher values are delivered first.
+    ///
+    /// # Parameters
+    ///
+    /// - `message`: Message.
+    ///
+    /// # Returns
+    ///
+    /// The delivery priority of the message.
+    ///
+    fn priority(message: &Message) -> u8 {
+        match message.message_type {
+            MessageType::Exception => 2,
+            MessageType::Interrupt => 1,
+            _ => 0,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Inserts an entry into a queue, before the first entry whose message has lower priority.
+    ///
+    /// # Parameters
+    ///
+    /// - `queue`: Target queue.
+    /// - `entry`: Entry to insert.
+    /// - `message`: Function that gets the message of an entry.
+    ///
+    fn insert_prioritized<T>(
+        queue: &mut LinkedList<T>,
+        entry: T,
+        message: impl Fn(&T) -> &Message,
+    ) {
+        let priority: u8 = Self::priority(message(&entry));
+        match queue.iter().position(|e| Self::priority(message(e)) < priority) {
+            Some(at) => {
+                let mut tail: LinkedList<T> = queue.split_off(at);
+                queue.push_back(entry);
+                queue.append(&mut tail);
+            },
+            None => queue.push_back(entry),
+        }
     }
 }
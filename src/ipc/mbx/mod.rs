@@ -7,8 +7,20 @@
 
 mod mailbox;
 
+#[cfg(test)]
+mod test;
+
 //==================================================================================================
 // Exports
 //==================================================================================================
 
 pub use mailbox::Mailbox;
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
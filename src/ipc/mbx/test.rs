@@ -0,0 +1,390 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::ipc::mbx::Mailbox;
+use ::sys::{
+    config,
+    error::ErrorCode,
+    ipc::{
+        Message,
+        MessageType,
+    },
+    pm::ProcessIdentifier,
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if [`Mailbox::drain()`] discards all pending messages.
+fn test_drain() -> bool {
+    const NMESSAGES: usize = 4;
+
+    let mut mailbox: Mailbox = Mailbox::default();
+    for _ in 0..NMESSAGES {
+        mailbox.send(Message::default());
+    }
+
+    // Check if the number of discarded messages matches.
+    let count: usize = mailbox.drain();
+    if count != NMESSAGES {
+        error!("unexpected number of discarded messages (expected={}, got={})", NMESSAGES, count);
+        return false;
+    }
+
+    // Check if the mailbox is empty.
+    if mailbox.receive().is_some() {
+        error!("mailbox is not empty");
+        return false;
+    }
+
+    true
+}
+
+/// Builds a message from a given source.
+fn message_from(source: ProcessIdentifier) -> Message {
+    Message {
+        source,
+        ..Message::default()
+    }
+}
+
+/// Tests if fair mode serves a second sender without draining a flooding sender first.
+fn test_fair_queuing() -> bool {
+    const NMESSAGES: usize = 8;
+
+    let flooder: ProcessIdentifier = ProcessIdentifier::from(2);
+    let other: ProcessIdentifier = ProcessIdentifier::from(3);
+
+    let mut mailbox: Mailbox = Mailbox::default();
+    mailbox.set_fair(true);
+    for _ in 0..NMESSAGES {
+        mailbox.send(message_from(flooder));
+    }
+    mailbox.send(message_from(other));
+
+    // Check if the second sender is served right after the first sender's head message.
+    let sources: [Option<ProcessIdentifier>; 2] = [
+        mailbox.receive().map(|m| m.source),
+        mailbox.receive().map(|m| m.source),
+    ];
+    if sources != [Some(flooder), Some(other)] {
+        error!("unexpected delivery order (got={:?})", sources);
+        return false;
+    }
+
+    // Check if the remaining messages of the first sender are still delivered.
+    let count: usize = mailbox.drain();
+    if count != NMESSAGES - 1 {
+        error!("unexpected number of pending messages (expected={}, got={})", NMESSAGES - 1, count);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if disabling fair mode delivers pending messages from several sources in arrival order.
+fn test_fair_disable_order() -> bool {
+    let first: ProcessIdentifier = ProcessIdentifier::from(2);
+    let second: ProcessIdentifier = ProcessIdentifier::from(3);
+    let third: ProcessIdentifier = ProcessIdentifier::from(4);
+    let sources: [ProcessIdentifier; 6] = [first, first, second, third, first, second];
+
+    let mut mailbox: Mailbox = Mailbox::default();
+    mailbox.set_fair(true);
+    for (id, source) in sources.iter().enumerate() {
+        mailbox.send(message_from(*source).with_correlation(id as u64));
+    }
+
+    // Set aside the head message and release it, so that it is placed back ahead of all others.
+    match mailbox.receive() {
+        Some(message) => {
+            if let Err(e) = mailbox.hold(message) {
+                error!("failed to hold message (error={:?})", e);
+                return false;
+            }
+        },
+        None => {
+            error!("missing message");
+            return false;
+        },
+    }
+    mailbox.release();
+
+    mailbox.set_fair(false);
+
+    // Check if no message was lost.
+    if mailbox.len() != sources.len() {
+        error!("unexpected mailbox depth (expected={}, len={})", sources.len(), mailbox.len());
+        return false;
+    }
+
+    // Check if messages are delivered in arrival order.
+    for expected in 0..sources.len() as u64 {
+        match mailbox.receive().map(|m| m.correlation()) {
+            Some(id) if id == expected => {},
+            id => {
+                error!("unexpected delivery order (expected={}, got={:?})", expected, id);
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Tests if FIFO mode delivers messages in arrival order.
+fn test_fifo_order() -> bool {
+    let first: ProcessIdentifier = ProcessIdentifier::from(2);
+    let second: ProcessIdentifier = ProcessIdentifier::from(3);
+
+    let mut mailbox: Mailbox = Mailbox::default();
+    mailbox.send(message_from(first));
+    mailbox.send(message_from(first));
+    mailbox.send(message_from(second));
+
+    let sources: [Option<ProcessIdentifier>; 3] = [
+        mailbox.receive().map(|m| m.source),
+        mailbox.receive().map(|m| m.source),
+        mailbox.receive().map(|m| m.source),
+    ];
+    if sources != [Some(first), Some(first), Some(second)] {
+        error!("unexpected delivery order (got={:?})", sources);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if [`Mailbox::peek()`] returns the next message without removing it.
+fn test_peek() -> bool {
+    let first: ProcessIdentifier = ProcessIdentifier::from(2);
+    let second: ProcessIdentifier = ProcessIdentifier::from(3);
+
+    for fair in [false, true] {
+        let mut mailbox: Mailbox = Mailbox::default();
+        mailbox.set_fair(fair);
+
+        // Check if an empty mailbox has nothing to peek at.
+        if mailbox.peek().is_some() {
+            error!("peeked at a message in an empty mailbox (fair={})", fair);
+            return false;
+        }
+
+        mailbox.send(message_from(first));
+        mailbox.send(message_from(second));
+
+        // Check if peeking repeatedly returns the head message.
+        for _ in 0..2 {
+            match mailbox.peek() {
+                Some(message) if { message.source } == first => {},
+                _ => {
+                    error!("unexpected peeked message (fair={})", fair);
+                    return false;
+                },
+            }
+        }
+
+        // Check if the peeked message is still delivered.
+        match mailbox.receive() {
+            Some(message) if { message.source } == first => {},
+            _ => {
+                error!("peeked message was not delivered (fair={})", fair);
+                return false;
+            },
+        }
+        if mailbox.len() != 1 {
+            error!("unexpected number of pending messages (fair={})", fair);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests if prioritized messages are delivered ahead of ordinary messages.
+fn test_send_prioritized() -> bool {
+    let source: ProcessIdentifier = ProcessIdentifier::from(2);
+    let message = |message_type: MessageType| -> Message {
+        Message {
+            source,
+            message_type,
+            ..Message::default()
+        }
+    };
+
+    let mut mailbox: Mailbox = Mailbox::default();
+    mailbox.send_prioritized(message(MessageType::Ipc));
+    mailbox.send_prioritized(message(MessageType::Interrupt));
+    mailbox.send_prioritized(message(MessageType::Ipc));
+    mailbox.send_prioritized(message(MessageType::Exception));
+    mailbox.send_prioritized(message(MessageType::Interrupt));
+
+    let expected: [MessageType; 5] = [
+        MessageType::Exception,
+        MessageType::Interrupt,
+        MessageType::Interrupt,
+        MessageType::Ipc,
+        MessageType::Ipc,
+    ];
+    for typ in expected {
+        match mailbox.receive() {
+            Some(m) if m.message_type == typ => {},
+            Some(m) => {
+                error!("unexpected message type (expected={:?}, got={:?})", typ, m.message_type);
+                return false;
+            },
+            None => {
+                error!("missing message (expected={:?})", typ);
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Tests if the depth and the high-water mark of a mailbox are tracked.
+fn test_high_water() -> bool {
+    let source: ProcessIdentifier = ProcessIdentifier::from(2);
+
+    for fair in [false, true] {
+        let mut mailbox: Mailbox = Mailbox::default();
+        mailbox.set_fair(fair);
+
+        for _ in 0..3 {
+            mailbox.send(message_from(source));
+        }
+        mailbox.receive();
+        mailbox.send_prioritized(message_from(source));
+        mailbox.receive();
+        mailbox.receive();
+
+        // Check if the depth and high-water mark match.
+        if mailbox.len() != 1 || mailbox.high_water() != 3 {
+            error!(
+                "unexpected mailbox statistics (fair={}, len={}, high_water={})",
+                fair,
+                mailbox.len(),
+                mailbox.high_water()
+            );
+            return false;
+        }
+
+        // Check if draining the mailbox resets its depth, but not its high-water mark.
+        mailbox.drain();
+        if !mailbox.is_empty() || mailbox.high_water() != 3 {
+            error!("unexpected mailbox statistics after drain (fair={})", fair);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests if held messages are delivered again, in order, once they are released.
+fn test_hold_release() -> bool {
+    let source: ProcessIdentifier = ProcessIdentifier::from(2);
+
+    for fair in [false, true] {
+        let mut mailbox: Mailbox = Mailbox::default();
+        mailbox.set_fair(fair);
+
+        for id in 0..3 {
+            mailbox.send(message_from(source).with_correlation(id));
+        }
+
+        // Set aside the first two messages.
+        for _ in 0..2 {
+            match mailbox.receive() {
+                Some(message) => {
+                    if let Err(e) = mailbox.hold(message) {
+                        error!("failed to hold message (fair={}, error={:?})", fair, e);
+                        return false;
+                    }
+                },
+                None => {
+                    error!("missing message (fair={})", fair);
+                    return false;
+                },
+            }
+        }
+
+        // Check if held messages are not delivered.
+        if mailbox.len() != 1 {
+            error!("unexpected mailbox depth (fair={}, len={})", fair, mailbox.len());
+            return false;
+        }
+
+        // Check if held messages are delivered ahead of pending ones, in the order they were held.
+        let released: usize = mailbox.release();
+        let ids: [Option<u64>; 3] = [
+            mailbox.receive().map(|m| m.correlation()),
+            mailbox.receive().map(|m| m.correlation()),
+            mailbox.receive().map(|m| m.correlation()),
+        ];
+        if released != 2 || ids != [Some(0), Some(1), Some(2)] {
+            error!(
+                "unexpected delivery order (fair={}, released={}, got={:?})",
+                fair, released, ids
+            );
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests if the number of messages that a mailbox holds is bounded.
+fn test_hold_bounded() -> bool {
+    let mut mailbox: Mailbox = Mailbox::default();
+
+    for _ in 0..config::kernel::MAX_IKC_MESSAGES {
+        if let Err(e) = mailbox.hold(Message::default()) {
+            error!("failed to hold message (error={:?})", e);
+            return false;
+        }
+    }
+
+    // Check if holding one more message fails.
+    match mailbox.hold(Message::default()) {
+        Err(e) if e.code == ErrorCode::TryAgain => {},
+        result => {
+            error!("held messages are not bounded (result={:?})", result);
+            return false;
+        },
+    }
+
+    // Check if messages may be held again once released.
+    if mailbox.release() != config::kernel::MAX_IKC_MESSAGES {
+        error!("unexpected number of released messages");
+        return false;
+    }
+    if let Err(e) = mailbox.hold(Message::default()) {
+        error!("failed to hold message after release (error={:?})", e);
+        return false;
+    }
+
+    true
+}
+
+/// Runs all unit tests for mailboxes.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_drain);
+    passed &= run_test!(test_fair_queuing);
+    passed &= run_test!(test_fair_disable_order);
+    passed &= run_test!(test_fifo_order);
+    passed &= run_test!(test_peek);
+    passed &= run_test!(test_send_prioritized);
+    passed &= run_test!(test_high_water);
+    passed &= run_test!(test_hold_release);
+    passed &= run_test!(test_hold_bounded);
+
+    passed
+}
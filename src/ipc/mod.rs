@@ -8,12 +8,36 @@
 mod kcall;
 mod mbx;
 
+#[cfg(test)]
+mod test;
+
 //==================================================================================================
 //  Exports
 //==================================================================================================
 
 pub use kcall::{
+    flush_mailbox,
+    hold_message,
+    mailbox_stats,
+    peek_message,
     recv,
+    release_messages,
     send,
+    send_bounded,
+    send_many,
+    send_timeout,
+    sendwait,
+    set_mailbox_fair,
+    try_recv,
 };
 pub use mbx::Mailbox;
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= mbx::test();
+    passed &= test::test();
+
+    passed
+}
@@ -0,0 +1,215 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    ipc::kcall,
+    kconfig,
+};
+use ::alloc::{
+    collections::VecDeque,
+    vec::Vec,
+};
+use ::core::cell::RefCell;
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    ipc::{
+        Message,
+        MessageType,
+    },
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if a sender whose tick budget is exhausted times out instead of waiting.
+fn test_send_timeout_expired() -> bool {
+    const DEADLINE: u64 = 16;
+
+    for now in [DEADLINE, DEADLINE + 1] {
+        match kcall::send_timeout_slice(now, DEADLINE) {
+            Err(e) if e.code == ErrorCode::TimedOut => {},
+            result => {
+                error!("unexpected result (now={}, result={:?})", now, result);
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Tests if a sender waits for the remainder of its tick budget, capped to a scheduling quantum.
+fn test_send_timeout_slice() -> bool {
+    let quantum: u64 = kconfig::get().scheduler_freq as u64;
+
+    // Check if a short budget is waited for in full.
+    match kcall::send_timeout_slice(0, 1) {
+        Ok(1) => {},
+        result => {
+            error!("unexpected wait for short budget (result={:?})", result);
+            return false;
+        },
+    }
+
+    // Check if a long budget is waited for in slices.
+    match kcall::send_timeout_slice(0, quantum * 4) {
+        Ok(ticks) if ticks == quantum => true,
+        result => {
+            error!("unexpected wait for long budget (result={:?})", result);
+            false
+        },
+    }
+}
+
+/// Builds a batch of messages.
+fn batch(count: usize) -> Vec<Message> {
+    (0..count).map(|_| Message::default()).collect()
+}
+
+/// Tests if a batch of messages stops at the first message that would block.
+fn test_send_batch_would_block() -> bool {
+    const NMESSAGES: usize = 4;
+    const ACCEPTED: usize = 2;
+
+    let mut attempts: usize = 0;
+    let result: Result<usize, Error> = kcall::send_batch(batch(NMESSAGES), |_| {
+        attempts += 1;
+        if attempts > ACCEPTED {
+            return Err(Error::new(ErrorCode::TryAgain, "buffer is full"));
+        }
+        Ok(())
+    });
+
+    // Check if the number of accepted messages is reported, and no message is sent past it.
+    match result {
+        Ok(ACCEPTED) if attempts == ACCEPTED + 1 => true,
+        result => {
+            error!("unexpected result (attempts={}, result={:?})", attempts, result);
+            false
+        },
+    }
+}
+
+/// Tests if a batch of messages reports errors only if no message was accepted.
+fn test_send_batch_error() -> bool {
+    const NMESSAGES: usize = 4;
+
+    // Check if failing on the first message reports the error.
+    match kcall::send_batch(batch(NMESSAGES), |_| {
+        Err(Error::new(ErrorCode::NoSuchProcess, "no such process"))
+    }) {
+        Err(e) if e.code == ErrorCode::NoSuchProcess => {},
+        result => {
+            error!("error on first message was not reported (result={:?})", result);
+            return false;
+        },
+    }
+
+    // Check if failing on a later message reports the messages that were accepted.
+    let mut attempts: usize = 0;
+    match kcall::send_batch(batch(NMESSAGES), |_| {
+        attempts += 1;
+        if attempts > 1 {
+            return Err(Error::new(ErrorCode::NoSuchProcess, "no such process"));
+        }
+        Ok(())
+    }) {
+        Ok(1) => {},
+        result => {
+            error!("accepted messages were not reported (result={:?})", result);
+            return false;
+        },
+    }
+
+    // Check if a batch with no failures is fully accepted.
+    match kcall::send_batch(batch(NMESSAGES), |_| Ok(())) {
+        Ok(NMESSAGES) => true,
+        result => {
+            error!("batch was not fully accepted (result={:?})", result);
+            false
+        },
+    }
+}
+
+/// Tests if a message is not sent when its reply cannot be waited for.
+fn test_sendwait_invalid_reply_type() -> bool {
+    let mut sent: bool = false;
+    let result: Result<Message, Error> = kcall::send_and_wait(
+        MessageType::Ikc,
+        || {
+            sent = true;
+            Ok(())
+        },
+        |_| Ok(Message::default()),
+    );
+
+    match result {
+        Err(e) if e.code == ErrorCode::InvalidArgument && !sent => true,
+        result => {
+            error!("unexpected result (sent={}, result={:?})", sent, result);
+            false
+        },
+    }
+}
+
+/// Tests if a reply that arrives before the caller starts waiting is still received.
+fn test_sendwait_early_reply() -> bool {
+    let mut reply: Message = Message::default();
+    reply.message_type = MessageType::SchedulingEvent;
+    let mailbox: RefCell<VecDeque<Message>> = RefCell::new(VecDeque::new());
+
+    // Reply as part of the send, before the wait starts, and wait without blocking.
+    let result: Result<Message, Error> = kcall::send_and_wait(
+        MessageType::SchedulingEvent,
+        || {
+            mailbox.borrow_mut().push_back(reply);
+            Ok(())
+        },
+        |reply_type| match mailbox.borrow_mut().pop_front() {
+            Some(message) if { message.message_type } == reply_type => Ok(message),
+            _ => Err(Error::new(ErrorCode::NoMessageAvailable, "reply was lost")),
+        },
+    );
+
+    match result {
+        Ok(message) if { message.message_type } == MessageType::SchedulingEvent => true,
+        result => {
+            error!("early reply was not received (result={:?})", result);
+            false
+        },
+    }
+}
+
+/// Tests if an invalid fair queuing flag is rejected.
+fn test_set_mailbox_fair_invalid() -> bool {
+    let result: i32 = kcall::set_mailbox_fair(2);
+    if result != ErrorCode::InvalidArgument.into_errno() {
+        error!("invalid flag was accepted (result={})", result);
+        return false;
+    }
+
+    true
+}
+
+/// Runs all unit tests for the IPC kernel calls.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_send_timeout_expired);
+    passed &= run_test!(test_send_timeout_slice);
+    passed &= run_test!(test_send_batch_would_block);
+    passed &= run_test!(test_send_batch_error);
+    passed &= run_test!(test_sendwait_invalid_reply_type);
+    passed &= run_test!(test_sendwait_early_reply);
+    passed &= run_test!(test_set_mailbox_fair_invalid);
+
+    passed
+}
@@ -0,0 +1,233 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    hal::mem::{
+        AccessPermission,
+        Address,
+        PageAligned,
+        VirtualAddress,
+    },
+    pm::{
+        self,
+        ProcessManager,
+    },
+};
+use ::core::mem;
+use ::sys::{
+    config,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    pm::ProcessIdentifier,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A type that represents a checked pointer to a read-only object in user space.
+///
+pub struct UserRef<T> {
+    /// Process that owns the object.
+    pid: ProcessIdentifier,
+    /// Address of the object.
+    ptr: *const T,
+}
+
+///
+/// # Description
+///
+/// A type that represents a checked pointer to a writable object in user space.
+///
+pub struct UserMut<T> {
+    /// Process that owns the object.
+    pid: ProcessIdentifier,
+    /// Address of the object.
+    ptr: *mut T,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<T: Default> UserRef<T> {
+    ///
+    /// # Description
+    ///
+    /// Reads the target object from user space.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a copy of the target object is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn read(&self) -> Result<T, Error> {
+        let mut value: T = T::default();
+        pm::copy_from_user(self.pid, &mut value, self.ptr)?;
+        Ok(value)
+    }
+}
+
+impl<T> UserMut<T> {
+    ///
+    /// # Description
+    ///
+    /// Writes the target object to user space.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: Value to write.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn write(&self, value: &T) -> Result<(), Error> {
+        pm::copy_to_user(self.pid, self.ptr, value)
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Checks whether an object lies in the user space of a process, is properly aligned, and is fully
+/// mapped with the requested access permissions.
+///
+/// # Parameters
+///
+/// - `pid`: Process that owns the object.
+/// - `addr`: Address of the object.
+/// - `access`: Requested access permissions.
+///
+/// # Returns
+///
+/// Upon success, empty is returned. Upon failure, an error is returned instead.
+///
+fn check_user_object<T>(
+    pid: ProcessIdentifier,
+    addr: usize,
+    access: AccessPermission,
+) -> Result<(), Error> {
+    check_user_object_with::<T>(addr, access, |vaddr, size, access| {
+        ProcessManager::check_user_access(pid, vaddr, size, access)
+    })
+}
+
+///
+/// # Description
+///
+/// Checks whether an object is properly aligned, and whether it passes a given access check.
+///
+/// # Parameters
+///
+/// - `addr`: Address of the object.
+/// - `access`: Requested access permissions.
+/// - `check`: Function that checks if a range of addresses is mapped with the requested access
+///   permissions.
+///
+/// # Returns
+///
+/// Upon success, empty is returned. Upon failure, an error is returned instead.
+///
+pub(super) fn check_user_object_with<T>(
+    addr: usize,
+    access: AccessPermission,
+    check: impl FnOnce(VirtualAddress, usize, AccessPermission) -> Result<(), Error>,
+) -> Result<(), Error> {
+    // Check if address is not properly aligned.
+    if addr % mem::align_of::<T>() != 0 {
+        let reason: &str = "misaligned user address";
+        error!("check_user_object(): {} (addr={:#x})", reason, addr);
+        return Err(Error::new(ErrorCode::BadAddress, reason));
+    }
+
+    let vaddr: VirtualAddress = VirtualAddress::from_raw_value(addr)?;
+    check(vaddr, mem::size_of::<T>(), access)
+}
+
+///
+/// # Description
+///
+/// Converts a raw kernel call argument into a checked pointer to a read-only object in user space.
+///
+/// # Parameters
+///
+/// - `pid`: Process that owns the object.
+/// - `addr`: Address of the object.
+///
+/// # Returns
+///
+/// Upon success, a checked pointer to the object is returned. Upon failure, an error is returned
+/// instead.
+///
+pub fn user_ref<T>(pid: ProcessIdentifier, addr: usize) -> Result<UserRef<T>, Error> {
+    check_user_object::<T>(pid, addr, AccessPermission::RDONLY)?;
+    Ok(UserRef {
+        pid,
+        ptr: addr as *const T,
+    })
+}
+
+///
+/// # Description
+///
+/// Converts a raw kernel call argument into a checked pointer to a writable object in user space.
+///
+/// # Parameters
+///
+/// - `pid`: Process that owns the object.
+/// - `addr`: Address of the object.
+///
+/// # Returns
+///
+/// Upon success, a checked pointer to the object is returned. Upon failure, an error is returned
+/// instead.
+///
+pub fn user_mut<T>(pid: ProcessIdentifier, addr: usize) -> Result<UserMut<T>, Error> {
+    check_user_object::<T>(pid, addr, AccessPermission::RDWR)?;
+    Ok(UserMut {
+        pid,
+        ptr: addr as *mut T,
+    })
+}
+
+///
+/// # Description
+///
+/// Converts a raw kernel call argument into the address of a page in user space.
+///
+/// # Parameters
+///
+/// - `addr`: Address of the page.
+///
+/// # Returns
+///
+/// Upon success, the page-aligned address is returned. Upon failure, an error is returned instead.
+///
+pub fn user_page(addr: usize) -> Result<PageAligned<VirtualAddress>, Error> {
+    let vaddr: PageAligned<VirtualAddress> = PageAligned::from_raw_value(addr)?;
+
+    // Check if page does not lie in user space.
+    if vaddr.into_inner() < config::memory_layout::USER_BASE
+        || vaddr.into_inner() >= config::memory_layout::USER_END
+    {
+        let reason: &str = "page does not lie in user space";
+        error!("user_page(): {} (addr={:#x})", reason, addr);
+        return Err(Error::new(ErrorCode::BadAddress, reason));
+    }
+
+    Ok(vaddr)
+}
@@ -6,10 +6,15 @@
 //==================================================================================================
 
 use crate::{
-    event,
     ipc,
-    kcall::ScoreBoard,
-    pm::ProcessManager,
+    kcall::{
+        stats,
+        ScoreBoard,
+    },
+    pm::{
+        self,
+        ProcessManager,
+    },
 };
 use ::sys::{
     error::Error,
@@ -36,6 +41,8 @@ use ::sys::{
 ///
 #[no_mangle]
 pub extern "C" fn do_kcall(number: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u32) -> i32 {
+    stats::record(number);
+
     match KcallNumber::from(number) {
         // Handle `getpid()` locally.
         KcallNumber::GetPid => match ProcessManager::get_pid() {
@@ -52,7 +59,22 @@ pub extern "C" fn do_kcall(number: u32, arg0: u32, arg1: u32, arg2: u32, arg3: u
             e.code.into_errno()
         },
         KcallNumber::Recv => ipc::recv(arg0 as usize),
-        KcallNumber::Resume => event::resume(arg0 as usize),
+        KcallNumber::TryRecv => ipc::try_recv(arg0 as usize),
+        KcallNumber::SendTimeout => ipc::send_timeout(arg0, arg1),
+        KcallNumber::SendWait => ipc::sendwait(arg0 as usize, arg1 as usize, arg2 as usize),
+        KcallNumber::FlushMailbox => ipc::flush_mailbox(),
+        KcallNumber::HoldMessage => ipc::hold_message(arg0 as usize),
+        KcallNumber::ReleaseMessages => ipc::release_messages(),
+        KcallNumber::SetMailboxFair => ipc::set_mailbox_fair(arg0),
+        KcallNumber::PeekMessage => ipc::peek_message(arg0 as usize),
+        // Handle `getticks()` locally.
+        KcallNumber::GetTicks => pm::getticks(arg0 as usize),
+        // Handle `yield_now()` locally, as it switches the context of the caller.
+        KcallNumber::Yield => pm::yield_now(),
+        // Handle `sleep()` locally, as it blocks the caller.
+        KcallNumber::Sleep => pm::sleep(arg0),
+        // Handle `kcall_stats()` locally.
+        KcallNumber::KcallStats => stats::kcall_stats(arg0 as usize, arg1 as usize),
         // Dispatch kernel call for remote execution.
         _ => match ScoreBoard::get_mut() {
             Ok(scoreboard) => match scoreboard.dispatch(number, arg0, arg1, arg2, arg3) {
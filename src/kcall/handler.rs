@@ -14,16 +14,23 @@ use crate::{
     hal::Hal,
     io,
     ipc,
-    kcall::ScoreBoard,
-    mm::VirtMemoryManager,
+    kcall::{
+        self,
+        ScoreBoard,
+    },
+    kmod,
+    mm::{
+        self,
+        VirtMemoryManager,
+    },
     pm::{
         self,
         ProcessManager,
     },
 };
+use ::alloc::vec::Vec;
 use ::sys::{
     error::ErrorCode,
-    event::ProcessTerminationInfo,
     number::KcallNumber,
     pm::ProcessIdentifier,
 };
@@ -48,18 +55,11 @@ pub fn kcall_handler(hal: &mut Hal, mm: &mut VirtMemoryManager, pm: &mut Process
                 Ok(args) => {
                     let ret: i32 = match KcallNumber::from(args.number) {
                         KcallNumber::Debug => debug::debug(args),
-                        KcallNumber::GetPid => {
-                            // NOTE: this should be handled by the dispatcher.
-                            // However we emit an invalid system call, just in case.
-                            error!("cannot handle getpid()");
-                            ErrorCode::InvalidSysCall.into_errno()
-                        },
-                        KcallNumber::GetTid => {
-                            // NOTE: this should be handled by the dispatcher.
-                            // However we emit an invalid system call, just in case.
-                            error!("cannot handle gettid()");
-                            ErrorCode::InvalidSysCall.into_errno()
-                        },
+                        // NOTE: `getpid()` and `gettid()` are usually handled by the dispatcher.
+                        // If they get here, answer with the identifiers of the caller, which were
+                        // recorded in the scoreboard when the call was dispatched.
+                        KcallNumber::GetPid => args.pid.into(),
+                        KcallNumber::GetTid => args.tid.into(),
                         KcallNumber::GetUid => pm::getuid(pm, args),
                         KcallNumber::GetGid => pm::getgid(pm, args),
                         KcallNumber::GetEuid => pm::geteuid(pm, args),
@@ -69,19 +69,34 @@ pub fn kcall_handler(hal: &mut Hal, mm: &mut VirtMemoryManager, pm: &mut Process
                         KcallNumber::SetEuid => pm::seteuid(pm, args),
                         KcallNumber::SetEgid => pm::setegid(pm, args),
                         KcallNumber::CapCtl => pm::capctl(pm, args),
+                        KcallNumber::CapList => pm::caplist(pm, args),
+                        KcallNumber::DropCap => pm::dropcap(pm, args),
                         KcallNumber::Terminate => pm::terminate(pm, args),
+                        KcallNumber::Watch => pm::watch(pm, args),
+                        KcallNumber::Unwatch => pm::unwatch(pm, args),
                         KcallNumber::EventCtrl => event::evctrl(pm, args),
+                        KcallNumber::Resume => event::resume(args),
+                        KcallNumber::IntAffinity => event::intaffinity(hal, args),
+                        KcallNumber::Signal => event::signal(pm, args),
+                        KcallNumber::PendingCounts => event::pending_counts(pm, args),
+                        KcallNumber::OwnedEvents => event::owned_events(pm, args),
                         KcallNumber::MemoryMap => pm::mmap(pm, mm, args),
                         KcallNumber::MemoryUnmap => pm::munmap(pm, mm, args),
                         KcallNumber::MemoryCtrl => pm::mctrl(pm, mm, args),
                         KcallNumber::MemoryCopy => pm::mcopy(mm, args),
+                        KcallNumber::MemInfo => mm::meminfo(mm, args),
                         KcallNumber::Send => ipc::send(pm, args),
+                        KcallNumber::SendTimeout => ipc::send_bounded(pm, args),
+                        KcallNumber::SendMany => ipc::send_many(pm, args),
+                        KcallNumber::MailboxStats => ipc::mailbox_stats(args),
                         KcallNumber::AllocMmio => io::mmio_alloc(hal, pm, args),
+                        KcallNumber::AllocMmioCaching => io::mmio_alloc_caching(hal, pm, args),
                         KcallNumber::FreeMmio => io::mmio_free(pm, args),
                         KcallNumber::AllocPmio => io::pmio_alloc(hal, pm, args),
                         KcallNumber::FreePmio => io::pmio_free(pm, args),
                         KcallNumber::ReadPmio => io::pmio_read(pm, args),
                         KcallNumber::WritePmio => io::pmio_write(pm, args),
+                        KcallNumber::GetBootModule => kmod::get_boot_module(args),
                         _ => {
                             error!("invalid kernel call");
                             ErrorCode::InvalidSysCall.into_errno()
@@ -94,7 +109,10 @@ pub fn kcall_handler(hal: &mut Hal, mm: &mut VirtMemoryManager, pm: &mut Process
                 Err(e) => match e.code {
                     ErrorCode::Interrupted => break,
                     ErrorCode::OperationWouldBlock => {
-                        if let Err(e) = ProcessManager::switch() {
+                        // Retry context switch if the process manager is transiently busy.
+                        if let Err(e) =
+                            kcall::retry(kcall::MAX_RETRIES, ProcessManager::switch, kcall::halt)
+                        {
                             error!("context switch failed: {:?}", e);
                         }
                     },
@@ -138,15 +156,21 @@ pub fn kcall_handler(hal: &mut Hal, mm: &mut VirtMemoryManager, pm: &mut Process
 
         match pm.harvest_zombies() {
             Ok(None) => {},
-            Ok(Some((pid, status))) => {
+            Ok(Some(info)) => {
                 // Check if init daemon process terminated.
-                if pid == ProcessIdentifier::INITD {
+                if info.pid == ProcessIdentifier::INITD {
                     // It was, so we should shutdown.
                     break;
                 }
-                match EventManager::notify_process_termination(ProcessTerminationInfo::new(
-                    pid, status,
-                )) {
+                // Get the processes that watch the terminated process.
+                let watchers: Vec<ProcessIdentifier> = match pm.watchers(info.pid) {
+                    Ok(watchers) => watchers,
+                    Err(e) => {
+                        error!("failed to get watchers: {:?}", e);
+                        Vec::new()
+                    },
+                };
+                match EventManager::notify_process_termination(info, &watchers) {
                     Ok(()) => {},
                     Err(e) => {
                         error!("failed to notify process termination: {:?}", e);
@@ -159,7 +183,7 @@ pub fn kcall_handler(hal: &mut Hal, mm: &mut VirtMemoryManager, pm: &mut Process
         }
     }
 
-    while let Ok(Some((pid, status))) = pm.harvest_zombies() {
-        info!("harvested zombie process: pid={:?}, status={:?}", pid, status);
+    while let Ok(Some(info)) = pm.harvest_zombies() {
+        info!("harvested zombie process: {:?}", info);
     }
 }
@@ -5,8 +5,13 @@
 // Modules
 //==================================================================================================
 
+pub mod args;
 mod dispatcher;
 mod handler;
+mod stats;
+
+#[cfg(test)]
+mod test;
 
 //==================================================================================================
 // Imports
@@ -40,6 +45,13 @@ use ::sys::{
 
 pub use handler::kcall_handler as handler;
 
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Maximum number of times that a transiently-failing operation is retried.
+const MAX_RETRIES: usize = 8;
+
 //==================================================================================================
 // Error Code
 //==================================================================================================
@@ -67,7 +79,7 @@ impl Debug for KcallArgs {
     }
 }
 
-struct ScoreBoard {
+pub struct ScoreBoard {
     lock: Mutex,
     dispatched: Semaphore,
     handled: Semaphore,
@@ -154,3 +166,54 @@ pub fn init() {
     info!("initializing kernel call handler...");
     ScoreBoard::init();
 }
+
+///
+/// # Description
+///
+/// Runs an operation, retrying it while it fails due to transient contention.
+///
+/// # Parameters
+///
+/// - `max_retries`: Maximum number of retries.
+/// - `op`: Operation to run.
+/// - `backoff`: Function that is called between attempts.
+///
+/// # Returns
+///
+/// The result of the last attempt to run the operation is returned. If retries are exhausted, the
+/// operation is attempted one last time.
+///
+fn retry<T>(
+    max_retries: usize,
+    mut op: impl FnMut() -> Result<T, Error>,
+    mut backoff: impl FnMut(),
+) -> Result<T, Error> {
+    for _ in 0..max_retries {
+        match op() {
+            Err(e) if e.code == ErrorCode::ResourceBusy => backoff(),
+            result => return result,
+        }
+    }
+
+    warn!("retry(): retries exhausted (max_retries={})", max_retries);
+    op()
+}
+
+///
+/// # Description
+///
+/// Halts the processor until the next interrupt arrives.
+///
+fn halt() {
+    // Safety: halting the processor has no side effects other than waiting for an interrupt.
+    unsafe { ::core::arch::asm!("hlt", options(nomem, nostack)) };
+}
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
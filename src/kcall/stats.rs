@@ -0,0 +1,127 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    kcall::args::{
+        user_mut,
+        UserMut,
+    },
+    pm::ProcessManager,
+};
+use ::core::{
+    mem,
+    sync::atomic::{
+        AtomicU32,
+        Ordering,
+    },
+};
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    number::KcallNumber,
+    pm::ProcessIdentifier,
+};
+
+//==================================================================================================
+// Global Variables
+//==================================================================================================
+
+/// Number of times that each kernel call was dispatched, indexed by kernel call number.
+static COUNTERS: [AtomicU32; KcallNumber::COUNT] =
+    [const { AtomicU32::new(0) }; KcallNumber::COUNT];
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Records that a kernel call was dispatched. Invalid kernel call numbers are not recorded.
+///
+/// # Parameters
+///
+/// - `number`: Number of the kernel call.
+///
+pub fn record(number: u32) {
+    if let Some(counter) = COUNTERS.get(number as usize) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+///
+/// # Description
+///
+/// Takes a snapshot of the kernel call counters.
+///
+/// # Returns
+///
+/// The number of times that each kernel call was dispatched, indexed by kernel call number.
+///
+pub fn snapshot() -> [u32; KcallNumber::COUNT] {
+    let mut counters: [u32; KcallNumber::COUNT] = [0; KcallNumber::COUNT];
+    for (dst, src) in counters.iter_mut().zip(COUNTERS.iter()) {
+        *dst = src.load(Ordering::Relaxed);
+    }
+    counters
+}
+
+///
+/// # Description
+///
+/// Copies a snapshot of the kernel call counters, one at a time.
+///
+/// # Parameters
+///
+/// - `len`: Number of counters that the destination can hold.
+/// - `write`: Writes a counter, given its index and value.
+///
+/// # Returns
+///
+/// Upon success, the number of counters that were copied is returned. This is the lesser of `len`
+/// and the number of kernel calls. Upon failure, an error is returned instead.
+///
+pub fn copy_stats(
+    len: usize,
+    mut write: impl FnMut(usize, u32) -> Result<(), Error>,
+) -> Result<usize, Error> {
+    let counters: [u32; KcallNumber::COUNT] = snapshot();
+    let count: usize = len.min(KcallNumber::COUNT);
+    for (index, counter) in counters.iter().take(count).enumerate() {
+        write(index, *counter)?;
+    }
+    Ok(count)
+}
+
+fn do_kcall_stats(pid: ProcessIdentifier, counters: usize, len: usize) -> Result<usize, Error> {
+    copy_stats(len, |index, counter| {
+        let addr: usize = match counters.checked_add(index * mem::size_of::<u32>()) {
+            Some(addr) => addr,
+            None => {
+                let reason: &str = "buffer overflows the address space";
+                error!("do_kcall_stats(): {} (counters={:#x}, len={})", reason, counters, len);
+                return Err(Error::new(ErrorCode::BadAddress, reason));
+            },
+        };
+        let counter_ref: UserMut<u32> = user_mut(pid, addr)?;
+        counter_ref.write(&counter)
+    })
+}
+
+pub fn kcall_stats(counters: usize, len: usize) -> i32 {
+    let pid: ProcessIdentifier = match ProcessManager::get_pid() {
+        Ok(pid) => pid,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match do_kcall_stats(pid, counters, len) {
+        Ok(count) => count as i32,
+        Err(e) => e.code.into_errno(),
+    }
+}
@@ -0,0 +1,291 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    hal::mem::{
+        AccessPermission,
+        Address,
+        VirtualAddress,
+    },
+    kcall::{
+        self,
+        args,
+        stats,
+    },
+};
+use ::alloc::vec::Vec;
+use ::arch::mem;
+use ::sys::{
+    config,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    number::KcallNumber,
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if [`kcall::retry()`] proceeds after a number of transient failures.
+fn test_retry_transient() -> bool {
+    const FAILURES: usize = 3;
+
+    let mut attempts: usize = 0;
+    let mut backoffs: usize = 0;
+    let result: Result<usize, Error> = kcall::retry(
+        kcall::MAX_RETRIES,
+        || {
+            attempts += 1;
+            if attempts <= FAILURES {
+                Err(Error::new(ErrorCode::ResourceBusy, "busy"))
+            } else {
+                Ok(attempts)
+            }
+        },
+        || backoffs += 1,
+    );
+
+    match result {
+        Ok(n) if n == FAILURES + 1 && backoffs == FAILURES => true,
+        Ok(n) => {
+            error!("unexpected number of attempts (attempts={}, backoffs={})", n, backoffs);
+            false
+        },
+        Err(e) => {
+            error!("operation did not proceed (error={:?})", e);
+            false
+        },
+    }
+}
+
+/// Tests if [`kcall::retry()`] gives up after the maximum number of retries.
+fn test_retry_exhausted() -> bool {
+    let mut attempts: usize = 0;
+    let result: Result<(), Error> = kcall::retry(
+        kcall::MAX_RETRIES,
+        || {
+            attempts += 1;
+            Err(Error::new(ErrorCode::ResourceBusy, "busy"))
+        },
+        || {},
+    );
+
+    match result {
+        Err(e) if e.code == ErrorCode::ResourceBusy && attempts == kcall::MAX_RETRIES + 1 => true,
+        _ => {
+            error!("unexpected outcome (attempts={})", attempts);
+            false
+        },
+    }
+}
+
+/// Tests if [`kcall::retry()`] does not retry non-transient failures.
+fn test_retry_non_transient() -> bool {
+    let mut attempts: usize = 0;
+    let result: Result<(), Error> = kcall::retry(
+        kcall::MAX_RETRIES,
+        || {
+            attempts += 1;
+            Err(Error::new(ErrorCode::InvalidArgument, "invalid"))
+        },
+        || {},
+    );
+
+    match result {
+        Err(e) if e.code == ErrorCode::InvalidArgument && attempts == 1 => true,
+        _ => {
+            error!("unexpected outcome (attempts={})", attempts);
+            false
+        },
+    }
+}
+
+/// Tests if [`stats::record()`] counts kernel calls by number and ignores invalid ones.
+fn test_stats_record() -> bool {
+    let number: u32 = KcallNumber::Debug.into();
+    let before: [u32; KcallNumber::COUNT] = stats::snapshot();
+
+    stats::record(number);
+    stats::record(number);
+    stats::record(u32::MAX);
+
+    let after: [u32; KcallNumber::COUNT] = stats::snapshot();
+    for (index, (before, after)) in before.iter().zip(after.iter()).enumerate() {
+        let expected: u32 = if index == number as usize {
+            before + 2
+        } else {
+            *before
+        };
+        if *after != expected {
+            error!("unexpected counter (index={}, expected={}, got={})", index, expected, after);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests if [`stats::copy_stats()`] copies no more counters than the destination can hold.
+fn test_stats_copy() -> bool {
+    for len in [0, 1, KcallNumber::COUNT, KcallNumber::COUNT + 1] {
+        let mut copied: Vec<usize> = Vec::new();
+        let count: usize = match stats::copy_stats(len, |index, _| {
+            copied.push(index);
+            Ok(())
+        }) {
+            Ok(count) => count,
+            Err(e) => {
+                error!("failed to copy counters (len={}, error={:?})", len, e);
+                return false;
+            },
+        };
+
+        let expected: usize = len.min(KcallNumber::COUNT);
+        if count != expected || copied.len() != expected {
+            error!(
+                "unexpected number of counters (len={}, expected={}, got={}, copied={})",
+                len,
+                expected,
+                count,
+                copied.len()
+            );
+            return false;
+        }
+        if copied.iter().enumerate().any(|(i, index)| i != *index) {
+            error!("counters copied out of order (len={})", len);
+            return false;
+        }
+    }
+
+    // Check if a failed write fails the copy.
+    match stats::copy_stats(KcallNumber::COUNT, |_, _| {
+        Err(Error::new(ErrorCode::BadAddress, "bad address"))
+    }) {
+        Err(e) if e.code == ErrorCode::BadAddress => true,
+        _ => {
+            error!("failed write did not fail the copy");
+            false
+        },
+    }
+}
+
+/// Checks if a range of addresses lies in the only page that is mapped by tests, at the user base.
+fn check_mapped(
+    vaddr: VirtualAddress,
+    size: usize,
+    _access: AccessPermission,
+) -> Result<(), Error> {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let start: usize = vaddr.into_raw_value();
+    if start >= base && start + size <= base + mem::PAGE_SIZE {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorCode::BadAddress, "unmapped address"))
+    }
+}
+
+/// Tests if [`args::check_user_object_with()`] accepts an aligned object in mapped memory.
+fn test_user_object_valid() -> bool {
+    let addr: usize = config::memory_layout::USER_BASE.into_raw_value();
+
+    let mut checked: Option<usize> = None;
+    let check = |vaddr: VirtualAddress, size: usize, access: AccessPermission| {
+        checked = Some(size);
+        check_mapped(vaddr, size, access)
+    };
+    let result: Result<(), Error> =
+        args::check_user_object_with::<u32>(addr, AccessPermission::RDONLY, check);
+
+    // Check if the whole object was checked.
+    match (result, checked) {
+        (Ok(()), Some(size)) if size == core::mem::size_of::<u32>() => true,
+        (result, checked) => {
+            error!("valid object was rejected (result={:?}, checked={:?})", result, checked);
+            false
+        },
+    }
+}
+
+/// Tests if [`args::check_user_object_with()`] rejects a misaligned object.
+fn test_user_object_misaligned() -> bool {
+    let addr: usize = config::memory_layout::USER_BASE.into_raw_value() + 1;
+
+    match args::check_user_object_with::<u32>(addr, AccessPermission::RDONLY, check_mapped) {
+        Err(e) if e.code == ErrorCode::BadAddress => true,
+        result => {
+            error!("misaligned object was accepted (result={:?})", result);
+            false
+        },
+    }
+}
+
+/// Tests if [`args::check_user_object_with()`] rejects an object that straddles an unmapped page.
+fn test_user_object_unmapped() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let addr: usize = base + mem::PAGE_SIZE - core::mem::size_of::<u32>();
+
+    match args::check_user_object_with::<[u32; 2]>(addr, AccessPermission::RDWR, check_mapped) {
+        Err(e) if e.code == ErrorCode::BadAddress => true,
+        result => {
+            error!("object straddling an unmapped page was accepted (result={:?})", result);
+            false
+        },
+    }
+}
+
+/// Tests if [`args::user_page()`] accepts only aligned pages in user space.
+fn test_user_page() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let end: usize = config::memory_layout::USER_END.into_raw_value();
+
+    // Check if a page in user space is accepted.
+    match args::user_page(base) {
+        Ok(vaddr) if vaddr.into_raw_value() == base => {},
+        result => {
+            error!("valid page was rejected (result={:?})", result);
+            return false;
+        },
+    }
+
+    // Check if a misaligned address is rejected.
+    if args::user_page(base + 1).is_ok() {
+        error!("misaligned page was accepted");
+        return false;
+    }
+
+    // Check if pages that do not lie in user space are rejected.
+    for addr in [0, base - mem::PAGE_SIZE, end] {
+        match args::user_page(addr) {
+            Err(e) if e.code == ErrorCode::BadAddress => {},
+            result => {
+                error!("out-of-range page was accepted (addr={:#x}, result={:?})", addr, result);
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Runs all unit tests for kernel call handling.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_retry_transient);
+    passed &= run_test!(test_retry_exhausted);
+    passed &= run_test!(test_retry_non_transient);
+    passed &= run_test!(test_stats_record);
+    passed &= run_test!(test_stats_copy);
+    passed &= run_test!(test_user_object_valid);
+    passed &= run_test!(test_user_object_misaligned);
+    passed &= run_test!(test_user_object_unmapped);
+    passed &= run_test!(test_user_page);
+
+    passed
+}
@@ -0,0 +1,269 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::klog::{
+    Klog,
+    KlogLevel,
+};
+use ::arch::cpu::pit;
+use ::sys::{
+    config,
+    error::{
+        Error,
+        ErrorCode,
+    },
+};
+
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Name of the command line argument that overrides the timer frequency.
+const TIMER_FREQ_ARG: &str = "timer_freq";
+
+/// Name of the command line argument that overrides the scheduler frequency.
+const SCHEDULER_FREQ_ARG: &str = "scheduler_freq";
+
+/// Name of the command line argument that overrides the runtime log level.
+const LOG_LEVEL_ARG: &str = "log_level";
+
+/// Prefix of the command line arguments that override the log level of messages with a given tag.
+const LOG_LEVEL_TAG_PREFIX: &str = "log_level.";
+
+/// Lowest timer frequency (in Hz), whose divisor still fits in the 16-bit counter of the PIT.
+const MIN_TIMER_FREQ: u32 = pit::PIT_FREQUENCY.div_ceil(0xffff);
+
+/// Highest timer frequency (in Hz), which is the input frequency of the PIT.
+const MAX_TIMER_FREQ: u32 = pit::PIT_FREQUENCY;
+
+//==================================================================================================
+// Global Variables
+//==================================================================================================
+
+/// Runtime configuration of the kernel.
+static mut KERNEL_CONFIG: KernelConfig = KernelConfig::new();
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Runtime configuration of the kernel.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KernelConfig {
+    /// Timer frequency (in Hz).
+    pub timer_freq: u32,
+    /// Scheduler frequency (in ticks).
+    pub scheduler_freq: usize,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl KernelConfig {
+    ///
+    /// # Description
+    ///
+    /// Instantiates a runtime configuration that matches the compile-time defaults.
+    ///
+    /// # Returns
+    ///
+    /// A new runtime configuration.
+    ///
+    pub const fn new() -> Self {
+        Self {
+            timer_freq: config::kernel::TIMER_FREQ,
+            scheduler_freq: config::kernel::SCHEDULER_FREQ,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Parses a runtime configuration from the kernel command line. The command line is a list of
+    /// `name=value` arguments separated by whitespace. Arguments that are absent fall back to the
+    /// compile-time defaults, and unknown arguments are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `cmdline`: Kernel command line.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the runtime configuration is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn parse(cmdline: &str) -> Result<Self, Error> {
+        let mut kconfig: Self = Self::new();
+
+        for arg in cmdline.split_whitespace() {
+            let (name, value): (&str, &str) = match arg.split_once('=') {
+                Some((name, value)) => (name, value),
+                None => continue,
+            };
+
+            match name {
+                TIMER_FREQ_ARG => kconfig.timer_freq = parse_value(name, value)?,
+                SCHEDULER_FREQ_ARG => kconfig.scheduler_freq = parse_value(name, value)?,
+                _ => continue,
+            }
+        }
+
+        kconfig.validate()?;
+
+        Ok(kconfig)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the runtime configuration is valid.
+    ///
+    /// # Returns
+    ///
+    /// If the configuration is valid, empty is returned. Otherwise, an error is returned instead.
+    ///
+    fn validate(&self) -> Result<(), Error> {
+        // Check if timer frequency is out of the range supported by the PIT.
+        if !(MIN_TIMER_FREQ..=MAX_TIMER_FREQ).contains(&self.timer_freq) {
+            let reason: &str = "timer frequency is out of range";
+            error!(
+                "validate(): {} (timer_freq={}, min={}, max={})",
+                reason, self.timer_freq, MIN_TIMER_FREQ, MAX_TIMER_FREQ
+            );
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
+        }
+
+        // Check if scheduler frequency is not a power of two.
+        if !self.scheduler_freq.is_power_of_two() {
+            let reason: &str = "scheduler frequency must be a power of two";
+            error!("validate(): {} (scheduler_freq={})", reason, self.scheduler_freq);
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
+        }
+
+        Ok(())
+    }
+}
+
+//==================================================================================================
+// Trait Implementations
+//==================================================================================================
+
+impl Default for KernelConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Parses the value of a command line argument.
+///
+/// # Parameters
+///
+/// - `name`: Name of the argument.
+/// - `value`: Value of the argument.
+///
+/// # Returns
+///
+/// Upon success, the parsed value is returned. Upon failure, an error is returned instead.
+///
+fn parse_value<T: ::core::str::FromStr>(name: &str, value: &str) -> Result<T, Error> {
+    match value.parse::<T>() {
+        Ok(value) => Ok(value),
+        Err(_) => {
+            let reason: &str = "invalid command line argument";
+            error!("parse_value(): {} (name={}, value={})", reason, name, value);
+            Err(Error::new(ErrorCode::InvalidArgument, reason))
+        },
+    }
+}
+
+///
+/// # Description
+///
+/// Applies the log levels that are set in the kernel command line. The runtime log level is set
+/// by a `log_level=<level>` argument, and the log level of messages with a given tag is set by a
+/// `log_level.<tag>=<level>` argument. Other arguments are ignored.
+///
+/// # Parameters
+///
+/// - `cmdline`: Kernel command line.
+///
+/// # Returns
+///
+/// Upon success, empty is returned. Upon failure, an error is returned instead.
+///
+pub fn set_log_levels(cmdline: &str) -> Result<(), Error> {
+    for arg in cmdline.split_whitespace() {
+        let (name, value): (&str, &str) = match arg.split_once('=') {
+            Some((name, value)) => (name, value),
+            None => continue,
+        };
+
+        if name == LOG_LEVEL_ARG {
+            Klog::set_level(parse_value::<KlogLevel>(name, value)?);
+        } else if let Some(tag) = name.strip_prefix(LOG_LEVEL_TAG_PREFIX) {
+            Klog::set_tag_level(tag, Some(parse_value::<KlogLevel>(name, value)?))?;
+        }
+    }
+
+    Ok(())
+}
+
+///
+/// # Description
+///
+/// Gets the runtime configuration of the kernel.
+///
+/// # Returns
+///
+/// The runtime configuration of the kernel. Before [`init()`] is called, this matches the
+/// compile-time defaults.
+///
+pub fn get() -> KernelConfig {
+    unsafe { KERNEL_CONFIG }
+}
+
+///
+/// # Description
+///
+/// Initializes the runtime configuration of the kernel.
+///
+/// # Parameters
+///
+/// - `kconfig`: Runtime configuration of the kernel.
+///
+pub fn init(kconfig: KernelConfig) {
+    info!("timer_freq={}, scheduler_freq={}", kconfig.timer_freq, kconfig.scheduler_freq);
+    unsafe { KERNEL_CONFIG = kconfig };
+}
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
@@ -0,0 +1,164 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    kconfig::{
+        self,
+        KernelConfig,
+    },
+    klog::{
+        self,
+        Klog,
+        KlogLevel,
+        MAX_LEVEL,
+    },
+};
+use ::sys::{
+    config,
+    error::ErrorCode,
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if [`KernelConfig::parse()`] falls back to the compile-time defaults.
+fn test_parse_defaults() -> bool {
+    match KernelConfig::parse("console=ttyS0 quiet") {
+        Ok(kconfig) if kconfig == KernelConfig::new() => true,
+        Ok(kconfig) => {
+            error!("unexpected configuration (kconfig={:?})", kconfig);
+            false
+        },
+        Err(err) => {
+            error!("failed to parse command line (err={:?})", err);
+            false
+        },
+    }
+}
+
+/// Tests if [`KernelConfig::parse()`] overrides the compile-time defaults.
+fn test_parse_overrides() -> bool {
+    match KernelConfig::parse("timer_freq=1000 scheduler_freq=16") {
+        Ok(kconfig) if kconfig.timer_freq == 1000 && kconfig.scheduler_freq == 16 => {},
+        Ok(kconfig) => {
+            error!("unexpected configuration (kconfig={:?})", kconfig);
+            return false;
+        },
+        Err(err) => {
+            error!("failed to parse command line (err={:?})", err);
+            return false;
+        },
+    }
+
+    // Check if an argument that is absent keeps its default value.
+    match KernelConfig::parse("timer_freq=250") {
+        Ok(kconfig) if kconfig.scheduler_freq == config::kernel::SCHEDULER_FREQ => true,
+        Ok(kconfig) => {
+            error!("unexpected configuration (kconfig={:?})", kconfig);
+            false
+        },
+        Err(err) => {
+            error!("failed to parse command line (err={:?})", err);
+            false
+        },
+    }
+}
+
+/// Tests if [`KernelConfig::parse()`] rejects invalid arguments.
+fn test_parse_invalid() -> bool {
+    let cmdlines: [&str; 6] = [
+        "scheduler_freq=100",
+        "scheduler_freq=0",
+        "timer_freq=0",
+        "timer_freq=18",
+        "timer_freq=1193183",
+        "timer_freq=fast",
+    ];
+
+    for cmdline in cmdlines.iter() {
+        match KernelConfig::parse(cmdline) {
+            Err(err) if err.code == ErrorCode::InvalidArgument => {},
+            Err(err) => {
+                error!("unexpected error (cmdline={:?}, err={:?})", cmdline, err);
+                return false;
+            },
+            Ok(kconfig) => {
+                error!(
+                    "accepted invalid command line (cmdline={:?}, kconfig={:?})",
+                    cmdline, kconfig
+                );
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Tests if [`kconfig::set_log_levels()`] applies the runtime log level and tag overrides.
+fn test_set_log_levels() -> bool {
+    const TAG: &str = "kconfig::test::tag";
+    const CMDLINE: &str = "console=ttyS0 log_level=panic log_level.kconfig::test::tag=error";
+
+    let saved: KlogLevel = klog::level();
+    let mut passed: bool = true;
+
+    // Check if the runtime log level and the tag override are applied.
+    match kconfig::set_log_levels(CMDLINE) {
+        Ok(()) => {
+            passed &= klog::level() == KlogLevel::Panic;
+            passed &= klog::should_log(TAG, KlogLevel::Error) == (KlogLevel::Error <= MAX_LEVEL);
+            passed &= !klog::should_log(TAG, KlogLevel::Warn);
+        },
+        Err(err) => {
+            Klog::set_level(saved);
+            error!("failed to set log levels (err={:?})", err);
+            return false;
+        },
+    }
+
+    Klog::set_level(saved);
+    if Klog::set_tag_level(TAG, None).is_err() {
+        error!("failed to remove tag override");
+        return false;
+    }
+
+    if !passed {
+        error!("log levels were not applied");
+        return false;
+    }
+
+    // Check if invalid log levels and tags are rejected.
+    let cmdlines: [&str; 2] = [
+        "log_level=loud",
+        "log_level.kconfig::test::a::tag::that::is::too::long::to::be::stored=warn",
+    ];
+    for cmdline in cmdlines.iter() {
+        match kconfig::set_log_levels(cmdline) {
+            Err(err) if err.code == ErrorCode::InvalidArgument => {},
+            result => {
+                error!("accepted invalid log level (cmdline={:?}, result={:?})", cmdline, result);
+                return false;
+            },
+        }
+    }
+
+    passed
+}
+
+/// Runs all unit tests for the runtime kernel configuration.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_parse_defaults);
+    passed &= run_test!(test_parse_overrides);
+    passed &= run_test!(test_parse_invalid);
+    passed &= run_test!(test_set_log_levels);
+
+    passed
+}
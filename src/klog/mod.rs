@@ -0,0 +1,644 @@
+// Copyright(c) 2dThe Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::hal::{
+    platform,
+    time,
+};
+use ::core::{
+    fmt,
+    fmt::Write,
+    sync::atomic::{
+        AtomicBool,
+        AtomicU8,
+        Ordering,
+    },
+};
+use ::sys::error::{
+    Error,
+    ErrorCode,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+/// Kernel log device.
+pub struct Klog {
+    /// Whether messages written to this instance are output.
+    enabled: bool,
+    /// Line that is being written, truncated to the length of a recent line.
+    line: [u8; RECENT_LINE_LENGTH],
+    /// Number of valid bytes in `line`.
+    len: usize,
+}
+
+/// Ring buffer of recently emitted log lines.
+struct Recent<const N: usize> {
+    /// Captured lines, truncated to a fixed length.
+    lines: [[u8; RECENT_LINE_LENGTH]; N],
+    /// Number of valid bytes in each line.
+    lengths: [usize; N],
+    /// Slot where the next line is captured.
+    next: usize,
+    /// Number of captured lines.
+    count: usize,
+}
+
+/// First serial port (COM1), used for early-boot output.
+struct Com1;
+
+/// Log level override for messages with a given tag.
+#[derive(Clone, Copy)]
+struct TagLevel {
+    /// Tag of the messages, truncated to its length.
+    tag: [u8; MAX_TAG_LENGTH],
+    /// Number of valid bytes in `tag`.
+    len: usize,
+    /// Log level for the tag.
+    level: KlogLevel,
+}
+
+//==================================================================================================
+// Enumerations
+//==================================================================================================
+
+/// Kernel log levels.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum KlogLevel {
+    Panic,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Maximum log level.
+pub const MAX_LEVEL: KlogLevel = if cfg!(feature = "trace") {
+    KlogLevel::Trace
+} else if cfg!(feature = "debug") {
+    KlogLevel::Debug
+} else if cfg!(feature = "info") {
+    KlogLevel::Info
+} else if cfg!(feature = "warn") {
+    KlogLevel::Warn
+} else if cfg!(feature = "error") {
+    KlogLevel::Error
+} else {
+    KlogLevel::Panic
+};
+
+/// Number of digits of the tick timestamp in each log line.
+const TICKS_WIDTH: usize = 8;
+
+/// Number of bytes in each line of a hexdump.
+const HEXDUMP_WIDTH: usize = 16;
+
+/// Number of recent log lines that are kept for post-mortem dumps.
+const RECENT_LINES: usize = 64;
+
+/// Maximum length of a recent log line. Longer lines are truncated.
+const RECENT_LINE_LENGTH: usize = 128;
+
+/// Maximum number of per-tag log level overrides.
+const MAX_TAG_OVERRIDES: usize = 8;
+
+/// Maximum length of a tag that has a log level override.
+const MAX_TAG_LENGTH: usize = 48;
+
+/// Data port of the first serial port (COM1).
+const COM1_DATA: u16 = 0x3f8;
+
+/// Line status port of the first serial port (COM1).
+const COM1_LSR: u16 = COM1_DATA + 5;
+
+/// Transmitter holding register empty bit of the line status register.
+const LSR_THRE: u8 = 1 << 5;
+
+//==================================================================================================
+// Traits
+//==================================================================================================
+
+/// Output device used while the kernel log is in raw mode.
+trait RawDevice {
+    /// Reads the line status register.
+    fn line_status(&mut self) -> u8;
+
+    /// Writes a byte to the data register.
+    fn write_data(&mut self, b: u8);
+}
+
+//==================================================================================================
+// Global Variables
+//==================================================================================================
+
+/// Runtime log level.
+static LEVEL: AtomicU8 = AtomicU8::new(MAX_LEVEL as u8);
+
+/// Whether the kernel log is in raw mode, writing straight to COM1. Only platforms whose standard
+/// output device is backed by a UART start in raw mode, because other platforms write to a debug
+/// console that requires no initialization.
+static RAW: AtomicBool = AtomicBool::new(cfg!(feature = "qemu-baremetal"));
+
+/// Recently emitted log lines.
+static mut RECENT: Recent<RECENT_LINES> = Recent::new();
+
+/// Per-tag log level overrides.
+static mut TAG_LEVELS: [Option<TagLevel>; MAX_TAG_OVERRIDES] = [None; MAX_TAG_OVERRIDES];
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl Klog {
+    ///
+    /// # Description
+    ///
+    /// Instantiates a kernel log with a given tag and level. If messages with this tag and level
+    /// are filtered out (see [`should_log()`]), the instance discards everything that is written
+    /// to it, including the prefix.
+    ///
+    /// # Parameters
+    ///
+    /// - `tag`: Tag of the kernel log.
+    /// - `level`: Level of the kernel log.
+    ///
+    /// # Returns
+    ///
+    /// A kernel log instance.
+    ///
+    pub fn get(tag: &str, level: KlogLevel) -> Self {
+        let mut ret: Self = Self {
+            enabled: should_log(tag, level),
+            line: [0; RECENT_LINE_LENGTH],
+            len: 0,
+        };
+
+        // Check if the message is suppressed, thus skipping the prefix.
+        if !ret.enabled {
+            return ret;
+        }
+
+        let _ = write_prefix(&mut ret, time::ticks(), tag, level);
+        ret
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Logs a memory region as a canonical hexdump, with 16 bytes per line, an offset prefix and
+    /// an ASCII gutter. Each line is written through its own kernel log instance.
+    ///
+    /// # Parameters
+    ///
+    /// - `tag`: Tag of the kernel log.
+    /// - `level`: Level of the kernel log.
+    /// - `bytes`: Memory region to dump.
+    ///
+    pub fn hexdump(tag: &str, level: KlogLevel, bytes: &[u8]) {
+        // Check if the dump is suppressed, thus skipping formatting altogether.
+        if !should_log(tag, level) {
+            return;
+        }
+
+        for (i, chunk) in bytes.chunks(HEXDUMP_WIDTH).enumerate() {
+            let mut klog: Klog = Self::get(tag, level);
+            let _ = write_hexdump_line(&mut klog, i * HEXDUMP_WIDTH, chunk);
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sets the runtime log level. Messages that are more verbose than [`MAX_LEVEL`] are never
+    /// logged, regardless of the runtime log level.
+    ///
+    /// # Parameters
+    ///
+    /// - `level`: New runtime log level.
+    ///
+    pub fn set_level(level: KlogLevel) {
+        LEVEL.store(level as u8, Ordering::Relaxed);
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Overrides the runtime log level for messages with a given tag. The tag is copied, thus it
+    /// does not need to outlive the override.
+    ///
+    /// # Parameters
+    ///
+    /// - `tag`: Tag of the messages.
+    /// - `level`: New log level for the tag, or `None` to remove the override.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn set_tag_level(tag: &str, level: Option<KlogLevel>) -> Result<(), Error> {
+        // Check if the tag is too long.
+        if tag.len() > MAX_TAG_LENGTH {
+            return Err(Error::new(ErrorCode::InvalidArgument, "tag is too long"));
+        }
+
+        // Safety: overrides are only changed by the kernel thread.
+        let tag_levels: &mut [Option<TagLevel>; MAX_TAG_OVERRIDES] =
+            unsafe { &mut *core::ptr::addr_of_mut!(TAG_LEVELS) };
+
+        // Update or remove an existing override.
+        for entry in tag_levels.iter_mut() {
+            if let Some(tag_level) = entry {
+                if tag_level.matches(tag) {
+                    match level {
+                        Some(level) => tag_level.level = level,
+                        None => *entry = None,
+                    }
+                    return Ok(());
+                }
+            }
+        }
+
+        // Removing an override that does not exist is a no-op.
+        let level: KlogLevel = match level {
+            Some(level) => level,
+            None => return Ok(()),
+        };
+
+        // Add a new override.
+        if let Some(entry) = tag_levels.iter_mut().find(|entry| entry.is_none()) {
+            let mut tag_level: TagLevel = TagLevel {
+                tag: [0; MAX_TAG_LENGTH],
+                len: tag.len(),
+                level,
+            };
+            tag_level.tag[..tag.len()].copy_from_slice(tag.as_bytes());
+            *entry = Some(tag_level);
+            return Ok(());
+        }
+
+        Err(Error::new(ErrorCode::OutOfMemory, "too many tag overrides"))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Replays recently emitted log lines, from the oldest to the newest. This does not allocate
+    /// memory, thus it may be used even if the kernel heap is corrupted.
+    ///
+    pub fn dump_recent() {
+        // Safety: the ring buffer is only changed by the kernel thread.
+        let recent: &Recent<RECENT_LINES> = unsafe { &*core::ptr::addr_of!(RECENT) };
+        for line in recent.iter() {
+            // Safety: lines are only truncated at character boundaries.
+            unsafe {
+                puts(core::str::from_utf8_unchecked(line));
+                puts("\n");
+            }
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Appends a string to the line that is being written, truncating it at a character boundary
+    /// if it does not fit.
+    ///
+    /// # Parameters
+    ///
+    /// - `s`: String to append.
+    ///
+    fn capture(&mut self, s: &str) {
+        let mut len: usize = s.len().min(RECENT_LINE_LENGTH - self.len);
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+        self.line[self.len..self.len + len].copy_from_slice(&s.as_bytes()[..len]);
+        self.len += len;
+    }
+}
+
+impl<const N: usize> Recent<N> {
+    /// Creates an empty ring buffer.
+    const fn new() -> Self {
+        Self {
+            lines: [[0; RECENT_LINE_LENGTH]; N],
+            lengths: [0; N],
+            next: 0,
+            count: 0,
+        }
+    }
+
+    /// Captures a line, overwriting the oldest one if the ring buffer is full.
+    fn push(&mut self, line: &[u8]) {
+        let len: usize = line.len().min(RECENT_LINE_LENGTH);
+        self.lines[self.next][..len].copy_from_slice(&line[..len]);
+        self.lengths[self.next] = len;
+        self.next = (self.next + 1) % N;
+        self.count = (self.count + 1).min(N);
+    }
+
+    /// Returns an iterator over captured lines, from the oldest to the newest.
+    fn iter(&self) -> impl Iterator<Item = &[u8]> {
+        let first: usize = (self.next + N - self.count) % N;
+        (0..self.count).map(move |i| {
+            let slot: usize = (first + i) % N;
+            &self.lines[slot][..self.lengths[slot]]
+        })
+    }
+}
+
+impl Drop for Klog {
+    fn drop(&mut self) {
+        if !self.enabled {
+            return;
+        }
+
+        unsafe { puts("\n") };
+
+        // Capture line for post-mortem dumps.
+        // Safety: the ring buffer is only changed by the kernel thread.
+        let recent: &mut Recent<RECENT_LINES> = unsafe { &mut *core::ptr::addr_of_mut!(RECENT) };
+        recent.push(&self.line[..self.len]);
+    }
+}
+
+impl fmt::Write for Klog {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.enabled {
+            self.capture(s);
+            unsafe { puts(s) };
+        }
+        Ok(())
+    }
+}
+
+impl TagLevel {
+    /// Checks whether the override applies to messages with a given tag.
+    fn matches(&self, tag: &str) -> bool {
+        &self.tag[..self.len] == tag.as_bytes()
+    }
+}
+
+impl RawDevice for Com1 {
+    fn line_status(&mut self) -> u8 {
+        unsafe { ::arch::io::in8(COM1_LSR) }
+    }
+
+    fn write_data(&mut self, b: u8) {
+        unsafe { ::arch::io::out8(COM1_DATA, b) }
+    }
+}
+
+impl KlogLevel {
+    /// Converts a raw value into a log level, saturating at the most verbose level.
+    fn from_raw(raw: u8) -> Self {
+        match raw {
+            0 => KlogLevel::Panic,
+            1 => KlogLevel::Error,
+            2 => KlogLevel::Warn,
+            3 => KlogLevel::Info,
+            4 => KlogLevel::Debug,
+            _ => KlogLevel::Trace,
+        }
+    }
+}
+
+impl ::core::str::FromStr for KlogLevel {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "panic" => Ok(KlogLevel::Panic),
+            "error" => Ok(KlogLevel::Error),
+            "warn" => Ok(KlogLevel::Warn),
+            "info" => Ok(KlogLevel::Info),
+            "debug" => Ok(KlogLevel::Debug),
+            "trace" => Ok(KlogLevel::Trace),
+            _ => Err(Error::new(ErrorCode::InvalidArgument, "invalid log level")),
+        }
+    }
+}
+
+impl core::fmt::Debug for KlogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KlogLevel::Trace => write!(f, "TRACE"),
+            KlogLevel::Debug => write!(f, "DEBUG"),
+            KlogLevel::Info => write!(f, "INFO"),
+            KlogLevel::Warn => write!(f, "WARN"),
+            KlogLevel::Error => write!(f, "ERROR"),
+            KlogLevel::Panic => write!(f, "PANIC"),
+        }
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Gets the runtime log level.
+///
+/// # Returns
+///
+/// The runtime log level.
+///
+pub fn level() -> KlogLevel {
+    KlogLevel::from_raw(LEVEL.load(Ordering::Relaxed))
+}
+
+///
+/// # Description
+///
+/// Checks whether a message should be logged.
+///
+/// # Parameters
+///
+/// - `tag`: Tag of the message.
+/// - `level`: Level of the message.
+///
+/// # Returns
+///
+/// `true` if the message should be logged and `false` otherwise.
+///
+#[inline]
+pub fn should_log(tag: &str, level: KlogLevel) -> bool {
+    // Check if the level is compiled out.
+    if level > MAX_LEVEL {
+        return false;
+    }
+
+    // Check if there is an override for the tag.
+    // Safety: overrides are only changed by the kernel thread.
+    for tag_level in unsafe { TAG_LEVELS.iter() }.flatten() {
+        if tag_level.matches(tag) {
+            return level <= tag_level.level;
+        }
+    }
+
+    level <= self::level()
+}
+
+///
+/// # Description
+///
+/// Writes the prefix of a log line, which carries a tick timestamp of fixed width followed by the
+/// uptime, the level and the tag of the message.
+///
+/// # Parameters
+///
+/// - `w`: Target writer.
+/// - `ticks`: Number of timer ticks since the system was started.
+/// - `tag`: Tag of the message.
+/// - `level`: Level of the message.
+///
+/// # Returns
+///
+/// Upon success, empty is returned. Upon failure, an error is returned instead.
+///
+fn write_prefix(w: &mut impl Write, ticks: u64, tag: &str, level: KlogLevel) -> fmt::Result {
+    let uptime: u64 = time::ticks_to_ms(ticks);
+    write!(
+        w,
+        "[{:0width$}][{:>5}.{:03}][{:?}][{}] ",
+        ticks,
+        uptime / 1000,
+        uptime % 1000,
+        level,
+        tag,
+        width = TICKS_WIDTH
+    )
+}
+
+///
+/// # Description
+///
+/// Writes a line of a hexdump.
+///
+/// # Parameters
+///
+/// - `w`: Target writer.
+/// - `offset`: Offset of the first byte in the line.
+/// - `chunk`: Bytes in the line, at most [`HEXDUMP_WIDTH`].
+///
+/// # Returns
+///
+/// Upon success, empty is returned. Upon failure, an error is returned instead.
+///
+fn write_hexdump_line(w: &mut impl Write, offset: usize, chunk: &[u8]) -> fmt::Result {
+    write!(w, "{:08x} ", offset)?;
+
+    // Write bytes in hexadecimal, padding short lines so that the gutter is aligned.
+    for i in 0..HEXDUMP_WIDTH {
+        if i % (HEXDUMP_WIDTH / 2) == 0 {
+            w.write_str(" ")?;
+        }
+        match chunk.get(i) {
+            Some(b) => write!(w, "{:02x} ", b)?,
+            None => w.write_str("   ")?,
+        }
+    }
+
+    // Write bytes in ASCII, replacing non-printable ones.
+    w.write_str(" |")?;
+    for b in chunk {
+        let c: char = if b.is_ascii_graphic() || *b == b' ' {
+            *b as char
+        } else {
+            '.'
+        };
+        w.write_char(c)?;
+    }
+    w.write_str("|")
+}
+
+///
+/// # Description
+///
+/// Switches the kernel log from raw mode to the platform's standard output device. This should be
+/// called once the platform is initialized.
+///
+pub fn init() {
+    RAW.store(false, Ordering::Release);
+}
+
+///
+/// # Description
+///
+/// Writes the string `s` to a raw output device, busy-waiting until the device is ready to accept
+/// each byte. This does not require any prior initialization of the device.
+///
+/// # Parameters
+///
+/// - `device`: Raw output device.
+/// - `s`: String to write.
+///
+fn raw_puts(device: &mut impl RawDevice, s: &str) {
+    for b in s.bytes() {
+        // Wait for the transmit buffer to be empty.
+        while (device.line_status() & LSR_THRE) == 0 {
+            ::core::hint::spin_loop();
+        }
+
+        device.write_data(b);
+    }
+}
+
+///
+/// # Description
+///
+/// Writes the string `s` to the platform's standard debug device. On UART-backed platforms, the
+/// string is written straight to COM1 instead until [`init()`] is called.
+///
+/// # Parameters
+///
+/// - `s`: String to write.
+///
+/// # Safety
+///
+/// This function is unsafe for multiple reasons:
+///
+/// - It assumes that the standard output device is present.
+/// - It assumes that the standard output device was properly initialized.
+/// - It does not prevent concurrent access to the standard output device.
+///
+pub unsafe fn puts(s: &str) {
+    // Check if the standard output device is not initialized yet.
+    if RAW.load(Ordering::Acquire) {
+        raw_puts(&mut Com1, s);
+        return;
+    }
+
+    // Write each byte of the string to the standard output device.
+    for b in s.bytes() {
+        platform::putb(b);
+    }
+}
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
@@ -0,0 +1,233 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::klog::{
+    self,
+    Klog,
+    KlogLevel,
+    RawDevice,
+    Recent,
+    LSR_THRE,
+    MAX_LEVEL,
+};
+use ::alloc::{
+    string::String,
+    vec::Vec,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+/// Stubbed serial port, which reports busy for a number of polls before accepting each byte.
+struct StubDevice {
+    /// Number of polls that report busy before the port is ready.
+    busy: usize,
+    /// Number of remaining busy polls.
+    remaining: usize,
+    /// Bytes written to the port.
+    written: Vec<u8>,
+}
+
+impl RawDevice for StubDevice {
+    fn line_status(&mut self) -> u8 {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            return 0;
+        }
+        LSR_THRE
+    }
+
+    fn write_data(&mut self, b: u8) {
+        // Check if the byte was written while the port was busy.
+        if self.remaining > 0 {
+            return;
+        }
+        self.written.push(b);
+        self.remaining = self.busy;
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Checks if [`klog::should_log()`] returns the expected value, accounting for [`MAX_LEVEL`].
+fn expect(tag: &str, level: KlogLevel, expected: bool) -> bool {
+    klog::should_log(tag, level) == (expected && level <= MAX_LEVEL)
+}
+
+/// Tests if the runtime log level and per-tag overrides are combined as expected.
+fn test_should_log() -> bool {
+    const TAG: &str = "klog::test::tag";
+    const OTHER: &str = "klog::test::other";
+
+    let saved: KlogLevel = klog::level();
+    let mut passed: bool = true;
+
+    // Global level only.
+    Klog::set_level(KlogLevel::Warn);
+    passed &= expect(TAG, KlogLevel::Error, true);
+    passed &= expect(TAG, KlogLevel::Warn, true);
+    passed &= expect(TAG, KlogLevel::Info, false);
+
+    // Override is more verbose than the global level.
+    passed &= Klog::set_tag_level(TAG, Some(KlogLevel::Trace)).is_ok();
+    passed &= expect(TAG, KlogLevel::Debug, true);
+    passed &= expect(OTHER, KlogLevel::Debug, false);
+
+    // Override is less verbose than the global level.
+    passed &= Klog::set_tag_level(TAG, Some(KlogLevel::Panic)).is_ok();
+    passed &= expect(TAG, KlogLevel::Error, false);
+    passed &= expect(OTHER, KlogLevel::Error, true);
+
+    // Removing the override falls back to the global level.
+    passed &= Klog::set_tag_level(TAG, None).is_ok();
+    passed &= expect(TAG, KlogLevel::Error, true);
+    passed &= expect(TAG, KlogLevel::Info, false);
+
+    Klog::set_level(saved);
+
+    passed
+}
+
+/// Tests if [`Klog::get()`] suppresses messages that are filtered out by a tag override.
+fn test_get_suppressed() -> bool {
+    const TAG: &str = "klog::test::suppressed";
+
+    if Klog::set_tag_level(TAG, Some(KlogLevel::Panic)).is_err() {
+        error!("failed to override tag level");
+        return false;
+    }
+
+    // Check if the instance discards output.
+    let suppressed: bool = !Klog::get(TAG, KlogLevel::Error).enabled;
+
+    if Klog::set_tag_level(TAG, None).is_err() {
+        error!("failed to remove tag override");
+        return false;
+    }
+
+    if !suppressed {
+        error!("message was not suppressed");
+        return false;
+    }
+
+    true
+}
+
+/// Tests if log lines are prefixed with a tick timestamp of fixed width.
+fn test_prefix_ticks() -> bool {
+    for ticks in [0, 12345, 99999999] {
+        let mut prefix: String = String::new();
+        if klog::write_prefix(&mut prefix, ticks, "tag", KlogLevel::Info).is_err() {
+            error!("failed to write prefix (ticks={})", ticks);
+            return false;
+        }
+
+        // Check if the timestamp leads the prefix, padded to a fixed width.
+        let expected: String = ::alloc::format!("[{:08}]", ticks);
+        if !prefix.starts_with(expected.as_str()) || !prefix.ends_with("[INFO][tag] ") {
+            error!("unexpected prefix (ticks={}, got={:?})", ticks, prefix);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests if the ring buffer of recent lines keeps only the latest lines, from oldest to newest.
+fn test_recent_wraps() -> bool {
+    const CAPACITY: usize = 4;
+    const NLINES: usize = 10;
+
+    let mut recent: Recent<CAPACITY> = Recent::new();
+    for i in 0..NLINES {
+        recent.push(::alloc::format!("line {}", i).as_bytes());
+    }
+
+    let lines: Vec<&[u8]> = recent.iter().collect();
+    if lines.len() != CAPACITY {
+        error!("unexpected number of lines (expected={}, got={})", CAPACITY, lines.len());
+        return false;
+    }
+
+    // Check if only the latest lines survive, in order.
+    for (i, line) in lines.iter().enumerate() {
+        let expected: String = ::alloc::format!("line {}", NLINES - CAPACITY + i);
+        if *line != expected.as_bytes() {
+            error!("unexpected line (expected={:?}, got={:?})", expected.as_bytes(), line);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests if hexdump lines carry an offset, padded hexadecimal bytes and an ASCII gutter.
+fn test_hexdump() -> bool {
+    const BYTES: &[u8; 20] = b"Hello, world!\n\x00\x01\x7fABC";
+    const EXPECTED: [&str; 2] = [
+        "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 01  |Hello, world!...|",
+        "00000010  7f 41 42 43                                       |.ABC|",
+    ];
+
+    for (i, chunk) in BYTES.chunks(16).enumerate() {
+        let mut line: String = String::new();
+        if klog::write_hexdump_line(&mut line, i * 16, chunk).is_err() {
+            error!("failed to write hexdump line (line={})", i);
+            return false;
+        }
+
+        // Check if line matches.
+        if line != EXPECTED[i] {
+            error!("unexpected hexdump line (expected={:?}, got={:?})", EXPECTED[i], line);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests if raw mode writes bytes to the port without prior initialization.
+fn test_raw_puts() -> bool {
+    const MESSAGE: &str = "early boot";
+
+    let mut device: StubDevice = StubDevice {
+        busy: 3,
+        remaining: 3,
+        written: Vec::new(),
+    };
+
+    klog::raw_puts(&mut device, MESSAGE);
+
+    // Check if all bytes were written in order.
+    if device.written != MESSAGE.as_bytes() {
+        error!(
+            "unexpected bytes written (expected={:?}, got={:?})",
+            MESSAGE.as_bytes(),
+            device.written
+        );
+        return false;
+    }
+
+    true
+}
+
+/// Runs all unit tests for the kernel log.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_should_log);
+    passed &= run_test!(test_get_suppressed);
+    passed &= run_test!(test_prefix_ticks);
+    passed &= run_test!(test_recent_wraps);
+    passed &= run_test!(test_hexdump);
+    passed &= run_test!(test_raw_puts);
+
+    passed
+}
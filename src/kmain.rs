@@ -81,6 +81,7 @@ mod io;
 mod ipc;
 mod kargs;
 mod kcall;
+mod kconfig;
 mod kimage;
 mod klog;
 mod kmod;
@@ -151,9 +152,51 @@ static mut CORES_ONLINE: AtomicUsize = AtomicUsize::new(1);
 
 #[cfg(test)]
 fn test() {
+    if !crate::klog::test() {
+        panic!("kernel log tests failed");
+    }
     if !crate::hal::mem::test() {
         panic!("memory tests failed");
     }
+    if !crate::hal::io::test() {
+        panic!("io tests failed");
+    }
+    if !crate::kmod::test() {
+        panic!("boot module tests failed");
+    }
+    if !crate::kconfig::test() {
+        panic!("kernel configuration tests failed");
+    }
+    if !crate::mm::elf::test() {
+        panic!("elf loader tests failed");
+    }
+    if !crate::mm::test() {
+        panic!("memory manager tests failed");
+    }
+    if !crate::hal::platform::acpi::test() {
+        panic!("acpi tests failed");
+    }
+    if !crate::hal::arch::x86::mem::mmu::test() {
+        panic!("page table tests failed");
+    }
+    if !crate::hal::arch::x86::cpu::test() {
+        panic!("cpu tests failed");
+    }
+    if !crate::hal::time::test() {
+        panic!("time tests failed");
+    }
+    if !crate::event::test() {
+        panic!("event manager tests failed");
+    }
+    if !crate::ipc::test() {
+        panic!("ipc tests failed");
+    }
+    if !crate::pm::test() {
+        panic!("process manager tests failed");
+    }
+    if !crate::kcall::test() {
+        panic!("kernel call tests failed");
+    }
 }
 
 ///
@@ -189,7 +232,7 @@ fn spawn_servers(
                 },
             }
         };
-        match pm.exec(mm, pid, elf) {
+        match pm.exec(mm, pid, elf, kmod.size()) {
             Ok(_) => {
                 count += 1;
             },
@@ -228,13 +271,16 @@ pub extern "C" fn kmain(kargs: &KernelArguments) {
     );
     let (madt, mem_lower, mut memory_regions, mut mmio_regions, kernel_modules): KernelArgs =
         match kargs.parse() {
-            Ok(bootinfo) => (
-                bootinfo.madt,
-                bootinfo.mem_lower,
-                bootinfo.memory_regions,
-                bootinfo.mmio_regions,
-                bootinfo.kernel_modules,
-            ),
+            Ok(bootinfo) => {
+                kconfig::init(bootinfo.kconfig);
+                (
+                    bootinfo.madt,
+                    bootinfo.mem_lower,
+                    bootinfo.memory_regions,
+                    bootinfo.mmio_regions,
+                    bootinfo.kernel_modules,
+                )
+            },
             Err(err) => {
                 panic!("failed to parse kernel arguments: {:?}", err);
             },
@@ -275,6 +321,9 @@ pub extern "C" fn kmain(kargs: &KernelArguments) {
         },
     };
 
+    // Switch the kernel log to the standard output device.
+    klog::init();
+
     // Initialize the memory manager.
     let (root, mut mm): (Vmem, VirtMemoryManager) =
         match mm::init(&kimage, memory_regions, mmio_regions) {
@@ -371,17 +420,15 @@ pub extern "C" fn kmain(kargs: &KernelArguments) {
     let cores_online: usize = unsafe { CORES_ONLINE.load(Ordering::Acquire) };
     info!("number of cores online: {}", cores_online);
 
+    // Record boot modules, so that servers can later locate them.
+    kmod::init(&kernel_modules);
+
     if spawn_servers(&mut mm, &mut pm, &kernel_modules) > 0 {
         // Initialize kernel call dispatcher.
         kcall::init();
 
-        // Enable timer interrupts, if they are supported.
-        if let Some(intman) = &mut hal.intman {
-            if let Err(e) = intman.unmask(hal::arch::InterruptNumber::Timer) {
-                panic!("failed to mask timer interrupt: {:?}", e);
-            }
-        }
-
+        // NOTE: timer interrupts are enabled once the event manager, which dispatches them, is
+        // initialized by the kernel call handler.
         kcall::handler(&mut hal, &mut mm, &mut pm)
     }
 
@@ -0,0 +1,75 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    hal::mem::{
+        Address,
+        PhysicalAddress,
+    },
+    kcall::{
+        args::{
+            user_mut,
+            UserMut,
+        },
+        KcallArgs,
+    },
+    pm::ProcessManager,
+};
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    pm::Capability,
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+fn do_get_boot_module(index: usize) -> Result<(PhysicalAddress, usize), Error> {
+    trace!("do_get_boot_module(): index={:?}", index);
+
+    super::boot_modules()?.get(index)
+}
+
+pub fn get_boot_module(args: &KcallArgs) -> i32 {
+    // Check if the calling process has memory management capabilities.
+    match ProcessManager::has_capability(args.pid, Capability::MemoryManagement) {
+        Ok(true) => (),
+        Ok(false) => {
+            let reason: &str = "process does not have memory management capabilities";
+            error!("get_boot_module(): {}", reason);
+            return ErrorCode::PermissionDenied.into_errno();
+        },
+        Err(e) => return e.code.into_errno(),
+    }
+
+    // Unpack kernel call arguments.
+    let index: usize = args.arg0 as usize;
+    let base: UserMut<usize> = match user_mut(args.pid, args.arg1 as usize) {
+        Ok(base) => base,
+        Err(e) => return e.code.into_errno(),
+    };
+    let size: UserMut<usize> = match user_mut(args.pid, args.arg2 as usize) {
+        Ok(size) => size,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match do_get_boot_module(index) {
+        Ok((start, len)) => {
+            if let Err(e) = base.write(&start.into_raw_value()) {
+                return e.code.into_errno();
+            }
+            if let Err(e) = size.write(&len) {
+                return e.code.into_errno();
+            }
+            0
+        },
+        Err(e) => e.code.into_errno(),
+    }
+}
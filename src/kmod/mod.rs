@@ -0,0 +1,214 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+mod kcall;
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Exports
+//==================================================================================================
+
+pub use kcall::get_boot_module;
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::hal::mem::PhysicalAddress;
+use ::alloc::{
+    collections::LinkedList,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use ::sys::error::{
+    Error,
+    ErrorCode,
+};
+
+//==================================================================================================
+// Global Variables
+//==================================================================================================
+
+/// Boot modules that were passed to the kernel by the bootloader.
+static mut BOOT_MODULES: Option<BootModuleTable> = None;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+pub struct KernelModule {
+    /// Start address.
+    start: PhysicalAddress,
+    /// Size.
+    size: usize,
+    /// Command line.
+    cmdline: String,
+}
+
+impl KernelModule {
+    /// Creates a new kernel module.
+    pub fn new(start: PhysicalAddress, size: usize, cmdline: String) -> Self {
+        Self {
+            start,
+            size,
+            cmdline,
+        }
+    }
+
+    /// Gets the start address of the module.
+    pub fn start(&self) -> PhysicalAddress {
+        self.start
+    }
+
+    /// Gets the size of the module.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Gets the command line of the module.
+    pub fn cmdline(&self) -> String {
+        self.cmdline.to_string()
+    }
+}
+
+impl core::fmt::Debug for KernelModule {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "kernel_module {{ start: {:?}, size: {:?}, cmdline: {:?} }}",
+            self.start, self.size, self.cmdline
+        )
+    }
+}
+
+///
+/// # Description
+///
+/// A table that describes the boot modules that were passed to the kernel by the bootloader.
+///
+pub struct BootModuleTable {
+    /// Start address and size of each boot module.
+    modules: Vec<(PhysicalAddress, usize)>,
+}
+
+impl BootModuleTable {
+    ///
+    /// # Description
+    ///
+    /// Builds a boot module table from a list of kernel modules.
+    ///
+    /// # Parameters
+    ///
+    /// - `kmods`: List of kernel modules.
+    ///
+    /// # Returns
+    ///
+    /// The new boot module table.
+    ///
+    pub fn new(kmods: &LinkedList<KernelModule>) -> Self {
+        Self {
+            modules: kmods
+                .iter()
+                .map(|kmod| (kmod.start(), kmod.size()))
+                .collect(),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the number of boot modules in the target table.
+    ///
+    /// # Returns
+    ///
+    /// The number of boot modules in the target table.
+    ///
+    fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the location of a boot module.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: Index of the boot module.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the physical start address and size of the boot module are returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn get(&self, index: usize) -> Result<(PhysicalAddress, usize), Error> {
+        match self.modules.get(index) {
+            Some(module) => Ok(*module),
+            None => {
+                let reason: &str = "invalid boot module index";
+                error!("get(): {} (index={}, count={})", reason, index, self.len());
+                Err(Error::new(ErrorCode::NoSuchEntry, reason))
+            },
+        }
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Gets the table of boot modules.
+///
+/// # Returns
+///
+/// Upon success, a reference to the table of boot modules is returned. Upon failure, an error is
+/// returned instead.
+///
+fn boot_modules() -> Result<&'static BootModuleTable, Error> {
+    unsafe {
+        match BOOT_MODULES.as_ref() {
+            Some(table) => Ok(table),
+            None => {
+                let reason: &str = "uninitialized boot module table";
+                error!("boot_modules(): {}", reason);
+                Err(Error::new(ErrorCode::TryAgain, reason))
+            },
+        }
+    }
+}
+
+///
+/// # Description
+///
+/// Initializes the table of boot modules.
+///
+/// # Parameters
+///
+/// - `kmods`: List of kernel modules that were passed to the kernel by the bootloader.
+///
+pub fn init(kmods: &LinkedList<KernelModule>) {
+    let table: BootModuleTable = BootModuleTable::new(kmods);
+    info!("number of boot modules: {}", table.len());
+    unsafe { BOOT_MODULES = Some(table) };
+}
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
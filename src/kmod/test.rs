@@ -0,0 +1,101 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    hal::mem::{
+        Address,
+        PhysicalAddress,
+    },
+    kmod::{
+        BootModuleTable,
+        KernelModule,
+    },
+};
+use ::alloc::{
+    collections::LinkedList,
+    string::ToString,
+};
+use ::sys::error::ErrorCode;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if [`BootModuleTable::get()`] reports the location of each boot module.
+fn test_get_boot_module() -> bool {
+    let modules: [(usize, usize); 3] =
+        [(0x00400000, 0x1000), (0x00800000, 0x3400), (0x01000000, 1)];
+
+    // Build a synthetic list of boot modules.
+    let mut kmods: LinkedList<KernelModule> = LinkedList::new();
+    for (start, size) in modules.iter() {
+        let start: PhysicalAddress = match PhysicalAddress::from_raw_value(*start) {
+            Ok(start) => start,
+            Err(err) => {
+                error!("failed to create address (err={:?})", err);
+                return false;
+            },
+        };
+        kmods.push_back(KernelModule::new(start, *size, "test".to_string()));
+    }
+
+    let table: BootModuleTable = BootModuleTable::new(&kmods);
+
+    // Check if the table has the expected number of boot modules.
+    if table.len() != modules.len() {
+        error!(
+            "unexpected number of boot modules (expected={}, got={})",
+            modules.len(),
+            table.len()
+        );
+        return false;
+    }
+
+    // Check if each boot module has the expected location.
+    for (index, (start, size)) in modules.iter().enumerate() {
+        match table.get(index) {
+            Ok((s, l)) if s.into_raw_value() == *start && l == *size => {},
+            Ok((s, l)) => {
+                error!(
+                    "unexpected boot module (index={}, start={:#x}, size={})",
+                    index,
+                    s.into_raw_value(),
+                    l
+                );
+                return false;
+            },
+            Err(err) => {
+                error!("failed to get boot module (index={}, err={:?})", index, err);
+                return false;
+            },
+        }
+    }
+
+    // Check if an out-of-range index is rejected.
+    match table.get(modules.len()) {
+        Err(err) if err.code == ErrorCode::NoSuchEntry => {},
+        Ok(_) => {
+            error!("got boot module with invalid index");
+            return false;
+        },
+        Err(err) => {
+            error!("unexpected error code (err={:?})", err);
+            return false;
+        },
+    }
+
+    true
+}
+
+/// Runs all unit tests for the boot module table.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_get_boot_module);
+
+    passed
+}
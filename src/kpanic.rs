@@ -35,6 +35,9 @@ use ::core::{
 ///
 #[panic_handler]
 pub fn kpanic(info: &PanicInfo) -> ! {
+    // Replay recent log lines, which may have scrolled away from the console.
+    Klog::dump_recent();
+
     // Create anonymous scope to release reference to kernel log.
     {
         let klog: &mut Klog = &mut crate::klog::Klog::get("kernel", KlogLevel::Panic);
@@ -34,7 +34,7 @@ macro_rules! info{
 		#[cfg(feature = "smp")]
 		use crate::macros::STDOUT_LOCK;
 		use ::core::fmt::Write;
-		if crate::klog::MAX_LEVEL >= crate::klog::KlogLevel::Info {
+		if crate::klog::should_log(module_path!(), crate::klog::KlogLevel::Info) {
 			#[cfg(feature = "smp")]
 			let _guard: crate::pm::sync::spinlock::SpinlockGuard = STDOUT_LOCK.lock();
 			let _ = write!(
@@ -59,7 +59,7 @@ macro_rules! trace{
 		#[cfg(feature = "smp")]
 		use crate::macros::STDOUT_LOCK;
 		use ::core::fmt::Write;
-		if crate::klog::MAX_LEVEL >= crate::klog::KlogLevel::Trace {
+		if crate::klog::should_log(module_path!(), crate::klog::KlogLevel::Trace) {
 			#[cfg(feature = "smp")]
 			let _guard: crate::pm::sync::spinlock::SpinlockGuard = STDOUT_LOCK.lock();
 			let _ = write!(
@@ -84,7 +84,7 @@ macro_rules! debug{
 		#[cfg(feature = "smp")]
 		use crate::macros::STDOUT_LOCK;
 		use ::core::fmt::Write;
-		if crate::klog::MAX_LEVEL >= crate::klog::KlogLevel::Debug {
+		if crate::klog::should_log(module_path!(), crate::klog::KlogLevel::Debug) {
 			#[cfg(feature = "smp")]
 			let _guard: crate::pm::sync::spinlock::SpinlockGuard = STDOUT_LOCK.lock();
 			let _ = write!(
@@ -109,7 +109,7 @@ macro_rules! warn{
 		#[cfg(feature = "smp")]
 		use crate::macros::STDOUT_LOCK;
 		use ::core::fmt::Write;
-		if crate::klog::MAX_LEVEL >= crate::klog::KlogLevel::Warn {
+		if crate::klog::should_log(module_path!(), crate::klog::KlogLevel::Warn) {
 			#[cfg(feature = "smp")]
 			let _guard: crate::pm::sync::spinlock::SpinlockGuard = STDOUT_LOCK.lock();
 			let _ = write!(
@@ -134,7 +134,7 @@ macro_rules! error{
 		#[cfg(feature = "smp")]
 		use crate::macros::STDOUT_LOCK;
 		use ::core::fmt::Write;
-		if crate::klog::MAX_LEVEL >= crate::klog::KlogLevel::Error {
+		if crate::klog::should_log(module_path!(), crate::klog::KlogLevel::Error) {
 			#[cfg(feature = "smp")]
 			let _guard: crate::pm::sync::spinlock::SpinlockGuard = STDOUT_LOCK.lock();
 			let _ = write!(
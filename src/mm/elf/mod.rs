@@ -0,0 +1,1052 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Lint Exceptions
+//==================================================================================================
+
+// Not all functions are used.
+#![allow(dead_code)]
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+#[cfg(test)]
+pub(crate) mod test;
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    hal::{
+        arch::x86::mem::mmu,
+        mem::{
+            AccessPermission,
+            Address,
+            ExecutePermission,
+            PageAligned,
+            PhysicalAddress,
+            ReadPermission,
+            VirtualAddress,
+            WritePermission,
+        },
+    },
+    mm::{
+        VirtMemoryManager,
+        Vmem,
+    },
+};
+use ::alloc::vec::Vec;
+use ::arch::mem;
+use ::core::{
+    cmp::{
+        max,
+        min,
+    },
+    mem::size_of,
+    ops::Range,
+};
+use ::sys::{
+    config,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::Alignment,
+};
+
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+// Number of indented elements in ELF header.
+const EI_NIDENT: usize = 16;
+
+// Indexes of elements in ELF header.
+const EI_CLASS: usize = 4; // File class.
+
+// ELF magic numbers.
+const ELFMAG0: u8 = 0x7f; // ELF magic number 0.
+const ELFMAG1: char = 'E'; // ELF magic number 1.
+const ELFMAG2: char = 'L'; // ELF magic number 2.
+const ELFMAG3: char = 'F'; // ELF magic number 3.
+
+// File classes.
+const ELFCLASSNONE: u8 = 0; // Invalid class.
+const ELFCLASS32: u8 = 1; // 32-bit object.
+const ELFCLASS64: u8 = 2; // 64-bit object.
+
+// Data encoding types.
+const ELFDATANONE: u8 = 0; // Invalid data encoding.
+const ELFDATA2LSB: u8 = 1; // Least significant byte in the lowest address.
+const ELFDATA2MSB: u8 = 2; // Most significant byte in the lowest address.
+
+// Segment permissions.
+const PF_X: u32 = 1 << 0; // Segment is executable.
+const PF_W: u32 = 1 << 1; // Segment is writable.
+const PF_R: u32 = 1 << 2; // Segment is readable.
+
+// Object file types.
+const ET_NONE: u16 = 0; // No file type.
+const ET_REL: u16 = 1; // Relocatable file.
+const ET_EXEC: u16 = 2; // Executable file.
+const ET_DYN: u16 = 3; // Shared object file.
+const ET_CORE: u16 = 4; // Core file.
+const ET_LOPROC: u16 = 0xff00; // Processor-specific.
+const ET_HIPROC: u16 = 0xffff; // Processor-specific.
+
+// Required machine architecture types.
+const EM_NONE: u16 = 0; // No machine.
+const EM_M32: u16 = 1; // AT&T WE 32100.
+const EM_SPARC: u16 = 2; // SPARC.
+const EM_386: u16 = 3; // Intel 80386.
+const EM_68K: u16 = 4; // Motorola 68000.
+const EM_88K: u16 = 5; // Motorola 88000.
+const EM_860: u16 = 7; // Intel 80860.
+const EM_MIPS: u16 = 8; // MIPS RS3000.
+const EM_X86_64: u16 = 62; // AMD x86-64.
+
+// Object file versions.
+const EV_NONE: u32 = 0; // Invalid version.
+const EV_CURRENT: u32 = 1; // Current version.
+
+// Segment types.
+const PT_NULL: u32 = 0; // Unused segment.
+const PT_LOAD: u32 = 1; // Loadable segment.
+const PT_DYNAMIC: u32 = 2; // Dynamic linking.
+const PT_INTERP: u32 = 3; // Interpreter.
+const PT_NOTE: u32 = 4; // Auxiliary information.
+const PT_SHLIB: u32 = 5; // Reserved.
+const PT_PHDR: u32 = 6; // Program header table.
+const PT_LOPROC: u32 = 0x70000000; // Low limit for processor-specific.
+const PT_HIPROC: u32 = 0x7fffffff; // High limit for processor-specific.
+
+// Maximum number of segments that the loader processes.
+const MAX_SEGMENTS: usize = 64;
+
+// Vendor-specific notes.
+const NOTE_NAME: &[u8] = b"Nanvix\0"; // Name of vendor-specific notes.
+const NT_NANVIX_CAPABILITIES: u32 = 1; // Capabilities required by the binary.
+
+// ELF 32 file header.
+#[repr(C)]
+pub struct Elf32Fhdr {
+    e_ident: [u8; EI_NIDENT], // ELF magic numbers and other info.
+    e_type: u16,              // Object file type.
+    e_machine: u16,           // Required machine architecture type.
+    e_version: u32,           // Object file version.
+    e_entry: u32,             // Virtual address of process's entry point.
+    e_phoff: u32,             // Program header table file offset.
+    e_shoff: u32,             // Section header table file offset.
+    e_flags: u32,             // Processor-specific flags.
+    e_ehsize: u16,            // ELF header’s size in bytes.
+    e_phentsize: u16,         // Program header table entry size.
+    e_phnum: u16,             // Entries in the program header table.
+    e_shentsize: u16,         // Section header table size.
+    e_shnum: u16,             // Entries in the section header table.
+    e_shstrndx: u16,          // Index for the section name string table.
+}
+
+impl Elf32Fhdr {
+    pub fn from_address(addr: usize) -> &'static Self {
+        unsafe { &*(addr as *const Self) }
+    }
+}
+
+// ELF 32 program header.
+#[repr(C)]
+struct Elf32Phdr {
+    p_type: u32,   // Segment type.
+    p_offset: u32, // Offset of the first byte.
+    p_vaddr: u32,  // Virtual address of the first byte.
+    p_paddr: u32,  // Physical address of the first byte.
+    p_filesz: u32, // Bytes in the file image.
+    p_memsz: u32,  // Bytes in the memory image.
+    p_flags: u32,  // Segment flags.
+    p_align: u32,  // Alignment value.
+}
+
+// ELF 64 file header.
+#[repr(C)]
+pub struct Elf64Fhdr {
+    e_ident: [u8; EI_NIDENT], // ELF magic numbers and other info.
+    e_type: u16,              // Object file type.
+    e_machine: u16,           // Required machine architecture type.
+    e_version: u32,           // Object file version.
+    e_entry: u64,             // Virtual address of process's entry point.
+    e_phoff: u64,             // Program header table file offset.
+    e_shoff: u64,             // Section header table file offset.
+    e_flags: u32,             // Processor-specific flags.
+    e_ehsize: u16,            // ELF header’s size in bytes.
+    e_phentsize: u16,         // Program header table entry size.
+    e_phnum: u16,             // Entries in the program header table.
+    e_shentsize: u16,         // Section header table size.
+    e_shnum: u16,             // Entries in the section header table.
+    e_shstrndx: u16,          // Index for the section name string table.
+}
+
+impl Elf64Fhdr {
+    pub fn from_address(addr: usize) -> &'static Self {
+        unsafe { &*(addr as *const Self) }
+    }
+}
+
+// ELF 64 program header.
+#[repr(C)]
+struct Elf64Phdr {
+    p_type: u32,   // Segment type.
+    p_flags: u32,  // Segment flags.
+    p_offset: u64, // Offset of the first byte.
+    p_vaddr: u64,  // Virtual address of the first byte.
+    p_paddr: u64,  // Physical address of the first byte.
+    p_filesz: u64, // Bytes in the file image.
+    p_memsz: u64,  // Bytes in the memory image.
+    p_align: u64,  // Alignment value.
+}
+
+// ELF 32 note header.
+#[repr(C)]
+struct Elf32Nhdr {
+    n_namesz: u32, // Size of the name.
+    n_descsz: u32, // Size of the descriptor.
+    n_type: u32,   // Note type.
+}
+
+///
+/// # Description
+///
+/// A program header, with fields converted to the native word size.
+///
+struct Segment {
+    p_type: u32,     // Segment type.
+    p_offset: usize, // Offset of the first byte.
+    p_vaddr: usize,  // Virtual address of the first byte.
+    p_filesz: usize, // Bytes in the file image.
+    p_memsz: usize,  // Bytes in the memory image.
+    p_flags: u32,    // Segment flags.
+    p_align: u32,    // Alignment value.
+}
+
+///
+/// # Description
+///
+/// Metadata about an ELF binary that was loaded into a virtual memory space.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct LoadedElf {
+    /// Entry point of the binary.
+    entry: VirtualAddress,
+    /// Number of pages required to back the image of the binary.
+    npages: usize,
+    /// Number of loadable segments of the binary.
+    segments: usize,
+    /// Virtual address past the end of the highest loadable segment, aligned to a page boundary.
+    highest_vaddr: VirtualAddress,
+    /// Bitmask of capabilities required by the binary.
+    capabilities: u32,
+}
+
+impl LoadedElf {
+    ///
+    /// # Description
+    ///
+    /// Returns the entry point of the target ELF binary.
+    ///
+    pub fn entry(&self) -> VirtualAddress {
+        self.entry
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of pages required to back the image of the target ELF binary.
+    ///
+    pub fn npages(&self) -> usize {
+        self.npages
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of loadable segments of the target ELF binary.
+    ///
+    pub fn segments(&self) -> usize {
+        self.segments
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the virtual address past the end of the highest loadable segment of the target ELF
+    /// binary. It is aligned to a page boundary, so that the heap of the process may start there.
+    ///
+    pub fn highest_vaddr(&self) -> VirtualAddress {
+        self.highest_vaddr
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the bitmask of capabilities required by the target ELF binary.
+    ///
+    pub fn capabilities(&self) -> u32 {
+        self.capabilities
+    }
+}
+
+//==================================================================================================
+// Traits
+//==================================================================================================
+
+///
+/// # Description
+///
+/// An ELF file header, either 32-bit or 64-bit.
+///
+trait ElfFhdr {
+    /// Type of program headers.
+    type Phdr: ElfPhdr;
+    /// File class that the header describes.
+    const CLASS: u8;
+    /// Machine architecture type that the loader accepts.
+    const MACHINE: u16;
+
+    /// Returns the identification bytes of the binary.
+    fn ident(&self) -> &[u8; EI_NIDENT];
+    /// Returns the machine architecture type of the binary.
+    fn machine(&self) -> u16;
+    /// Returns the virtual address of the entry point of the binary.
+    fn entry(&self) -> u64;
+    /// Returns the file offset of the program header table.
+    fn phoff(&self) -> u64;
+    /// Returns the number of entries in the program header table.
+    fn phnum(&self) -> u16;
+    /// Returns the size of an entry in the program header table.
+    fn phentsize(&self) -> u16;
+
+    /// Checks if the binary is valid for this class of headers.
+    fn is_valid(&self) -> bool {
+        let ident: &[u8; EI_NIDENT] = self.ident();
+        if ident[0] != ELFMAG0
+            || ident[1] != ELFMAG1 as u8
+            || ident[2] != ELFMAG2 as u8
+            || ident[3] != ELFMAG3 as u8
+        {
+            error!("header is NULL or invalid magic");
+            return false;
+        }
+
+        if ident[EI_CLASS] != Self::CLASS {
+            error!("unexpected file class (class={})", ident[EI_CLASS]);
+            return false;
+        }
+
+        if self.machine() != Self::MACHINE {
+            error!("unexpected machine (machine={})", self.machine());
+            return false;
+        }
+
+        true
+    }
+}
+
+///
+/// # Description
+///
+/// An ELF program header, either 32-bit or 64-bit.
+///
+trait ElfPhdr {
+    /// Converts the program header to the native word size.
+    fn segment(&self) -> Result<Segment, Error>;
+}
+
+impl ElfFhdr for Elf32Fhdr {
+    type Phdr = Elf32Phdr;
+    const CLASS: u8 = ELFCLASS32;
+    const MACHINE: u16 = EM_386;
+
+    fn ident(&self) -> &[u8; EI_NIDENT] {
+        &self.e_ident
+    }
+
+    fn machine(&self) -> u16 {
+        self.e_machine
+    }
+
+    fn entry(&self) -> u64 {
+        u64::from(self.e_entry)
+    }
+
+    fn phoff(&self) -> u64 {
+        u64::from(self.e_phoff)
+    }
+
+    fn phnum(&self) -> u16 {
+        self.e_phnum
+    }
+
+    fn phentsize(&self) -> u16 {
+        self.e_phentsize
+    }
+}
+
+impl ElfPhdr for Elf32Phdr {
+    fn segment(&self) -> Result<Segment, Error> {
+        Ok(Segment {
+            p_type: self.p_type,
+            p_offset: self.p_offset as usize,
+            p_vaddr: self.p_vaddr as usize,
+            p_filesz: self.p_filesz as usize,
+            p_memsz: self.p_memsz as usize,
+            p_flags: self.p_flags,
+            p_align: self.p_align,
+        })
+    }
+}
+
+impl ElfFhdr for Elf64Fhdr {
+    type Phdr = Elf64Phdr;
+    const CLASS: u8 = ELFCLASS64;
+    const MACHINE: u16 = EM_X86_64;
+
+    fn ident(&self) -> &[u8; EI_NIDENT] {
+        &self.e_ident
+    }
+
+    fn machine(&self) -> u16 {
+        self.e_machine
+    }
+
+    fn entry(&self) -> u64 {
+        self.e_entry
+    }
+
+    fn phoff(&self) -> u64 {
+        self.e_phoff
+    }
+
+    fn phnum(&self) -> u16 {
+        self.e_phnum
+    }
+
+    fn phentsize(&self) -> u16 {
+        self.e_phentsize
+    }
+}
+
+impl ElfPhdr for Elf64Phdr {
+    fn segment(&self) -> Result<Segment, Error> {
+        Ok(Segment {
+            p_type: self.p_type,
+            p_offset: to_usize(self.p_offset)?,
+            p_vaddr: to_usize(self.p_vaddr)?,
+            p_filesz: to_usize(self.p_filesz)?,
+            p_memsz: to_usize(self.p_memsz)?,
+            p_flags: self.p_flags,
+            p_align: u32::try_from(self.p_align).map_err(|_| {
+                Error::new(ErrorCode::BadFile, "invalid alignment value in elf file")
+            })?,
+        })
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Converts a field of an ELF header to the native word size.
+///
+/// # Parameters
+///
+/// - `value`: Value of the field.
+///
+/// # Returns
+///
+/// Upon successful completion, the converted value is returned. Otherwise, an error code is
+/// returned.
+///
+fn to_usize(value: u64) -> Result<usize, Error> {
+    usize::try_from(value).map_err(|_| {
+        let reason: &str = "value does not fit in address space";
+        error!("to_usize(): {} (value={:#x})", reason, value);
+        Error::new(ErrorCode::BadFile, reason)
+    })
+}
+
+///
+/// # Description
+///
+/// Copies the file image of a segment page by page. The last page of the segment is copied only
+/// partially, so that exactly `filesz` bytes are copied in total.
+///
+/// # Parameters
+///
+/// - `filesz`: Size of the file image of the segment.
+/// - `copy`: Function that copies `size` bytes at offset `offset` of the segment.
+///
+/// # Returns
+///
+/// Upon successful completion, empty is returned. Otherwise, an error code is returned.
+///
+fn copy_segment(
+    filesz: usize,
+    mut copy: impl FnMut(usize, usize) -> Result<(), Error>,
+) -> Result<(), Error> {
+    for offset in (0..filesz).step_by(mem::PAGE_SIZE) {
+        copy(offset, min(mem::PAGE_SIZE, filesz - offset))?;
+    }
+
+    Ok(())
+}
+
+///
+/// # Description
+///
+/// Zeroes the tail of a segment that lies past its file image (the BSS) page by page, so that no
+/// call crosses a page boundary.
+///
+/// # Parameters
+///
+/// - `filesz`: Size of the file image of the segment.
+/// - `memsz`: Size of the memory image of the segment.
+/// - `zero`: Function that zeroes `size` bytes at offset `offset` of the segment.
+///
+/// # Returns
+///
+/// Upon successful completion, empty is returned. Otherwise, an error code is returned.
+///
+fn zero_segment(
+    filesz: usize,
+    memsz: usize,
+    mut zero: impl FnMut(usize, usize) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let mut offset: usize = filesz;
+    while offset < memsz {
+        let end: usize =
+            min(memsz, ::sys::mm::align_down(offset, mmu::PAGE_ALIGNMENT) + mem::PAGE_SIZE);
+        zero(offset, end - offset)?;
+        offset = end;
+    }
+
+    Ok(())
+}
+
+///
+/// # Description
+///
+/// Parses the notes of a `PT_NOTE` segment and collects the capabilities that they require.
+///
+/// # Parameters
+///
+/// - `image`: Base address of the ELF image.
+/// - `phdr`: Program header of the note segment.
+/// - `size`: Size of the ELF image.
+///
+/// # Returns
+///
+/// Upon successful completion, the bitmask of required capabilities is returned. Otherwise, an
+/// error code is returned.
+///
+fn parse_notes(image: *const u8, phdr: &Segment, size: usize) -> Result<u32, Error> {
+    // Check if the segment lies within the image.
+    if phdr
+        .p_offset
+        .checked_add(phdr.p_filesz)
+        .map_or(true, |end| end > size)
+    {
+        let reason: &str = "note segment exceeds image";
+        error!(
+            "parse_notes(): {} (offset={:#x}, filesz={:#x}, size={})",
+            reason, phdr.p_offset, phdr.p_filesz, size
+        );
+        return Err(Error::new(ErrorCode::BadFile, reason));
+    }
+
+    let base: *const u8 = unsafe { image.add(phdr.p_offset) };
+    let size: usize = phdr.p_filesz;
+    let mut capabilities: u32 = 0;
+    let mut offset: usize = 0;
+
+    while offset < size {
+        // Check if the note header fits in the segment.
+        if size - offset < size_of::<Elf32Nhdr>() {
+            let reason: &str = "truncated note header";
+            error!("parse_notes(): {}", reason);
+            return Err(Error::new(ErrorCode::BadFile, reason));
+        }
+        let nhdr: Elf32Nhdr = unsafe { (base.add(offset) as *const Elf32Nhdr).read_unaligned() };
+        offset += size_of::<Elf32Nhdr>();
+
+        // Check if the name and the descriptor fit in the segment.
+        let name_offset: usize = offset;
+        let desc_offset: Option<usize> = (nhdr.n_namesz as usize)
+            .checked_next_multiple_of(4)
+            .and_then(|namesz| name_offset.checked_add(namesz));
+        let next: Option<usize> = desc_offset.and_then(|desc_offset| {
+            (nhdr.n_descsz as usize)
+                .checked_next_multiple_of(4)
+                .and_then(|descsz| desc_offset.checked_add(descsz))
+        });
+        let (desc_offset, next): (usize, usize) = match (desc_offset, next) {
+            (Some(desc_offset), Some(next)) if next <= size => (desc_offset, next),
+            _ => {
+                let reason: &str = "truncated note";
+                error!("parse_notes(): {}", reason);
+                return Err(Error::new(ErrorCode::BadFile, reason));
+            },
+        };
+        let name: &[u8] =
+            unsafe { core::slice::from_raw_parts(base.add(name_offset), nhdr.n_namesz as usize) };
+        let desc: *const u8 = unsafe { base.add(desc_offset) };
+        offset = next;
+
+        // Skip notes of other vendors.
+        if name != NOTE_NAME || nhdr.n_type != NT_NANVIX_CAPABILITIES {
+            continue;
+        }
+
+        // Check if the descriptor is not a capability bitmask.
+        if nhdr.n_descsz as usize != size_of::<u32>() {
+            let reason: &str = "invalid capabilities note";
+            error!("parse_notes(): {}", reason);
+            return Err(Error::new(ErrorCode::BadFile, reason));
+        }
+        capabilities |= unsafe { (desc as *const u32).read_unaligned() };
+    }
+
+    Ok(capabilities)
+}
+
+///
+/// # Description
+///
+/// Gets the name of the interpreter that a `PT_INTERP` segment requests.
+///
+/// # Parameters
+///
+/// - `image`: Base address of the ELF image.
+/// - `phdr`: Program header of the interpreter segment.
+/// - `size`: Size of the ELF image.
+///
+/// # Returns
+///
+/// If the segment lies within the image and holds a valid string, the name of the interpreter is
+/// returned. Otherwise, `None` is returned.
+///
+fn interpreter_name<'a>(image: *const u8, phdr: &Segment, size: usize) -> Option<&'a str> {
+    // Check if the segment lies within the image.
+    if phdr.p_offset.checked_add(phdr.p_filesz)? > size {
+        return None;
+    }
+
+    let name: &[u8] =
+        unsafe { core::slice::from_raw_parts(image.add(phdr.p_offset), phdr.p_filesz) };
+    let len: usize = name.iter().position(|b| *b == 0).unwrap_or(name.len());
+    core::str::from_utf8(&name[..len]).ok()
+}
+
+///
+/// # Description
+///
+/// Computes the range of pages that a loadable segment spans.
+///
+/// # Parameters
+///
+/// - `phdr`: Program header of the segment.
+///
+/// # Returns
+///
+/// Upon successful completion, the range of page-aligned virtual addresses that the segment spans
+/// is returned. Otherwise, an error code is returned.
+///
+fn segment_pages(phdr: &Segment) -> Result<Range<usize>, Error> {
+    let align: Alignment = phdr
+        .p_align
+        .try_into()
+        .map_err(|_| Error::new(ErrorCode::BadFile, "invalid alignment value in elf file"))?;
+    let virt_addr: usize = ::sys::mm::align_down(phdr.p_vaddr, align);
+
+    let segment_size: usize = max(phdr.p_filesz, phdr.p_memsz);
+    let virt_addr_end: usize = match virt_addr
+        .checked_add(segment_size)
+        .and_then(|end| ::sys::mm::align_down(end, mmu::PAGE_ALIGNMENT).checked_add(mem::PAGE_SIZE))
+    {
+        Some(end) => end,
+        None => {
+            let reason: &str = "segment wraps around address space";
+            error!("segment_pages(): {} (vaddr={:#x})", reason, phdr.p_vaddr);
+            return Err(Error::new(ErrorCode::BadFile, reason));
+        },
+    };
+
+    Ok(virt_addr..virt_addr_end)
+}
+
+///
+/// # Description
+///
+/// Computes the access permissions of a loadable segment from its flags.
+///
+/// # Parameters
+///
+/// - `flags`: Segment flags.
+///
+/// # Returns
+///
+/// Upon successful completion, the access permissions of the segment are returned. Otherwise, an
+/// error code is returned.
+///
+fn segment_access(flags: u32) -> Result<AccessPermission, Error> {
+    // Check if the segment has unknown flags.
+    if flags & !(PF_R | PF_W | PF_X) != 0 {
+        let reason: &str = "unknown segment flags";
+        error!("segment_access(): {} (flags={:#x})", reason, flags);
+        return Err(Error::new(ErrorCode::BadFile, reason));
+    }
+
+    // Check if the segment is not readable.
+    if flags & PF_R == 0 {
+        let reason: &str = "segment is not readable";
+        error!("segment_access(): {} (flags={:#x})", reason, flags);
+        return Err(Error::new(ErrorCode::BadFile, reason));
+    }
+
+    Ok(AccessPermission::new(
+        ReadPermission::Allow,
+        if flags & PF_W != 0 {
+            WritePermission::Allow
+        } else {
+            WritePermission::Deny
+        },
+        if flags & PF_X != 0 {
+            ExecutePermission::Allow
+        } else {
+            ExecutePermission::Deny
+        },
+    ))
+}
+
+///
+/// # Description
+///
+/// Checks if the pages spanned by loadable segments overlap.
+///
+/// # Parameters
+///
+/// - `phdrs`: Program headers of the binary.
+///
+/// # Returns
+///
+/// If no two loadable segments share a page, empty is returned. Otherwise, an error code is
+/// returned.
+///
+fn check_overlapping_segments<P: ElfPhdr>(phdrs: &[P]) -> Result<(), Error> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for phdr in phdrs {
+        let phdr: Segment = phdr.segment()?;
+        if phdr.p_type == PT_LOAD {
+            ranges.push(segment_pages(&phdr)?);
+        }
+    }
+
+    // Sort segments by start address, so that only neighbors need to be compared.
+    ranges.sort_unstable_by_key(|range| range.start);
+    for pair in ranges.windows(2) {
+        if pair[0].end > pair[1].start {
+            let reason: &str = "overlapping segments";
+            error!(
+                "check_overlapping_segments(): {} ({:#x?} and {:#x?})",
+                reason, pair[0], pair[1]
+            );
+            return Err(Error::new(ErrorCode::BadFile, reason));
+        }
+    }
+
+    Ok(())
+}
+
+///
+/// # Description
+///
+/// Loads an ELF binary into a target virtual memory space.
+///
+/// # Parameters
+///
+/// - `target`: Virtual memory manager and target virtual memory space. If `None`, the binary is
+///   checked but nothing is mapped (dry-run mode).
+/// - `elf`: ELF file header.
+/// - `size`: Size of the ELF image.
+///
+/// # Returns
+///
+/// Upon successful completion, metadata about the ELF binary is returned. Otherwise, an error
+/// code is returned and the virtual memory space may be left in an inconsistent state.
+///
+fn do_elf_load<H: ElfFhdr>(
+    mut target: Option<(&mut VirtMemoryManager, &mut Vmem)>,
+    elf: &H,
+    size: usize,
+) -> Result<LoadedElf, Error> {
+    let dry_run: bool = target.is_none();
+    trace!("do_elf_load(): dry_run={}, size={}", dry_run, size);
+
+    // Check if the file header fits in the image.
+    if size < size_of::<H>() {
+        let reason: &str = "truncated file header";
+        error!("do_elf_load: {} (size={})", reason, size);
+        return Err(Error::new(ErrorCode::BadFile, reason));
+    }
+
+    if !elf.is_valid() {
+        return Err(Error::new(ErrorCode::BadFile, "invalid elf file"));
+    }
+
+    let image: *const u8 = elf as *const H as *const u8;
+    let entry: VirtualAddress = VirtualAddress::new(to_usize(elf.entry())?);
+
+    // Check if entry point does not match what we expect.
+    if entry != config::memory_layout::USER_BASE {
+        let reason: &str = "invalid binary entry point";
+        error!("do_elf_load: {} (entry={:?})", reason, entry);
+        return Err(Error::new(ErrorCode::BadFile, "invalid entry point"));
+    }
+
+    // Check if the binary has too many segments.
+    if elf.phnum() as usize > MAX_SEGMENTS {
+        let reason: &str = "too many segments";
+        error!("do_elf_load: {} (phnum={})", reason, elf.phnum());
+        return Err(Error::new(ErrorCode::BadFile, reason));
+    }
+
+    // Check if program headers have the expected size.
+    if elf.phentsize() as usize != size_of::<H::Phdr>() {
+        let reason: &str = "invalid program header size";
+        error!("do_elf_load: {} (phentsize={})", reason, elf.phentsize());
+        return Err(Error::new(ErrorCode::BadFile, reason));
+    }
+
+    // Check if the program header table fits in the image.
+    let phoff: usize = to_usize(elf.phoff())?;
+    let phtab_end: Option<usize> = (elf.phnum() as usize)
+        .checked_mul(size_of::<H::Phdr>())
+        .and_then(|phtab_size| phoff.checked_add(phtab_size));
+    if phtab_end.map_or(true, |end| end > size) {
+        let reason: &str = "program header table exceeds image";
+        error!(
+            "do_elf_load: {} (phoff={:#x}, phnum={}, size={})",
+            reason,
+            phoff,
+            elf.phnum(),
+            size
+        );
+        return Err(Error::new(ErrorCode::BadFile, reason));
+    }
+
+    let phdr_base = unsafe { image.add(phoff) as *const H::Phdr };
+    let phdrs = unsafe { core::slice::from_raw_parts(phdr_base, elf.phnum() as usize) };
+
+    // Check if loadable segments overlap.
+    check_overlapping_segments(phdrs)?;
+
+    // Number of pages required to back the image.
+    let mut npages: usize = 0;
+
+    // Number of loadable segments.
+    let mut segments: usize = 0;
+
+    // Virtual address past the end of the highest loadable segment.
+    let mut highest_vaddr: usize = config::memory_layout::USER_BASE.into_raw_value();
+
+    // Capabilities required by the binary.
+    let mut capabilities: u32 = 0;
+
+    // Load segments.
+    for phdr in phdrs {
+        let phdr: Segment = phdr.segment()?;
+
+        if phdr.p_type == PT_NOTE {
+            capabilities |= parse_notes(image, &phdr, size)?;
+            continue;
+        }
+
+        // Check if the binary requests an interpreter.
+        if phdr.p_type == PT_INTERP {
+            let reason: &str = "dynamic linking is not supported";
+            error!(
+                "do_elf_load: {} (interpreter={:?})",
+                reason,
+                interpreter_name(image, &phdr, size).unwrap_or("<invalid>")
+            );
+            return Err(Error::new(ErrorCode::OperationNotSupported, reason));
+        }
+
+        if phdr.p_type != PT_LOAD {
+            continue;
+        }
+
+        // Check if the segment is not valid.
+        if phdr.p_filesz > phdr.p_memsz {
+            return Err(Error::new(ErrorCode::BadFile, "corrupted elf file"));
+        }
+
+        // Check if the file image of the segment lies within the image.
+        if phdr
+            .p_offset
+            .checked_add(phdr.p_filesz)
+            .map_or(true, |end| end > size)
+        {
+            let reason: &str = "segment exceeds image";
+            error!(
+                "do_elf_load: {} (offset={:#x}, filesz={:#x}, size={})",
+                reason, phdr.p_offset, phdr.p_filesz, size
+            );
+            return Err(Error::new(ErrorCode::BadFile, reason));
+        }
+
+        let pages: Range<usize> = segment_pages(&phdr)?;
+        let virt_addr: usize = pages.start;
+        highest_vaddr = max(highest_vaddr, pages.end);
+        segments += 1;
+
+        // Compute access permissions.
+        let access: AccessPermission = segment_access(phdr.p_flags)?;
+
+        // Allocate segment.
+        for vaddr in pages.step_by(mem::PAGE_SIZE) {
+            let vaddr: VirtualAddress = VirtualAddress::new(vaddr);
+            // Check if address lies in user space.
+            if vaddr < config::memory_layout::USER_BASE {
+                let reason: &str = "invalid load address";
+                error!("do_elf_load: {}", reason);
+                return Err(Error::new(ErrorCode::BadFile, reason));
+            }
+
+            let vaddr: PageAligned<VirtualAddress> = PageAligned::from_address(vaddr)?;
+
+            if let Some((mm, vmem)) = target.as_mut() {
+                mm.alloc_upage(vmem, vaddr, access)?;
+            }
+
+            npages += 1;
+        }
+
+        let phys_addr_base: usize = unsafe { image.add(phdr.p_offset) as usize };
+
+        // Load segment page by page, copying exactly the file image of the segment.
+        copy_segment(phdr.p_filesz, |offset, size| {
+            let vaddr: VirtualAddress = VirtualAddress::new(virt_addr + offset);
+
+            if vaddr < config::memory_layout::USER_BASE {
+                let reason: &str = "invalid load address";
+                error!("do_elf_load: {}", reason);
+                return Err(Error::new(ErrorCode::BadFile, "invalid load address"));
+            }
+
+            let paddr: PageAligned<PhysicalAddress> =
+                PageAligned::from_raw_value(phys_addr_base + offset)?;
+            let vaddr: PageAligned<VirtualAddress> = PageAligned::from_address(vaddr)?;
+
+            if let Some((_, vmem)) = target.as_mut() {
+                // TODO: write a detailed comment about this.
+                unsafe { vmem.physcopy(vaddr, paddr, size)? };
+            }
+
+            Ok(())
+        })?;
+
+        // Zero the remainder of the segment, so that no stale data leaks into user space.
+        zero_segment(phdr.p_filesz, phdr.p_memsz, |offset, size| {
+            let vaddr: usize = virt_addr + offset;
+            let page: PageAligned<VirtualAddress> =
+                PageAligned::from_raw_value(::sys::mm::align_down(vaddr, mmu::PAGE_ALIGNMENT))?;
+
+            if let Some((_, vmem)) = target.as_mut() {
+                unsafe { vmem.physzero(page, vaddr % mem::PAGE_SIZE, size)? };
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(LoadedElf {
+        entry,
+        npages,
+        segments,
+        highest_vaddr: VirtualAddress::new(highest_vaddr),
+        capabilities,
+    })
+}
+
+///
+/// # Description
+///
+/// Checks an ELF32 binary without loading it.
+///
+/// # Parameters
+///
+/// - `elf`: ELF32 file header.
+/// - `size`: Size of the ELF32 image.
+///
+/// # Returns
+///
+/// Upon successful completion, metadata about the ELF32 binary is returned. Otherwise, an error
+/// code is returned.
+///
+pub fn elf32_inspect(elf: &Elf32Fhdr, size: usize) -> Result<LoadedElf, Error> {
+    do_elf_load(None, elf, size)
+}
+
+pub fn elf32_load(
+    mm: &mut VirtMemoryManager,
+    vmem: &mut Vmem,
+    elf: &Elf32Fhdr,
+    size: usize,
+) -> Result<LoadedElf, Error> {
+    // Validate the binary before touching the address space.
+    do_elf_load(None, elf, size)?;
+
+    do_elf_load(Some((mm, vmem)), elf, size)
+}
+
+///
+/// # Description
+///
+/// Loads an ELF64 binary into a target virtual memory space.
+///
+/// # Parameters
+///
+/// - `mm`: Virtual memory manager.
+/// - `vmem`: Target virtual memory space.
+/// - `elf`: ELF64 file header.
+/// - `size`: Size of the ELF64 image.
+///
+/// # Returns
+///
+/// Upon successful completion, metadata about the ELF64 binary is returned. Otherwise, an error
+/// code is returned.
+///
+pub fn elf64_load(
+    mm: &mut VirtMemoryManager,
+    vmem: &mut Vmem,
+    elf: &Elf64Fhdr,
+    size: usize,
+) -> Result<LoadedElf, Error> {
+    // Validate the binary before touching the address space.
+    do_elf_load(None, elf, size)?;
+
+    do_elf_load(Some((mm, vmem)), elf, size)
+}
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
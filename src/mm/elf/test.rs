@@ -0,0 +1,821 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::mm::elf::{
+    self,
+    Elf32Fhdr,
+    Elf32Phdr,
+    Elf64Fhdr,
+    Elf64Phdr,
+    LoadedElf,
+    EI_CLASS,
+    EI_NIDENT,
+    ELFCLASS32,
+    ELFCLASS64,
+    ELFDATA2LSB,
+    ELFMAG0,
+    ELFMAG1,
+    ELFMAG2,
+    ELFMAG3,
+    EM_386,
+    EM_X86_64,
+    ET_EXEC,
+    EV_CURRENT,
+    NOTE_NAME,
+    NT_NANVIX_CAPABILITIES,
+    PF_R,
+    PF_W,
+    PF_X,
+    PT_INTERP,
+    PT_LOAD,
+    PT_NOTE,
+};
+use ::alloc::vec::Vec;
+use ::arch::mem;
+use ::core::{
+    mem::size_of,
+    ptr,
+};
+use ::sys::{
+    config,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    pm::Capability,
+};
+
+//==================================================================================================
+// Helper Functions
+//==================================================================================================
+
+/// Builds a program header for a loadable segment.
+fn load_segment(vaddr: usize, filesz: usize, memsz: usize, flags: u32) -> Elf32Phdr {
+    Elf32Phdr {
+        p_type: PT_LOAD,
+        p_offset: 0,
+        p_vaddr: vaddr as u32,
+        p_paddr: vaddr as u32,
+        p_filesz: filesz as u32,
+        p_memsz: memsz as u32,
+        p_flags: flags,
+        p_align: mem::PAGE_SIZE as u32,
+    }
+}
+
+/// Builds a program header for a note segment.
+fn note_segment(offset: usize, filesz: usize) -> Elf32Phdr {
+    Elf32Phdr {
+        p_type: PT_NOTE,
+        p_offset: offset as u32,
+        p_vaddr: 0,
+        p_paddr: 0,
+        p_filesz: filesz as u32,
+        p_memsz: 0,
+        p_flags: PF_R,
+        p_align: 4,
+    }
+}
+
+/// Builds a note that declares the capabilities required by a binary.
+fn capabilities_note(capabilities: u32) -> Vec<u8> {
+    let mut note: Vec<u8> = Vec::new();
+    note.extend_from_slice(&(NOTE_NAME.len() as u32).to_le_bytes());
+    note.extend_from_slice(&(size_of::<u32>() as u32).to_le_bytes());
+    note.extend_from_slice(&NT_NANVIX_CAPABILITIES.to_le_bytes());
+    note.extend_from_slice(NOTE_NAME);
+    note.resize(note.len().next_multiple_of(4), 0);
+    note.extend_from_slice(&capabilities.to_le_bytes());
+    note
+}
+
+/// Builds a synthetic ELF32 image of a binary that declares the capabilities it requires.
+pub(crate) fn capabilities_image(capabilities: u32) -> Image {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let note: Vec<u8> = capabilities_note(capabilities);
+    let offset: usize = mem::PAGE_SIZE / 2;
+    let phdrs: [Elf32Phdr; 2] = [
+        load_segment(base, 0x100, 0x100, PF_R | PF_X),
+        note_segment(offset, note.len()),
+    ];
+
+    let mut image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    image.write(offset, &note);
+    image
+}
+
+/// Builds a program header for a 64-bit loadable segment.
+fn load_segment64(vaddr: usize, filesz: usize, memsz: usize, flags: u32) -> Elf64Phdr {
+    Elf64Phdr {
+        p_type: PT_LOAD,
+        p_flags: flags,
+        p_offset: 0,
+        p_vaddr: vaddr as u64,
+        p_paddr: vaddr as u64,
+        p_filesz: filesz as u64,
+        p_memsz: memsz as u64,
+        p_align: mem::PAGE_SIZE as u64,
+    }
+}
+
+/// A synthetic ELF image, aligned to a page boundary.
+pub(crate) struct Image {
+    /// Underlying storage.
+    storage: Vec<u8>,
+    /// Offset of the image within the underlying storage.
+    offset: usize,
+}
+
+impl Image {
+    /// Allocates storage for a synthetic ELF image of `size` bytes.
+    fn alloc(size: usize) -> Self {
+        // Over-allocate storage, so that the image can be aligned to a page boundary.
+        let mut storage: Vec<u8> = Vec::new();
+        storage.resize(size + mem::PAGE_SIZE, 0);
+        let offset: usize = (storage.as_ptr() as usize).next_multiple_of(mem::PAGE_SIZE)
+            - storage.as_ptr() as usize;
+
+        Self { storage, offset }
+    }
+
+    /// Builds the identification bytes of a synthetic ELF image of class `class`.
+    fn ident(class: u8) -> [u8; EI_NIDENT] {
+        let mut e_ident: [u8; EI_NIDENT] = [0; EI_NIDENT];
+        e_ident[0] = ELFMAG0;
+        e_ident[1] = ELFMAG1 as u8;
+        e_ident[2] = ELFMAG2 as u8;
+        e_ident[3] = ELFMAG3 as u8;
+        e_ident[EI_CLASS] = class;
+        e_ident[5] = ELFDATA2LSB;
+        e_ident
+    }
+
+    /// Builds a synthetic ELF32 image of `size` bytes with the given program headers.
+    fn new(phdrs: &[Elf32Phdr], size: usize) -> Self {
+        let phoff: usize = size_of::<Elf32Fhdr>();
+        let size: usize = size.max(phoff + phdrs.len() * size_of::<Elf32Phdr>());
+        let mut image: Image = Self::alloc(size);
+
+        let fhdr: Elf32Fhdr = Elf32Fhdr {
+            e_ident: Self::ident(ELFCLASS32),
+            e_type: ET_EXEC,
+            e_machine: EM_386,
+            e_version: EV_CURRENT,
+            e_entry: config::memory_layout::USER_BASE.into_raw_value() as u32,
+            e_phoff: phoff as u32,
+            e_shoff: 0,
+            e_flags: 0,
+            e_ehsize: size_of::<Elf32Fhdr>() as u16,
+            e_phentsize: size_of::<Elf32Phdr>() as u16,
+            e_phnum: phdrs.len() as u16,
+            e_shentsize: 0,
+            e_shnum: 0,
+            e_shstrndx: 0,
+        };
+
+        // Safety: the image is large enough and suitably aligned to hold the headers.
+        unsafe {
+            let base: *mut u8 = image.storage.as_mut_ptr().add(image.offset);
+            (base as *mut Elf32Fhdr).write(fhdr);
+            ptr::copy_nonoverlapping(
+                phdrs.as_ptr(),
+                base.add(phoff) as *mut Elf32Phdr,
+                phdrs.len(),
+            );
+        }
+
+        image
+    }
+
+    /// Builds a synthetic ELF64 image of `size` bytes with the given program headers.
+    fn new64(phdrs: &[Elf64Phdr], size: usize) -> Self {
+        let phoff: usize = size_of::<Elf64Fhdr>();
+        let size: usize = size.max(phoff + phdrs.len() * size_of::<Elf64Phdr>());
+        let mut image: Image = Self::alloc(size);
+
+        let fhdr: Elf64Fhdr = Elf64Fhdr {
+            e_ident: Self::ident(ELFCLASS64),
+            e_type: ET_EXEC,
+            e_machine: EM_X86_64,
+            e_version: EV_CURRENT,
+            e_entry: config::memory_layout::USER_BASE.into_raw_value() as u64,
+            e_phoff: phoff as u64,
+            e_shoff: 0,
+            e_flags: 0,
+            e_ehsize: size_of::<Elf64Fhdr>() as u16,
+            e_phentsize: size_of::<Elf64Phdr>() as u16,
+            e_phnum: phdrs.len() as u16,
+            e_shentsize: 0,
+            e_shnum: 0,
+            e_shstrndx: 0,
+        };
+
+        // Safety: the image is large enough and suitably aligned to hold the headers.
+        unsafe {
+            let base: *mut u8 = image.storage.as_mut_ptr().add(image.offset);
+            (base as *mut Elf64Fhdr).write(fhdr);
+            ptr::copy_nonoverlapping(
+                phdrs.as_ptr(),
+                base.add(phoff) as *mut Elf64Phdr,
+                phdrs.len(),
+            );
+        }
+
+        image
+    }
+
+    /// Returns the size of the target image.
+    pub(crate) fn len(&self) -> usize {
+        self.storage.len() - self.offset
+    }
+
+    /// Returns the file header of the target image.
+    pub(crate) fn header(&self) -> &Elf32Fhdr {
+        // Safety: the image starts with a file header.
+        unsafe { &*(self.storage.as_ptr().add(self.offset) as *const Elf32Fhdr) }
+    }
+
+    /// Returns the file header of the target image, interpreted as an ELF64 header.
+    fn header64(&self) -> &Elf64Fhdr {
+        // Safety: the image is large enough and suitably aligned to hold a 64-bit file header.
+        unsafe { &*(self.storage.as_ptr().add(self.offset) as *const Elf64Fhdr) }
+    }
+
+    /// Writes `bytes` at offset `offset` of the target image.
+    fn write(&mut self, offset: usize, bytes: &[u8]) {
+        let start: usize = self.offset + offset;
+        self.storage[start..start + bytes.len()].copy_from_slice(bytes);
+    }
+
+    /// Returns a mutable reference to the file header of the target image.
+    fn header_mut(&mut self) -> &mut Elf32Fhdr {
+        // Safety: the image starts with a file header.
+        unsafe { &mut *(self.storage.as_mut_ptr().add(self.offset) as *mut Elf32Fhdr) }
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if the dry run of the ELF loader reports the number of pages required by a binary.
+fn test_elf32_npages() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let phdrs: [Elf32Phdr; 3] = [
+        load_segment(base, 0x1800, 0x1800, PF_R | PF_X),
+        load_segment(base + 0x4000, 0x0200, 0x0200, PF_R),
+        load_segment(base + 0x8000, 0x0100, 0x2100, PF_R | PF_W),
+    ];
+
+    // Compute the expected number of pages.
+    let expected: usize = phdrs
+        .iter()
+        .map(|phdr| (phdr.p_memsz as usize).div_ceil(mem::PAGE_SIZE))
+        .sum();
+
+    let image: Image = Image::new(&phdrs, 2 * mem::PAGE_SIZE);
+
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Ok(loaded) if loaded.npages() == expected => true,
+        Ok(loaded) => {
+            error!("unexpected number of pages (expected={}, got={})", expected, loaded.npages());
+            false
+        },
+        Err(e) => {
+            error!("failed to load elf (error={:?})", e);
+            false
+        },
+    }
+}
+
+/// Tests if the ELF loader reports the loadable segments and the end of the highest one.
+fn test_elf32_load_info() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let note: Vec<u8> = capabilities_note(0);
+    let offset: usize = mem::PAGE_SIZE / 2;
+    let phdrs: [Elf32Phdr; 3] = [
+        load_segment(base + 0x8000, 0x0100, 0x2100, PF_R | PF_W),
+        note_segment(offset, note.len()),
+        load_segment(base, 0x1800, 0x1800, PF_R | PF_X),
+    ];
+
+    let mut image: Image = Image::new(&phdrs, 2 * mem::PAGE_SIZE);
+    image.write(offset, &note);
+    let loaded: LoadedElf = match elf::do_elf_load(None, image.header(), image.len()) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            error!("failed to load elf (error={:?})", e);
+            return false;
+        },
+    };
+
+    // Check if only loadable segments were counted.
+    if loaded.segments() != 2 {
+        error!("unexpected number of segments (expected=2, got={})", loaded.segments());
+        return false;
+    }
+
+    // Check if the end of the highest segment is reported, regardless of the order of segments.
+    let expected: usize = base + 0xb000;
+    if loaded.highest_vaddr().into_raw_value() != expected {
+        error!(
+            "unexpected highest address (expected={:#x}, got={:?})",
+            expected,
+            loaded.highest_vaddr()
+        );
+        return false;
+    }
+
+    true
+}
+
+/// Tests if a corrupted ELF is rejected with the error that describes the corruption.
+fn test_elf32_load_corrupted() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let phdrs: [Elf32Phdr; 1] = [load_segment(base, 0x100, 0x100, PF_R | PF_X)];
+
+    // Corrupt the magic number.
+    let mut image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    image.header_mut().e_ident[0] = 0;
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Err(e) if e.code == ErrorCode::BadFile => {},
+        Err(e) => {
+            error!("unexpected error for bad magic (error={:?})", e);
+            return false;
+        },
+        Ok(loaded) => {
+            error!("bad magic was not rejected (entry={:?})", loaded.entry());
+            return false;
+        },
+    }
+
+    // Corrupt a segment, so that its file size exceeds its memory size.
+    let phdrs: [Elf32Phdr; 1] = [load_segment(base, 0x200, 0x100, PF_R | PF_X)];
+    let image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Err(e) if e.code == ErrorCode::BadFile => true,
+        Err(e) => {
+            error!("unexpected error for bad segment (error={:?})", e);
+            false
+        },
+        Ok(loaded) => {
+            error!("bad segment was not rejected (entry={:?})", loaded.entry());
+            false
+        },
+    }
+}
+
+/// Tests if a binary with more segments than the loader processes is rejected.
+fn test_elf32_too_many_segments() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let phdrs: Vec<Elf32Phdr> = (0..=MAX_SEGMENTS)
+        .map(|_| load_segment(base, 0x100, 0x100, PF_R | PF_X))
+        .collect();
+
+    let image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Err(e) if e.code == ErrorCode::BadFile && e.reason == "too many segments" => true,
+        Err(e) => {
+            error!("unexpected error for too many segments (error={:?})", e);
+            false
+        },
+        Ok(loaded) => {
+            error!("too many segments were not rejected (npages={})", loaded.npages());
+            false
+        },
+    }
+}
+
+/// Tests if the capabilities declared in a note are reported by the ELF loader.
+fn test_elf32_capabilities_note() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let requested: u32 = 1 << Capability::InterruptControl as u32;
+    let note: Vec<u8> = capabilities_note(requested);
+    let offset: usize = mem::PAGE_SIZE / 2;
+    let phdrs: [Elf32Phdr; 2] = [
+        load_segment(base, 0x100, 0x100, PF_R | PF_X),
+        note_segment(offset, note.len()),
+    ];
+
+    let mut image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    image.write(offset, &note);
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Ok(loaded) if loaded.capabilities() == requested => {},
+        Ok(loaded) => {
+            error!(
+                "unexpected capabilities (expected={:#x}, got={:#x})",
+                requested,
+                loaded.capabilities()
+            );
+            return false;
+        },
+        Err(e) => {
+            error!("failed to load elf (error={:?})", e);
+            return false;
+        },
+    }
+
+    // Truncate the note and check if it is rejected.
+    let phdrs: [Elf32Phdr; 2] = [
+        load_segment(base, 0x100, 0x100, PF_R | PF_X),
+        note_segment(offset, note.len() - 1),
+    ];
+    let mut image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    image.write(offset, &note);
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Err(e) if e.code == ErrorCode::BadFile => true,
+        Err(e) => {
+            error!("unexpected error for truncated note (error={:?})", e);
+            false
+        },
+        Ok(loaded) => {
+            error!("truncated note was not rejected (entry={:?})", loaded.entry());
+            false
+        },
+    }
+}
+
+/// Tests if malformed notes are rejected instead of being read out of bounds.
+fn test_elf32_malformed_note() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let note: Vec<u8> = capabilities_note(1 << Capability::InterruptControl as u32);
+    let offset: usize = mem::PAGE_SIZE / 2;
+
+    // Check if a note segment that runs past the end of the image is rejected.
+    let phdrs: [Elf32Phdr; 2] = [
+        load_segment(base, 0x100, 0x100, PF_R | PF_X),
+        note_segment(offset, 2 * mem::PAGE_SIZE),
+    ];
+    let mut image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    image.write(offset, &note);
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Err(e) if e.code == ErrorCode::BadFile => {},
+        Err(e) => {
+            error!("unexpected error for note past image (error={:?})", e);
+            return false;
+        },
+        Ok(loaded) => {
+            error!("note past image was not rejected (entry={:?})", loaded.entry());
+            return false;
+        },
+    }
+
+    // Check if a note whose name size overflows when aligned is rejected.
+    let mut bad_note: Vec<u8> = note.clone();
+    bad_note[0..size_of::<u32>()].copy_from_slice(&u32::MAX.to_le_bytes());
+    let phdrs: [Elf32Phdr; 2] = [
+        load_segment(base, 0x100, 0x100, PF_R | PF_X),
+        note_segment(offset, note.len()),
+    ];
+    let mut image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    image.write(offset, &bad_note);
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Err(e) if e.code == ErrorCode::BadFile => true,
+        Err(e) => {
+            error!("unexpected error for oversized note name (error={:?})", e);
+            false
+        },
+        Ok(loaded) => {
+            error!("oversized note name was not rejected (entry={:?})", loaded.entry());
+            false
+        },
+    }
+}
+
+/// Tests if the dry run of the ELF loader accepts an ELF64 binary and rejects an ELF32 one.
+fn test_elf64_load() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let phdrs: [Elf64Phdr; 2] = [
+        load_segment64(base, 0x1800, 0x1800, PF_R | PF_X),
+        load_segment64(base + 0x4000, 0x0100, 0x2100, PF_R | PF_W),
+    ];
+
+    // Compute the expected number of pages.
+    let expected: usize = phdrs
+        .iter()
+        .map(|phdr| (phdr.p_memsz as usize).div_ceil(mem::PAGE_SIZE))
+        .sum();
+
+    let image: Image = Image::new64(&phdrs, 2 * mem::PAGE_SIZE);
+    match elf::do_elf_load(None, image.header64(), image.len()) {
+        Ok(loaded) if loaded.npages() == expected => {},
+        Ok(loaded) => {
+            error!("unexpected number of pages (expected={}, got={})", expected, loaded.npages());
+            return false;
+        },
+        Err(e) => {
+            error!("failed to load elf64 (error={:?})", e);
+            return false;
+        },
+    }
+
+    // Check if an ELF32 binary is rejected by the ELF64 loader.
+    let phdrs: [Elf32Phdr; 1] = [load_segment(base, 0x100, 0x100, PF_R | PF_X)];
+    let image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    match elf::do_elf_load(None, image.header64(), image.len()) {
+        Err(e) if e.code == ErrorCode::BadFile => true,
+        Err(e) => {
+            error!("unexpected error for elf32 binary (error={:?})", e);
+            false
+        },
+        Ok(loaded) => {
+            error!("elf32 binary was not rejected (entry={:?})", loaded.entry());
+            false
+        },
+    }
+}
+
+/// Tests if a program header table that does not fit in the image is rejected.
+fn test_elf32_phdrs_out_of_bounds() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let phdrs: [Elf32Phdr; 1] = [load_segment(base, 0x100, 0x100, PF_R | PF_X)];
+
+    // Point the program header table past the end of the image.
+    let mut image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    image.header_mut().e_phoff = image.len() as u32;
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Err(e) if e.code == ErrorCode::BadFile => {},
+        Err(e) => {
+            error!("unexpected error for bad program header offset (error={:?})", e);
+            return false;
+        },
+        Ok(loaded) => {
+            error!("bad program header offset was not rejected (entry={:?})", loaded.entry());
+            return false;
+        },
+    }
+
+    // Corrupt the size of program headers.
+    let mut image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    image.header_mut().e_phentsize += 1;
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Err(e) if e.code == ErrorCode::BadFile => true,
+        Err(e) => {
+            error!("unexpected error for bad program header size (error={:?})", e);
+            false
+        },
+        Ok(loaded) => {
+            error!("bad program header size was not rejected (entry={:?})", loaded.entry());
+            false
+        },
+    }
+}
+
+/// Tests if a loadable segment whose file image does not fit in the image is rejected.
+fn test_elf32_segment_out_of_bounds() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+
+    // Segments that start past the end of the image, end past the end of the image and whose end
+    // overflows, respectively.
+    let segments: [(u32, usize); 3] = [
+        (mem::PAGE_SIZE as u32, 0x100),
+        (0, mem::PAGE_SIZE + 1),
+        (u32::MAX, 0x100),
+    ];
+
+    for (offset, filesz) in segments {
+        let mut phdr: Elf32Phdr = load_segment(base, filesz, 2 * mem::PAGE_SIZE, PF_R | PF_X);
+        phdr.p_offset = offset;
+        let image: Image = Image::new(&[phdr], mem::PAGE_SIZE);
+        match elf::do_elf_load(None, image.header(), image.len()) {
+            Err(e) if e.code == ErrorCode::BadFile => {},
+            Err(e) => {
+                error!(
+                    "unexpected error (offset={:#x}, filesz={:#x}, error={:?})",
+                    offset, filesz, e
+                );
+                return false;
+            },
+            Ok(loaded) => {
+                error!(
+                    "segment was not rejected (offset={:#x}, filesz={:#x}, entry={:?})",
+                    offset,
+                    filesz,
+                    loaded.entry()
+                );
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Tests if a binary whose loadable segments share a page is rejected.
+fn test_elf32_overlapping_segments() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+
+    // Stack a writable segment on the last page of an executable one.
+    let phdrs: [Elf32Phdr; 2] = [
+        load_segment(base, 0x1800, 0x1800, PF_R | PF_X),
+        load_segment(base + 0x1000, 0x100, 0x100, PF_R | PF_W),
+    ];
+
+    let image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Err(e) if e.code == ErrorCode::BadFile && e.reason == "overlapping segments" => true,
+        Err(e) => {
+            error!("unexpected error for overlapping segments (error={:?})", e);
+            false
+        },
+        Ok(loaded) => {
+            error!("overlapping segments were not rejected (npages={})", loaded.npages());
+            false
+        },
+    }
+}
+
+/// Tests if segment flags are mapped to the exact access permissions that they describe.
+fn test_segment_access() -> bool {
+    // Check if every readable combination is mapped precisely.
+    for flags in [PF_R, PF_R | PF_W, PF_R | PF_X, PF_R | PF_W | PF_X] {
+        match elf::segment_access(flags) {
+            Ok(access)
+                if access.is_readable()
+                    && access.is_writable() == (flags & PF_W != 0)
+                    && access.is_executable() == (flags & PF_X != 0) => {},
+            Ok(access) => {
+                error!("unexpected access permissions (flags={:#x}, access={:?})", flags, access);
+                return false;
+            },
+            Err(e) => {
+                error!("failed to compute access permissions (flags={:#x}, error={:?})", flags, e);
+                return false;
+            },
+        }
+    }
+
+    // Check if segments that are not readable or that have unknown flags are rejected.
+    for flags in [PF_W, PF_X, PF_W | PF_X, PF_R | (1 << 3)] {
+        match elf::segment_access(flags) {
+            Err(e) if e.code == ErrorCode::BadFile => {},
+            Err(e) => {
+                error!("unexpected error (flags={:#x}, error={:?})", flags, e);
+                return false;
+            },
+            Ok(access) => {
+                error!("invalid flags were not rejected (flags={:#x}, access={:?})", flags, access);
+                return false;
+            },
+        }
+    }
+
+    // Check if a binary with a write-only segment is rejected.
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let phdrs: [Elf32Phdr; 1] = [load_segment(base, 0x100, 0x100, PF_W)];
+    let image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Err(e) if e.code == ErrorCode::BadFile => true,
+        Err(e) => {
+            error!("unexpected error for write-only segment (error={:?})", e);
+            false
+        },
+        Ok(loaded) => {
+            error!("write-only segment was not rejected (entry={:?})", loaded.entry());
+            false
+        },
+    }
+}
+
+/// Tests if a binary that requests an interpreter is rejected.
+fn test_elf32_interp() -> bool {
+    let base: usize = config::memory_layout::USER_BASE.into_raw_value();
+    let interp: &[u8] = b"/lib/ld.so\0";
+    let offset: usize = mem::PAGE_SIZE / 2;
+    let phdrs: [Elf32Phdr; 2] = [
+        Elf32Phdr {
+            p_type: PT_INTERP,
+            p_offset: offset as u32,
+            p_vaddr: 0,
+            p_paddr: 0,
+            p_filesz: interp.len() as u32,
+            p_memsz: interp.len() as u32,
+            p_flags: PF_R,
+            p_align: 1,
+        },
+        load_segment(base, 0x100, 0x100, PF_R | PF_X),
+    ];
+
+    let mut image: Image = Image::new(&phdrs, mem::PAGE_SIZE);
+    image.write(offset, interp);
+    match elf::do_elf_load(None, image.header(), image.len()) {
+        Err(e) if e.code == ErrorCode::OperationNotSupported => true,
+        Err(e) => {
+            error!("unexpected error for interpreter (error={:?})", e);
+            false
+        },
+        Ok(loaded) => {
+            error!("interpreter was not rejected (entry={:?})", loaded.entry());
+            false
+        },
+    }
+}
+
+/// Tests if segments whose file size is not a multiple of the page size are fully copied.
+fn test_copy_segment_partial_page() -> bool {
+    let filesz: usize = mem::PAGE_SIZE + 0x123;
+
+    // Fill source with a recognizable pattern and clear destination.
+    let src: Vec<u8> = (0..filesz).map(|i| (i % 251) as u8 + 1).collect();
+    let mut dst: Vec<u8> = Vec::new();
+    dst.resize(2 * mem::PAGE_SIZE, 0);
+
+    let mut copied: usize = 0;
+    if let Err(e) = elf::copy_segment(filesz, |offset, size| {
+        dst[offset..offset + size].copy_from_slice(&src[offset..offset + size]);
+        copied += size;
+        Ok(())
+    }) {
+        error!("failed to copy segment (error={:?})", e);
+        return false;
+    }
+
+    // Check if exactly the file image of the segment was copied.
+    if copied != filesz {
+        error!("unexpected number of bytes copied (expected={}, got={})", filesz, copied);
+        return false;
+    }
+
+    // Check if the final bytes of the segment are present.
+    if dst[..filesz] != src[..] {
+        error!("final bytes of segment are missing");
+        return false;
+    }
+
+    // Check if nothing was copied past the end of the segment.
+    if dst[filesz..].iter().any(|b| *b != 0) {
+        error!("bytes copied past the end of segment");
+        return false;
+    }
+
+    true
+}
+
+/// Tests if the tail of a segment past its file image is zeroed without crossing page boundaries.
+fn test_zero_segment_bss() -> bool {
+    let filesz: usize = mem::PAGE_SIZE - 0x10;
+    let memsz: usize = 2 * mem::PAGE_SIZE + 0x20;
+
+    // Fill destination with stale data.
+    let mut dst: Vec<u8> = Vec::new();
+    dst.resize(3 * mem::PAGE_SIZE, 0xff);
+
+    if let Err(e) = elf::zero_segment(filesz, memsz, |offset, size| {
+        // Check if the range crosses a page boundary.
+        if offset / mem::PAGE_SIZE != (offset + size - 1) / mem::PAGE_SIZE {
+            error!("range crosses page boundary (offset={:#x}, size={:#x})", offset, size);
+            return Err(Error::new(ErrorCode::InvalidArgument, "range crosses page boundary"));
+        }
+        dst[offset..offset + size].fill(0);
+        Ok(())
+    }) {
+        error!("failed to zero segment (error={:?})", e);
+        return false;
+    }
+
+    // Check if the file image was left untouched.
+    if dst[..filesz].iter().any(|b| *b != 0xff) {
+        error!("file image of segment was modified");
+        return false;
+    }
+
+    // Check if the BSS was zeroed.
+    if dst[filesz..memsz].iter().any(|b| *b != 0) {
+        error!("bss of segment was not zeroed");
+        return false;
+    }
+
+    // Check if nothing was zeroed past the end of the segment.
+    if dst[memsz..].iter().any(|b| *b != 0xff) {
+        error!("bytes zeroed past the end of segment");
+        return false;
+    }
+
+    true
+}
+
+/// Runs all unit tests for the ELF loader.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_elf32_npages);
+    passed &= run_test!(test_elf32_load_info);
+    passed &= run_test!(test_elf32_load_corrupted);
+    passed &= run_test!(test_elf32_too_many_segments);
+    passed &= run_test!(test_elf32_capabilities_note);
+    passed &= run_test!(test_elf32_malformed_note);
+    passed &= run_test!(test_elf64_load);
+    passed &= run_test!(test_elf32_phdrs_out_of_bounds);
+    passed &= run_test!(test_elf32_segment_out_of_bounds);
+    passed &= run_test!(test_elf32_overlapping_segments);
+    passed &= run_test!(test_segment_access);
+    passed &= run_test!(test_elf32_interp);
+    passed &= run_test!(test_copy_segment_partial_page);
+    passed &= run_test!(test_zero_segment_bss);
+
+    passed
+}
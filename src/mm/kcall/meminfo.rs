@@ -0,0 +1,36 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    kcall::{
+        args::{
+            user_mut,
+            UserMut,
+        },
+        KcallArgs,
+    },
+    mm::VirtMemoryManager,
+};
+use ::sys::mm::MemInfo;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+pub fn meminfo(mm: &VirtMemoryManager, args: &KcallArgs) -> i32 {
+    trace!("meminfo(): pid={:?}, info={:?}", args.pid, args.arg0);
+
+    let info: UserMut<MemInfo> = match user_mut(args.pid, args.arg0 as usize) {
+        Ok(info) => info,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match info.write(&mm.meminfo()) {
+        Ok(()) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
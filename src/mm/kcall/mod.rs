@@ -0,0 +1,14 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+mod meminfo;
+
+//==================================================================================================
+// Exports
+//==================================================================================================
+
+pub use meminfo::meminfo;
@@ -68,6 +68,8 @@ struct Kheap {
     slab_256_bytes: Slab,
     slab_512_bytes: Slab,
     slab_4096_bytes: Slab,
+    /// Number of bytes in use.
+    usage: usize,
 }
 
 //==================================================================================================
@@ -152,11 +154,13 @@ impl Kheap {
                 slab_size,
                 SlabSize::Slab4096 as usize,
             )?,
+            usage: 0,
         })
     }
 
     unsafe fn allocate(&mut self, layout: Layout) -> Result<*mut u8, AllocError> {
-        match Kheap::layout_to_allocator(&layout)? {
+        let slab_size: SlabSize = Kheap::layout_to_allocator(&layout)?;
+        let ptr: *mut u8 = match slab_size {
             SlabSize::Slab8 => self.slab_8_bytes.allocate().map_err(|_| AllocError),
             SlabSize::Slab16 => self.slab_16_bytes.allocate().map_err(|_| AllocError),
             SlabSize::Slab32 => self.slab_32_bytes.allocate().map_err(|_| AllocError),
@@ -165,11 +169,14 @@ impl Kheap {
             SlabSize::Slab256 => self.slab_256_bytes.allocate().map_err(|_| AllocError),
             SlabSize::Slab512 => self.slab_512_bytes.allocate().map_err(|_| AllocError),
             SlabSize::Slab4096 => self.slab_4096_bytes.allocate().map_err(|_| AllocError),
-        }
+        }?;
+        self.usage += slab_size as usize;
+        Ok(ptr)
     }
 
     unsafe fn deallocate(&mut self, ptr: *mut u8, layout: Layout) -> Result<(), AllocError> {
-        match Kheap::layout_to_allocator(&layout)? {
+        let slab_size: SlabSize = Kheap::layout_to_allocator(&layout)?;
+        match slab_size {
             SlabSize::Slab8 => self.slab_8_bytes.deallocate(ptr).map_err(|_| AllocError),
             SlabSize::Slab16 => self.slab_16_bytes.deallocate(ptr).map_err(|_| AllocError),
             SlabSize::Slab32 => self.slab_32_bytes.deallocate(ptr).map_err(|_| AllocError),
@@ -178,7 +185,9 @@ impl Kheap {
             SlabSize::Slab256 => self.slab_256_bytes.deallocate(ptr).map_err(|_| AllocError),
             SlabSize::Slab512 => self.slab_512_bytes.deallocate(ptr).map_err(|_| AllocError),
             SlabSize::Slab4096 => self.slab_4096_bytes.deallocate(ptr).map_err(|_| AllocError),
-        }
+        }?;
+        self.usage -= slab_size as usize;
+        Ok(())
     }
 
     pub fn layout_to_allocator(layout: &Layout) -> Result<SlabSize, AllocError> {
@@ -237,3 +246,20 @@ pub unsafe fn init() -> Result<(), Error> {
 
     Ok(())
 }
+
+///
+/// # Description
+///
+/// Returns the number of bytes in use in the kernel heap.
+///
+/// # Returns
+///
+/// The number of bytes in use in the kernel heap.
+///
+pub fn usage() -> usize {
+    let heap: *const Option<Kheap> = unsafe { ptr::addr_of!(HEAP) };
+    match unsafe { &*heap } {
+        Some(heap) => heap.usage,
+        None => 0,
+    }
+}
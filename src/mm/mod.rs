@@ -6,6 +6,7 @@
 //==================================================================================================
 
 pub mod elf;
+mod kcall;
 mod phys;
 mod virt;
 
@@ -14,6 +15,7 @@ mod virt;
 //==================================================================================================
 
 pub mod kheap;
+pub use kcall::*;
 pub use virt::{
     KernelPage,
     VirtMemoryManager,
@@ -196,7 +198,6 @@ pub fn init(
 
     let physman: PhysMemoryManager = phys::init(
         TruncatedMemoryRegion::from_virtual_memory_region(kimage.kpool())?,
-        physical_memory_regions,
         &mmio_regions,
     )?;
 
@@ -209,6 +210,16 @@ pub fn init(
     let (mut vmem, mut mm): (Vmem, VirtMemoryManager) =
         VirtMemoryManager::new(kernel_pages, kernel_page_tables, physman)?;
 
+    // Reserve physical memory that is not usable, such as firmware tables and the kernel image.
+    info!("reserving physical memory regions ...");
+    for region in physical_memory_regions.iter() {
+        info!("reserving: {:?}", region);
+        let start: PhysicalAddress = region.start().into_inner();
+        let end: PhysicalAddress =
+            PhysicalAddress::from_raw_value(start.into_raw_value() + region.size())?;
+        mm.reserve_physical(start..end, &region.name())?;
+    }
+
     // Map virtual memory regions that lie outside the physical memory.
     while let Some(region) = other_virtual_memory_regions.pop_front() {
         info!("mapping: {:?}", region);
@@ -234,3 +245,13 @@ pub fn init(
 pub fn user_stack_top() -> PageAligned<VirtualAddress> {
     PageAligned::from_address(config::memory_layout::USER_STACK_BASE).unwrap()
 }
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= phys::test();
+    passed &= virt::test();
+
+    passed
+}
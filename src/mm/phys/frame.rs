@@ -11,16 +11,18 @@ use crate::{
         RawArray,
     },
     hal::mem::{
+        Address,
         FrameAddress,
         PageAligned,
         PhysicalAddress,
-        TruncatedMemoryRegion,
     },
 };
+use ::alloc::vec::Vec;
 use ::arch::mem::{
     self,
     paging::FrameNumber,
 };
+use ::core::ops::Range;
 use ::sys::{
     constants,
     error::{
@@ -42,6 +44,8 @@ use ::sys::{
 pub struct FrameAllocator {
     /// A bitmap that keeps track of free/used frames.
     bitmap: Bitmap,
+    /// Ranges of frame numbers that are permanently unavailable.
+    reserved: Vec<Range<usize>>,
 }
 
 //==================================================================================================
@@ -59,7 +63,10 @@ impl FrameAllocator {
     /// - `bitmap`: A bitmap to keeps track of free/used frames.
     ///
     pub fn new(bitmap: Bitmap) -> Self {
-        let frame_allocator: FrameAllocator = Self { bitmap };
+        let frame_allocator: FrameAllocator = Self {
+            bitmap,
+            reserved: Vec::new(),
+        };
 
         info!(
             "frame allocator capacity: {} frames, {} MB",
@@ -74,6 +81,19 @@ impl FrameAllocator {
         Ok(Self::new(Bitmap::from_raw_array(storage)))
     }
 
+    ///
+    /// # Description
+    ///
+    /// Returns the number of free frames.
+    ///
+    /// # Returns
+    ///
+    /// The number of free frames.
+    ///
+    pub fn number_of_free_frames(&self) -> usize {
+        self.bitmap.number_of_bits() - self.bitmap.usage()
+    }
+
     ///
     /// # Description
     ///
@@ -112,6 +132,12 @@ impl FrameAllocator {
     ///
     pub fn free(&mut self, frame: FrameAddress) -> Result<(), Error> {
         let frame_number: usize = frame.into_frame_number().into_raw_value();
+
+        // Check if the frame is reserved.
+        if self.is_reserved(frame_number) {
+            return Err(Error::new(ErrorCode::InvalidArgument, "frame is reserved"));
+        }
+
         self.bitmap.clear(frame_number)
     }
 
@@ -137,43 +163,83 @@ impl FrameAllocator {
     ///
     /// # Description
     ///
-    /// Allocates all frames in the range `[start, end]`.
+    /// Reserves all frames that overlap a range of physical addresses, so that they are never
+    /// handed out nor freed.
     ///
     /// # Parameters
     ///
-    /// - `start`: Start page frame address.
-    /// - `end`: End page frame address.
+    /// - `range`: Range of physical addresses to reserve.
+    /// - `reason`: Why the range is reserved.
     ///
     /// # Returns
     ///
     /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
     ///
-    pub fn alloc_range(
-        &mut self,
-        region: &TruncatedMemoryRegion<PhysicalAddress>,
-    ) -> Result<(), Error> {
-        let start_frame_number: usize = region.start().into_frame_number().into_raw_value();
-        let end_frame_number: usize = start_frame_number + region.size() / mem::FRAME_SIZE - 1;
+    pub fn reserve(&mut self, range: Range<PhysicalAddress>, reason: &str) -> Result<(), Error> {
+        let start_frame_number: usize = range.start.into_raw_value() / mem::FRAME_SIZE;
+        let end_frame_number: usize = range.end.into_raw_value().div_ceil(mem::FRAME_SIZE);
+        self.reserve_frames(start_frame_number..end_frame_number, reason)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Reserves all frames in a range of frame numbers.
+    ///
+    /// # Parameters
+    ///
+    /// - `frames`: Range of frame numbers to reserve.
+    /// - `reason`: Why the range is reserved.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    fn reserve_frames(&mut self, frames: Range<usize>, reason: &str) -> Result<(), Error> {
+        if frames.is_empty() {
+            return Err(Error::new(ErrorCode::InvalidArgument, "empty range"));
+        }
 
         // Check if all frames in the range are free.
-        for index in start_frame_number..=end_frame_number {
-            match self.bitmap.test(index) {
-                Ok(false) => continue,
-                Ok(true) => {
-                    return Err(Error::new(ErrorCode::OutOfMemory, "frame is already allocated"));
-                },
-                Err(err) => return Err(err),
+        for index in frames.clone() {
+            if self.is_reserved(index) {
+                return Err(Error::new(ErrorCode::ResourceBusy, "frame is already reserved"));
+            }
+            if self.bitmap.test(index)? {
+                return Err(Error::new(ErrorCode::ResourceBusy, "frame is already allocated"));
             }
         }
 
         // Book all frames in the range.
-        for index in start_frame_number..=end_frame_number {
+        for index in frames.clone() {
             if let Err(e) = self.bitmap.set(index) {
                 warn!("fail to book frame {}, but we should not", index);
                 return Err(e);
             }
         }
 
+        info!("reserved frames {:?} ({})", frames, reason);
+        self.reserved.push(frames);
+
         Ok(())
     }
+
+    ///
+    /// # Description
+    ///
+    /// Checks whether a frame is reserved.
+    ///
+    /// # Parameters
+    ///
+    /// - `frame_number`: Number of the frame.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the frame is reserved and `false` otherwise.
+    ///
+    fn is_reserved(&self, frame_number: usize) -> bool {
+        self.reserved
+            .iter()
+            .any(|frames| frames.contains(&frame_number))
+    }
 }
@@ -222,4 +222,17 @@ impl Kpool {
 
         Ok(kpages)
     }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of frames in use in the kernel frame pool.
+    ///
+    /// # Return Values
+    ///
+    /// The number of frames in use in the kernel frame pool.
+    ///
+    pub fn number_of_used_frames(&self) -> usize {
+        self.inner.borrow().bitmap.usage()
+    }
 }
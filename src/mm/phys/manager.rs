@@ -5,17 +5,21 @@
 // Imports
 //==================================================================================================
 
-use crate::mm::phys::{
-    kpool::{
-        KernelFrame,
-        Kpool,
-    },
-    upool::{
-        Upool,
-        UserFrame,
+use crate::{
+    hal::mem::PhysicalAddress,
+    mm::phys::{
+        kpool::{
+            KernelFrame,
+            Kpool,
+        },
+        upool::{
+            Upool,
+            UserFrame,
+        },
     },
 };
 use ::alloc::vec::Vec;
+use ::core::ops::Range;
 use ::sys::error::Error;
 
 //==================================================================================================
@@ -79,4 +83,48 @@ impl PhysMemoryManager {
     ) -> Result<Vec<KernelFrame>, Error> {
         self.kpool.alloc_many(clear, count)
     }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of free user frames.
+    ///
+    /// # Return Values
+    ///
+    /// The number of free user frames.
+    ///
+    pub fn number_of_free_user_frames(&self) -> usize {
+        self.upool.number_of_free_frames()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of kernel frames in use.
+    ///
+    /// # Return Values
+    ///
+    /// The number of kernel frames in use.
+    ///
+    pub fn number_of_used_kernel_frames(&self) -> usize {
+        self.kpool.number_of_used_frames()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Permanently reserves all frames that overlap a range of physical addresses.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: Range of physical addresses to reserve.
+    /// - `reason`: Why the range is reserved.
+    ///
+    /// # Return Values
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn reserve(&mut self, range: Range<PhysicalAddress>, reason: &str) -> Result<(), Error> {
+        self.upool.reserve(range, reason)
+    }
 }
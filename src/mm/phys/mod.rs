@@ -10,6 +10,9 @@ mod kpool;
 mod manager;
 mod upool;
 
+#[cfg(test)]
+mod test;
+
 //==================================================================================================
 // Imports
 //==================================================================================================
@@ -64,21 +67,6 @@ static mut FRAME_ALLOCATOR_STORAGE: [u8; config::kernel::MEMORY_SIZE
 // Standalone Functions
 //==================================================================================================
 
-fn book_physical_memory_regions(
-    frame_allocator: &mut FrameAllocator,
-    physical_memory_regions: LinkedList<TruncatedMemoryRegion<PhysicalAddress>>,
-) -> Result<(), Error> {
-    info!("booking physical memory regions ...");
-
-    // Book physical memory that is not usable.
-    for region in physical_memory_regions.iter() {
-        info!("booking: {:?}", region);
-        frame_allocator.alloc_range(region)?;
-    }
-
-    Ok(())
-}
-
 fn book_mmio_regions(
     frame_allocator: &mut FrameAllocator,
     mmio_regions: &LinkedList<TruncatedMemoryRegion<VirtualAddress>>,
@@ -117,7 +105,6 @@ fn book_mmio_regions(
 
 pub fn init(
     kpool: TruncatedMemoryRegion<PhysicalAddress>,
-    physical_memory_regions: LinkedList<TruncatedMemoryRegion<PhysicalAddress>>,
     mmio_regions: &LinkedList<TruncatedMemoryRegion<VirtualAddress>>,
 ) -> Result<PhysMemoryManager, Error> {
     // Initialize frame allocator.
@@ -131,8 +118,6 @@ pub fn init(
         };
         FrameAllocator::from_raw_storage(storage)?
     };
-    book_physical_memory_regions(&mut frame_allocator, physical_memory_regions)?;
-
     book_mmio_regions(&mut frame_allocator, mmio_regions)?;
 
     // Initialize kernel page pool.
@@ -145,3 +130,12 @@ pub fn init(
 
     Ok(PhysMemoryManager::new(kpool, upool))
 }
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
@@ -0,0 +1,135 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::Bitmap,
+    hal::mem::{
+        Address,
+        FrameAddress,
+        PhysicalAddress,
+    },
+    mm::phys::frame::FrameAllocator,
+};
+use ::alloc::vec::Vec;
+use ::arch::mem;
+use ::core::ops::Range;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if allocating and freeing frames is reflected in the number of free frames.
+fn test_free_frames_accounting() -> bool {
+    const NFRAMES: usize = 8;
+
+    let bitmap: Bitmap = match Bitmap::new(4) {
+        Ok(bitmap) => bitmap,
+        Err(_) => return false,
+    };
+    let mut frame_allocator: FrameAllocator = FrameAllocator::new(bitmap);
+
+    let before: usize = frame_allocator.number_of_free_frames();
+
+    let mut frames: Vec<FrameAddress> = Vec::new();
+    for _ in 0..NFRAMES {
+        match frame_allocator.alloc() {
+            Ok(frame) => frames.push(frame),
+            Err(_) => return false,
+        }
+    }
+
+    // Check if the number of free frames decreased by the number of allocated frames.
+    if frame_allocator.number_of_free_frames() != before - NFRAMES {
+        return false;
+    }
+
+    for frame in frames {
+        if frame_allocator.free(frame).is_err() {
+            return false;
+        }
+    }
+
+    // Check if the number of free frames was restored.
+    frame_allocator.number_of_free_frames() == before
+}
+
+/// Tests if the frame allocator never hands out frames from a reserved range.
+fn test_reserve_physical() -> bool {
+    const RESERVED: Range<usize> = 8..16;
+
+    let bitmap: Bitmap = match Bitmap::new(4) {
+        Ok(bitmap) => bitmap,
+        Err(_) => return false,
+    };
+    let mut frame_allocator: FrameAllocator = FrameAllocator::new(bitmap);
+
+    let range: Range<PhysicalAddress> = match (
+        PhysicalAddress::from_raw_value(RESERVED.start * mem::FRAME_SIZE),
+        PhysicalAddress::from_raw_value(RESERVED.end * mem::FRAME_SIZE),
+    ) {
+        (Ok(start), Ok(end)) => start..end,
+        _ => return false,
+    };
+
+    if frame_allocator.reserve(range.clone(), "test").is_err() {
+        return false;
+    }
+
+    // Check if reserving the same range again fails.
+    if frame_allocator.reserve(range, "test").is_ok() {
+        return false;
+    }
+
+    // Check if freeing a reserved frame fails.
+    match FrameAddress::from_raw_value(RESERVED.start * mem::FRAME_SIZE) {
+        Ok(frame) => {
+            if frame_allocator.free(frame).is_ok() {
+                return false;
+            }
+        },
+        Err(_) => return false,
+    }
+
+    // Exhaust the frame allocator and check that no frame lies within the reserved range.
+    let mut frames: Vec<FrameAddress> = Vec::new();
+    while let Ok(frame) = frame_allocator.alloc() {
+        if RESERVED.contains(&frame.into_frame_number().into_raw_value()) {
+            return false;
+        }
+        frames.push(frame);
+    }
+
+    // Check if reserving an allocated range fails.
+    let allocated: Range<PhysicalAddress> = match (
+        PhysicalAddress::from_raw_value(0),
+        PhysicalAddress::from_raw_value(mem::FRAME_SIZE),
+    ) {
+        (Ok(start), Ok(end)) => start..end,
+        _ => return false,
+    };
+    if frame_allocator.reserve(allocated, "test").is_ok() {
+        return false;
+    }
+
+    for frame in frames {
+        if frame_allocator.free(frame).is_err() {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Runs all unit tests for the physical memory manager.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_free_frames_accounting);
+    passed &= run_test!(test_reserve_physical);
+
+    passed
+}
@@ -6,7 +6,10 @@
 //==================================================================================================
 
 use crate::{
-    hal::mem::FrameAddress,
+    hal::mem::{
+        FrameAddress,
+        PhysicalAddress,
+    },
     mm::phys::frame::FrameAllocator,
 };
 use ::alloc::{
@@ -19,6 +22,7 @@ use ::core::{
     ops::{
         Deref,
         DerefMut,
+        Range,
     },
 };
 use ::sys::error::Error;
@@ -109,6 +113,37 @@ impl UpoolInner {
     fn free(&mut self, page_addr: FrameAddress) -> Result<(), Error> {
         self.frame_allocator.free(page_addr)
     }
+
+    ///
+    /// # Description
+    ///
+    /// Permanently reserves all frames that overlap a range of physical addresses.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: Range of physical addresses to reserve.
+    /// - `reason`: Why the range is reserved.
+    ///
+    /// # Returns
+    ///
+    /// On success, `Ok(())` is returned. On failure, an error is returned.
+    ///
+    fn reserve(&mut self, range: Range<PhysicalAddress>, reason: &str) -> Result<(), Error> {
+        self.frame_allocator.reserve(range, reason)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of free frames in the user frame pool.
+    ///
+    /// # Returns
+    ///
+    /// The number of free frames in the user frame pool.
+    ///
+    fn number_of_free_frames(&self) -> usize {
+        self.frame_allocator.number_of_free_frames()
+    }
 }
 
 //==================================================================================================
@@ -256,4 +291,35 @@ impl Upool {
 
         Ok(upages)
     }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of free frames in the user frame pool.
+    ///
+    /// # Returns
+    ///
+    /// The number of free frames in the user frame pool.
+    ///
+    pub fn number_of_free_frames(&self) -> usize {
+        self.inner.borrow().number_of_free_frames()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Permanently reserves all frames that overlap a range of physical addresses.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: Range of physical addresses to reserve.
+    /// - `reason`: Why the range is reserved.
+    ///
+    /// # Returns
+    ///
+    /// On success, `Ok(())` is returned. On failure, an error is returned.
+    ///
+    pub fn reserve(&mut self, range: Range<PhysicalAddress>, reason: &str) -> Result<(), Error> {
+        self.inner.borrow_mut().reserve(range, reason)
+    }
 }
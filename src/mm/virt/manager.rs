@@ -13,6 +13,7 @@ use crate::{
             Address,
             PageAligned,
             PageTableAddress,
+            PhysicalAddress,
             VirtualAddress,
         },
     },
@@ -20,7 +21,9 @@ use crate::{
         elf::{
             self,
             Elf32Fhdr,
+            LoadedElf,
         },
+        kheap,
         phys::{
             KernelFrame,
             PhysMemoryManager,
@@ -37,7 +40,12 @@ use ::alloc::{
     vec::Vec,
 };
 use ::arch::mem;
-use ::sys::error::Error;
+use ::core::ops::Range;
+use ::sys::{
+    config,
+    error::Error,
+    mm::MemInfo,
+};
 
 //==================================================================================================
 // Structures
@@ -90,6 +98,47 @@ impl VirtMemoryManager {
         Ok(new_vmem)
     }
 
+    ///
+    /// # Description
+    ///
+    /// Gets the state of system memory.
+    ///
+    /// # Returns
+    ///
+    /// The state of system memory.
+    ///
+    pub fn meminfo(&self) -> MemInfo {
+        MemInfo {
+            total: config::kernel::MEMORY_SIZE,
+            free_frames: self.physman.number_of_free_user_frames(),
+            kpool_used: self.physman.number_of_used_kernel_frames(),
+            kheap_used: kheap::usage(),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Marks a range of physical memory as permanently unavailable, so that no frame within it is
+    /// ever allocated.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: Range of physical addresses to reserve.
+    /// - `reason`: Why the range is reserved.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn reserve_physical(
+        &mut self,
+        range: Range<PhysicalAddress>,
+        reason: &str,
+    ) -> Result<(), Error> {
+        self.physman.reserve(range, reason)
+    }
+
     pub fn alloc_upage(
         &mut self,
         vmem: &mut Vmem,
@@ -215,10 +264,35 @@ impl VirtMemoryManager {
         Ok(pages)
     }
 
+    ///
+    /// # Description
+    ///
+    /// Translates a virtual address into a physical address in a target virtual memory space.
+    ///
+    /// # Parameters
+    ///
+    /// - `vmem`: Virtual memory space where the address is mapped.
+    /// - `vaddr`: Virtual address to translate.
+    ///
+    /// # Return Values
+    ///
+    /// Upon success, the corresponding physical address is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    #[allow(dead_code)]
+    pub fn translate(&self, vmem: &Vmem, vaddr: VirtualAddress) -> Result<PhysicalAddress, Error> {
+        vmem.translate(vaddr)
+    }
+
     /// Load an ELF image into a virtual address space.
-    pub fn load_elf(&mut self, vmem: &mut Vmem, elf: &Elf32Fhdr) -> Result<VirtualAddress, Error> {
-        let entry: VirtualAddress = elf::elf32_load(self, vmem, elf)?;
+    pub fn load_elf(
+        &mut self,
+        vmem: &mut Vmem,
+        elf: &Elf32Fhdr,
+        size: usize,
+    ) -> Result<LoadedElf, Error> {
+        let loaded: LoadedElf = elf::elf32_load(self, vmem, elf, size)?;
 
-        Ok(entry)
+        Ok(loaded)
     }
 }
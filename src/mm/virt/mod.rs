@@ -10,6 +10,9 @@ mod manager;
 mod upage;
 mod vmem;
 
+#[cfg(test)]
+mod test;
+
 //==================================================================================================
 // Imports
 //==================================================================================================
@@ -173,3 +176,12 @@ pub fn init(
 
     Ok(root_pagetables)
 }
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
@@ -0,0 +1,122 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    hal::{
+        arch::x86::mem::mmu::page_table::{
+            PageTable,
+            PageTableStorage,
+        },
+        mem::{
+            AccessPermission,
+            Address,
+            FrameAddress,
+            PageAddress,
+            PageAligned,
+            PageTableAddress,
+            PageTableAligned,
+            PhysicalAddress,
+            VirtualAddress,
+        },
+    },
+    mm::virt::Vmem,
+};
+use ::alloc::collections::LinkedList;
+use ::arch::mem;
+use ::sys::error::{
+    Error,
+    ErrorCode,
+};
+
+//==================================================================================================
+// Constants
+//==================================================================================================
+
+/// Kernel virtual address of the page that is mapped by tests.
+const PAGE: usize = 0x00800000;
+
+/// Physical address of the frame that backs the page that is mapped by tests.
+const FRAME: usize = 0x01234000;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Creates a virtual memory space where [`PAGE`] is mapped to [`FRAME`].
+fn vmem_with_page() -> Result<Vmem, Error> {
+    let mut page_table: PageTable = PageTable::new(PageTableStorage::new());
+    let vaddr: PageAddress = PageAddress::new(PageAligned::from_raw_value(PAGE)?);
+    let paddr: FrameAddress =
+        FrameAddress::new(PageAligned::from_address(PhysicalAddress::from_raw_value(FRAME)?)?);
+    page_table.map(vaddr, paddr, true, false, true, AccessPermission::RDWR)?;
+
+    let pt_vaddr: PageTableAddress = PageTableAddress::new(PageTableAligned::from_raw_value(PAGE)?);
+    let mut page_tables: LinkedList<(PageTableAddress, PageTable)> = LinkedList::new();
+    page_tables.push_back((pt_vaddr, page_table));
+
+    Vmem::new(LinkedList::new(), page_tables)
+}
+
+/// Tests if [`Vmem::translate()`] translates a mapped address and preserves its page offset.
+fn test_translate_mapped() -> bool {
+    const OFFSET: usize = 0x123;
+
+    let vmem: Vmem = match vmem_with_page() {
+        Ok(vmem) => vmem,
+        Err(e) => {
+            error!("failed to create virtual memory space (error={:?})", e);
+            return false;
+        },
+    };
+
+    for offset in [0, OFFSET, mem::PAGE_SIZE - 1] {
+        match vmem.translate(VirtualAddress::new(PAGE + offset)) {
+            Ok(paddr) if paddr.into_raw_value() == FRAME + offset => {},
+            result => {
+                error!("unexpected translation (offset={:#x}, result={:?})", offset, result);
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Tests if [`Vmem::translate()`] rejects unmapped addresses.
+fn test_translate_unmapped() -> bool {
+    let vmem: Vmem = match vmem_with_page() {
+        Ok(vmem) => vmem,
+        Err(e) => {
+            error!("failed to create virtual memory space (error={:?})", e);
+            return false;
+        },
+    };
+
+    // Check if an unmapped page in a present page table is rejected, and likewise for an address
+    // whose page table is not present.
+    for vaddr in [PAGE + mem::PAGE_SIZE, PAGE + mem::PGTAB_SIZE] {
+        match vmem.translate(VirtualAddress::new(vaddr)) {
+            Err(e) if e.code == ErrorCode::NoSuchEntry => {},
+            result => {
+                error!("unexpected translation (vaddr={:#010x}, result={:?})", vaddr, result);
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Runs all unit tests for virtual memory.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_translate_mapped);
+    passed &= run_test!(test_translate_unmapped);
+
+    passed
+}
@@ -46,13 +46,17 @@ use ::arch::mem::{
     self,
     paging::PageDirectoryEntry,
 };
-use ::core::cell::RefCell;
+use ::core::{
+    cell::RefCell,
+    ptr,
+};
 use ::sys::{
     config,
     error::{
         Error,
         ErrorCode,
     },
+    mm::CachingMode,
 };
 
 //==================================================================================================
@@ -425,6 +429,176 @@ impl Vmem {
         Err(Error::new(ErrorCode::NoSuchEntry, reason))
     }
 
+    ///
+    /// # Description
+    ///
+    /// Translates a virtual address into the physical address that it is mapped to in the target
+    /// virtual memory space.
+    ///
+    /// # Parameters
+    ///
+    /// - `vaddr`: Virtual address to translate.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the physical address that `vaddr` is mapped to is returned, with the in-page
+    /// offset preserved. Upon failure, an error code is returned instead.
+    ///
+    pub fn translate(&self, vaddr: VirtualAddress) -> Result<PhysicalAddress, Error> {
+        let page_vaddr: PageAligned<VirtualAddress> =
+            PageAligned::from_address(vaddr.align_down(mmu::PAGE_ALIGNMENT)?)?;
+        let offset: usize = vaddr.into_raw_value() - page_vaddr.into_raw_value();
+        let pt_vaddr: PageTableAligned<VirtualAddress> = PageTableAligned::from_raw_value(
+            ::sys::mm::align_down(vaddr.into_raw_value(), mmu::PGTAB_ALIGNMENT),
+        )?;
+
+        // Get the corresponding page directory entry.
+        let pde: PageDirectoryEntry = match self.pgdir.read_pde(PageTableAddress::new(pt_vaddr)) {
+            Some(pde) => pde,
+            None => {
+                let reason: &str = "failed to read page directory entry";
+                error!("translate(): {}", reason);
+                return Err(Error::new(ErrorCode::TryAgain, reason));
+            },
+        };
+
+        // Check if corresponding page table does not exist.
+        if !pde.is_present() {
+            let reason: &str = "page table not present";
+            error!("translate(): {} (vaddr={:?})", reason, vaddr);
+            return Err(Error::new(ErrorCode::NoSuchEntry, reason));
+        }
+
+        let pgtab_addr: FrameAddress = FrameAddress::from_frame_number(pde.frame())?;
+        let page_address: PageAddress = PageAddress::new(page_vaddr);
+
+        // Lookup frame address in the corresponding page table.
+        let frame_address: FrameAddress = if Self::is_user_addr(vaddr) {
+            match self
+                .user_page_tables
+                .iter()
+                .find(|pt| pt.physical_address().ok() == Some(pgtab_addr))
+            {
+                Some(pt) => pt.lookup(page_address)?,
+                None => {
+                    let reason: &str = "page table not found";
+                    error!("translate(): {} (vaddr={:?})", reason, vaddr);
+                    return Err(Error::new(ErrorCode::NoSuchEntry, reason));
+                },
+            }
+        } else {
+            match self
+                .kernel_page_tables
+                .iter()
+                .find(|entry| entry.borrow().1.physical_address().ok() == Some(pgtab_addr))
+            {
+                Some(entry) => entry.borrow().1.lookup(page_address)?,
+                None => {
+                    let reason: &str = "page table not found";
+                    error!("translate(): {} (vaddr={:?})", reason, vaddr);
+                    return Err(Error::new(ErrorCode::NoSuchEntry, reason));
+                },
+            }
+        };
+
+        PhysicalAddress::from_raw_value(frame_address.into_raw_value() + offset)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks whether a range of addresses lies in user space and is fully mapped in the target
+    /// virtual memory space, with the requested access permissions.
+    ///
+    /// # Parameters
+    ///
+    /// - `vaddr`: Start address of the range.
+    /// - `size`: Size of the range in bytes.
+    /// - `access`: Requested access permissions.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn check_user_access(
+        &self,
+        vaddr: VirtualAddress,
+        size: usize,
+        access: AccessPermission,
+    ) -> Result<(), Error> {
+        // Check if size is invalid.
+        if size == 0 {
+            let reason: &str = "zero-length range";
+            error!("check_user_access(): {}", reason);
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
+        }
+
+        // Check if range wraps around or does not lie in user space.
+        let end: VirtualAddress = match vaddr.into_raw_value().checked_add(size - 1) {
+            Some(end) => VirtualAddress::new(end),
+            None => {
+                let reason: &str = "wrapping address range";
+                error!("check_user_access(): {} (vaddr={:?}, size={:?})", reason, vaddr, size);
+                return Err(Error::new(ErrorCode::BadAddress, reason));
+            },
+        };
+        if !Self::is_user_addr(vaddr) || !Self::is_user_addr(end) {
+            let reason: &str = "address range does not lie in user space";
+            error!("check_user_access(): {} (vaddr={:?}, size={:?})", reason, vaddr, size);
+            return Err(Error::new(ErrorCode::BadAddress, reason));
+        }
+
+        // Check every page that the range spans.
+        let mut page: VirtualAddress = vaddr.align_down(mmu::PAGE_ALIGNMENT)?;
+        while page <= end {
+            let page_vaddr: PageAligned<VirtualAddress> = PageAligned::from_address(page)?;
+
+            // Check if page is not mapped.
+            if self.find_page(page_vaddr).is_err() {
+                let reason: &str = "address range is not fully mapped";
+                error!("check_user_access(): {} (page={:?})", reason, page);
+                return Err(Error::new(ErrorCode::BadAddress, reason));
+            }
+
+            // Check if page is not writable, when write access is requested.
+            if access.is_writable() {
+                let pt_vaddr: PageTableAligned<VirtualAddress> = PageTableAligned::from_raw_value(
+                    ::sys::mm::align_down(page.into_raw_value(), mmu::PGTAB_ALIGNMENT),
+                )?;
+                let pde: PageDirectoryEntry =
+                    match self.pgdir.read_pde(PageTableAddress::new(pt_vaddr)) {
+                        Some(pde) => pde,
+                        None => {
+                            let reason: &str = "failed to read page directory entry";
+                            error!("check_user_access(): {}", reason);
+                            return Err(Error::new(ErrorCode::TryAgain, reason));
+                        },
+                    };
+                let pgtab_addr: FrameAddress = FrameAddress::from_frame_number(pde.frame())?;
+                let writable: bool = match self
+                    .user_page_tables
+                    .iter()
+                    .find(|pt| pt.physical_address().ok() == Some(pgtab_addr))
+                {
+                    Some(pt) => pt.is_writable(PageAddress::new(page_vaddr))?,
+                    None => false,
+                };
+                if !writable {
+                    let reason: &str = "address range is not writable";
+                    error!("check_user_access(): {} (page={:?})", reason, page);
+                    return Err(Error::new(ErrorCode::PermissionDenied, reason));
+                }
+            }
+
+            page = match page.into_raw_value().checked_add(mem::PAGE_SIZE) {
+                Some(next) => VirtualAddress::new(next),
+                None => break,
+            };
+        }
+
+        Ok(())
+    }
+
     ///
     /// # Description
     ///
@@ -656,18 +830,72 @@ impl Vmem {
         &mut self,
         dst: PageAligned<VirtualAddress>,
         src: PageAligned<PhysicalAddress>,
+        size: usize,
     ) -> Result<(), Error> {
         extern "C" {
             fn __physcopy(dst: *mut u8, src: *const u8, size: usize);
         }
 
+        // Check if size exceeds a page.
+        if size > mem::PAGE_SIZE {
+            let reason: &str = "size exceeds page size";
+            error!("physcopy(): {} (size={:?})", reason, size);
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
+        }
+
         // Get corresponding user page.
         let page = self.find_page(dst)?;
         let uframe: FrameAddress = page.frame_address();
         let dst: PageAligned<PhysicalAddress> = uframe.into_physical_address();
         let dst: *mut u8 = dst.into_raw_value() as *mut u8;
         let src: *const u8 = (src.into_raw_value()) as *const u8;
-        __physcopy(dst, src, mem::PAGE_SIZE);
+        __physcopy(dst, src, size);
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Zeroes part of a user page, through its underlying physical frame.
+    ///
+    /// # Parameters
+    ///
+    /// - `dst`: Virtual address of the target page.
+    /// - `offset`: Offset within the target page.
+    /// - `size`: Number of bytes to zero.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error code is returned instead.
+    ///
+    /// # Safety
+    ///
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// - The frame that backs `dst` must be identity mapped in the kernel address space.
+    ///
+    pub unsafe fn physzero(
+        &mut self,
+        dst: PageAligned<VirtualAddress>,
+        offset: usize,
+        size: usize,
+    ) -> Result<(), Error> {
+        // Check if the range exceeds the page.
+        if offset
+            .checked_add(size)
+            .map_or(true, |end| end > mem::PAGE_SIZE)
+        {
+            let reason: &str = "range exceeds page size";
+            error!("physzero(): {} (offset={:?}, size={:?})", reason, offset, size);
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
+        }
+
+        // Get corresponding user page.
+        let page = self.find_page(dst)?;
+        let uframe: FrameAddress = page.frame_address();
+        let dst: PageAligned<PhysicalAddress> = uframe.into_physical_address();
+        let dst: *mut u8 = (dst.into_raw_value() + offset) as *mut u8;
+        ptr::write_bytes(dst, 0, size);
         Ok(())
     }
 
@@ -851,6 +1079,57 @@ impl Vmem {
 
         Ok(())
     }
+
+    // Changes the caching mode of a kernel page.
+    pub fn kcaching(
+        &mut self,
+        vaddr: PageAligned<VirtualAddress>,
+        mode: CachingMode,
+    ) -> Result<(), Error> {
+        trace!("kcaching(): vaddr={:?}, mode={:?}", vaddr, mode);
+
+        // Check if the provided address lies outside the kernel space.
+        if !Self::is_kernel_addr(vaddr.into_inner()) {
+            let reason: &str = "address is not in kernel space";
+            error!("kcaching(): {}", reason);
+            return Err(Error::new(ErrorCode::BadAddress, reason));
+        }
+
+        // Get corresponding page table.
+        let page_table = {
+            let vaddr: PageTableAligned<VirtualAddress> = PageTableAligned::from_raw_value(
+                ::sys::mm::align_down(vaddr.into_raw_value(), mmu::PGTAB_ALIGNMENT),
+            )?;
+            // Get the corresponding page directory entry.
+            let pde: PageDirectoryEntry = match self.pgdir.read_pde(PageTableAddress::new(vaddr)) {
+                Some(pde) => pde,
+                None => {
+                    let reason: &str = "failed to read page directory entry";
+                    error!("kcaching(): {}", reason);
+                    return Err(Error::new(ErrorCode::TryAgain, reason));
+                },
+            };
+
+            // Check if corresponding page table does not exist.
+            if !pde.is_present() {
+                let reason: &str = "page table not present";
+                error!("kcaching(): {}", reason);
+                return Err(Error::new(ErrorCode::NoSuchEntry, reason));
+            };
+
+            self.lookup_kernel_page_table(&pde)?
+        };
+
+        let page_address: PageAddress = PageAddress::new(vaddr);
+
+        // Change caching mode of the page.
+        page_table.borrow_mut().1.set_caching(page_address, mode)?;
+
+        // Flush the stale translation of the page, which still carries the old caching mode.
+        unsafe { mmu::invalidate_page(vaddr.into_raw_value()) };
+
+        Ok(())
+    }
 }
 
 impl Drop for Vmem {
@@ -43,13 +43,40 @@ fn do_capctl(
     pm.capctl(pid, capability, value)
 }
 
+fn do_capctl_mask(
+    pm: &mut ProcessManager,
+    pid: ProcessIdentifier,
+    mask: u32,
+    value: bool,
+) -> Result<(), Error> {
+    trace!("do_capctl_mask(): pid={:?}, mask={:#x}, value={:?}", pid, mask, value);
+
+    // Checks if the process has enough privileges.
+    if pm.geteuid(pid)? != UserIdentifier::ROOT {
+        let reason: &str = "permission denied";
+        error!("do_capctl_mask: {}", reason);
+        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+    }
+
+    pm.capctl_mask(pid, mask, value)
+}
+
 pub fn capctl(pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
+    let value: bool = args.arg1 != 0;
+
+    // Check if a mask of capabilities was supplied.
+    if args.arg2 != 0 {
+        return match do_capctl_mask(pm, args.pid, args.arg0, value) {
+            Ok(_) => 0,
+            Err(e) => e.code.into_errno(),
+        };
+    }
+
     // Unpack arguments.
     let capability: Capability = match Capability::try_from(args.arg0) {
         Ok(capability) => capability,
         Err(e) => return e.code.into_errno(),
     };
-    let value: bool = args.arg1 != 0;
 
     match do_capctl(pm, args.pid, capability, value) {
         Ok(_) => 0,
@@ -0,0 +1,51 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    kcall::KcallArgs,
+    pm::ProcessManager,
+};
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    pm::{
+        Capability,
+        ProcessIdentifier,
+    },
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+fn do_caplist(
+    pm: &ProcessManager,
+    pid: ProcessIdentifier,
+    target: ProcessIdentifier,
+) -> Result<u32, Error> {
+    trace!("do_caplist(): pid={:?}, target={:?}", pid, target);
+
+    // Check if process may not inspect capabilities of another process.
+    if pid != target && !ProcessManager::has_capability(pid, Capability::ProcessManagement)? {
+        let reason: &str = "process does not have process management capabilities";
+        error!("do_caplist(): {}", reason);
+        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+    }
+
+    pm.capabilities(target)
+}
+
+pub fn caplist(pm: &ProcessManager, args: &KcallArgs) -> i32 {
+    let target: ProcessIdentifier = ProcessIdentifier::from(args.arg0);
+
+    match do_caplist(pm, args.pid, target) {
+        Ok(bits) => bits as i32,
+        Err(e) => e.code.into_errno(),
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    kcall::KcallArgs,
+    pm::ProcessManager,
+};
+use ::sys::{
+    error::Error,
+    pm::{
+        Capability,
+        ProcessIdentifier,
+    },
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+fn do_dropcap(
+    pm: &mut ProcessManager,
+    pid: ProcessIdentifier,
+    capability: Capability,
+) -> Result<(), Error> {
+    trace!("do_dropcap(): pid={:?}, capability={:?}", pid, capability);
+
+    // NOTE: no privileges are required, because a process may only drop its own capabilities.
+    pm.drop_capability(pid, capability)
+}
+
+pub fn dropcap(pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
+    // Unpack arguments.
+    let capability: Capability = match Capability::try_from(args.arg0) {
+        Ok(capability) => capability,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match do_dropcap(pm, args.pid, capability) {
+        Ok(_) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    hal::time,
+    kcall::args::{
+        user_mut,
+        UserMut,
+    },
+    pm::ProcessManager,
+};
+use ::sys::pm::ProcessIdentifier;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+pub fn getticks(ticks: usize) -> i32 {
+    let pid: ProcessIdentifier = match ProcessManager::get_pid() {
+        Ok(pid) => pid,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    let ticks: UserMut<u64> = match user_mut(pid, ticks) {
+        Ok(ticks) => ticks,
+        Err(e) => return e.code.into_errno(),
+    };
+
+    match ticks.write(&time::ticks()) {
+        Ok(()) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
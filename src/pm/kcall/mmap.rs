@@ -8,19 +8,18 @@
 use crate::{
     hal::mem::{
         AccessPermission,
-        Address,
         PageAligned,
         VirtualAddress,
     },
-    kcall::KcallArgs,
+    kcall::{
+        args,
+        KcallArgs,
+    },
     mm::VirtMemoryManager,
     pm::ProcessManager,
 };
 use ::sys::{
-    error::{
-        Error,
-        ErrorCode,
-    },
+    error::Error,
     pm::{
         Capability,
         ProcessIdentifier,
@@ -43,19 +42,13 @@ fn do_mmap(
 
 pub fn mmap(pm: &mut ProcessManager, mm: &mut VirtMemoryManager, args: &KcallArgs) -> i32 {
     // Check if the calling process has memory management capabilities.
-    match ProcessManager::has_capability(args.pid, Capability::MemoryManagement) {
-        Ok(true) => (),
-        Ok(false) => {
-            let reason: &str = "process does not have memory management capabilities";
-            error!("mmap(): {}", reason);
-            return ErrorCode::PermissionDenied.into_errno();
-        },
-        Err(e) => return e.code.into_errno(),
+    if let Err(e) = ProcessManager::check_capability(args.pid, Capability::MemoryManagement) {
+        return e.code.into_errno();
     }
 
     // Unpack kernel call arguments.
     let pid: ProcessIdentifier = ProcessIdentifier::from(args.arg0);
-    let vaddr: PageAligned<VirtualAddress> = match PageAligned::from_raw_value(args.arg1 as usize) {
+    let vaddr: PageAligned<VirtualAddress> = match args::user_page(args.arg1 as usize) {
         Ok(vaddr) => vaddr,
         Err(e) => return e.code.into_errno(),
     };
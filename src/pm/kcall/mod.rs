@@ -6,9 +6,12 @@
 //==================================================================================================
 
 mod capctl;
+mod caplist;
+mod dropcap;
 mod getegid;
 mod geteuid;
 mod getgid;
+mod getticks;
 mod getuid;
 mod mcopy;
 mod mctrl;
@@ -18,16 +21,23 @@ mod setegid;
 mod seteuid;
 mod setgid;
 mod setuid;
+mod sleep;
 mod terminate;
+mod unwatch;
+mod watch;
+mod yield_now;
 
 //==================================================================================================
 // Exports
 //==================================================================================================
 
 pub use capctl::capctl;
+pub use caplist::caplist;
+pub use dropcap::dropcap;
 pub use getegid::getegid;
 pub use geteuid::geteuid;
 pub use getgid::getgid;
+pub use getticks::getticks;
 pub use getuid::getuid;
 pub use mcopy::mcopy;
 pub use mctrl::mctrl;
@@ -37,4 +47,8 @@ pub use setegid::setegid;
 pub use seteuid::seteuid;
 pub use setgid::setgid;
 pub use setuid::setuid;
+pub use sleep::sleep;
 pub use terminate::terminate;
+pub use unwatch::unwatch;
+pub use watch::watch;
+pub use yield_now::yield_now;
@@ -0,0 +1,24 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::pm;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+pub fn sleep(ticks: u32) -> i32 {
+    // Check if the caller asked to sleep for no time at all.
+    if ticks == 0 {
+        return pm::yield_now();
+    }
+
+    match pm::sleep_ticks(u64::from(ticks)) {
+        Ok(()) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
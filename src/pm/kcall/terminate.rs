@@ -6,6 +6,7 @@
 //==================================================================================================
 
 use crate::{
+    event::EventManager,
     kcall::KcallArgs,
     pm::ProcessManager,
 };
@@ -16,7 +17,14 @@ use ::sys::pm::ProcessIdentifier;
 //==================================================================================================
 
 pub fn terminate(pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
-    match pm.terminate(ProcessIdentifier::from(args.arg0)) {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(args.arg0);
+
+    if let Err(e) = pm.terminate(pid) {
+        return e.code.into_errno();
+    }
+
+    // Cancel any wait of the terminated process.
+    match EventManager::cancel_wait(pid) {
         Ok(_) => 0,
         Err(e) => e.code.into_errno(),
     }
@@ -0,0 +1,36 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    kcall::KcallArgs,
+    pm::ProcessManager,
+};
+use ::sys::{
+    error::Error,
+    pm::ProcessIdentifier,
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+fn do_unwatch(
+    pm: &mut ProcessManager,
+    watcher: ProcessIdentifier,
+    pid: ProcessIdentifier,
+) -> Result<(), Error> {
+    trace!("do_unwatch(): watcher={:?}, pid={:?}", watcher, pid);
+
+    pm.unwatch(watcher, pid)
+}
+
+pub fn unwatch(pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
+    match do_unwatch(pm, args.pid, ProcessIdentifier::from(args.arg0)) {
+        Ok(_) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
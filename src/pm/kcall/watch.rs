@@ -0,0 +1,51 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    kcall::KcallArgs,
+    pm::ProcessManager,
+};
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    pm::{
+        Capability,
+        ProcessIdentifier,
+    },
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+fn do_watch(
+    pm: &mut ProcessManager,
+    watcher: ProcessIdentifier,
+    pid: ProcessIdentifier,
+) -> Result<(), Error> {
+    trace!("do_watch(): watcher={:?}, pid={:?}", watcher, pid);
+
+    // Check if process may not watch a process that is owned by another user.
+    if pm.geteuid(watcher)? != pm.getuid(pid)?
+        && !ProcessManager::has_capability(watcher, Capability::ProcessManagement)?
+    {
+        let reason: &str = "process does not have process management capabilities";
+        error!("do_watch(): {}", reason);
+        return Err(Error::new(ErrorCode::PermissionDenied, reason));
+    }
+
+    pm.watch(watcher, pid)
+}
+
+pub fn watch(pm: &mut ProcessManager, args: &KcallArgs) -> i32 {
+    match do_watch(pm, args.pid, ProcessIdentifier::from(args.arg0)) {
+        Ok(_) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
@@ -0,0 +1,19 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::pm::ProcessManager;
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+pub fn yield_now() -> i32 {
+    match ProcessManager::switch() {
+        Ok(()) => 0,
+        Err(e) => e.code.into_errno(),
+    }
+}
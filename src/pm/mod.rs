@@ -15,23 +15,34 @@ pub mod thread;
 //==================================================================================================
 
 use crate::{
+    event::{
+        EventManager,
+        InterruptCallback,
+    },
     hal::{
         arch::InterruptNumber,
         mem::{
             Address,
             VirtualAddress,
         },
+        time,
         Hal,
     },
+    kconfig,
     mm::Vmem,
-    pm::thread::{
-        ReadyThread,
-        ThreadManager,
+    pm::{
+        sync::condvar::Condvar,
+        thread::{
+            ReadyThread,
+            ThreadManager,
+        },
     },
 };
 use ::sys::{
-    config,
-    error::Error,
+    error::{
+        Error,
+        ErrorCode,
+    },
     pm::ProcessIdentifier,
 };
 
@@ -42,6 +53,16 @@ use ::sys::{
 pub use kcall::*;
 pub use process::ProcessManager;
 
+//==================================================================================================
+// Global Variables
+//==================================================================================================
+
+/// Threads that are sleeping for a number of timer ticks.
+static mut SLEEPERS: Option<Condvar> = None;
+
+/// Threads that wait for room in the kernel message buffer.
+static mut SENDERS: Option<Condvar> = None;
+
 //==================================================================================================
 // Standalone Functions
 //==================================================================================================
@@ -62,16 +83,133 @@ pub fn copy_to_user<T>(pid: ProcessIdentifier, dst: *mut T, src: &T) -> Result<(
     ProcessManager::vmcopy_to_user(pid, dst, src, size)
 }
 
-pub fn timer_handler(_intnum: InterruptNumber) {
-    static mut TIMER_TICKS: usize = 0;
+///
+/// # Description
+///
+/// Kernel callback of the timer line, which drives time keeping and scheduling.
+///
+/// # Parameters
+///
+/// - `_intnum`: Interrupt number.
+///
+/// # Returns
+///
+/// This function always returns `false`, because timer ticks are consumed by the kernel.
+///
+pub fn timer_handler(_intnum: InterruptNumber) -> bool {
+    time::tick();
+
+    if let Err(e) = EventManager::expire_waits(time::ticks()) {
+        error!("failed to expire waits: {:?}", e);
+    }
+
+    if let Some(sleepers) = unsafe { SLEEPERS.as_ref() } {
+        if let Err(e) = sleepers.expire(time::ticks()) {
+            error!("failed to expire sleeps: {:?}", e);
+        }
+    }
 
-    unsafe { TIMER_TICKS = TIMER_TICKS.wrapping_add(1) };
+    if let Some(senders) = unsafe { SENDERS.as_ref() } {
+        if let Err(e) = senders.expire(time::ticks()) {
+            error!("failed to expire sends: {:?}", e);
+        }
+    }
 
-    if unsafe { TIMER_TICKS } % config::kernel::SCHEDULER_FREQ == 0 {
+    if time::ticks() % kconfig::get().scheduler_freq as u64 == 0 {
         if let Err(e) = ProcessManager::switch() {
             error!("context switch failed: {:?}", e);
         }
     }
+
+    false
+}
+
+///
+/// # Description
+///
+/// Puts the calling thread to sleep for a number of timer ticks.
+///
+/// # Parameters
+///
+/// - `ticks`: Number of timer ticks to sleep for.
+///
+/// # Returns
+///
+/// Upon successful completion, empty is returned. Otherwise, an error is returned instead.
+///
+/// # Notes
+///
+/// - No one notifies sleeping threads, so the calling thread is woken up only once its deadline
+///   expires on the timer interrupt handler.
+///
+pub fn sleep_ticks(ticks: u64) -> Result<(), Error> {
+    let sleepers: &Condvar = match unsafe { SLEEPERS.as_ref() } {
+        Some(sleepers) => sleepers,
+        None => {
+            let reason: &str = "sleep queue is not initialized";
+            error!("sleep_ticks(): {}", reason);
+            return Err(Error::new(ErrorCode::TryAgain, reason));
+        },
+    };
+
+    let deadline: u64 = time::ticks().saturating_add(ticks);
+    loop {
+        // Check if the deadline has expired.
+        let now: u64 = time::ticks();
+        if now >= deadline {
+            break Ok(());
+        }
+
+        sleepers.wait_timeout(deadline - now)?;
+    }
+}
+
+///
+/// # Description
+///
+/// Waits for the kernel message buffer to have room for one more message.
+///
+/// # Parameters
+///
+/// - `ticks`: Maximum number of timer ticks to wait for.
+///
+/// # Returns
+///
+/// Upon successful completion, a boolean indicating whether the wait timed out is returned.
+/// Otherwise, an error is returned instead.
+///
+pub fn wait_buffer_space(ticks: u64) -> Result<bool, Error> {
+    match unsafe { SENDERS.as_ref() } {
+        Some(senders) => senders.wait_timeout(ticks),
+        None => {
+            let reason: &str = "send queue is not initialized";
+            error!("wait_buffer_space(): {}", reason);
+            Err(Error::new(ErrorCode::TryAgain, reason))
+        },
+    }
+}
+
+///
+/// # Description
+///
+/// Wakes up threads that wait for room in the kernel message buffer.
+///
+/// # Parameters
+///
+/// - `count`: Number of messages that were removed from the buffer.
+///
+/// # Returns
+///
+/// Upon successful completion, empty is returned. Otherwise, an error is returned instead.
+///
+pub fn notify_buffer_space(count: usize) -> Result<(), Error> {
+    if let Some(senders) = unsafe { SENDERS.as_ref() } {
+        for _ in 0..count {
+            senders.notify_first()?;
+        }
+    }
+
+    Ok(())
 }
 
 ///
@@ -85,7 +223,7 @@ pub fn timer_handler(_intnum: InterruptNumber) {
 ///
 fn check_config() {
     // Check if scheduler frequency is a power of two.
-    if !config::kernel::SCHEDULER_FREQ.is_power_of_two() {
+    if !kconfig::get().scheduler_freq.is_power_of_two() {
         panic!("scheduler frequency is not a power of two and it should");
     }
 }
@@ -96,12 +234,17 @@ pub fn init(hal: &mut Hal, root: Vmem) -> Result<ProcessManager, Error> {
 
     check_config();
 
+    unsafe { SLEEPERS = Some(Condvar::new()) };
+    unsafe { SENDERS = Some(Condvar::new()) };
+
     let interrupt_capable: bool = hal.intman.is_some();
 
-    // Register timer handler, if interrupts are supported.
-    if let Some(intman) = &mut hal.intman {
+    // Register timer handler, if interrupts are supported. The timer line is dispatched by the
+    // event manager, which runs the handler as a kernel callback of the line.
+    if interrupt_capable {
         info!("registering timer interrupt handler...");
-        intman.register_handler(InterruptNumber::Timer, timer_handler)?;
+        let callback: InterruptCallback = timer_handler;
+        EventManager::register_interrupt_callback(InterruptNumber::Timer, callback)?;
     }
 
     // Initialize the thread manager.
@@ -111,3 +254,13 @@ pub fn init(hal: &mut Hal, root: Vmem) -> Result<ProcessManager, Error> {
 
     Ok(pm)
 }
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= process::test();
+    passed &= sync::test();
+
+    passed
+}
@@ -0,0 +1,248 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::mm::elf::{
+    self,
+    Elf32Fhdr,
+};
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    pm::Capability,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+#[derive(Default, Clone, Copy)]
+pub struct Capabilities(u32);
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl Capabilities {
+    /// Number of capabilities.
+    const NUMBER_CAPABILITIES: u32 = Capability::COUNT as u32;
+    /// Maximum number of capabilities that a set can hold.
+    const CAPACITY: u32 = u32::BITS;
+
+    ///
+    /// # Description
+    ///
+    /// Returns a set that contains all capabilities.
+    ///
+    pub fn all() -> Self {
+        Self(((1u64 << Self::NUMBER_CAPABILITIES) - 1) as u32)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Builds a set of capabilities from a raw bitmask, where bit `i` stands for the capability
+    /// whose number is `i`.
+    ///
+    /// # Parameters
+    ///
+    /// - `mask`: Raw bitmask.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the set of capabilities is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn from_raw(mask: u32) -> Result<Self, Error> {
+        if mask.checked_shr(Self::NUMBER_CAPABILITIES).unwrap_or(0) != 0 {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid capability"));
+        }
+
+        Ok(Self(mask))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the raw bitmask of the target set, where bit `i` stands for the capability whose
+    /// number is `i`.
+    ///
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub fn set(&mut self, capability: Capability) {
+        self.set_index(capability as u32)
+    }
+
+    pub fn clear(&mut self, capability: Capability) {
+        self.clear_index(capability as u32)
+    }
+
+    pub fn has(&self, capability: Capability) -> bool {
+        self.has_index(capability as u32)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sets all capabilities in a raw bitmask. Bits that do not stand for a capability are
+    /// ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `mask`: Raw bitmask of capabilities to set.
+    ///
+    pub fn set_all(&mut self, mask: u32) {
+        self.0 |= mask & Self::all().0;
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Clears all capabilities in a raw bitmask.
+    ///
+    /// # Parameters
+    ///
+    /// - `mask`: Raw bitmask of capabilities to clear.
+    ///
+    pub fn clear_all(&mut self, mask: u32) {
+        self.0 &= !mask;
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the capabilities that are both in the target set and in `other`.
+    ///
+    pub fn intersect(&self, other: Capabilities) -> Capabilities {
+        Self(self.0 & other.0)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the capabilities that are either in the target set or in `other`.
+    ///
+    #[allow(dead_code)]
+    pub fn union(&self, other: Capabilities) -> Capabilities {
+        Self(self.0 | other.0)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns an iterator over the capabilities in the target set, in ascending order of number.
+    ///
+    #[allow(dead_code)]
+    pub fn iter(&self) -> impl Iterator<Item = Capability> {
+        let capabilities: Capabilities = *self;
+        Capability::VALUES
+            .into_iter()
+            .filter(move |capability| capabilities.has(*capability))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the bit that stands for the capability whose number is `index`.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: Number of the capability.
+    ///
+    /// # Returns
+    ///
+    /// The bit that stands for the capability, or zero if `index` does not fit in a set.
+    ///
+    fn bit(index: u32) -> u32 {
+        1u32.checked_shl(index).unwrap_or(0)
+    }
+
+    fn set_index(&mut self, index: u32) {
+        self.0 |= Self::bit(index);
+    }
+
+    fn clear_index(&mut self, index: u32) {
+        self.0 &= !Self::bit(index);
+    }
+
+    fn has_index(&self, index: u32) -> bool {
+        (self.0 & Self::bit(index)) != 0
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if all capabilities in `requested` may be delegated by the holder of the target set.
+    ///
+    /// # Parameters
+    ///
+    /// - `requested`: Capabilities that are requested.
+    ///
+    /// # Returns
+    ///
+    /// If every requested capability is in the target set, empty is returned. Otherwise, an error
+    /// is returned instead.
+    ///
+    pub fn check_delegation(&self, requested: Capabilities) -> Result<(), Error> {
+        if requested.0 & !self.0 != 0 {
+            let reason: &str = "capabilities cannot be delegated";
+            error!(
+                "check_delegation(): {} (held={:#x}, requested={:#x})",
+                reason, self.0, requested.0
+            );
+            return Err(Error::new(ErrorCode::PermissionDenied, reason));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if all capabilities that an ELF32 image requires may be delegated by the holder of
+    /// the target set.
+    ///
+    /// # Parameters
+    ///
+    /// - `elf`: File header of the ELF32 image.
+    /// - `size`: Size of the ELF32 image.
+    ///
+    /// # Returns
+    ///
+    /// If the image is valid and every capability that it requires is in the target set, empty is
+    /// returned. Otherwise, an error is returned instead.
+    ///
+    pub fn check_image(&self, elf: &Elf32Fhdr, size: usize) -> Result<(), Error> {
+        let requested: Capabilities =
+            Capabilities::from_raw(elf::elf32_inspect(elf, size)?.capabilities())
+                .map_err(|_| Error::new(ErrorCode::BadFile, "unknown capability in elf file"))?;
+
+        self.check_delegation(requested)
+    }
+}
+
+// Ensure that all capabilities fit in a set.
+const _: () = assert!(Capabilities::NUMBER_CAPABILITIES <= Capabilities::CAPACITY);
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
@@ -0,0 +1,305 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    mm::{
+        elf::test::{
+            capabilities_image,
+            Image,
+        },
+        Vmem,
+    },
+    pm::process::{
+        capability::Capabilities,
+        identity::ProcessIdentity,
+        state::ProcessState,
+    },
+};
+use ::alloc::{
+    collections::LinkedList,
+    vec::Vec,
+};
+use ::sys::{
+    error::ErrorCode,
+    pm::{
+        Capability,
+        GroupIdentifier,
+        ProcessIdentifier,
+        UserIdentifier,
+    },
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if raw bitmasks are converted into sets of capabilities.
+fn test_from_raw() -> bool {
+    let mask: u32 = 1 << Capability::InterruptControl as u32;
+    match Capabilities::from_raw(mask) {
+        Ok(capabilities) if capabilities.has(Capability::InterruptControl) => {},
+        Ok(_) => {
+            error!("capability missing from set");
+            return false;
+        },
+        Err(e) => {
+            error!("failed to convert bitmask (error={:?})", e);
+            return false;
+        },
+    }
+
+    // Check if unknown capabilities are rejected.
+    match Capabilities::from_raw(1 << 31) {
+        Err(e) if e.code == ErrorCode::InvalidArgument => true,
+        Err(e) => {
+            error!("unexpected error for unknown capability (error={:?})", e);
+            false
+        },
+        Ok(_) => {
+            error!("unknown capability was not rejected");
+            false
+        },
+    }
+}
+
+/// Tests if the bitmask of a set reflects exactly the capabilities that were set.
+fn test_bits() -> bool {
+    let mut capabilities: Capabilities = Capabilities::default();
+    capabilities.set(Capability::InterruptControl);
+    capabilities.set(Capability::ProcessManagement);
+
+    let expected: u32 =
+        (1 << Capability::InterruptControl as u32) | (1 << Capability::ProcessManagement as u32);
+    if capabilities.bits() != expected {
+        error!("unexpected bitmask (expected={:#x}, got={:#x})", expected, capabilities.bits());
+        return false;
+    }
+
+    // Check if clearing a capability is reflected.
+    capabilities.clear(Capability::InterruptControl);
+    let expected: u32 = 1 << Capability::ProcessManagement as u32;
+    if capabilities.bits() != expected {
+        error!("unexpected bitmask (expected={:#x}, got={:#x})", expected, capabilities.bits());
+        return false;
+    }
+
+    true
+}
+
+/// Tests if iterating a set yields exactly the capabilities that were set.
+fn test_iter() -> bool {
+    let mut capabilities: Capabilities = Capabilities::default();
+    capabilities.set(Capability::ProcessManagement);
+    capabilities.set(Capability::InterruptControl);
+
+    let collected: Vec<u32> = capabilities.iter().map(|c| c as u32).collect();
+    let expected: [u32; 2] = [
+        Capability::InterruptControl as u32,
+        Capability::ProcessManagement as u32,
+    ];
+    if collected != expected {
+        error!("unexpected capabilities (expected={:?}, got={:?})", expected, collected);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if capabilities whose number does not fit in a byte are set, checked and cleared.
+fn test_wide_index() -> bool {
+    for index in [8, Capabilities::CAPACITY - 1] {
+        let mut capabilities: Capabilities = Capabilities::default();
+        capabilities.set_index(index);
+
+        // Check if only the target capability is set.
+        if !capabilities.has_index(index) || capabilities.bits() != 1 << index {
+            error!("capability is not set (index={}, bits={:#x})", index, capabilities.bits());
+            return false;
+        }
+
+        // Check if clearing the capability is reflected.
+        capabilities.clear_index(index);
+        if capabilities.has_index(index) || capabilities.bits() != 0 {
+            error!("capability is not cleared (index={}, bits={:#x})", index, capabilities.bits());
+            return false;
+        }
+    }
+
+    // Check if indexes that do not fit in a set are ignored.
+    let mut capabilities: Capabilities = Capabilities::default();
+    capabilities.set_index(Capabilities::CAPACITY);
+    if capabilities.has_index(Capabilities::CAPACITY) || capabilities.bits() != 0 {
+        error!("out-of-range capability was set (bits={:#x})", capabilities.bits());
+        return false;
+    }
+
+    true
+}
+
+/// Tests if capabilities are set and cleared in bulk.
+fn test_bulk() -> bool {
+    let mask: u32 =
+        (1 << Capability::InterruptControl as u32) | (1 << Capability::ProcessManagement as u32);
+
+    let mut capabilities: Capabilities = Capabilities::default();
+    capabilities.set_all(mask);
+
+    // Check if all capabilities in the mask were granted.
+    if !capabilities.has(Capability::InterruptControl)
+        || !capabilities.has(Capability::ProcessManagement)
+        || capabilities.bits() != mask
+    {
+        error!("unexpected capabilities (expected={:#x}, got={:#x})", mask, capabilities.bits());
+        return false;
+    }
+
+    // Check if set operations match.
+    let mut other: Capabilities = Capabilities::default();
+    other.set(Capability::ProcessManagement);
+    other.set(Capability::IoManagement);
+    let expected: u32 = 1 << Capability::ProcessManagement as u32;
+    if capabilities.intersect(other).bits() != expected {
+        error!("unexpected intersection (got={:#x})", capabilities.intersect(other).bits());
+        return false;
+    }
+    let expected: u32 = mask | (1 << Capability::IoManagement as u32);
+    if capabilities.union(other).bits() != expected {
+        error!("unexpected union (got={:#x})", capabilities.union(other).bits());
+        return false;
+    }
+
+    // Check if all capabilities in the mask are revoked.
+    capabilities.clear_all(mask);
+    if capabilities.bits() != 0 {
+        error!("capabilities were not revoked (got={:#x})", capabilities.bits());
+        return false;
+    }
+
+    true
+}
+
+/// Tests if a parent cannot delegate a capability that it does not hold.
+fn test_check_delegation() -> bool {
+    let mut requested: Capabilities = Capabilities::default();
+    requested.set(Capability::InterruptControl);
+
+    // Check if delegation fails when the parent lacks the capability.
+    let mut parent: Capabilities = Capabilities::default();
+    parent.set(Capability::IoManagement);
+    match parent.check_delegation(requested) {
+        Err(e) if e.code == ErrorCode::PermissionDenied => {},
+        Err(e) => {
+            error!("unexpected error for missing capability (error={:?})", e);
+            return false;
+        },
+        Ok(()) => {
+            error!("missing capability was delegated");
+            return false;
+        },
+    }
+
+    // Check if delegation succeeds once the parent holds the capability.
+    parent.set(Capability::InterruptControl);
+    if let Err(e) = parent.check_delegation(requested) {
+        error!("failed to delegate held capability (error={:?})", e);
+        return false;
+    }
+
+    // Check if the full set may delegate anything.
+    if let Err(e) = Capabilities::all().check_delegation(requested) {
+        error!("failed to delegate from full set (error={:?})", e);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if spawning a binary that declares a capability fails when the parent lacks it.
+fn test_check_image() -> bool {
+    let image: Image = capabilities_image(1 << Capability::InterruptControl as u32);
+
+    // Check if spawning fails when the parent lacks the capability.
+    let mut parent: Capabilities = Capabilities::default();
+    parent.set(Capability::IoManagement);
+    match parent.check_image(image.header(), image.len()) {
+        Err(e) if e.code == ErrorCode::PermissionDenied => {},
+        Err(e) => {
+            error!("unexpected error for missing capability (error={:?})", e);
+            return false;
+        },
+        Ok(()) => {
+            error!("binary was spawned without the capability it requires");
+            return false;
+        },
+    }
+
+    // Check if spawning succeeds once the parent holds the capability.
+    parent.set(Capability::InterruptControl);
+    if let Err(e) = parent.check_image(image.header(), image.len()) {
+        error!("failed to spawn binary with held capability (error={:?})", e);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if a kernel call that requires a dropped capability is denied, while other capabilities
+/// still pass the check.
+fn test_dropped_capability_denied() -> bool {
+    let vmem: Vmem = match Vmem::new(LinkedList::new(), LinkedList::new()) {
+        Ok(vmem) => vmem,
+        Err(e) => {
+            error!("failed to create virtual memory space (error={:?})", e);
+            return false;
+        },
+    };
+    let identity: ProcessIdentity =
+        ProcessIdentity::new(UserIdentifier::ROOT, GroupIdentifier::ROOT);
+    let mut state: ProcessState = ProcessState::new(ProcessIdentifier::from(2), identity, vmem);
+    state.set_capability(Capability::MemoryManagement);
+    state.set_capability(Capability::InterruptControl);
+
+    // Check if the capability passes the check while it is held.
+    if let Err(e) = state.check_capability(Capability::MemoryManagement) {
+        error!("held capability was denied (error={:?})", e);
+        return false;
+    }
+
+    // Check if the capability is denied once it is dropped.
+    state.drop_capability(Capability::MemoryManagement);
+    match state.check_capability(Capability::MemoryManagement) {
+        Err(e) if e.code == ErrorCode::PermissionDenied => {},
+        result => {
+            error!("dropped capability was not denied (result={:?})", result);
+            return false;
+        },
+    }
+
+    // Check if other capabilities still pass the check.
+    if let Err(e) = state.check_capability(Capability::InterruptControl) {
+        error!("capability that was not dropped was denied (error={:?})", e);
+        return false;
+    }
+
+    true
+}
+
+/// Runs all unit tests for capabilities.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_from_raw);
+    passed &= run_test!(test_bits);
+    passed &= run_test!(test_wide_index);
+    passed &= run_test!(test_iter);
+    passed &= run_test!(test_bulk);
+    passed &= run_test!(test_check_delegation);
+    passed &= run_test!(test_check_image);
+    passed &= run_test!(test_dropped_capability_denied);
+
+    passed
+}
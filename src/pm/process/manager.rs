@@ -1,12 +1,22 @@
 // Copyright(c) The Maintainers of Nanvix.
 // Licensed under the MIT License.
 
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+#[cfg(test)]
+mod test;
+
 //==================================================================================================
 // Imports
 //==================================================================================================
 
 use crate::{
-    event::EventOwnership,
+    event::{
+        EventOwnership,
+        OwnershipKind,
+    },
     hal::{
         self,
         arch::ContextInformation,
@@ -25,14 +35,20 @@ use crate::{
     },
     mm::{
         self,
-        elf::Elf32Fhdr,
+        elf::{
+            Elf32Fhdr,
+            LoadedElf,
+        },
         KernelPage,
         VirtMemoryManager,
         Vmem,
     },
     pm::{
+        self,
         process::{
+            capability::Capabilities,
             identity::ProcessIdentity,
+            pid::PidAllocator,
             state::{
                 InterruptReason,
                 InterruptedProcess,
@@ -68,8 +84,16 @@ use ::sys::{
         Error,
         ErrorCode,
     },
-    event::Event,
-    ipc::Message,
+    event::{
+        Event,
+        ProcessTerminationInfo,
+        TerminationCause,
+    },
+    ipc::{
+        MailboxStats,
+        Message,
+    },
+    mm::CachingMode,
     pm::{
         Capability,
         GroupIdentifier,
@@ -93,8 +117,8 @@ struct ProcessManagerInner {
     interrupt_capable: bool,
     /// Reason for the last interrupt.
     interrupt_reason: Option<InterruptReason>,
-    /// Next process identifier.
-    next_pid: ProcessIdentifier,
+    /// Process identifier allocator.
+    pids: PidAllocator,
     /// Running process.
     running: Option<RunningProcess>,
     /// Ready processes.
@@ -131,7 +155,7 @@ impl ProcessManagerInner {
         Self {
             interrupt_capable,
             interrupt_reason: None,
-            next_pid: ProcessIdentifier::from(1),
+            pids: PidAllocator::new(ProcessIdentifier::from(1)),
             ready: LinkedList::new(),
             suspended: LinkedList::new(),
             interrupted: LinkedList::new(),
@@ -267,8 +291,13 @@ impl ProcessManagerInner {
         )?;
 
         // Create process.
-        let pid: ProcessIdentifier = self.next_pid;
-        self.next_pid = ProcessIdentifier::from(Into::<u32>::into(pid) + 1);
+        let pid: ProcessIdentifier = self.pids.alloc(|pid| {
+            self.running.iter().any(|p| p.state().pid() == pid)
+                || self.ready.iter().any(|p| p.state().pid() == pid)
+                || self.suspended.iter().any(|p| p.state().pid() == pid)
+                || self.interrupted.iter().any(|p| p.state().pid() == pid)
+                || self.zombies.iter().any(|p| p.state().pid() == pid)
+        })?;
         let identity: ProcessIdentity = self.get_running().state().identity().clone();
         let process: RunnableProcess = RunnableProcess::new(pid, identity, thread, vmem);
 
@@ -306,7 +335,17 @@ impl ProcessManagerInner {
         mm: &mut VirtMemoryManager,
         pid: ProcessIdentifier,
         elf: &Elf32Fhdr,
+        size: usize,
     ) -> Result<(), Error> {
+        // Check if the binary requires capabilities that the parent cannot delegate.
+        let parent: &ProcessState = self.get_running().state();
+        let delegable: Capabilities = if parent.pid() == ProcessIdentifier::KERNEL {
+            Capabilities::all()
+        } else {
+            parent.capabilities()
+        };
+        delegable.check_image(elf, size)?;
+
         // Find corresponding process.
         let process: &mut RunnableProcess =
             match self.ready.iter_mut().find(|p| p.state().pid() == pid) {
@@ -318,7 +357,14 @@ impl ProcessManagerInner {
                 },
             };
 
-        process.exec(mm, elf)?;
+        let loaded: LoadedElf = process.exec(mm, elf, size)?;
+        trace!(
+            "exec(): pid={:?}, entry={:?}, segments={}, highest_vaddr={:?}",
+            pid,
+            loaded.entry(),
+            loaded.segments(),
+            loaded.highest_vaddr()
+        );
 
         Ok(())
     }
@@ -392,6 +438,7 @@ impl ProcessManagerInner {
     pub fn exit(
         &mut self,
         status: i32,
+        cause: TerminationCause,
     ) -> Result<(*mut ContextInformation, *mut ContextInformation), Error> {
         let running_process: RunningProcess = self.take_running();
 
@@ -400,7 +447,7 @@ impl ProcessManagerInner {
             panic!("kernel process cannot exit");
         }
 
-        match running_process.exit(status) {
+        match running_process.exit(status, cause) {
             Ok((runnable_process, previous_context)) => {
                 let (running_process, next_context) = runnable_process.run();
                 self.running = Some(running_process);
@@ -439,7 +486,7 @@ impl ProcessManagerInner {
         // Check if target process is ready.
         if let Some(process) = self.ready.iter().position(|p| p.state().pid() == pid) {
             let process: RunnableProcess = self.ready.remove(process);
-            let process: ZombieProcess = process.terminate();
+            let process: ZombieProcess = process.terminate(TerminationCause::Killed);
             self.zombies.push_back(process);
             return Ok(());
         }
@@ -482,6 +529,13 @@ impl ProcessManagerInner {
 
         // Check wether the capability should be set or cleared.
         if set {
+            // Check if capability was permanently dropped.
+            if process.state_mut().is_capability_dropped(capability) {
+                let reason: &str = "capability was dropped";
+                error!("capctl(): {}", reason);
+                return Err(Error::new(ErrorCode::PermissionDenied, reason));
+            }
+
             // Check if capability is already set.
             if process.state_mut().has_capability(capability) {
                 let reason: &str = "capability already set";
@@ -502,14 +556,117 @@ impl ProcessManagerInner {
         Ok(())
     }
 
+    pub fn capctl_mask(
+        &mut self,
+        pid: ProcessIdentifier,
+        capabilities: Capabilities,
+        set: bool,
+    ) -> Result<(), Error> {
+        let mut process: ProcessRefMut = self.find_process_mut(pid)?;
+
+        // Check wether the capabilities should be set or cleared.
+        if set {
+            // Check if any capability was permanently dropped.
+            let dropped: Capabilities = process
+                .state_mut()
+                .dropped_capabilities()
+                .intersect(capabilities);
+            if dropped.bits() != 0 {
+                let reason: &str = "capability was dropped";
+                error!("capctl_mask(): {} (dropped={:#x})", reason, dropped.bits());
+                return Err(Error::new(ErrorCode::PermissionDenied, reason));
+            }
+            process.state_mut().set_capabilities(capabilities);
+        } else {
+            process.state_mut().clear_capabilities(capabilities);
+        }
+
+        Ok(())
+    }
+
+    pub fn drop_capability(
+        &mut self,
+        pid: ProcessIdentifier,
+        capability: Capability,
+    ) -> Result<(), Error> {
+        let mut process: ProcessRefMut = self.find_process_mut(pid)?;
+
+        // Clear capability and prevent it from being granted again.
+        process.state_mut().drop_capability(capability);
+
+        Ok(())
+    }
+
+    pub fn watch(
+        &mut self,
+        watcher: ProcessIdentifier,
+        pid: ProcessIdentifier,
+    ) -> Result<(), Error> {
+        // Check if target process exists.
+        self.find_process(pid)?;
+
+        self.pids.watch(watcher, pid)
+    }
+
+    pub fn unwatch(
+        &mut self,
+        watcher: ProcessIdentifier,
+        pid: ProcessIdentifier,
+    ) -> Result<(), Error> {
+        self.pids.unwatch(watcher, pid)
+    }
+
+    pub fn watchers(&self, pid: ProcessIdentifier) -> Vec<ProcessIdentifier> {
+        self.pids.watchers(pid)
+    }
+
     fn interrupt_reason(&mut self) -> Option<InterruptReason> {
         self.interrupt_reason.take()
     }
 
-    pub fn harvest_zombies(&mut self) -> Option<(ProcessIdentifier, i32)> {
+    ///
+    /// # Description
+    ///
+    /// Terminates processes whose termination was deferred after they raised an exception that
+    /// could not be handled. Processes that cannot be terminated yet, because they are running,
+    /// are kept for a later attempt.
+    ///
+    /// # Parameters
+    ///
+    /// - `deferred`: Processes whose termination was deferred, along with their exceptions.
+    ///
+    fn kill_faulted(&mut self, deferred: &mut LinkedList<(ProcessIdentifier, u32)>) {
+        let mut pending: LinkedList<(ProcessIdentifier, u32)> = LinkedList::new();
+
+        while let Some((pid, exception)) = deferred.pop_front() {
+            // Check if the process is ready, thus it may be terminated due to its exception.
+            if let Some(process) = self.ready.iter().position(|p| p.state().pid() == pid) {
+                let process: RunnableProcess = self.ready.remove(process);
+                let process: ZombieProcess =
+                    process.terminate(TerminationCause::Faulted { exception });
+                self.zombies.push_back(process);
+                continue;
+            }
+
+            match self.terminate(pid) {
+                Ok(()) => {},
+                // The process has already terminated.
+                Err(e) if e.code == ErrorCode::NoSuchProcess => {},
+                Err(_) => pending.push_back((pid, exception)),
+            }
+        }
+
+        deferred.append(&mut pending);
+    }
+
+    pub fn harvest_zombies(&mut self) -> Option<ProcessTerminationInfo> {
         if let Some(mut zombie) = self.zombies.pop_front() {
-            let (_thread, state, status) = zombie.bury();
-            Some((state.pid(), status))
+            let (_thread, _state, info) = zombie.bury();
+
+            // Release all watches that were held by the dead process.
+            self.pids.release_watcher(info.pid);
+
+            Some(info)
         } else {
             None
         }
@@ -582,6 +739,9 @@ pub struct ProcessManager(Rc<RefCell<ProcessManagerInner>>);
 
 static mut PROCESS_MANAGER: Option<ProcessManager> = None;
 
+/// Processes whose termination was deferred, along with the exceptions that they raised.
+static mut DEFERRED_FAULTS: LinkedList<(ProcessIdentifier, u32)> = LinkedList::new();
+
 impl ProcessManager {
     /// Creates a new process.
     pub fn create_process(
@@ -596,8 +756,9 @@ impl ProcessManager {
         mm: &mut VirtMemoryManager,
         pid: ProcessIdentifier,
         elf: &Elf32Fhdr,
+        size: usize,
     ) -> Result<(), Error> {
-        self.try_borrow_mut()?.exec(mm, pid, elf)
+        self.try_borrow_mut()?.exec(mm, pid, elf, size)
     }
 
     pub fn getuid(&self, pid: ProcessIdentifier) -> Result<UserIdentifier, Error> {
@@ -653,6 +814,129 @@ impl ProcessManager {
         self.try_borrow_mut()?.capctl(pid, capability, value)
     }
 
+    ///
+    /// # Description
+    ///
+    /// Sets or clears a set of capabilities of a process at once. Setting fails without changes
+    /// if any of the capabilities was permanently dropped. Capabilities that are already set, or
+    /// already cleared, are left untouched.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Target process.
+    /// - `mask`: Raw bitmask of capabilities to set or clear.
+    /// - `value`: Whether the capabilities should be set or cleared.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. Otherwise, an error code is returned instead.
+    ///
+    pub fn capctl_mask(
+        &mut self,
+        pid: ProcessIdentifier,
+        mask: u32,
+        value: bool,
+    ) -> Result<(), Error> {
+        let capabilities: Capabilities = Capabilities::from_raw(mask)?;
+        self.try_borrow_mut()?.capctl_mask(pid, capabilities, value)
+    }
+
+    pub fn drop_capability(
+        &mut self,
+        pid: ProcessIdentifier,
+        capability: Capability,
+    ) -> Result<(), Error> {
+        self.try_borrow_mut()?.drop_capability(pid, capability)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Registers a watch on a process. The identifier of a watched process is not reused until
+    /// all watches on it are released, and its termination is delivered to every watcher. Watches
+    /// are released when the watcher is reaped.
+    ///
+    /// # Parameters
+    ///
+    /// - `watcher`: Process that holds the watch.
+    /// - `pid`: Process to watch.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. Otherwise, an error code is returned instead.
+    ///
+    pub fn watch(
+        &mut self,
+        watcher: ProcessIdentifier,
+        pid: ProcessIdentifier,
+    ) -> Result<(), Error> {
+        self.try_borrow_mut()?.watch(watcher, pid)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Releases a watch on a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `watcher`: Process that holds the watch.
+    /// - `pid`: Watched process.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. Otherwise, an error code is returned instead.
+    ///
+    pub fn unwatch(
+        &mut self,
+        watcher: ProcessIdentifier,
+        pid: ProcessIdentifier,
+    ) -> Result<(), Error> {
+        self.try_borrow_mut()?.unwatch(watcher, pid)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the processes that hold a watch on a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Watched process.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, the processes that hold a watch on `pid` are returned.
+    /// Otherwise, an error code is returned instead.
+    ///
+    pub fn watchers(&self, pid: ProcessIdentifier) -> Result<Vec<ProcessIdentifier>, Error> {
+        Ok(self.try_borrow()?.watchers(pid))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the capabilities that are held by a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, the bitmask of capabilities held by the process is returned,
+    /// where bit `i` stands for the capability whose number is `i`. Otherwise, an error code is
+    /// returned instead.
+    ///
+    pub fn capabilities(&self, pid: ProcessIdentifier) -> Result<u32, Error> {
+        Ok(self
+            .try_borrow()?
+            .find_process(pid)?
+            .state()
+            .capabilities()
+            .bits())
+    }
+
     pub fn has_capability(pid: ProcessIdentifier, capability: Capability) -> Result<bool, Error> {
         Ok(Self::get()?
             .try_borrow()?
@@ -661,10 +945,102 @@ impl ProcessManager {
             .has_capability(capability))
     }
 
+    ///
+    /// # Description
+    ///
+    /// Checks whether a process holds a capability, so that kernel calls that require it may be
+    /// rejected.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: ID of the target process.
+    /// - `capability`: Capability to check.
+    ///
+    /// # Returns
+    ///
+    /// If the capability is held, empty is returned. Otherwise, an error is returned instead.
+    ///
+    pub fn check_capability(pid: ProcessIdentifier, capability: Capability) -> Result<(), Error> {
+        Self::get()?
+            .try_borrow()?
+            .find_process(pid)?
+            .state()
+            .check_capability(capability)
+    }
+
     pub fn exit(status: i32) -> Result<!, Error> {
         trace!("exit({:?})", status);
+        Self::do_exit(status, TerminationCause::Exited)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Terminates the calling process due to an exception that could not be handled.
+    ///
+    /// # Parameters
+    ///
+    /// - `exception`: Number of the exception.
+    ///
+    /// # Returns
+    ///
+    /// This function does not return on success. Otherwise, an error is returned instead.
+    ///
+    pub fn fault(exception: u32) -> Result<!, Error> {
+        trace!("fault({:?})", exception);
+        Self::do_exit(ZombieProcess::KILLED, TerminationCause::Faulted { exception })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Defers the termination of a process that raised an exception that could not be handled,
+    /// because it could not be terminated right away. The process is terminated on the next
+    /// harvest of zombie processes.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: ID of the faulting process.
+    /// - `exception`: Number of the exception.
+    ///
+    pub fn defer_fault(pid: ProcessIdentifier, exception: u32) {
+        // Safety: deferred faults are only changed by the kernel, on a single core.
+        let deferred: &mut LinkedList<(ProcessIdentifier, u32)> =
+            unsafe { &mut *core::ptr::addr_of_mut!(DEFERRED_FAULTS) };
+
+        // Check if termination of the process was already deferred.
+        if deferred.iter().any(|(p, _)| *p == pid) {
+            return;
+        }
+
+        deferred.push_back((pid, exception));
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks whether the termination of a process was deferred.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: ID of the target process.
+    ///
+    /// # Returns
+    ///
+    /// If the termination of the process was deferred, `true` is returned. Otherwise, `false` is
+    /// returned instead.
+    ///
+    #[cfg(test)]
+    pub fn is_fault_deferred(pid: ProcessIdentifier) -> bool {
+        // Safety: deferred faults are only changed by the kernel, on a single core.
+        unsafe { &*core::ptr::addr_of!(DEFERRED_FAULTS) }
+            .iter()
+            .any(|(p, _)| *p == pid)
+    }
+
+    fn do_exit(status: i32, cause: TerminationCause) -> Result<!, Error> {
         let (from, to): (*mut ContextInformation, *mut ContextInformation) =
-            Self::get_mut()?.try_borrow_mut()?.exit(status)?;
+            Self::get_mut()?.try_borrow_mut()?.exit(status, cause)?;
 
         unsafe {
             ContextInformation::switch(from, to);
@@ -702,8 +1078,27 @@ impl ProcessManager {
             .copy_to_user_unaligned(dst, src, size)
     }
 
-    pub fn harvest_zombies(&mut self) -> Result<Option<(ProcessIdentifier, i32)>, Error> {
-        Ok(self.try_borrow_mut()?.harvest_zombies())
+    pub fn check_user_access(
+        pid: ProcessIdentifier,
+        vaddr: VirtualAddress,
+        size: usize,
+        access: AccessPermission,
+    ) -> Result<(), Error> {
+        Self::get()?
+            .try_borrow()?
+            .find_process(pid)?
+            .state()
+            .check_user_access(vaddr, size, access)
+    }
+
+    pub fn harvest_zombies(&mut self) -> Result<Option<ProcessTerminationInfo>, Error> {
+        let mut pm: RefMut<ProcessManagerInner> = self.try_borrow_mut()?;
+
+        // Terminate faulting processes whose termination was deferred.
+        // Safety: deferred faults are only changed by the kernel, on a single core.
+        pm.kill_faulted(unsafe { &mut *core::ptr::addr_of_mut!(DEFERRED_FAULTS) });
+
+        Ok(pm.harvest_zombies())
     }
 
     pub fn mmap(
@@ -748,6 +1143,7 @@ impl ProcessManager {
         &mut self,
         pid: ProcessIdentifier,
         region: IoMemoryRegion,
+        mode: CachingMode,
     ) -> Result<(), Error> {
         let mut pm: RefMut<ProcessManagerInner> = self.try_borrow_mut()?;
         let mut process: ProcessRefMut = pm.find_process_mut(pid)?;
@@ -756,6 +1152,7 @@ impl ProcessManager {
         // TODO: change page permissions.
         let vmem: &mut Vmem = state.vmem_mut();
         vmem.kctrl(region.base(), region.perm())?;
+        vmem.kcaching(region.base(), mode)?;
 
         state.add_mmio(region);
 
@@ -907,11 +1304,11 @@ impl ProcessManager {
         Ok(())
     }
 
-    pub fn remove_event(&mut self, ev: &Event) -> Result<(), Error> {
+    pub fn remove_event(&mut self, ev: &Event, kind: OwnershipKind) -> Result<(), Error> {
         self.try_borrow_mut()?
             .get_running_mut()
             .state_mut()
-            .remove_event(ev);
+            .remove_event(ev, kind);
 
         Ok(())
     }
@@ -919,7 +1316,54 @@ impl ProcessManager {
     ///
     /// # Description
     ///
-    /// Sends a message to a process.
+    /// Transfers the exclusive ownership of an event from the running process to another process.
+    ///
+    /// # Parameters
+    ///
+    /// - `ev`: Event.
+    /// - `to`: Identifier of the process that becomes the owner of the event.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. Otherwise, an error code is returned instead.
+    ///
+    pub fn transfer_event(&mut self, ev: &Event, to: ProcessIdentifier) -> Result<(), Error> {
+        let mut pm: RefMut<ProcessManagerInner> = self.try_borrow_mut()?;
+
+        let mut ownership: EventOwnership = match pm
+            .get_running_mut()
+            .state_mut()
+            .take_event(ev, OwnershipKind::Exclusive)
+        {
+            Some(ownership) => ownership,
+            None => {
+                let reason: &str = "process does not own event";
+                error!("transfer_event(): {} (ev={:?})", reason, ev);
+                return Err(Error::new(ErrorCode::NoSuchEntry, reason));
+            },
+        };
+
+        // NOTE: if the target process is not found, ownership is dropped and the event unregistered.
+        ownership.set_pid(to);
+        pm.find_process_mut(to)?.state_mut().add_event(ownership);
+
+        Ok(())
+    }
+
+    pub fn remove_default_event(&mut self) -> Result<(), Error> {
+        self.try_borrow_mut()?
+            .get_running_mut()
+            .state_mut()
+            .remove_default_event();
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sends a message to a process. Exception and interrupt messages that are created by the
+    /// kernel are delivered ahead of other pending messages.
     ///
     /// # Parameters
     ///
@@ -949,15 +1393,182 @@ impl ProcessManager {
     /// instead.
     ///
     pub fn try_recv() -> Result<Option<Message>, Error> {
+        let message: Option<Message> = {
+            let mut pm: RefMut<ProcessManagerInner> = Self::get_mut()?.try_borrow_mut()?;
+            let running: &mut RunningProcess = pm.get_running_mut();
+            let message: Option<Message> = running.state_mut().receive_message();
+            if message.is_some() {
+                pm.number_buffered_messages -= 1;
+            }
+            message
+        };
+
+        // Wake up a sender that waits for room in the message buffer.
+        if message.is_some() {
+            pm::notify_buffer_space(1)?;
+        }
+
+        Ok(message)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the next message that is pending in the mailbox of the running process, without
+    /// removing it.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, a copy of the next message is returned, or `None` if the
+    /// mailbox is empty. Otherwise, an error code is returned instead.
+    ///
+    pub fn peek_message() -> Result<Option<Message>, Error> {
+        let pm: Ref<ProcessManagerInner> = Self::get()?.try_borrow()?;
+        let running: &RunningProcess = pm.get_running();
+        // NOTE: messages are not cloneable, thus the next message is copied field by field.
+        Ok(running.state().peek_message().map(|message| Message {
+            message_type: message.message_type,
+            source: message.source,
+            destination: message.destination,
+            status: message.status,
+            payload: message.payload,
+        }))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Discards all messages that are pending in the mailbox of the running process.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, the number of discarded messages is returned. Otherwise, an
+    /// error code is returned instead.
+    ///
+    pub fn flush_mailbox() -> Result<usize, Error> {
+        let count: usize = {
+            let mut pm: RefMut<ProcessManagerInner> = Self::get_mut()?.try_borrow_mut()?;
+            let running: &mut RunningProcess = pm.get_running_mut();
+            let count: usize = running.state_mut().flush_mailbox();
+            pm.number_buffered_messages -= count;
+            count
+        };
+
+        // Wake up senders that wait for room in the message buffer.
+        pm::notify_buffer_space(count)?;
+
+        Ok(count)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Enables or disables fair queuing on the mailbox of the running process.
+    ///
+    /// # Parameters
+    ///
+    /// - `fair`: Whether fair queuing should be enabled.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. Otherwise, an error code is returned instead.
+    ///
+    pub fn set_mailbox_fair(fair: bool) -> Result<(), Error> {
         let mut pm: RefMut<ProcessManagerInner> = Self::get_mut()?.try_borrow_mut()?;
         let running: &mut RunningProcess = pm.get_running_mut();
-        match running.state_mut().receive_message() {
-            Some(message) => {
-                pm.number_buffered_messages -= 1;
-                Ok(Some(message))
-            },
-            None => Ok(None),
+        running.state_mut().set_mailbox_fair(fair);
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sets aside a message that was received by the running process, so that it is not delivered
+    /// again until held messages are released.
+    ///
+    /// # Parameters
+    ///
+    /// - `message`: Message to hold.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. Otherwise, an error code is returned instead.
+    ///
+    pub fn hold_message(message: Message) -> Result<(), Error> {
+        let mut pm: RefMut<ProcessManagerInner> = Self::get_mut()?.try_borrow_mut()?;
+        let running: &mut RunningProcess = pm.get_running_mut();
+
+        // Check if message was not addressed to the running process.
+        if { message.destination } != running.state().pid() {
+            let reason: &str = "message is not addressed to running process";
+            error!("hold_message(): {}", reason);
+            return Err(Error::new(ErrorCode::InvalidArgument, reason));
         }
+
+        running.state_mut().hold_message(message)?;
+        pm.number_buffered_messages += 1;
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Releases all messages that are held by the running process, so that they are delivered
+    /// again ahead of pending messages.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, the number of released messages is returned. Otherwise, an
+    /// error code is returned instead.
+    ///
+    pub fn release_messages() -> Result<usize, Error> {
+        let mut pm: RefMut<ProcessManagerInner> = Self::get_mut()?.try_borrow_mut()?;
+        let running: &mut RunningProcess = pm.get_running_mut();
+        Ok(running.state_mut().release_messages())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of messages that are pending in the mailbox of a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, the number of pending messages is returned. Otherwise, an error
+    /// code is returned instead.
+    ///
+    pub fn number_pending_messages(pid: ProcessIdentifier) -> Result<usize, Error> {
+        Ok(Self::get()?
+            .try_borrow()?
+            .find_process(pid)?
+            .state()
+            .number_pending_messages())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the pressure on the mailbox of a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, the statistics of the mailbox are returned. Otherwise, an error
+    /// code is returned instead.
+    ///
+    pub fn mailbox_stats(pid: ProcessIdentifier) -> Result<MailboxStats, Error> {
+        Ok(Self::get()?
+            .try_borrow()?
+            .find_process(pid)?
+            .state()
+            .mailbox_stats())
     }
 
     ///
@@ -970,7 +1581,6 @@ impl ProcessManager {
     /// Upon successful completion, the number of buffered messages is returned. Otherwise, an error
     /// code is returned instead.
     ///
-    #[cfg(feature = "stdio")]
     pub fn number_buffered_messages(&self) -> Result<usize, Error> {
         Ok(self.try_borrow()?.number_buffered_messages)
     }
@@ -1044,3 +1654,12 @@ pub fn init(
 
     ProcessManager(pm)
 }
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
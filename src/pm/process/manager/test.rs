@@ -0,0 +1,120 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    hal::arch::ContextInformation,
+    mm::Vmem,
+    pm::{
+        process::{
+            identity::ProcessIdentity,
+            manager::ProcessManagerInner,
+            state::{
+                RunnableProcess,
+                ZombieProcess,
+            },
+        },
+        thread::{
+            self,
+            ReadyThread,
+            ThreadManager,
+        },
+    },
+};
+use ::alloc::collections::LinkedList;
+use ::sys::{
+    error::Error,
+    event::{
+        ProcessTerminationInfo,
+        TerminationCause,
+    },
+    pm::{
+        GroupIdentifier,
+        ProcessIdentifier,
+        UserIdentifier,
+    },
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Creates a process manager where a process with a given identifier is ready to run.
+fn pm_with_process(pid: ProcessIdentifier) -> Result<ProcessManagerInner, Error> {
+    let (kernel, mut tm): (ReadyThread, ThreadManager) = thread::init();
+    let thread: ReadyThread = tm.create_thread(ContextInformation::default())?;
+    let identity: ProcessIdentity =
+        ProcessIdentity::new(UserIdentifier::ROOT, GroupIdentifier::ROOT);
+    let vmem: Vmem = Vmem::new(LinkedList::new(), LinkedList::new())?;
+    let root: Vmem = Vmem::new(LinkedList::new(), LinkedList::new())?;
+
+    let mut pm: ProcessManagerInner = ProcessManagerInner::new(false, kernel, root, tm);
+    pm.ready.push_back(RunnableProcess::new(pid, identity, thread, vmem));
+
+    Ok(pm)
+}
+
+/// Tests if a faulting process whose termination was deferred is terminated and reaped on the next
+/// harvest, while processes that cannot be terminated yet are kept for a later attempt.
+fn test_kill_faulted() -> bool {
+    const EXCEPTION: u32 = 14;
+    let faulting: ProcessIdentifier = ProcessIdentifier::from(3);
+    let gone: ProcessIdentifier = ProcessIdentifier::from(4);
+
+    let mut pm: ProcessManagerInner = match pm_with_process(faulting) {
+        Ok(pm) => pm,
+        Err(e) => {
+            error!("failed to create process manager (error={:?})", e);
+            return false;
+        },
+    };
+
+    // The kernel process is running, thus it cannot be terminated.
+    let mut deferred: LinkedList<(ProcessIdentifier, u32)> = LinkedList::new();
+    deferred.push_back((faulting, EXCEPTION));
+    deferred.push_back((gone, EXCEPTION));
+    deferred.push_back((ProcessIdentifier::KERNEL, EXCEPTION));
+    pm.kill_faulted(&mut deferred);
+
+    // Check if only the process that cannot be terminated yet was kept.
+    if deferred.len() != 1 || deferred.front() != Some(&(ProcessIdentifier::KERNEL, EXCEPTION)) {
+        error!("unexpected deferred faults (len={})", deferred.len());
+        return false;
+    }
+
+    // Check if the faulting process is reaped with its exception as the cause of termination.
+    let expected: ProcessTerminationInfo = ProcessTerminationInfo::new(
+        faulting,
+        ZombieProcess::KILLED,
+        TerminationCause::Faulted {
+            exception: EXCEPTION,
+        },
+    );
+    match pm.harvest_zombies() {
+        Some(info) if info == expected => {},
+        info => {
+            error!("unexpected termination info (expected={:?}, got={:?})", expected, info);
+            return false;
+        },
+    }
+
+    // Check if no other process was reaped.
+    if let Some(info) = pm.harvest_zombies() {
+        error!("unexpected zombie process (info={:?})", info);
+        return false;
+    }
+
+    true
+}
+
+/// Runs all unit tests for the process manager.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_kill_faulted);
+
+    passed
+}
@@ -8,6 +8,7 @@
 mod capability;
 mod identity;
 mod manager;
+mod pid;
 mod state;
 
 //==================================================================================================
@@ -18,3 +19,15 @@ pub use manager::{
     init,
     ProcessManager,
 };
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= capability::test();
+    passed &= manager::test();
+    passed &= pid::test();
+    passed &= state::test();
+
+    passed
+}
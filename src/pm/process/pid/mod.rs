@@ -0,0 +1,253 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::alloc::{
+    collections::LinkedList,
+    vec::Vec,
+};
+use ::sys::{
+    config,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    pm::ProcessIdentifier,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A type that allocates process identifiers.
+///
+/// # Notes
+///
+/// - Process identifiers are handed out in increasing order and wrap around once the largest
+///   identifier that fits in a kernel call return value is reached.
+/// - A process identifier is never reused while some process holds a watch on it.
+///
+pub struct PidAllocator {
+    /// First process identifier.
+    first: ProcessIdentifier,
+    /// Next process identifier.
+    next: ProcessIdentifier,
+    /// Watches held on process identifiers (watcher and watched process).
+    watches: LinkedList<(ProcessIdentifier, ProcessIdentifier)>,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl PidAllocator {
+    /// Largest process identifier.
+    const MAX_PID: u32 = i32::MAX as u32;
+
+    ///
+    /// # Description
+    ///
+    /// Instantiates a process identifier allocator.
+    ///
+    /// # Parameters
+    ///
+    /// - `first`: First process identifier to hand out.
+    ///
+    /// # Returns
+    ///
+    /// A new process identifier allocator.
+    ///
+    pub fn new(first: ProcessIdentifier) -> Self {
+        Self {
+            first,
+            next: first,
+            watches: LinkedList::new(),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates a process identifier.
+    ///
+    /// # Parameters
+    ///
+    /// - `in_use`: Function that checks whether a process identifier is in use.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a process identifier that is neither in use nor watched is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn alloc(
+        &mut self,
+        in_use: impl Fn(ProcessIdentifier) -> bool,
+    ) -> Result<ProcessIdentifier, Error> {
+        for _ in u32::from(self.first)..=Self::MAX_PID {
+            let pid: ProcessIdentifier = self.next;
+
+            // Advance to the next process identifier, wrapping around if needed.
+            self.next = if u32::from(pid) >= Self::MAX_PID {
+                self.first
+            } else {
+                ProcessIdentifier::from(u32::from(pid) + 1)
+            };
+
+            // Skip identifiers that are still referenced.
+            if !in_use(pid) && !self.is_watched(pid) {
+                return Ok(pid);
+            }
+        }
+
+        let reason: &str = "no process identifier available";
+        error!("alloc(): {}", reason);
+        Err(Error::new(ErrorCode::OutOfMemory, reason))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Registers a watch on a process identifier.
+    ///
+    /// # Parameters
+    ///
+    /// - `watcher`: Process that holds the watch.
+    /// - `pid`: Process identifier to watch.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn watch(
+        &mut self,
+        watcher: ProcessIdentifier,
+        pid: ProcessIdentifier,
+    ) -> Result<(), Error> {
+        // Check if the watcher already holds a watch on the target process identifier.
+        if self.watches.contains(&(watcher, pid)) {
+            let reason: &str = "watch already exists";
+            error!("watch(): {} (watcher={:?}, pid={:?})", reason, watcher, pid);
+            return Err(Error::new(ErrorCode::EntryExists, reason));
+        }
+
+        // Check if the watcher holds too many watches.
+        if self.watches.iter().filter(|&&(w, _)| w == watcher).count()
+            >= config::kernel::MAX_WATCHES
+        {
+            let reason: &str = "too many watches";
+            error!("watch(): {} (watcher={:?})", reason, watcher);
+            return Err(Error::new(ErrorCode::TryAgain, reason));
+        }
+
+        self.watches.push_back((watcher, pid));
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Releases a watch on a process identifier.
+    ///
+    /// # Parameters
+    ///
+    /// - `watcher`: Process that holds the watch.
+    /// - `pid`: Watched process identifier.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn unwatch(
+        &mut self,
+        watcher: ProcessIdentifier,
+        pid: ProcessIdentifier,
+    ) -> Result<(), Error> {
+        match self.watches.iter().position(|&w| w == (watcher, pid)) {
+            Some(at) => {
+                self.watches.remove(at);
+                Ok(())
+            },
+            None => {
+                let reason: &str = "no such watch";
+                error!("unwatch(): {} (watcher={:?}, pid={:?})", reason, watcher, pid);
+                Err(Error::new(ErrorCode::NoSuchEntry, reason))
+            },
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks whether a process identifier is watched.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// `true` if some process holds a watch on `pid` and `false` otherwise.
+    ///
+    pub fn is_watched(&self, pid: ProcessIdentifier) -> bool {
+        self.watches.iter().any(|&(_, p)| p == pid)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the processes that hold a watch on a process identifier.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Process identifier.
+    ///
+    /// # Returns
+    ///
+    /// The processes that hold a watch on `pid`, without duplicates.
+    ///
+    pub fn watchers(&self, pid: ProcessIdentifier) -> Vec<ProcessIdentifier> {
+        let mut watchers: Vec<ProcessIdentifier> = Vec::new();
+        for &(watcher, p) in self.watches.iter() {
+            if p == pid && !watchers.contains(&watcher) {
+                watchers.push(watcher);
+            }
+        }
+        watchers
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Releases all watches that are held by a process.
+    ///
+    /// # Parameters
+    ///
+    /// - `watcher`: Process that holds the watches.
+    ///
+    pub fn release_watcher(&mut self, watcher: ProcessIdentifier) {
+        self.watches.retain(|&(w, _)| w != watcher);
+    }
+}
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
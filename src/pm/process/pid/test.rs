@@ -0,0 +1,176 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::pm::process::pid::PidAllocator;
+use ::alloc::vec::Vec;
+use ::sys::{
+    config,
+    error::ErrorCode,
+    pm::ProcessIdentifier,
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if a watched process identifier is not reused until the watch is released.
+fn test_watch_prevents_reuse() -> bool {
+    let supervisor: ProcessIdentifier = ProcessIdentifier::INITD;
+    let mut allocator: PidAllocator = PidAllocator::new(ProcessIdentifier::from(2));
+
+    // Spawn a child and watch it.
+    let child: ProcessIdentifier = match allocator.alloc(|_| false) {
+        Ok(pid) => pid,
+        Err(e) => {
+            error!("failed to allocate pid (error={:?})", e);
+            return false;
+        },
+    };
+    if let Err(e) = allocator.watch(supervisor, child) {
+        error!("failed to watch pid (error={:?})", e);
+        return false;
+    }
+
+    // Let the child die and force the allocator to wrap around.
+    allocator.next = ProcessIdentifier::from(PidAllocator::MAX_PID);
+    let _ = allocator.alloc(|_| false);
+
+    // Check if the child's identifier is not reused while it is watched.
+    match allocator.alloc(|_| false) {
+        Ok(pid) if pid != child => {},
+        Ok(pid) => {
+            error!("watched pid was reused (pid={:?})", pid);
+            return false;
+        },
+        Err(e) => {
+            error!("failed to allocate pid (error={:?})", e);
+            return false;
+        },
+    }
+
+    // Drop the watch and force the allocator to wrap around again.
+    if let Err(e) = allocator.unwatch(supervisor, child) {
+        error!("failed to release watch (error={:?})", e);
+        return false;
+    }
+    allocator.next = ProcessIdentifier::from(PidAllocator::MAX_PID);
+    let _ = allocator.alloc(|_| false);
+
+    // Check if the child's identifier is reusable after the watch is dropped.
+    match allocator.alloc(|_| false) {
+        Ok(pid) if pid == child => true,
+        Ok(pid) => {
+            error!("unexpected pid (expected={:?}, got={:?})", child, pid);
+            false
+        },
+        Err(e) => {
+            error!("failed to allocate pid (error={:?})", e);
+            false
+        },
+    }
+}
+
+/// Tests if the watches held by a process are released when it is reaped.
+fn test_release_watcher() -> bool {
+    let supervisor: ProcessIdentifier = ProcessIdentifier::INITD;
+    let watcher: ProcessIdentifier = ProcessIdentifier::from(2);
+    let child: ProcessIdentifier = ProcessIdentifier::from(3);
+    let mut allocator: PidAllocator = PidAllocator::new(ProcessIdentifier::from(2));
+
+    // Watch the child from two processes.
+    for pid in [supervisor, watcher] {
+        if let Err(e) = allocator.watch(pid, child) {
+            error!("failed to watch pid (error={:?})", e);
+            return false;
+        }
+    }
+
+    // Check if every watcher is reported exactly once.
+    let watchers: Vec<ProcessIdentifier> = allocator.watchers(child);
+    if watchers != [supervisor, watcher] {
+        error!("unexpected watchers (watchers={:?})", watchers);
+        return false;
+    }
+
+    // Release the watches held by the watcher and check if the others are kept.
+    allocator.release_watcher(watcher);
+    if allocator.watchers(child) != [supervisor] {
+        error!("watches of another process were released");
+        return false;
+    }
+
+    // Release the remaining watch and check if the child is no longer watched.
+    allocator.release_watcher(supervisor);
+    if allocator.is_watched(child) {
+        error!("released watch still held (pid={:?})", child);
+        return false;
+    }
+
+    true
+}
+
+/// Tests if duplicate watches are rejected and watches held by a process are bounded.
+fn test_watch_bounded() -> bool {
+    let watcher: ProcessIdentifier = ProcessIdentifier::from(2);
+    let other: ProcessIdentifier = ProcessIdentifier::from(3);
+    let first: u32 = 4;
+    let mut allocator: PidAllocator = PidAllocator::new(ProcessIdentifier::from(2));
+
+    // Check if a duplicate watch is rejected.
+    let child: ProcessIdentifier = ProcessIdentifier::from(first);
+    if let Err(e) = allocator.watch(watcher, child) {
+        error!("failed to watch pid (error={:?})", e);
+        return false;
+    }
+    match allocator.watch(watcher, child) {
+        Err(e) if e.code == ErrorCode::EntryExists => {},
+        result => {
+            error!("duplicate watch was not rejected (result={:?})", result);
+            return false;
+        },
+    }
+
+    // Watch as many processes as allowed.
+    for i in 1..config::kernel::MAX_WATCHES {
+        let pid: ProcessIdentifier = ProcessIdentifier::from(first + i as u32);
+        if let Err(e) = allocator.watch(watcher, pid) {
+            error!("failed to watch pid (pid={:?}, error={:?})", pid, e);
+            return false;
+        }
+    }
+
+    // Check if a further watch is rejected.
+    let pid: ProcessIdentifier =
+        ProcessIdentifier::from(first + config::kernel::MAX_WATCHES as u32);
+    match allocator.watch(watcher, pid) {
+        Err(e) if e.code == ErrorCode::TryAgain => {},
+        result => {
+            error!("watch beyond the limit was not rejected (result={:?})", result);
+            return false;
+        },
+    }
+
+    // Check if other processes may still watch.
+    match allocator.watch(other, pid) {
+        Ok(()) => true,
+        Err(e) => {
+            error!("watch of another process was rejected (error={:?})", e);
+            false
+        },
+    }
+}
+
+/// Runs all unit tests for process identifier allocation.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_watch_prevents_reuse);
+    passed &= run_test!(test_release_watcher);
+    passed &= run_test!(test_watch_bounded);
+
+    passed
+}
@@ -11,12 +11,18 @@ mod running;
 mod suspended;
 mod zombie;
 
+#[cfg(test)]
+mod test;
+
 //==================================================================================================
 // Imports
 //==================================================================================================
 
 use crate::{
-    event::EventOwnership,
+    event::{
+        EventOwnership,
+        OwnershipKind,
+    },
     hal::{
         io::{
             AnyIoPort,
@@ -24,6 +30,7 @@ use crate::{
             IoPortWidth,
         },
         mem::{
+            AccessPermission,
             PageAligned,
             VirtualAddress,
         },
@@ -42,7 +49,10 @@ use ::sys::{
         ErrorCode,
     },
     event::Event,
-    ipc::Message,
+    ipc::{
+        MailboxStats,
+        Message,
+    },
     pm::{
         Capability,
         GroupIdentifier,
@@ -124,6 +134,8 @@ pub struct ProcessState {
     identity: ProcessIdentity,
     /// Capabilities.
     capabilities: Capabilities,
+    /// Capabilities that were permanently dropped.
+    dropped_capabilities: Capabilities,
     /// Memory address space.
     vmem: Vmem,
     /// Event ownerships.
@@ -142,6 +154,7 @@ impl ProcessState {
             pid,
             identity,
             capabilities: Capabilities::default(),
+            dropped_capabilities: Capabilities::default(),
             vmem,
             events: LinkedList::new(),
             mailbox: Mailbox::default(),
@@ -202,6 +215,56 @@ impl ProcessState {
         self.capabilities.has(capability)
     }
 
+    ///
+    /// # Description
+    ///
+    /// Checks whether the target process holds a capability. Capabilities that were dropped are
+    /// no longer held.
+    ///
+    /// # Parameters
+    ///
+    /// - `capability`: Capability to check.
+    ///
+    /// # Returns
+    ///
+    /// If the capability is held, empty is returned. Otherwise, an error with
+    /// [`ErrorCode::PermissionDenied`] is returned instead.
+    ///
+    pub fn check_capability(&self, capability: Capability) -> Result<(), Error> {
+        if !self.has_capability(capability) {
+            let reason: &str = "process does not have capability";
+            error!("check_capability(): {} (capability={:?})", reason, capability);
+            return Err(Error::new(ErrorCode::PermissionDenied, reason));
+        }
+
+        Ok(())
+    }
+
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    pub fn set_capabilities(&mut self, capabilities: Capabilities) {
+        self.capabilities.set_all(capabilities.bits())
+    }
+
+    pub fn clear_capabilities(&mut self, capabilities: Capabilities) {
+        self.capabilities.clear_all(capabilities.bits())
+    }
+
+    pub fn dropped_capabilities(&self) -> Capabilities {
+        self.dropped_capabilities
+    }
+
+    pub fn drop_capability(&mut self, capability: Capability) {
+        self.capabilities.clear(capability);
+        self.dropped_capabilities.set(capability);
+    }
+
+    pub fn is_capability_dropped(&self, capability: Capability) -> bool {
+        self.dropped_capabilities.has(capability)
+    }
+
     pub fn vmem(&self) -> &Vmem {
         &self.vmem
     }
@@ -228,22 +291,80 @@ impl ProcessState {
         self.vmem.copy_to_user_unaligned(dst, src, size)
     }
 
+    pub fn check_user_access(
+        &self,
+        vaddr: VirtualAddress,
+        size: usize,
+        access: AccessPermission,
+    ) -> Result<(), Error> {
+        self.vmem.check_user_access(vaddr, size, access)
+    }
+
     pub fn add_event(&mut self, ownership: EventOwnership) {
         self.events.push_back(ownership)
     }
 
-    pub fn remove_event(&mut self, ev: &Event) {
-        self.events.retain(|o| o.event() != ev)
+    pub fn remove_event(&mut self, ev: &Event, kind: OwnershipKind) {
+        self.events.retain(|o| o.kind() != kind || o.event() != ev)
+    }
+
+    pub fn take_event(&mut self, ev: &Event, kind: OwnershipKind) -> Option<EventOwnership> {
+        let idx: usize = self
+            .events
+            .iter()
+            .position(|o| o.kind() == kind && o.event() == ev)?;
+        Some(self.events.remove(idx))
+    }
+
+    pub fn remove_default_event(&mut self) {
+        self.events.retain(|o| o.kind() != OwnershipKind::Default)
     }
 
     pub fn post_message(&mut self, message: Message) {
-        self.mailbox.send(message)
+        // Only messages that are created by the kernel itself may jump ahead of pending messages,
+        // otherwise any sender could forge the type of its message to do so.
+        if { message.source } == ProcessIdentifier::KERNEL {
+            self.mailbox.send_prioritized(message)
+        } else {
+            self.mailbox.send(message)
+        }
     }
 
     pub fn receive_message(&mut self) -> Option<Message> {
         self.mailbox.receive()
     }
 
+    pub fn peek_message(&self) -> Option<&Message> {
+        self.mailbox.peek()
+    }
+
+    pub fn flush_mailbox(&mut self) -> usize {
+        self.mailbox.drain()
+    }
+
+    pub fn set_mailbox_fair(&mut self, fair: bool) {
+        self.mailbox.set_fair(fair)
+    }
+
+    pub fn hold_message(&mut self, message: Message) -> Result<(), Error> {
+        self.mailbox.hold(message)
+    }
+
+    pub fn release_messages(&mut self) -> usize {
+        self.mailbox.release()
+    }
+
+    pub fn number_pending_messages(&self) -> usize {
+        self.mailbox.len()
+    }
+
+    pub fn mailbox_stats(&self) -> MailboxStats {
+        MailboxStats {
+            depth: self.mailbox.len(),
+            high_water: self.mailbox.high_water(),
+        }
+    }
+
     pub fn add_mmio(&mut self, region: IoMemoryRegion) {
         self.mmio.push_back(region)
     }
@@ -307,3 +428,12 @@ impl ProcessState {
         }
     }
 }
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
@@ -6,12 +6,12 @@
 //==================================================================================================
 
 use crate::{
-    hal::{
-        arch::ContextInformation,
-        mem::VirtualAddress,
-    },
+    hal::arch::ContextInformation,
     mm::{
-        elf::Elf32Fhdr,
+        elf::{
+            Elf32Fhdr,
+            LoadedElf,
+        },
         VirtMemoryManager,
         Vmem,
     },
@@ -29,6 +29,7 @@ use crate::{
 };
 use ::sys::{
     error::Error,
+    event::TerminationCause,
     pm::ProcessIdentifier,
 };
 
@@ -85,11 +86,11 @@ impl RunnableProcess {
         self.state.as_mut().unwrap()
     }
 
-    pub fn terminate(mut self) -> ZombieProcess {
+    pub fn terminate(mut self, cause: TerminationCause) -> ZombieProcess {
         let state = self.state.take().unwrap();
         let thread = self.thread.take().unwrap();
         let thread = thread.terminate();
-        ZombieProcess::new(state, thread, ZombieProcess::KILLED)
+        ZombieProcess::new(state, thread, ZombieProcess::KILLED, cause)
     }
 
     pub fn run(mut self) -> (RunningProcess, *mut ContextInformation) {
@@ -102,7 +103,8 @@ impl RunnableProcess {
         &mut self,
         mm: &mut VirtMemoryManager,
         elf: &Elf32Fhdr,
-    ) -> Result<VirtualAddress, Error> {
-        mm.load_elf(self.state.as_mut().unwrap().vmem_mut(), elf)
+        size: usize,
+    ) -> Result<LoadedElf, Error> {
+        mm.load_elf(self.state.as_mut().unwrap().vmem_mut(), elf, size)
     }
 }
@@ -19,7 +19,10 @@ use crate::{
 };
 use ::alloc::rc::Rc;
 use ::core::cell::RefCell;
-use ::sys::pm::ThreadIdentifier;
+use ::sys::{
+    event::TerminationCause,
+    pm::ThreadIdentifier,
+};
 
 //==================================================================================================
 // Structures
@@ -78,12 +81,13 @@ impl RunningProcess {
     pub fn exit(
         mut self,
         status: i32,
+        cause: TerminationCause,
     ) -> Result<(RunnableProcess, *mut ContextInformation), (ZombieProcess, *mut ContextInformation)>
     {
         let running_thread = self.running.borrow_mut().take().unwrap();
         let (zombie_thread, ctx) = running_thread.exit();
 
-        Err((ZombieProcess::new(self.state.take().unwrap(), zombie_thread, status), ctx))
+        Err((ZombieProcess::new(self.state.take().unwrap(), zombie_thread, status, cause), ctx))
     }
 
     pub fn get_tid(&self) -> ThreadIdentifier {
@@ -0,0 +1,95 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    mm::Vmem,
+    pm::process::{
+        identity::ProcessIdentity,
+        state::ProcessState,
+    },
+};
+use ::alloc::collections::LinkedList;
+use ::sys::{
+    ipc::{
+        Message,
+        MessageType,
+    },
+    pm::{
+        GroupIdentifier,
+        ProcessIdentifier,
+        UserIdentifier,
+    },
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Builds a message of a given type from a given source.
+fn message(source: ProcessIdentifier, message_type: MessageType) -> Message {
+    Message {
+        source,
+        message_type,
+        ..Message::default()
+    }
+}
+
+/// Tests if a message whose type is forged by a process does not jump ahead of pending messages,
+/// while a message of the same type that is created by the kernel does.
+fn test_post_message_forged_type() -> bool {
+    let sender: ProcessIdentifier = ProcessIdentifier::from(3);
+
+    let vmem: Vmem = match Vmem::new(LinkedList::new(), LinkedList::new()) {
+        Ok(vmem) => vmem,
+        Err(e) => {
+            error!("failed to create virtual memory space (error={:?})", e);
+            return false;
+        },
+    };
+    let identity: ProcessIdentity =
+        ProcessIdentity::new(UserIdentifier::ROOT, GroupIdentifier::ROOT);
+    let mut state: ProcessState = ProcessState::new(ProcessIdentifier::from(2), identity, vmem);
+
+    state.post_message(message(sender, MessageType::Ipc));
+    state.post_message(message(sender, MessageType::Exception));
+    state.post_message(message(ProcessIdentifier::KERNEL, MessageType::Exception));
+
+    // Check if only the message that is created by the kernel was delivered out of order.
+    let expected: [(ProcessIdentifier, MessageType); 3] = [
+        (ProcessIdentifier::KERNEL, MessageType::Exception),
+        (sender, MessageType::Ipc),
+        (sender, MessageType::Exception),
+    ];
+    for (source, typ) in expected {
+        match state.receive_message() {
+            Some(m) if { m.source } == source && m.message_type == typ => {},
+            Some(m) => {
+                error!(
+                    "unexpected message (expected={:?}, got={:?})",
+                    (source, typ),
+                    ({ m.source }, m.message_type)
+                );
+                return false;
+            },
+            None => {
+                error!("missing message");
+                return false;
+            },
+        }
+    }
+
+    true
+}
+
+/// Runs all unit tests for process states.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_post_message_forged_type);
+
+    passed
+}
@@ -9,6 +9,10 @@ use crate::pm::{
     process::state::ProcessState,
     thread::ZombieThread,
 };
+use ::sys::event::{
+    ProcessTerminationInfo,
+    TerminationCause,
+};
 
 //==================================================================================================
 // Zombie Process
@@ -24,16 +28,23 @@ pub struct ZombieProcess {
     zombie: Option<ZombieThread>,
     process: Option<ProcessState>,
     status: i32,
+    cause: TerminationCause,
 }
 
 impl ZombieProcess {
     pub const KILLED: i32 = -1;
 
-    pub fn new(process: ProcessState, zombie: ZombieThread, status: i32) -> Self {
+    pub fn new(
+        process: ProcessState,
+        zombie: ZombieThread,
+        status: i32,
+        cause: TerminationCause,
+    ) -> Self {
         Self {
             zombie: Some(zombie),
             process: Some(process),
             status,
+            cause,
         }
     }
 
@@ -45,7 +56,10 @@ impl ZombieProcess {
         self.process.as_mut().unwrap()
     }
 
-    pub fn bury(&mut self) -> (ZombieThread, ProcessState, i32) {
-        (self.zombie.take().unwrap(), self.process.take().unwrap(), self.status)
+    pub fn bury(&mut self) -> (ZombieThread, ProcessState, ProcessTerminationInfo) {
+        let process: ProcessState = self.process.take().unwrap();
+        let info: ProcessTerminationInfo =
+            ProcessTerminationInfo::new(process.pid(), self.status, self.cause);
+        (self.zombie.take().unwrap(), process, info)
     }
 }
@@ -5,11 +5,20 @@
 // Imports
 //==================================================================================================
 
-use crate::pm::ProcessManager;
-use ::alloc::collections::LinkedList;
+use crate::{
+    hal::time,
+    pm::ProcessManager,
+};
+use ::alloc::{
+    collections::LinkedList,
+    vec::Vec,
+};
 use ::core::cell::RefCell;
 use ::sys::{
-    error::Error,
+    error::{
+        Error,
+        ErrorCode,
+    },
     pm::{
         ProcessIdentifier,
         ThreadIdentifier,
@@ -28,6 +37,12 @@ use ::sys::{
 pub struct Condvar {
     /// Threads that are sleeping on the condition variable.
     sleeping: RefCell<LinkedList<(ProcessIdentifier, ThreadIdentifier)>>,
+    /// Threads whose wait on the condition variable was canceled.
+    canceled: RefCell<LinkedList<(ProcessIdentifier, ThreadIdentifier)>>,
+    /// Deadlines, in timer ticks, of threads that are sleeping with a timeout.
+    deadlines: RefCell<LinkedList<(ThreadIdentifier, u64)>>,
+    /// Threads whose wait on the condition variable timed out.
+    timedout: RefCell<LinkedList<ThreadIdentifier>>,
 }
 
 //==================================================================================================
@@ -47,6 +62,9 @@ impl Condvar {
     pub fn new() -> Self {
         Self {
             sleeping: RefCell::new(LinkedList::new()),
+            canceled: RefCell::new(LinkedList::new()),
+            deadlines: RefCell::new(LinkedList::new()),
+            timedout: RefCell::new(LinkedList::new()),
         }
     }
 
@@ -93,19 +111,341 @@ impl Condvar {
         Ok(())
     }
 
+    ///
+    /// # Description
+    ///
+    /// Cancels the wait of all threads of a process that are waiting on the target condition
+    /// variable. Canceled threads are woken up and their wait fails with
+    /// [`ErrorCode::Interrupted`].
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Identifier of the target process.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. Otherwise, an error is returned instead.
+    ///
+    pub fn cancel(&self, pid: ProcessIdentifier) -> Result<(), Error> {
+        self.cancel_with(pid, ProcessManager::wakeup)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Cancels the wait of all threads of a process that are waiting on the target condition
+    /// variable, using a given function to wake up threads.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Identifier of the target process.
+    /// - `wakeup`: Function that wakes up a thread.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. Otherwise, an error is returned instead.
+    ///
+    pub(super) fn cancel_with(
+        &self,
+        pid: ProcessIdentifier,
+        wakeup: impl Fn(ThreadIdentifier) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        loop {
+            // Find process.
+            let idx: Option<usize> = self.sleeping.borrow().iter().position(|&(p, _)| p == pid);
+
+            // Remove process from sleeping queue.
+            let tid: ThreadIdentifier = match idx {
+                Some(at) => self.sleeping.borrow_mut().remove(at).1,
+                None => break,
+            };
+            self.canceled.borrow_mut().push_back((pid, tid));
+
+            // NOTE: if the process was terminated, the thread is no longer sleeping and it is
+            // resumed by the process manager instead.
+            match wakeup(tid) {
+                Ok(()) => {},
+                Err(e) if e.code == ErrorCode::NoSuchEntry => {},
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Discards the canceled waits of all threads of a process. This should be called once the
+    /// process is gone, so that threads that exit before they observe the cancellation do not leave
+    /// it behind for threads that later reuse their identifiers.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Identifier of the target process.
+    ///
+    pub fn release_process(&self, pid: ProcessIdentifier) {
+        self.canceled.borrow_mut().retain(|&(p, _)| p != pid);
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Wakes up all threads whose timed wait on the target condition variable has expired. Expired
+    /// threads are woken up and their wait reports a timeout.
+    ///
+    /// # Parameters
+    ///
+    /// - `now`: Current time, in timer ticks.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. Otherwise, an error is returned instead.
+    ///
+    /// # Notes
+    ///
+    /// - This function is called from the timer interrupt handler. If the condition variable or the
+    ///   process manager is busy, expired threads are woken up on a later tick instead.
+    ///
+    pub fn expire(&self, now: u64) -> Result<(), Error> {
+        self.expire_with(now, ProcessManager::wakeup)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Wakes up all threads whose timed wait on the target condition variable has expired, using a
+    /// given function to wake up threads.
+    ///
+    /// # Parameters
+    ///
+    /// - `now`: Current time, in timer ticks.
+    /// - `wakeup`: Function that wakes up a thread.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. Otherwise, an error is returned instead.
+    ///
+    pub(super) fn expire_with(
+        &self,
+        now: u64,
+        wakeup: impl Fn(ThreadIdentifier) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let (mut sleeping, mut deadlines, mut timedout) = match (
+            self.sleeping.try_borrow_mut(),
+            self.deadlines.try_borrow_mut(),
+            self.timedout.try_borrow_mut(),
+        ) {
+            (Ok(sleeping), Ok(deadlines), Ok(timedout)) => (sleeping, deadlines, timedout),
+            _ => return Ok(()),
+        };
+
+        let expired: Vec<ThreadIdentifier> = deadlines
+            .iter()
+            .filter(|&&(_, deadline)| deadline <= now)
+            .map(|&(tid, _)| tid)
+            .collect();
+
+        for tid in expired {
+            // Check if the thread is still sleeping. If not, it was notified or killed, and its
+            // deadline is discarded so that it does not leak.
+            if let Some(at) = sleeping.iter().position(|&(_, t)| t == tid) {
+                // Record the timeout before waking up the thread, so that it observes it.
+                timedout.push_back(tid);
+
+                match wakeup(tid) {
+                    Ok(()) => {},
+                    // NOTE: if the process was terminated, the thread is no longer sleeping and it
+                    // is resumed by the process manager instead.
+                    Err(e) if e.code == ErrorCode::NoSuchEntry => {
+                        timedout.pop_back();
+                    },
+                    // The thread was not woken up, so it is left in place for a later tick.
+                    Err(e) => {
+                        timedout.pop_back();
+                        if e.code == ErrorCode::ResourceBusy {
+                            return Ok(());
+                        }
+                        return Err(e);
+                    },
+                }
+
+                sleeping.remove(at);
+            }
+
+            if let Some(at) = deadlines.iter().position(|&(t, _)| t == tid) {
+                deadlines.remove(at);
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Enqueues a thread on the target condition variable.
+    ///
+    /// # Parameters
+    ///
+    /// - `pid`: Identifier of the process of the thread.
+    /// - `tid`: Identifier of the thread.
+    /// - `deadline`: Time, in timer ticks, at which the wait expires, if any.
+    ///
+    pub(crate) fn enqueue(
+        &self,
+        pid: ProcessIdentifier,
+        tid: ThreadIdentifier,
+        deadline: Option<u64>,
+    ) {
+        self.sleeping.borrow_mut().push_back((pid, tid));
+
+        // NOTE: the deadline is recorded after the thread is sleeping, so that it is not mistaken
+        // for the deadline of a thread that is no longer sleeping.
+        if let Some(deadline) = deadline {
+            self.deadlines.borrow_mut().push_back((tid, deadline));
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks whether the wait of a thread on the target condition variable timed out, and clears
+    /// the timeout.
+    ///
+    /// # Parameters
+    ///
+    /// - `tid`: Identifier of the thread.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the wait of the thread timed out and `false` otherwise.
+    ///
+    pub(super) fn take_timedout(&self, tid: ThreadIdentifier) -> bool {
+        let idx: Option<usize> = self.timedout.borrow().iter().position(|&t| t == tid);
+        match idx {
+            Some(at) => {
+                self.timedout.borrow_mut().remove(at);
+                true
+            },
+            None => false,
+        }
+    }
+
     ///
     /// # Description
     ///
     /// Waits on the condition variable.
     ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. If the wait was canceled, an error with
+    /// [`ErrorCode::Interrupted`] is returned. Otherwise, an error is returned instead.
+    ///
     pub fn wait(&self) -> Result<(), Error> {
+        self.wait_until(None)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Waits on the condition variable for at most a given number of timer ticks.
+    ///
+    /// # Parameters
+    ///
+    /// - `ticks`: Maximum number of timer ticks to wait for.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, a boolean indicating whether the wait timed out is returned. If
+    /// the wait was canceled, an error with [`ErrorCode::Interrupted`] is returned. Otherwise, an
+    /// error is returned instead.
+    ///
+    pub fn wait_timeout(&self, ticks: u64) -> Result<bool, Error> {
+        // Check if the wait has already expired.
+        if ticks == 0 {
+            return Ok(true);
+        }
+
+        let tid: ThreadIdentifier = ProcessManager::get_tid()?;
+        let deadline: u64 = time::ticks().saturating_add(ticks);
+
+        let result: Result<(), Error> = self.wait_until(Some(deadline));
+
+        // Remove deadline, in case the thread was notified before it expired.
+        let idx: Option<usize> = self.deadlines.borrow().iter().position(|&(t, _)| t == tid);
+        if let Some(at) = idx {
+            self.deadlines.borrow_mut().remove(at);
+        }
+
+        // Check if wait timed out.
+        let timedout: bool = self.take_timedout(tid);
+
+        result?;
+
+        Ok(timedout)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Waits on the condition variable, optionally until a deadline.
+    ///
+    /// # Parameters
+    ///
+    /// - `deadline`: Time, in timer ticks, at which the wait expires, if any.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. If the wait was canceled, an error with
+    /// [`ErrorCode::Interrupted`] is returned. Otherwise, an error is returned instead.
+    ///
+    fn wait_until(&self, deadline: Option<u64>) -> Result<(), Error> {
         let pid: ProcessIdentifier = ProcessManager::get_pid()?;
         let tid: ThreadIdentifier = ProcessManager::get_tid()?;
-        self.sleeping.borrow_mut().push_back((pid, tid));
+        self.enqueue(pid, tid, deadline);
 
-        ProcessManager::sleep()?;
+        let result: Result<(), Error> = ProcessManager::sleep();
 
-        Ok(())
+        self.finish_wait(tid, result)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Completes the wait of a thread on the condition variable, once the thread is woken up.
+    ///
+    /// # Parameters
+    ///
+    /// - `tid`: Identifier of the thread.
+    /// - `result`: Result of the sleep of the thread.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, empty is returned. If the wait was canceled, an error with
+    /// [`ErrorCode::Interrupted`] is returned. Otherwise, an error is returned instead.
+    ///
+    pub(super) fn finish_wait(
+        &self,
+        tid: ThreadIdentifier,
+        result: Result<(), Error>,
+    ) -> Result<(), Error> {
+        // Remove thread from sleeping queue, in case sleep was interrupted.
+        let idx: Option<usize> = self.sleeping.borrow().iter().position(|&(_, t)| t == tid);
+        if let Some(at) = idx {
+            self.sleeping.borrow_mut().remove(at);
+        }
+
+        // Check if wait was canceled.
+        let idx: Option<usize> = self.canceled.borrow().iter().position(|&(_, t)| t == tid);
+        if let Some(at) = idx {
+            self.canceled.borrow_mut().remove(at);
+            let reason: &str = "wait was canceled";
+            error!("wait(): {}", reason);
+            return Err(Error::new(ErrorCode::Interrupted, reason));
+        }
+
+        result
     }
 }
 
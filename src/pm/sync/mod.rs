@@ -9,8 +9,20 @@ pub mod condvar;
 pub mod mutex;
 pub mod semaphore;
 
+#[cfg(test)]
+mod test;
+
 #[cfg(feature = "smp")]
 pub mod spinlock;
 
 #[cfg(feature = "smp")]
 pub mod fence;
+
+#[cfg(test)]
+pub fn test() -> bool {
+    let mut passed = true;
+
+    passed &= test::test();
+
+    passed
+}
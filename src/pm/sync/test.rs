@@ -0,0 +1,258 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::pm::sync::condvar::Condvar;
+use ::core::cell::Cell;
+use ::sys::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    pm::{
+        ProcessIdentifier,
+        ThreadIdentifier,
+    },
+};
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Tests if a timed wait with no ticks left times out without sleeping.
+fn test_wait_timeout_expired() -> bool {
+    let condvar: Condvar = Condvar::new();
+
+    match condvar.wait_timeout(0) {
+        Ok(true) => true,
+        Ok(false) => {
+            error!("wait did not time out");
+            false
+        },
+        Err(e) => {
+            error!("failed to wait on condition variable (error={:?})", e);
+            false
+        },
+    }
+}
+
+/// Tests if expiring waits on a condition variable with no sleeping threads has no effect.
+fn test_expire_no_sleepers() -> bool {
+    let condvar: Condvar = Condvar::new();
+
+    if let Err(e) = condvar.expire(u64::MAX) {
+        error!("failed to expire waits (error={:?})", e);
+        return false;
+    }
+
+    // Check if a subsequent timed wait is not affected.
+    match condvar.wait_timeout(0) {
+        Ok(true) => true,
+        Ok(false) => {
+            error!("wait did not time out");
+            false
+        },
+        Err(e) => {
+            error!("failed to wait on condition variable (error={:?})", e);
+            false
+        },
+    }
+}
+
+/// Tests if a thread whose deadline passes is woken up and observes the timeout.
+fn test_expire_deadline() -> bool {
+    const DEADLINE: u64 = 8;
+
+    let condvar: Condvar = Condvar::new();
+    let tid: ThreadIdentifier = ThreadIdentifier::from(4);
+    condvar.enqueue(ProcessIdentifier::from(2), tid, Some(DEADLINE));
+
+    let woken: Cell<usize> = Cell::new(0);
+    let wakeup = |t: ThreadIdentifier| -> Result<(), Error> {
+        if t == tid {
+            woken.set(woken.get() + 1);
+        }
+        Ok(())
+    };
+
+    // Check if the thread is not woken up before its deadline.
+    if let Err(e) = condvar.expire_with(DEADLINE - 1, wakeup) {
+        error!("failed to expire waits (error={:?})", e);
+        return false;
+    }
+    if woken.get() != 0 || condvar.take_timedout(tid) {
+        error!("thread was woken up before its deadline");
+        return false;
+    }
+
+    // Check if the thread is woken up once its deadline passes.
+    if let Err(e) = condvar.expire_with(DEADLINE, wakeup) {
+        error!("failed to expire waits (error={:?})", e);
+        return false;
+    }
+    if woken.get() != 1 || !condvar.take_timedout(tid) {
+        error!("thread was not woken up at its deadline (woken={})", woken.get());
+        return false;
+    }
+
+    true
+}
+
+/// Tests if a thread that cannot be woken up because the process manager is busy is woken up on a
+/// later tick.
+fn test_expire_busy() -> bool {
+    const DEADLINE: u64 = 8;
+
+    let condvar: Condvar = Condvar::new();
+    let tid: ThreadIdentifier = ThreadIdentifier::from(4);
+    condvar.enqueue(ProcessIdentifier::from(2), tid, Some(DEADLINE));
+
+    // Fail to wake up the thread, as if the process manager was busy.
+    let busy = |_: ThreadIdentifier| -> Result<(), Error> {
+        Err(Error::new(ErrorCode::ResourceBusy, "process manager is busy"))
+    };
+    if let Err(e) = condvar.expire_with(DEADLINE, busy) {
+        error!("failed to expire waits (error={:?})", e);
+        return false;
+    }
+    if condvar.take_timedout(tid) {
+        error!("timeout was recorded for a thread that was not woken up");
+        return false;
+    }
+
+    // Check if the thread is woken up on the next tick.
+    let woken: Cell<usize> = Cell::new(0);
+    let wakeup = |_: ThreadIdentifier| -> Result<(), Error> {
+        woken.set(woken.get() + 1);
+        Ok(())
+    };
+    if let Err(e) = condvar.expire_with(DEADLINE + 1, wakeup) {
+        error!("failed to expire waits (error={:?})", e);
+        return false;
+    }
+    if woken.get() != 1 || !condvar.take_timedout(tid) {
+        error!("thread was not woken up on a later tick (woken={})", woken.get());
+        return false;
+    }
+
+    true
+}
+
+/// Tests if a sleeper is woken up exactly once after its deadline, however many ticks pass.
+fn test_expire_once() -> bool {
+    const DEADLINE: u64 = 8;
+    const NTICKS: u64 = 4 * DEADLINE;
+
+    let condvar: Condvar = Condvar::new();
+    let tid: ThreadIdentifier = ThreadIdentifier::from(4);
+    condvar.enqueue(ProcessIdentifier::from(2), tid, Some(DEADLINE));
+
+    // Run the timer for a while, waking up the sleeper when its deadline expires.
+    let woken: Cell<Option<u64>> = Cell::new(None);
+    let nwakeups: Cell<usize> = Cell::new(0);
+    for now in 0..NTICKS {
+        let wakeup = |_: ThreadIdentifier| -> Result<(), Error> {
+            woken.set(Some(now));
+            nwakeups.set(nwakeups.get() + 1);
+            Ok(())
+        };
+        if let Err(e) = condvar.expire_with(now, wakeup) {
+            error!("failed to expire waits (now={}, error={:?})", now, e);
+            return false;
+        }
+    }
+
+    // Check if the sleeper was woken up once, right at its deadline.
+    if nwakeups.get() != 1 || woken.get() != Some(DEADLINE) {
+        error!("unexpected wake ups (count={}, last={:?})", nwakeups.get(), woken.get());
+        return false;
+    }
+
+    true
+}
+
+/// Tests if a thread whose wait is canceled is woken up and its wait is interrupted.
+fn test_cancel() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let tid: ThreadIdentifier = ThreadIdentifier::from(4);
+    let condvar: Condvar = Condvar::new();
+    condvar.enqueue(pid, tid, None);
+
+    let woken: Cell<usize> = Cell::new(0);
+    let wakeup = |t: ThreadIdentifier| -> Result<(), Error> {
+        if t == tid {
+            woken.set(woken.get() + 1);
+        }
+        Ok(())
+    };
+
+    // Cancel the wait of the process.
+    if let Err(e) = condvar.cancel_with(pid, wakeup) {
+        error!("failed to cancel wait (error={:?})", e);
+        return false;
+    }
+    if woken.get() != 1 {
+        error!("canceled thread was not woken up (woken={})", woken.get());
+        return false;
+    }
+
+    // Check if the wait of the thread is interrupted, only once.
+    match condvar.finish_wait(tid, Ok(())) {
+        Err(e) if e.code == ErrorCode::Interrupted => {},
+        result => {
+            error!("wait was not interrupted (result={:?})", result);
+            return false;
+        },
+    }
+    match condvar.finish_wait(tid, Ok(())) {
+        Ok(()) => true,
+        result => {
+            error!("wait was interrupted twice (result={:?})", result);
+            false
+        },
+    }
+}
+
+/// Tests if a canceled wait of a thread that is gone does not interrupt a thread that reuses its
+/// identifier.
+fn test_cancel_released() -> bool {
+    let pid: ProcessIdentifier = ProcessIdentifier::from(2);
+    let tid: ThreadIdentifier = ThreadIdentifier::from(4);
+    let condvar: Condvar = Condvar::new();
+    condvar.enqueue(pid, tid, None);
+
+    // Cancel the wait of the process, which then goes away without observing it.
+    if let Err(e) = condvar.cancel_with(pid, |_| Ok(())) {
+        error!("failed to cancel wait (error={:?})", e);
+        return false;
+    }
+    condvar.release_process(pid);
+
+    // Check if a thread that reuses the identifier is not interrupted.
+    condvar.enqueue(ProcessIdentifier::from(3), tid, None);
+    match condvar.finish_wait(tid, Ok(())) {
+        Ok(()) => true,
+        result => {
+            error!("wait of a new thread was interrupted (result={:?})", result);
+            false
+        },
+    }
+}
+
+/// Runs all unit tests for synchronization primitives.
+pub fn test() -> bool {
+    let mut passed: bool = true;
+
+    passed &= run_test!(test_wait_timeout_expired);
+    passed &= run_test!(test_expire_no_sleepers);
+    passed &= run_test!(test_expire_deadline);
+    passed &= run_test!(test_expire_busy);
+    passed &= run_test!(test_expire_once);
+    passed &= run_test!(test_cancel);
+    passed &= run_test!(test_cancel_released);
+
+    passed
+}
@@ -56,6 +56,11 @@ pub mod kernel {
     ///
     /// Timer frequency (in Hz).
     ///
+    /// # Notes
+    ///
+    /// - This is a default value, which may be overridden with `timer_freq=` on the kernel command
+    ///   line.
+    ///
     pub const TIMER_FREQ: u32 = 100;
 
     ///
@@ -66,6 +71,8 @@ pub mod kernel {
     /// # Notes
     ///
     /// - This should be a power of two.
+    /// - This is a default value, which may be overridden with `scheduler_freq=` on the kernel
+    ///   command line.
     ///
     pub const SCHEDULER_FREQ: usize = 128;
 
@@ -81,6 +88,60 @@ pub mod kernel {
     ///
     pub const MAX_IKC_MESSAGES: usize = 128;
 
+    ///
+    /// # Description
+    ///
+    /// Maximum number of exceptions of a given type that can be pending resume.
+    ///
+    /// # Notes
+    ///
+    /// - When this threshold is reached, processes that trigger further exceptions of the same type
+    ///   are terminated instead.
+    /// - This value should be set according to the amount of memory available in the kernel heap.
+    ///
+    pub const MAX_PENDING_EXCEPTIONS: usize = 32;
+
+    ///
+    /// # Description
+    ///
+    /// Maximum number of interrupt copies that can be pending delivery to a given monitor.
+    ///
+    /// # Notes
+    ///
+    /// - When this threshold is reached, further copies for the same monitor are dropped.
+    ///
+    pub const MAX_MONITORED_INTERRUPTS: usize = 32;
+
+    ///
+    /// # Description
+    ///
+    /// Maximum number of interrupts that can be pending delivery on a given interrupt line.
+    ///
+    /// # Notes
+    ///
+    /// - When this threshold is reached, further interrupts on the same line are dropped.
+    /// - This value should be set according to the amount of memory available in the kernel heap.
+    ///
+    pub const MAX_PENDING_INTERRUPTS: usize = 64;
+
+    ///
+    /// # Description
+    ///
+    /// Maximum number of processes that can subscribe to a given scheduling event.
+    ///
+    pub const MAX_SCHEDULING_SUBSCRIBERS: usize = 4;
+
+    ///
+    /// # Description
+    ///
+    /// Maximum number of watches that a given process can hold on other processes.
+    ///
+    /// # Notes
+    ///
+    /// - This value should be set according to the amount of memory available in the kernel heap.
+    ///
+    pub const MAX_WATCHES: usize = 16;
+
     ///
     /// # Description
     ///
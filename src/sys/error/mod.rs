@@ -285,6 +285,11 @@ pub enum ErrorCode {
 }
 
 impl ErrorCode {
+    /// Operation timed out (ETIMEDOUT). This is the same code as [`ErrorCode::ConnectionTimeout`],
+    /// named for timeouts that are not related to connections.
+    #[allow(non_upper_case_globals)]
+    pub const TimedOut: ErrorCode = ErrorCode::ConnectionTimeout;
+
     ///
     /// # Description
     ///
@@ -29,6 +29,9 @@ impl EventDescriptor {
     const ID_MASK: usize = !(1 << (usize::BITS - 1) | Self::EVENT_MASK);
     const ID_SHIFT: usize = Self::EVENT_SHIFT + Self::EVENT_MASKLEN;
 
+    /// Largest event identifier that fits in a descriptor.
+    pub const MAX_ID: usize = Self::ID_MASK >> Self::ID_SHIFT;
+
     pub fn into_raw(&self) -> usize {
         self.0
     }
@@ -9,6 +9,9 @@ mod descriptor;
 mod exception;
 mod information;
 mod interrupt;
+mod owned;
+mod payload;
+mod pending;
 mod request;
 mod scheduling;
 
@@ -20,6 +23,9 @@ pub use descriptor::*;
 pub use exception::*;
 pub use information::*;
 pub use interrupt::*;
+pub use owned::*;
+pub use payload::*;
+pub use pending::*;
 pub use request::*;
 pub use scheduling::*;
 
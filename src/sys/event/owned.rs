@@ -0,0 +1,56 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::event::Event;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A set of the events that are owned by a process.
+///
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OwnedEvents {
+    /// Bitmask of interrupts that are owned.
+    pub interrupts: usize,
+    /// Bitmask of exceptions that are delivered, including those owned as the default handler.
+    pub exceptions: usize,
+    /// Bitmask of scheduling events that are owned.
+    pub scheduling: usize,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl OwnedEvents {
+    ///
+    /// # Description
+    ///
+    /// Checks whether an event is in the target set.
+    ///
+    /// # Parameters
+    ///
+    /// - `ev`: Event.
+    ///
+    /// # Returns
+    ///
+    /// If the event is in the target set, `true` is returned. Otherwise, `false` is returned
+    /// instead.
+    ///
+    pub fn contains(&self, ev: Event) -> bool {
+        match ev {
+            Event::Interrupt(ev) => self.interrupts & (1 << usize::from(ev)) != 0,
+            Event::Exception(ev) => self.exceptions & (1 << usize::from(ev)) != 0,
+            Event::Scheduling(ev) => self.scheduling & (1 << usize::from(ev)) != 0,
+        }
+    }
+}
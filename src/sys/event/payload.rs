@@ -0,0 +1,110 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// This structure packs information about an interrupt that is delivered to a process.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct InterruptMessagePayload {
+    /// Interrupt line that fired.
+    pub number: usize,
+    /// Identifier of the interrupt event.
+    pub id: usize,
+    /// Number of occurrences of the interrupt that the event stands for. This is greater than one
+    /// only if the line coalesces pending interrupts.
+    pub count: usize,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl InterruptMessagePayload {
+    ///
+    /// # Description
+    ///
+    /// Creates a new [`InterruptMessagePayload`] with the given information.
+    ///
+    /// # Parameters
+    ///
+    /// - `number`: Interrupt line that fired.
+    /// - `id`: Identifier of the interrupt event.
+    /// - `count`: Number of occurrences of the interrupt that the event stands for.
+    ///
+    /// # Returns
+    ///
+    /// The new [`InterruptMessagePayload`].
+    ///
+    pub fn new(number: usize, id: usize, count: usize) -> Self {
+        Self { number, id, count }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the memory representation of the target [`InterruptMessagePayload`] as a byte array
+    /// in native byte order.
+    ///
+    /// # Returns
+    ///
+    /// The memory representation of the target [`InterruptMessagePayload`] as a byte array in
+    /// native byte order.
+    ///
+    pub fn to_ne_bytes(self) -> [u8; core::mem::size_of::<InterruptMessagePayload>()] {
+        let mut bytes: [u8; core::mem::size_of::<InterruptMessagePayload>()] =
+            [0; core::mem::size_of::<InterruptMessagePayload>()];
+
+        let mut offset: usize = 0;
+        bytes[offset..offset + core::mem::size_of::<usize>()]
+            .copy_from_slice(&self.number.to_ne_bytes());
+        offset += core::mem::size_of::<usize>();
+
+        bytes[offset..offset + core::mem::size_of::<usize>()]
+            .copy_from_slice(&self.id.to_ne_bytes());
+        offset += core::mem::size_of::<usize>();
+
+        bytes[offset..offset + core::mem::size_of::<usize>()]
+            .copy_from_slice(&self.count.to_ne_bytes());
+
+        bytes
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Creates a new [`InterruptMessagePayload`] from a byte array in native byte order.
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The byte array in native byte order.
+    ///
+    /// # Returns
+    ///
+    /// The new [`InterruptMessagePayload`].
+    ///
+    pub fn from_ne_bytes(bytes: [u8; core::mem::size_of::<InterruptMessagePayload>()]) -> Self {
+        let mut offset: usize = 0;
+        let mut number: [u8; core::mem::size_of::<usize>()] = [0; core::mem::size_of::<usize>()];
+        number.copy_from_slice(&bytes[offset..offset + core::mem::size_of::<usize>()]);
+        offset += core::mem::size_of::<usize>();
+
+        let mut id: [u8; core::mem::size_of::<usize>()] = [0; core::mem::size_of::<usize>()];
+        id.copy_from_slice(&bytes[offset..offset + core::mem::size_of::<usize>()]);
+        offset += core::mem::size_of::<usize>();
+
+        let mut count: [u8; core::mem::size_of::<usize>()] = [0; core::mem::size_of::<usize>()];
+        count.copy_from_slice(&bytes[offset..offset + core::mem::size_of::<usize>()]);
+
+        Self {
+            number: usize::from_ne_bytes(number),
+            id: usize::from_ne_bytes(id),
+            count: usize::from_ne_bytes(count),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A snapshot of the number of events of each class that are pending on a process.
+///
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PendingCounts {
+    /// Number of pending interrupts, including copies of monitored interrupts.
+    pub interrupts: usize,
+    /// Number of pending exceptions, including those that were delivered but not yet resumed.
+    pub exceptions: usize,
+    /// Number of pending scheduling events. Pending signals count as a single event.
+    pub scheduling: usize,
+    /// Number of pending IPC messages.
+    pub ipc: usize,
+}
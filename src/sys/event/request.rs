@@ -5,9 +5,12 @@
 // Imports
 //==================================================================================================
 
-use crate::error::{
-    Error,
-    ErrorCode,
+use crate::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    pm::ProcessIdentifier,
 };
 
 //==================================================================================================
@@ -16,8 +19,69 @@ use crate::error::{
 
 #[derive(Debug, Clone, Copy)]
 pub enum EventCtrlRequest {
+    /// Registers the calling process as the owner of an event.
     Register,
+    /// Unregisters the calling process as the owner of an event.
     Unregister,
+    /// Registers the calling process as the owner of all exceptions that have no owner.
+    RegisterDefault,
+    /// Unregisters the calling process as the owner of all exceptions that have no owner.
+    UnregisterDefault,
+    /// Registers the calling process as an observer of an event, without taking ownership.
+    Monitor,
+    /// Unregisters the calling process as an observer of an event.
+    Unmonitor,
+    /// Transfers ownership of an event from the calling process to another process.
+    Transfer {
+        /// Process that becomes the owner of the event.
+        to: ProcessIdentifier,
+    },
+    /// Coalesces occurrences of an interrupt that fire while another one is pending delivery.
+    Coalesce,
+    /// Queues every occurrence of an interrupt for delivery (default).
+    Uncoalesce,
+}
+
+impl EventCtrlRequest {
+    ///
+    /// # Description
+    ///
+    /// Gets the argument of the target request.
+    ///
+    /// # Returns
+    ///
+    /// The raw argument of the target request, or zero if the request takes no argument.
+    ///
+    pub fn argument(&self) -> u32 {
+        match self {
+            EventCtrlRequest::Transfer { to } => u32::from(*to),
+            _ => 0,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Builds an event control request from its raw representation and its raw argument.
+    ///
+    /// # Parameters
+    ///
+    /// - `raw`: Raw representation of the request.
+    /// - `arg`: Raw argument of the request.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the event control request is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn from_raw_parts(raw: u32, arg: u32) -> Result<Self, Error> {
+        match raw {
+            6 => Ok(Self::Transfer {
+                to: ProcessIdentifier::from(arg),
+            }),
+            _ => Self::try_from(raw),
+        }
+    }
 }
 
 impl From<EventCtrlRequest> for u32 {
@@ -25,6 +89,13 @@ impl From<EventCtrlRequest> for u32 {
         match req {
             EventCtrlRequest::Register => 0,
             EventCtrlRequest::Unregister => 1,
+            EventCtrlRequest::RegisterDefault => 2,
+            EventCtrlRequest::UnregisterDefault => 3,
+            EventCtrlRequest::Monitor => 4,
+            EventCtrlRequest::Unmonitor => 5,
+            EventCtrlRequest::Transfer { .. } => 6,
+            EventCtrlRequest::Coalesce => 7,
+            EventCtrlRequest::Uncoalesce => 8,
         }
     }
 }
@@ -36,6 +107,13 @@ impl TryFrom<u32> for EventCtrlRequest {
         match raw {
             0 => Ok(Self::Register),
             1 => Ok(Self::Unregister),
+            2 => Ok(Self::RegisterDefault),
+            3 => Ok(Self::UnregisterDefault),
+            4 => Ok(Self::Monitor),
+            5 => Ok(Self::Unmonitor),
+            6 => Err(Error::new(ErrorCode::InvalidArgument, "transfer requires a target process")),
+            7 => Ok(Self::Coalesce),
+            8 => Ok(Self::Uncoalesce),
             _ => Err(Error::new(ErrorCode::InvalidArgument, "invalid event control request")),
         }
     }
@@ -24,6 +24,8 @@ use ::core::fmt::Debug;
 pub enum SchedulingEvent {
     /// Process termination.
     ProcessTermination,
+    /// Signals were raised. The payload carries the bitmask of pending signals.
+    Signal,
 }
 
 //==================================================================================================
@@ -32,10 +34,10 @@ pub enum SchedulingEvent {
 
 impl SchedulingEvent {
     /// Number of scheduling events.
-    pub const NUMBER_EVENTS: usize = 1;
+    pub const NUMBER_EVENTS: usize = 2;
 
     /// Scheduling events.
-    pub const VALUES: [Self; Self::NUMBER_EVENTS] = [Self::ProcessTermination];
+    pub const VALUES: [Self; Self::NUMBER_EVENTS] = [Self::ProcessTermination, Self::Signal];
 }
 
 impl From<SchedulingEvent> for u32 {
@@ -50,6 +52,7 @@ impl TryFrom<u32> for SchedulingEvent {
     fn try_from(raw: u32) -> Result<Self, Self::Error> {
         match raw {
             0 => Ok(Self::ProcessTermination),
+            1 => Ok(Self::Signal),
             _ => Err(Error::new(ErrorCode::InvalidArgument, "invalid scheduling event identifier")),
         }
     }
@@ -8,6 +8,28 @@
 use crate::pm::ProcessIdentifier;
 use ::core::fmt::Debug;
 
+//==================================================================================================
+// Enumerations
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Cause of the termination of a process.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TerminationCause {
+    /// The process exited on its own.
+    Exited,
+    /// The process was terminated due to an exception that could not be handled.
+    Faulted {
+        /// Number of the exception.
+        exception: u32,
+    },
+    /// The process was terminated by another process.
+    Killed,
+}
+
 //==================================================================================================
 // Structures
 //==================================================================================================
@@ -23,12 +45,78 @@ pub struct ProcessTerminationInfo {
     pub pid: ProcessIdentifier,
     /// Exit status of the process that terminated.
     pub status: i32,
+    /// Cause of the termination.
+    pub cause: TerminationCause,
 }
 
+// `ProcessTerminationInfo` must be 16 bytes long. This must match its memory representation.
+crate::static_assert_size!(ProcessTerminationInfo, 16);
+
 //==================================================================================================
 // Implementations
 //==================================================================================================
 
+impl TerminationCause {
+    /// Size of the memory representation of a termination cause.
+    pub const SIZE: usize = 2 * core::mem::size_of::<u32>();
+
+    /// Tag of a process that exited on its own.
+    const EXITED: u32 = 0;
+    /// Tag of a process that was terminated due to an exception.
+    const FAULTED: u32 = 1;
+    /// Tag of a process that was terminated by another process.
+    const KILLED: u32 = 2;
+
+    ///
+    /// # Description
+    ///
+    /// Returns the memory representation of the target [`TerminationCause`] as a byte array in
+    /// native byte order.
+    ///
+    /// # Returns
+    ///
+    /// The memory representation of the target [`TerminationCause`] as a byte array in native byte
+    /// order.
+    ///
+    pub fn to_ne_bytes(self) -> [u8; Self::SIZE] {
+        let (tag, exception): (u32, u32) = match self {
+            TerminationCause::Exited => (Self::EXITED, 0),
+            TerminationCause::Faulted { exception } => (Self::FAULTED, exception),
+            TerminationCause::Killed => (Self::KILLED, 0),
+        };
+
+        let mut bytes: [u8; Self::SIZE] = [0; Self::SIZE];
+        bytes[0..core::mem::size_of::<u32>()].copy_from_slice(&tag.to_ne_bytes());
+        bytes[core::mem::size_of::<u32>()..].copy_from_slice(&exception.to_ne_bytes());
+
+        bytes
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Creates a new [`TerminationCause`] from a byte array in native byte order.
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The byte array in native byte order.
+    ///
+    /// # Returns
+    ///
+    /// The new [`TerminationCause`]. Unknown causes are reported as [`TerminationCause::Killed`].
+    ///
+    pub fn from_ne_bytes(bytes: [u8; Self::SIZE]) -> Self {
+        let tag: u32 = u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let exception: u32 = u32::from_ne_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+
+        match tag {
+            Self::EXITED => TerminationCause::Exited,
+            Self::FAULTED => TerminationCause::Faulted { exception },
+            _ => TerminationCause::Killed,
+        }
+    }
+}
+
 impl ProcessTerminationInfo {
     ///
     /// # Description
@@ -39,13 +127,14 @@ impl ProcessTerminationInfo {
     ///
     /// - `pid`: Identifier of the process that terminated.
     /// - `status`: Exit status of the process that terminated.
+    /// - `cause`: Cause of the termination.
     ///
     /// # Returns
     ///
     /// The new [`ProcessTerminationInfo`].
     ///
-    pub fn new(pid: ProcessIdentifier, status: i32) -> Self {
-        Self { pid, status }
+    pub fn new(pid: ProcessIdentifier, status: i32, cause: TerminationCause) -> Self {
+        Self { pid, status, cause }
     }
 
     ///
@@ -70,6 +159,9 @@ impl ProcessTerminationInfo {
 
         bytes[offset..offset + core::mem::size_of::<i32>()]
             .copy_from_slice(&self.status.to_ne_bytes());
+        offset += core::mem::size_of::<i32>();
+
+        bytes[offset..offset + TerminationCause::SIZE].copy_from_slice(&self.cause.to_ne_bytes());
 
         bytes
     }
@@ -103,7 +195,12 @@ impl ProcessTerminationInfo {
             bytes[offset + 2],
             bytes[offset + 3],
         ]);
+        offset += core::mem::size_of::<i32>();
+
+        let mut cause: [u8; TerminationCause::SIZE] = [0; TerminationCause::SIZE];
+        cause.copy_from_slice(&bytes[offset..offset + TerminationCause::SIZE]);
+        let cause: TerminationCause = TerminationCause::from_ne_bytes(cause);
 
-        Self { pid, status }
+        Self { pid, status, cause }
     }
 }
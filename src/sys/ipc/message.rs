@@ -55,6 +55,8 @@ impl Message {
         2 * mem::size_of::<ProcessIdentifier>() + MessageType::SIZE + mem::size_of::<i32>();
     /// The size of the message's payload.
     pub const PAYLOAD_SIZE: usize = config::kernel::IPC_MESSAGE_SIZE - Self::HEADER_SIZE;
+    /// The size of the correlation id, which is stored in the first bytes of the payload.
+    pub const CORRELATION_SIZE: usize = mem::size_of::<u64>();
 
     ///
     /// # Description
@@ -93,6 +95,46 @@ impl Message {
         }
     }
 
+    ///
+    /// # Description
+    ///
+    /// Tags the target message with a correlation id, so that a reply can be matched against its
+    /// request.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: Correlation id.
+    ///
+    /// # Returns
+    ///
+    /// The tagged message.
+    ///
+    /// # Notes
+    ///
+    /// - The first [`Message::CORRELATION_SIZE`] bytes of the payload are reserved for the
+    ///   correlation id, and they are overwritten by this function.
+    ///
+    pub fn with_correlation(mut self, id: u64) -> Self {
+        self.payload[..Self::CORRELATION_SIZE].copy_from_slice(&id.to_ne_bytes());
+        self
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Gets the correlation id of the target message.
+    ///
+    /// # Returns
+    ///
+    /// The correlation id of the target message, which is read from the first
+    /// [`Message::CORRELATION_SIZE`] bytes of the payload.
+    ///
+    pub fn correlation(&self) -> u64 {
+        let mut id: [u8; Self::CORRELATION_SIZE] = [0; Self::CORRELATION_SIZE];
+        id.copy_from_slice(&self.payload[..Self::CORRELATION_SIZE]);
+        u64::from_ne_bytes(id)
+    }
+
     ///
     /// # Description
     ///
@@ -6,6 +6,7 @@
 //==================================================================================================
 
 mod message;
+mod stats;
 mod typ;
 
 //==================================================================================================
@@ -13,4 +14,5 @@ mod typ;
 //==================================================================================================
 
 pub use message::*;
+pub use stats::*;
 pub use typ::*;
@@ -0,0 +1,20 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A type that describes the pressure on the mailbox of a process.
+///
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct MailboxStats {
+    /// Number of pending messages.
+    pub depth: usize,
+    /// Highest number of pending messages ever observed.
+    pub high_water: usize,
+}
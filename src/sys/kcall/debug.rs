@@ -40,3 +40,32 @@ pub fn debug(buf: *const u8, size: usize) -> Result<(), Error> {
         Err(Error::new(ErrorCode::try_from(result)?, "failed to debug()"))
     }
 }
+
+///
+/// # Description
+///
+/// Gets the number of times that each kernel call was dispatched since the system was started.
+///
+/// # Parameters
+/// - `counters` - Buffer where the counters are stored, indexed by kernel call number.
+///
+/// # Return Values
+///
+/// Upon success, the number of counters that were stored is returned. This is the lesser of the
+/// length of `counters` and [`KcallNumber::COUNT`]. Upon failure, an error is returned instead.
+///
+pub fn kcall_stats(counters: &mut [u32]) -> Result<usize, Error> {
+    let result: i32 = unsafe {
+        arch::kcall2(
+            KcallNumber::KcallStats.into(),
+            counters.as_mut_ptr() as usize as u32,
+            counters.len() as u32,
+        )
+    };
+
+    if result >= 0 {
+        Ok(result as usize)
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to kcall_stats()"))
+    }
+}
@@ -14,9 +14,13 @@ use crate::{
         Event,
         EventCtrlRequest,
         EventDescriptor,
+        InterruptEvent,
+        OwnedEvents,
+        PendingCounts,
     },
     kcall::arch,
     number::KcallNumber,
+    pm::ProcessIdentifier,
 };
 
 //==================================================================================================
@@ -24,8 +28,33 @@ use crate::{
 //==================================================================================================
 
 pub fn resume(event: EventDescriptor) -> Result<(), Error> {
+    resume_signal(event, 0)
+}
+
+///
+/// # Description
+///
+/// Resumes a process that is waiting on an exception, and raises signals on it before it runs
+/// again.
+///
+/// # Parameters
+///
+/// - `event`: Descriptor of the exception event.
+/// - `signals`: Bitmask of signals to raise on the resumed process, or zero for none.
+///
+/// # Returns
+///
+/// Upon successful completion, empty is returned. Otherwise, an error is returned instead.
+///
+/// # Notes
+///
+/// - Only the owner of the exception may resume the faulting process.
+/// - The `Resume` kernel call takes the bitmask of signals as its second argument. Callers that
+///   issue it directly, rather than through [`resume`] or this function, must pass zero there.
+///
+pub fn resume_signal(event: EventDescriptor, signals: u32) -> Result<(), Error> {
     let result: i32 =
-        unsafe { arch::kcall1(KcallNumber::Resume.into(), usize::from(event) as u32) };
+        unsafe { arch::kcall2(KcallNumber::Resume.into(), usize::from(event) as u32, signals) };
 
     if result == 0 {
         Ok(())
@@ -39,8 +68,9 @@ pub fn resume(event: EventDescriptor) -> Result<(), Error> {
 //==================================================================================================
 
 pub fn evctrl(ev: Event, req: EventCtrlRequest) -> Result<(), Error> {
-    let result: i32 =
-        unsafe { arch::kcall2(KcallNumber::EventCtrl.into(), u32::from(ev), u32::from(req)) };
+    let result: i32 = unsafe {
+        arch::kcall3(KcallNumber::EventCtrl.into(), u32::from(ev), u32::from(req), req.argument())
+    };
 
     if result == 0 {
         Ok(())
@@ -48,3 +78,76 @@ pub fn evctrl(ev: Event, req: EventCtrlRequest) -> Result<(), Error> {
         Err(Error::new(ErrorCode::try_from(result)?, "failed to evctrl()"))
     }
 }
+
+//==================================================================================================
+// Sets the Affinity of an Interrupt
+//==================================================================================================
+
+pub fn intaffinity(ev: InterruptEvent, cpu: u8) -> Result<(), Error> {
+    let result: i32 =
+        unsafe { arch::kcall2(KcallNumber::IntAffinity.into(), u32::from(ev), cpu as u32) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to intaffinity()"))
+    }
+}
+
+//==================================================================================================
+// Raises Signals on a Process
+//==================================================================================================
+
+pub fn signal(pid: ProcessIdentifier, signals: u32) -> Result<(), Error> {
+    let result: i32 = unsafe { arch::kcall2(KcallNumber::Signal.into(), pid.into(), signals) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to signal()"))
+    }
+}
+
+//==================================================================================================
+// Counts the Events Pending on a Process
+//==================================================================================================
+
+pub fn pending_counts(pid: ProcessIdentifier) -> Result<PendingCounts, Error> {
+    let mut counts: PendingCounts = PendingCounts::default();
+
+    let result: i32 = unsafe {
+        arch::kcall2(
+            KcallNumber::PendingCounts.into(),
+            pid.into(),
+            &mut counts as *mut PendingCounts as usize as u32,
+        )
+    };
+
+    if result == 0 {
+        Ok(counts)
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to pending_counts()"))
+    }
+}
+
+//==================================================================================================
+// Lists the Events Owned by a Process
+//==================================================================================================
+
+pub fn owned_events(pid: ProcessIdentifier) -> Result<OwnedEvents, Error> {
+    let mut owned: OwnedEvents = OwnedEvents::default();
+
+    let result: i32 = unsafe {
+        arch::kcall2(
+            KcallNumber::OwnedEvents.into(),
+            pid.into(),
+            &mut owned as *mut OwnedEvents as usize as u32,
+        )
+    };
+
+    if result == 0 {
+        Ok(owned)
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to owned_events()"))
+    }
+}
@@ -10,9 +10,14 @@ use crate::{
         Error,
         ErrorCode,
     },
-    ipc::Message,
+    ipc::{
+        MailboxStats,
+        Message,
+        MessageType,
+    },
     kcall::arch,
     number::KcallNumber,
+    pm::ProcessIdentifier,
 };
 
 //==================================================================================================
@@ -31,6 +36,84 @@ pub fn send(message: &Message) -> Result<(), Error> {
     }
 }
 
+//==================================================================================================
+// Send Message With Timeout
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Sends a message, giving up if it cannot be enqueued within a number of ticks.
+///
+/// # Parameters
+///
+/// - `message`: Message to send.
+/// - `ticks`: Tick budget. If zero, a single non-blocking attempt is made.
+///
+/// # Returns
+///
+/// Upon successful completion, empty is returned. If the message could not be enqueued within
+/// the tick budget, an error with [`ErrorCode::TimedOut`] is returned. Otherwise, an
+/// error is returned instead.
+///
+pub fn send_timeout(message: &Message, ticks: u32) -> Result<(), Error> {
+    let result: i32 = unsafe {
+        arch::kcall2(
+            KcallNumber::SendTimeout.into(),
+            message as *const Message as usize as u32,
+            ticks,
+        )
+    };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to send_timeout()"))
+    }
+}
+
+//==================================================================================================
+// Send Multiple Messages
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Sends multiple messages in a single kernel call.
+///
+/// # Parameters
+///
+/// - `messages`: Messages to send.
+///
+/// # Returns
+///
+/// Upon successful completion, the number of messages that were accepted is returned, so that the
+/// caller knows where to resume. Otherwise, an error is returned instead.
+///
+/// # Notes
+///
+/// - All messages are validated before any of them is sent, so an invalid message fails the whole
+///   batch.
+/// - At most [`crate::config::kernel::MAX_IKC_MESSAGES`] messages are sent per call.
+/// - Fewer messages than given are accepted if the kernel buffer fills up or if a message cannot
+///   be delivered. Sending the first message that was not accepted again reports the error.
+///
+pub fn send_many(messages: &[Message]) -> Result<usize, Error> {
+    let result: i32 = unsafe {
+        arch::kcall2(
+            KcallNumber::SendMany.into(),
+            messages.as_ptr() as usize as u32,
+            messages.len() as u32,
+        )
+    };
+
+    if result >= 0 {
+        Ok(result as usize)
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to send_many()"))
+    }
+}
+
 //==================================================================================================
 // Receive Message
 //==================================================================================================
@@ -48,3 +131,281 @@ pub fn recv() -> Result<Message, Error> {
         Err(Error::new(ErrorCode::try_from(result)?, "failed to recv()"))
     }
 }
+
+//==================================================================================================
+// Receive Message Without Blocking
+//==================================================================================================
+
+pub fn try_recv() -> Result<Option<Message>, Error> {
+    let mut message: Message = Default::default();
+
+    let result: i32 = unsafe {
+        arch::kcall1(KcallNumber::TryRecv.into(), &mut message as *mut Message as usize as u32)
+    };
+
+    if result == 0 {
+        return Ok(Some(message));
+    }
+
+    // Check if no message is pending.
+    match ErrorCode::try_from(result)? {
+        ErrorCode::TryAgain => Ok(None),
+        code => Err(Error::new(code, "failed to try_recv()")),
+    }
+}
+
+//==================================================================================================
+// Peek Message
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Gets the next message that is pending in the mailbox of the calling process, without removing
+/// it.
+///
+/// # Returns
+///
+/// Upon successful completion, the next message is returned, or `None` if the mailbox is empty.
+/// Otherwise, an error is returned instead.
+///
+/// # Notes
+///
+/// - Interrupts and exceptions are not queued in the mailbox, thus they are not peeked.
+///
+pub fn peek() -> Result<Option<Message>, Error> {
+    let mut message: Message = Default::default();
+
+    let result: i32 = unsafe {
+        arch::kcall1(KcallNumber::PeekMessage.into(), &mut message as *mut Message as usize as u32)
+    };
+
+    if result == 0 {
+        return Ok(Some(message));
+    }
+
+    // Check if no message is pending.
+    match ErrorCode::try_from(result)? {
+        ErrorCode::TryAgain => Ok(None),
+        code => Err(Error::new(code, "failed to peek()")),
+    }
+}
+
+//==================================================================================================
+// Receive Correlated Message
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Receives the first IPC message that is tagged with a given correlation id. IPC messages that do
+/// not match are set aside by the kernel and delivered again, in order, once a match is found.
+///
+/// # Parameters
+///
+/// - `id`: Correlation id to match.
+///
+/// # Returns
+///
+/// Upon successful completion, the matching message is returned. Otherwise, an error is returned
+/// instead.
+///
+/// # Notes
+///
+/// - The correlation id is stored in the first [`Message::CORRELATION_SIZE`] bytes of the
+///   payload, thus senders should tag messages with [`Message::with_correlation()`].
+/// - Messages of other types (e.g. interrupts and exceptions) carry no correlation id. They are
+///   returned as soon as they arrive, so that they are not delayed until a match is found.
+/// - The kernel holds at most [`crate::config::kernel::MAX_IKC_MESSAGES`] messages. If more
+///   messages do not match, an error with [`ErrorCode::TryAgain`] is returned.
+///
+pub fn recv_correlated(id: u64) -> Result<Message, Error> {
+    loop {
+        let message: Message = match recv() {
+            Ok(message) => message,
+            Err(e) => {
+                // Deliver messages that were set aside, so that none is lost.
+                release_messages()?;
+                return Err(e);
+            },
+        };
+
+        if message.message_type != MessageType::Ipc || message.correlation() == id {
+            release_messages()?;
+            return Ok(message);
+        }
+
+        if let Err(e) = hold_message(&message) {
+            release_messages()?;
+            return Err(e);
+        }
+    }
+}
+
+fn hold_message(message: &Message) -> Result<(), Error> {
+    let result: i32 = unsafe {
+        arch::kcall1(KcallNumber::HoldMessage.into(), message as *const Message as usize as u32)
+    };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to hold_message()"))
+    }
+}
+
+fn release_messages() -> Result<usize, Error> {
+    let result: i32 = unsafe { arch::kcall0(KcallNumber::ReleaseMessages.into()) };
+
+    if result >= 0 {
+        Ok(result as usize)
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to release_messages()"))
+    }
+}
+
+//==================================================================================================
+// Send Message and Wait for Reply
+//==================================================================================================
+
+pub fn sendwait(message: &Message, reply_type: MessageType) -> Result<Message, Error> {
+    let mut reply: Message = Default::default();
+
+    let result: i32 = unsafe {
+        arch::kcall3(
+            KcallNumber::SendWait.into(),
+            message as *const Message as usize as u32,
+            reply_type.to_bytes()[0] as u32,
+            &mut reply as *mut Message as usize as u32,
+        )
+    };
+
+    if result == 0 {
+        Ok(reply)
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to sendwait()"))
+    }
+}
+
+//==================================================================================================
+// Yield
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Voluntarily relinquishes the processor, without blocking on an event.
+///
+/// # Returns
+///
+/// Upon success, empty is returned. Upon failure, an error is returned instead.
+///
+/// # Notes
+///
+/// - This is only a hint to the scheduler. If the calling process is the only runnable one, it
+///   may be rescheduled right away.
+///
+pub fn yield_now() -> Result<(), Error> {
+    let result: i32 = unsafe { arch::kcall0(KcallNumber::Yield.into()) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to yield_now()"))
+    }
+}
+
+//==================================================================================================
+// Sleep
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Blocks the calling process until a number of timer ticks elapse.
+///
+/// # Parameters
+///
+/// - `ticks`: Number of timer ticks to sleep for.
+///
+/// # Returns
+///
+/// Upon success, empty is returned. Upon failure, an error is returned instead.
+///
+/// # Notes
+///
+/// - If `ticks` is zero, this behaves like [`yield_now()`].
+///
+pub fn sleep(ticks: u32) -> Result<(), Error> {
+    let result: i32 = unsafe { arch::kcall1(KcallNumber::Sleep.into(), ticks) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to sleep()"))
+    }
+}
+
+//==================================================================================================
+// Flush Mailbox
+//==================================================================================================
+
+pub fn flush_mailbox() -> Result<usize, Error> {
+    let result: i32 = unsafe { arch::kcall0(KcallNumber::FlushMailbox.into()) };
+
+    match usize::try_from(result) {
+        Ok(count) => Ok(count),
+        Err(_) => Err(Error::new(ErrorCode::try_from(result)?, "failed to flush_mailbox()")),
+    }
+}
+
+//==================================================================================================
+// Set Mailbox Fairness
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Enables or disables fair queuing on the mailbox of the calling process. In fair mode, messages
+/// are delivered round-robin across distinct senders. Otherwise, messages are delivered in arrival
+/// order, which is the default.
+///
+/// # Parameters
+///
+/// - `fair`: Whether fair queuing should be enabled.
+///
+/// # Returns
+///
+/// Upon success, empty is returned. Upon failure, an error is returned instead.
+///
+pub fn set_mailbox_fair(fair: bool) -> Result<(), Error> {
+    let result: i32 = unsafe { arch::kcall1(KcallNumber::SetMailboxFair.into(), fair as u32) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to set_mailbox_fair()"))
+    }
+}
+
+//==================================================================================================
+// Get Mailbox Statistics
+//==================================================================================================
+
+pub fn mailbox_stats(pid: ProcessIdentifier) -> Result<MailboxStats, Error> {
+    let mut stats: MailboxStats = MailboxStats::default();
+
+    let result: i32 = unsafe {
+        arch::kcall2(
+            KcallNumber::MailboxStats.into(),
+            pid.into(),
+            &mut stats as *mut MailboxStats as usize as u32,
+        )
+    };
+
+    if result == 0 {
+        Ok(stats)
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to mailbox_stats()"))
+    }
+}
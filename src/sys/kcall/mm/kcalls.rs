@@ -14,6 +14,8 @@ use crate::{
     mm::{
         AccessPermission,
         Address,
+        CachingMode,
+        MemInfo,
         VirtualAddress,
     },
     number::KcallNumber,
@@ -60,3 +62,65 @@ pub fn munmap(pid: ProcessIdentifier, vaddr: VirtualAddress) -> Result<(), Error
         Err(Error::new(ErrorCode::try_from(result)?, "failed to munmap()"))
     }
 }
+
+//==================================================================================================
+// Allocate Memory-Mapped I/O Region
+//==================================================================================================
+
+pub fn mmio_alloc(vaddr: VirtualAddress, mode: CachingMode) -> Result<(), Error> {
+    let result: i32 = unsafe {
+        arch::kcall2(
+            KcallNumber::AllocMmioCaching.into(),
+            vaddr.into_raw_value() as u32,
+            mode.into(),
+        )
+    };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to mmio_alloc()"))
+    }
+}
+
+//==================================================================================================
+// Get Boot Module
+//==================================================================================================
+
+pub fn get_boot_module(index: usize) -> Result<(usize, usize), Error> {
+    let mut base: usize = 0;
+    let mut size: usize = 0;
+
+    let result: i32 = unsafe {
+        arch::kcall3(
+            KcallNumber::GetBootModule.into(),
+            index as u32,
+            &mut base as *mut usize as usize as u32,
+            &mut size as *mut usize as usize as u32,
+        )
+    };
+
+    if result == 0 {
+        Ok((base, size))
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to get_boot_module()"))
+    }
+}
+
+//==================================================================================================
+// Get Memory Information
+//==================================================================================================
+
+pub fn meminfo() -> Result<MemInfo, Error> {
+    let mut info: MemInfo = MemInfo::default();
+
+    let result: i32 = unsafe {
+        arch::kcall1(KcallNumber::MemInfo.into(), &mut info as *mut MemInfo as usize as u32)
+    };
+
+    if result == 0 {
+        Ok(info)
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to meminfo()"))
+    }
+}
@@ -61,6 +61,34 @@ pub fn gettid() -> Result<ThreadIdentifier, Error> {
     ThreadIdentifier::try_from(result)
 }
 
+//==================================================================================================
+// Get Timer Ticks
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Gets the number of timer ticks since the system was started.
+///
+/// # Return Values
+///
+/// Upon successful completion, the number of timer ticks since the system was started is
+/// returned. Upon failure, an error is returned instead.
+///
+pub fn getticks() -> Result<u64, Error> {
+    let mut ticks: u64 = 0;
+
+    let result: i32 = unsafe {
+        arch::kcall1(KcallNumber::GetTicks.into(), &mut ticks as *mut u64 as usize as u32)
+    };
+
+    if result == 0 {
+        Ok(ticks)
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to getticks()"))
+    }
+}
+
 //==================================================================================================
 // Get User Identifier
 //==================================================================================================
@@ -166,7 +194,7 @@ pub fn exit(status: i32) -> Result<!, Error> {
 
 pub fn capctl(capability: Capability, value: bool) -> Result<(), Error> {
     let result: i32 =
-        unsafe { arch::kcall2(KcallNumber::CapCtl.into(), capability as u32, value as u32) };
+        unsafe { arch::kcall3(KcallNumber::CapCtl.into(), capability as u32, value as u32, 0) };
 
     if result == 0 {
         Ok(())
@@ -175,6 +203,62 @@ pub fn capctl(capability: Capability, value: bool) -> Result<(), Error> {
     }
 }
 
+///
+/// # Description
+///
+/// Sets or clears a set of capabilities at once.
+///
+/// # Parameters
+///
+/// - `mask`: Bitmask of capabilities, where bit `i` stands for the capability whose number is `i`.
+/// - `value`: Whether the capabilities should be set or cleared.
+///
+/// # Returns
+///
+/// Upon successful completion, empty is returned. Otherwise, an error is returned instead.
+///
+pub fn capctl_mask(mask: u32, value: bool) -> Result<(), Error> {
+    let result: i32 = unsafe { arch::kcall3(KcallNumber::CapCtl.into(), mask, value as u32, 1) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to capctl_mask()"))
+    }
+}
+
+//==================================================================================================
+// List Capabilities
+//==================================================================================================
+
+pub fn cap_list() -> Result<u32, Error> {
+    cap_list_of(getpid()?)
+}
+
+pub fn cap_list_of(pid: ProcessIdentifier) -> Result<u32, Error> {
+    let result: i32 = unsafe { arch::kcall1(KcallNumber::CapList.into(), pid.into()) };
+
+    if result >= 0 {
+        Ok(result as u32)
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to cap_list()"))
+    }
+}
+
+//==================================================================================================
+// Drop Capability
+//==================================================================================================
+
+pub fn dropcap(capability: Capability) -> Result<(), Error> {
+    let result: i32 = unsafe { arch::kcall1(KcallNumber::DropCap.into(), capability as u32) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to dropcap()"))
+    }
+}
+
 //==================================================================================================
 // Terminate
 //==================================================================================================
@@ -189,3 +273,65 @@ pub fn terminate(pid: ProcessIdentifier) -> Result<(), Error> {
         Err(Error::new(ErrorCode::try_from(result)?, "failed to terminate()"))
     }
 }
+
+//==================================================================================================
+// Watch Process
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A handle to a watch on a process. The identifier of the watched process is not reused while
+/// the handle is alive. The watch is released when the handle is dropped.
+///
+#[derive(Debug)]
+pub struct WatchHandle {
+    /// Watched process.
+    pid: ProcessIdentifier,
+}
+
+impl WatchHandle {
+    ///
+    /// # Description
+    ///
+    /// Gets the identifier of the watched process.
+    ///
+    /// # Return Values
+    ///
+    /// The identifier of the watched process.
+    ///
+    pub fn pid(&self) -> ProcessIdentifier {
+        self.pid
+    }
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        let _ = unsafe { arch::kcall1(KcallNumber::Unwatch.into(), usize::from(self.pid) as u32) };
+    }
+}
+
+///
+/// # Description
+///
+/// Watches a process, preventing its identifier from being reused until the returned handle is
+/// dropped.
+///
+/// # Parameters
+///
+/// - `pid`: Process to watch.
+///
+/// # Return Values
+///
+/// Upon successful completion, a handle to the watch is returned. Upon failure, an error is
+/// returned instead.
+///
+pub fn watch(pid: ProcessIdentifier) -> Result<WatchHandle, Error> {
+    let result: i32 = unsafe { arch::kcall1(KcallNumber::Watch.into(), usize::from(pid) as u32) };
+
+    if result == 0 {
+        Ok(WatchHandle { pid })
+    } else {
+        Err(Error::new(ErrorCode::try_from(result)?, "failed to watch()"))
+    }
+}
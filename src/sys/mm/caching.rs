@@ -0,0 +1,50 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::error::{
+    Error,
+    ErrorCode,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A type that represents the caching mode of a memory mapping.
+///
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum CachingMode {
+    /// Accesses are not cached.
+    #[default]
+    Uncacheable = 0,
+    /// Writes are combined in a buffer before reaching memory.
+    WriteCombining = 1,
+    /// Reads and writes are cached.
+    WriteBack = 2,
+}
+
+impl From<CachingMode> for u32 {
+    fn from(value: CachingMode) -> Self {
+        value as u32
+    }
+}
+
+impl TryFrom<u32> for CachingMode {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(CachingMode::Uncacheable),
+            1 => Ok(CachingMode::WriteCombining),
+            2 => Ok(CachingMode::WriteBack),
+            _ => Err(Error::new(ErrorCode::InvalidArgument, "invalid caching mode")),
+        }
+    }
+}
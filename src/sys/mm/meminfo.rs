@@ -0,0 +1,24 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A type that describes the state of system memory.
+///
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct MemInfo {
+    /// Total physical memory (in bytes).
+    pub total: usize,
+    /// Number of free user frames.
+    pub free_frames: usize,
+    /// Number of kernel pool frames in use.
+    pub kpool_used: usize,
+    /// Kernel heap usage (in bytes).
+    pub kheap_used: usize,
+}
@@ -8,6 +8,8 @@
 mod access;
 mod address;
 mod alignment;
+mod caching;
+mod meminfo;
 
 //==================================================================================================
 // Exports
@@ -16,3 +18,5 @@ mod alignment;
 pub use access::*;
 pub use address::*;
 pub use alignment::*;
+pub use caching::*;
+pub use meminfo::*;
@@ -69,10 +69,69 @@ pub enum KcallNumber {
     ReadPmio,
     /// Writes a value to a port-mapped I/O port.
     WritePmio,
+    /// Gets the location of a boot module.
+    GetBootModule,
+    /// Permanently drops a capability.
+    DropCap,
+    /// Sends a message and waits for a reply.
+    SendWait,
+    /// Sets the affinity of an interrupt.
+    IntAffinity,
+    /// Gets the number of timer ticks since the system was started.
+    GetTicks,
+    /// Discards all messages pending in the mailbox of the calling process.
+    FlushMailbox,
+    /// Watches a process.
+    Watch,
+    /// Releases a watch on a process.
+    Unwatch,
+    /// Gets the state of system memory.
+    MemInfo,
+    /// Raises signals on a process.
+    Signal,
+    /// Lists the capabilities of a process.
+    CapList,
+    /// Counts the events that are pending on a process.
+    PendingCounts,
+    /// Lists the events that are owned by a process.
+    OwnedEvents,
+    /// Gets the pressure on the mailbox of a process.
+    MailboxStats,
+    /// Receives a message, without blocking.
+    TryRecv,
+    /// Sends a message, giving up after a number of ticks.
+    SendTimeout,
+    /// Sends multiple messages.
+    SendMany,
+    /// Sets aside a received message.
+    HoldMessage,
+    /// Releases messages that were set aside.
+    ReleaseMessages,
+    /// Yields the processor.
+    Yield,
+    /// Sleeps for a number of timer ticks.
+    Sleep,
+    /// Gets kernel call statistics.
+    KcallStats,
+    /// Allocates a memory-mapped I/O region with a caching mode.
+    AllocMmioCaching,
+    /// Enables or disables fair queuing on the mailbox of the calling process.
+    SetMailboxFair,
+    /// Gets the next message pending in the mailbox of the calling process, without removing it.
+    PeekMessage,
     /// Invalid.
     Invalid,
 }
 
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl KcallNumber {
+    /// Number of valid kernel calls.
+    pub const COUNT: usize = KcallNumber::Invalid as usize;
+}
+
 //==================================================================================================
 // Trait Implementations
 //==================================================================================================
@@ -108,6 +167,31 @@ impl From<usize> for KcallNumber {
             25 => KcallNumber::FreePmio,
             26 => KcallNumber::ReadPmio,
             27 => KcallNumber::WritePmio,
+            28 => KcallNumber::GetBootModule,
+            29 => KcallNumber::DropCap,
+            30 => KcallNumber::SendWait,
+            31 => KcallNumber::IntAffinity,
+            32 => KcallNumber::GetTicks,
+            33 => KcallNumber::FlushMailbox,
+            34 => KcallNumber::Watch,
+            35 => KcallNumber::Unwatch,
+            36 => KcallNumber::MemInfo,
+            37 => KcallNumber::Signal,
+            38 => KcallNumber::CapList,
+            39 => KcallNumber::PendingCounts,
+            40 => KcallNumber::OwnedEvents,
+            41 => KcallNumber::MailboxStats,
+            42 => KcallNumber::TryRecv,
+            43 => KcallNumber::SendTimeout,
+            44 => KcallNumber::SendMany,
+            45 => KcallNumber::HoldMessage,
+            46 => KcallNumber::ReleaseMessages,
+            47 => KcallNumber::Yield,
+            48 => KcallNumber::Sleep,
+            49 => KcallNumber::KcallStats,
+            50 => KcallNumber::AllocMmioCaching,
+            51 => KcallNumber::SetMailboxFair,
+            52 => KcallNumber::PeekMessage,
             _ => KcallNumber::Invalid,
         }
     }
@@ -37,6 +37,20 @@ pub enum Capability {
 // Implementations
 //==================================================================================================
 
+impl Capability {
+    /// Number of capabilities.
+    pub const COUNT: usize = 5;
+
+    /// All capabilities.
+    pub const VALUES: [Self; Self::COUNT] = [
+        Self::ExceptionControl,
+        Self::InterruptControl,
+        Self::IoManagement,
+        Self::MemoryManagement,
+        Self::ProcessManagement,
+    ];
+}
+
 impl TryFrom<u32> for Capability {
     type Error = Error;
 